@@ -74,7 +74,8 @@ async fn import(opt: &ImportOptions, assets: &AssetCache) -> anyhow::Result<()>
     } else {
         anyhow::bail!("Unsupported file type");
     }
-    ambient_build::build_assets(assets, Path::new("assets"), Path::new("build"), true).await?;
+    ambient_build::build_assets(assets, Path::new("assets"), Path::new("build"), None, true)
+        .await?;
 
     Ok(())
 }
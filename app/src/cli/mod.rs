@@ -74,6 +74,21 @@ pub struct ClientCli {
     #[arg(long)]
     pub mute_audio: bool,
 
+    /// If set, audio is rendered to this WAV file instead of being played on an audio device.
+    /// A fixed block of audio is rendered every frame, so it doesn't depend on the sound card
+    /// or on how long the frames take
+    #[arg(long, conflicts_with = "mute_audio")]
+    pub offline_audio: Option<PathBuf>,
+
+    /// The sample rate of the audio rendered with `--offline-audio`
+    #[arg(long, default_value_t = 48000)]
+    pub offline_audio_sample_rate: u32,
+
+    /// The number of audio frames rendered every frame with `--offline-audio`. Defaults to a
+    /// 60th of a second
+    #[arg(long)]
+    pub offline_audio_block_size: Option<usize>,
+
     /// Run in headless mode
     #[arg(long)]
     pub headless: bool,
@@ -86,6 +101,10 @@ pub struct ClientCli {
     #[clap(short, long)]
     pub user_id: Option<String>,
 
+    /// The token to authenticate the user ID with, if the server requires one
+    #[arg(long)]
+    pub auth_token: Option<String>,
+
     /// Allows connecting to servers with a mismatched version. Only available in non-production builds.
     ///
     /// DO NOT USE THIS UNLESS YOU KNOW WHAT YOU ARE DOING.
@@ -64,11 +64,13 @@ pub async fn handle_inner(
 
     let build_wasm_only = package_cli.build_wasm_only;
     let clean_build = package_cli.clean_build;
+    let force = package_cli.force;
 
     self::build(
         assets,
         main_package_fs_path,
         clean_build,
+        force,
         false,
         release_build,
         build_wasm_only,
@@ -87,6 +89,7 @@ pub async fn build<
     assets: &AssetCache,
     main_package_fs_path: PathBuf,
     clean_build: bool,
+    force: bool,
     deploy: bool,
     release: bool,
     wasm_only: bool,
@@ -137,6 +140,7 @@ pub async fn build<
         release,
         wasm_only,
         deploy,
+        force,
     };
 
     // For each package, build the package using a fresh semantic.
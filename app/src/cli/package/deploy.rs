@@ -121,6 +121,7 @@ pub async fn handle(args: &Deploy, assets: &AssetCache, release_build: bool) ->
             assets,
             package_path.clone(),
             package.clean_build,
+            package.force,
             true,
             release_build,
             package.build_wasm_only,
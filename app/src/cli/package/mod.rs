@@ -58,6 +58,10 @@ pub struct PackageArgs {
     /// Perform a clean build
     pub clean_build: bool,
 
+    #[arg(long)]
+    /// Rebuild the package and its assets even if they are unchanged, without removing the build directory
+    pub force: bool,
+
     #[arg(long)]
     /// Only build the WASM modules
     pub build_wasm_only: bool,
@@ -120,6 +124,32 @@ pub struct HostCli {
     /// Shutdown the server after the specified number of seconds of inactivity
     #[arg(long)]
     pub shutdown_after_inactivity_seconds: Option<u64>,
+
+    /// Restore the world from this snapshot on startup, if it exists
+    #[arg(long)]
+    pub load_world: Option<PathBuf>,
+
+    /// Save snapshots of the world to this file periodically and on shutdown
+    #[arg(long)]
+    pub save_world: Option<PathBuf>,
+
+    /// How often the world is saved with `--save-world`. Use 0 to only save on shutdown
+    ///
+    /// Defaults to 60
+    #[arg(long, requires("save_world"))]
+    pub autosave_interval_seconds: Option<u64>,
+
+    /// Only accept clients whose token is the HMAC-SHA256 of their user id, keyed with the secret in this file
+    #[arg(long, conflicts_with("auth_jwt_public_key"))]
+    pub auth_shared_secret_file: Option<PathBuf>,
+
+    /// Only accept clients with a JWT for their user id, signed by the Ed25519 public key in this PEM file
+    #[arg(long)]
+    pub auth_jwt_public_key: Option<PathBuf>,
+
+    /// The audience that JWTs must be issued for
+    #[arg(long, requires("auth_jwt_public_key"))]
+    pub auth_jwt_audience: Option<String>,
 }
 
 pub fn handle(
@@ -1,7 +1,7 @@
 use std::{collections::HashMap, path::PathBuf, sync::Arc, time::Duration};
 
 use ambient_app::{fps_stats, window_title, AppBuilder};
-use ambient_audio::{AudioMixer, AudioStream};
+use ambient_audio::{AudioMixer, AudioStream, OfflineAudioStream};
 use ambient_cameras::UICamera;
 use ambient_client_shared::game_view::GameView;
 use ambient_core::{
@@ -26,6 +26,7 @@ use ambient_settings::SettingsKey;
 use ambient_sys::time::Instant;
 use ambient_ui_native::{Dock, WindowSized};
 use glam::uvec2;
+use parking_lot::Mutex;
 
 use crate::{
     cli::{ClientCli, GoldenImageCommand},
@@ -42,7 +43,24 @@ pub fn run(
     args: &ClientCli,
     golden_image_output_dir: Option<PathBuf>,
 ) -> anyhow::Result<()> {
-    let audio_stream = if !args.mute_audio {
+    let offline_audio = match &args.offline_audio {
+        Some(path) if !args.mute_audio => {
+            match OfflineAudioStream::to_wav(path, args.offline_audio_sample_rate as _) {
+                Ok(mut v) => {
+                    if let Some(block_size) = args.offline_audio_block_size {
+                        v = v.with_block_size(block_size);
+                    }
+                    Some(Arc::new(Mutex::new(v)))
+                }
+                Err(err) => {
+                    tracing::error!("Failed to create offline audio output {path:?}: {err}");
+                    None
+                }
+            }
+        }
+        _ => None,
+    };
+    let audio_stream = if !args.mute_audio && args.offline_audio.is_none() {
         match AudioStream::new() {
             Ok(v) => Some(v),
             Err(err) => {
@@ -53,7 +71,10 @@ pub fn run(
     } else {
         None
     };
-    let mixer = audio_stream.as_ref().map(|v| v.mixer().clone());
+    let mixer = audio_stream
+        .as_ref()
+        .map(|v| v.mixer().clone())
+        .or_else(|| offline_audio.as_ref().map(|v| v.lock().mixer().clone()));
     let settings = SettingsKey.get(&assets);
 
     let user_id = match args.user_id.clone().or(settings.general.user_id) {
@@ -125,17 +146,26 @@ pub fn run(
     MainApp {
         server_addr,
         user_id,
+        auth_token: args.auth_token.clone(),
         fail_on_version_mismatch,
         show_debug: is_debug,
         golden_image_cmd: args.golden_image,
         golden_image_output_dir,
         cert,
         mixer,
+        offline_audio: offline_audio.clone(),
     }
     .el()
     .spawn_interactive(&mut app.world);
 
     let status = app.run_blocking();
+    if let (Some(offline_audio), Some(path)) = (offline_audio, &args.offline_audio) {
+        let mut offline_audio = offline_audio.lock();
+        match offline_audio.flush() {
+            Ok(()) => tracing::info!("Rendered {:?} of audio to {path:?}", offline_audio.time()),
+            Err(err) => tracing::error!("Failed to write offline audio to {path:?}: {err}"),
+        }
+    }
     match status {
         ExitStatus::SUCCESS => Ok(()),
         ExitStatus::FAILURE => {
@@ -181,11 +211,13 @@ fn MainApp(
     server_addr: ResolvedAddr,
     golden_image_output_dir: Option<PathBuf>,
     user_id: String,
+    auth_token: Option<String>,
     fail_on_version_mismatch: bool,
     show_debug: bool,
     golden_image_cmd: Option<GoldenImageCommand>,
     cert: Option<Vec<u8>>,
     mixer: Option<AudioMixer>,
+    offline_audio: Option<Arc<Mutex<OfflineAudioStream>>>,
 ) -> Element {
     let (loaded, set_loaded) = use_state(hooks, false);
 
@@ -195,6 +227,7 @@ fn MainApp(
         WindowSized::el([ClientView {
             server_addr,
             user_id,
+            auth_token,
             fail_on_version_mismatch,
             // NOTE: client.game_state is **locked** and accesible through game_state.
             //
@@ -206,7 +239,7 @@ fn MainApp(
                 let world = &mut game_state.world;
                 let assets = world.resource(asset_cache()).clone();
 
-                wasm::initialize(world, &assets, mixer.clone()).unwrap();
+                wasm::initialize(world, &assets, mixer.clone(), offline_audio.clone()).unwrap();
 
                 UICamera.el().spawn_static(world);
                 set_loaded(true);
@@ -233,6 +266,11 @@ fn MainApp(
                 let dgram_handlers = HashMap::new();
                 resources.set(ambient_network::client::datagram_handlers(), dgram_handlers);
 
+                resources.set(
+                    ambient_network::prediction::prediction_simulator(),
+                    Arc::new(ambient_physics::prediction::CharacterMovementSimulator::default()),
+                );
+
                 (systems(), resources)
             }),
             cert,
@@ -409,6 +447,8 @@ fn systems() -> SystemGroup {
             Box::new(ambient_water::systems()),
             Box::new(ambient_gizmos::client_systems()),
             Box::new(wasm::systems()),
+            Box::new(ambient_network::prediction::client_systems()),
+            Box::new(ambient_network::interpolation::client_systems()),
             Box::new(ambient_client_shared::player::systems_final()),
         ],
     )
@@ -1,8 +1,9 @@
-use ambient_audio::AudioMixer;
+use ambient_audio::{AudioMixer, OfflineAudioStream};
 use ambient_ecs::{EntityId, SystemGroup, World};
 use ambient_native_std::asset_cache::AssetCache;
 use ambient_wasm::shared::{module_name, MessageType};
 
+use parking_lot::Mutex;
 use std::sync::Arc;
 
 pub fn systems() -> SystemGroup {
@@ -13,6 +14,7 @@ pub fn initialize(
     world: &mut World,
     assets: &AssetCache,
     mixer: Option<AudioMixer>,
+    offline_audio: Option<Arc<Mutex<OfflineAudioStream>>>,
 ) -> anyhow::Result<()> {
     let messenger = Arc::new(
         |world: &World, id: EntityId, ty: MessageType, message: &str| {
@@ -31,6 +33,9 @@ pub fn initialize(
     if let Some(mixer) = mixer {
         world.add_resource(ambient_world_audio::audio_mixer(), mixer);
     }
+    if let Some(offline_audio) = offline_audio {
+        world.add_resource(ambient_world_audio::offline_audio(), offline_audio);
+    }
 
     ambient_wasm::client::initialize(world, assets, messenger)?;
 
@@ -29,14 +29,17 @@ fn main() -> anyhow::Result<()> {
 
     let runtime = rt.handle();
     let assets = AssetCache::new(runtime.clone());
-    let _settings = SettingsKey.get(&assets);
+    let settings = SettingsKey.get(&assets);
+    for (asset_type, megabytes) in &settings.general.asset_memory_budgets {
+        assets.set_memory_budget(asset_type, Some(megabytes * 1024 * 1024));
+    }
 
     // _guard and _handle need to be kept around for the lifetime of the application
     let _guard: sentry::ClientInitGuard;
     let _handle: Result<sentry_rust_minidump::ClientHandle, sentry_rust_minidump::Error>;
     #[cfg(feature = "production")]
-    if _settings.general.sentry.enabled {
-        let sentry_dsn = _settings.general.sentry.dsn;
+    if settings.general.sentry.enabled {
+        let sentry_dsn = settings.general.sentry.dsn;
         _guard = init_sentry(&sentry_dsn);
         _handle = sentry_rust_minidump::init(&_guard);
         match _handle {
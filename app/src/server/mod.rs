@@ -19,6 +19,7 @@ use ambient_native_std::{
     cb,
 };
 use ambient_network::{
+    auth::{AllowAnonymous, Authenticator, JwtAuthenticator, SharedSecretAuthenticator},
     is_persistent_resources, is_synced_resources,
     native::{
         client::ResolvedAddr,
@@ -40,6 +41,7 @@ use tower_http::{cors::CorsLayer, services::ServeDir};
 
 use crate::{cli::package::HostCli, shared};
 
+pub mod persistence;
 pub mod wasm;
 
 pub struct ServerHandle {
@@ -67,6 +69,7 @@ pub async fn start(
     crypto: Crypto,
 ) -> ServerHandle {
     let quic_interface_port = host_cli.quic_interface_port;
+    let world_persistence = persistence::WorldPersistence::from_cli(host_cli);
 
     let proxy_settings = (!host_cli.no_proxy).then(|| ProxySettings {
         // default to getting a proxy from the dims-web Google App Engine app
@@ -83,7 +86,7 @@ pub async fn start(
             .to_string(),
     });
 
-    let server = if let Some(port) = quic_interface_port {
+    let mut server = if let Some(port) = quic_interface_port {
         GameServer::new_with_port(
             SocketAddr::new(host_cli.bind_address, port),
             host_cli
@@ -110,6 +113,9 @@ pub async fn start(
         .with_context(|| format!("Failed to create game server with port in range {port_range:?}"))
         .unwrap()
     };
+    server.authenticator = create_authenticator(host_cli)
+        .context("Failed to set up authentication")
+        .unwrap();
 
     let addr = server.local_addr();
 
@@ -179,12 +185,24 @@ pub async fn start(
                 Default::default(),
             )
             .spawn(&mut server_world);
-        // Note: this should not be reset every time the server is created. Remove this when it becomes possible to load/save worlds.
+        // Replaced by the persistent resources from the world snapshot, if one is loaded
         Entity::new()
             .with(ambient_core::name(), "Persistent resources".to_string())
             .with(is_persistent_resources(), ())
             .spawn(&mut server_world);
 
+        if let Some(load_path) = &world_persistence.load_path {
+            match persistence::read_snapshot(load_path) {
+                Ok(Some(snapshot)) => {
+                    server_world
+                        .add_resource(persistence::pending_world_snapshot(), Arc::new(snapshot));
+                }
+                Ok(None) => {}
+                Err(err) => tracing::error!("{err:?}; starting with a fresh world"),
+            }
+        }
+        server_world.add_resource(persistence::world_persistence(), world_persistence);
+
         wasm::initialize(&mut server_world, &assets, working_directory.join("data"))
             .await
             .unwrap();
@@ -217,6 +235,8 @@ fn systems(_world: &mut World) -> SystemGroup {
     SystemGroup::new(
         "server",
         vec![
+            // Moves predicted players before the physics step
+            Box::new(ambient_network::prediction::server_systems()),
             ambient_physics::run_simulation_system(),
             // Can happen *during* the physics step
             Box::new(ambient_core::async_ecs::async_ecs_systems()),
@@ -233,6 +253,7 @@ fn systems(_world: &mut World) -> SystemGroup {
             Box::new(ambient_core::camera::camera_systems()),
             Box::new(ambient_physics::server_systems()),
             Box::new(ambient_package_semantic_native::server_systems()),
+            Box::new(persistence::systems()),
             Box::new(wasm::systems()),
         ],
     )
@@ -241,6 +262,7 @@ fn on_forking_systems() -> SystemGroup<ForkingEvent> {
     SystemGroup::new(
         "on_forking_systems",
         vec![
+            Box::new(persistence::on_forking_systems()),
             Box::new(ambient_physics::on_forking_systems()),
             Box::new(wasm::on_forking_systems()),
         ],
@@ -250,6 +272,8 @@ fn on_shutdown_systems() -> SystemGroup<ShutdownEvent> {
     SystemGroup::new(
         "on_shutdown_systems",
         vec![
+            // Save before anything is torn down
+            Box::new(persistence::on_shutdown_systems()),
             Box::new(ambient_physics::on_shutdown_systems()),
             Box::new(wasm::on_shutdown_systems()),
         ],
@@ -260,6 +284,28 @@ fn is_sync_component(component: ComponentDesc, _: WorldStreamCompEvent) -> bool
     component.has_attribute::<Networked>()
 }
 
+fn create_authenticator(host_cli: &HostCli) -> anyhow::Result<Arc<dyn Authenticator>> {
+    if let Some(path) = &host_cli.auth_shared_secret_file {
+        let secret = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read shared secret from {path:?}"))?;
+        tracing::info!("Clients need a token derived from the shared secret to connect");
+        return Ok(Arc::new(SharedSecretAuthenticator::new(
+            secret.trim().as_bytes(),
+        )));
+    }
+
+    if let Some(path) = &host_cli.auth_jwt_public_key {
+        let pem = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read JWT public key from {path:?}"))?;
+        let mut authenticator = JwtAuthenticator::from_ed25519_pem(&pem)?;
+        authenticator.audience = host_cli.auth_jwt_audience.clone();
+        tracing::info!("Clients need a signed JWT to connect");
+        return Ok(Arc::new(authenticator));
+    }
+
+    Ok(Arc::new(AllowAnonymous))
+}
+
 fn create_resources(assets: AssetCache) -> Entity {
     let mut server_resources = Entity::new()
         .with(name(), "Resources".to_string())
@@ -271,6 +317,10 @@ fn create_resources(assets: AssetCache) -> Entity {
     server_resources.set(ambient_core::runtime(), RuntimeHandle::current());
 
     server_resources.merge(ambient_core::time_resources_start(FIXED_SERVER_TICK_TIME));
+    server_resources.set(
+        ambient_network::prediction::prediction_simulator(),
+        Arc::new(ambient_physics::prediction::CharacterMovementSimulator::default()),
+    );
 
     let mut bistream_handlers = HashMap::new();
     ambient_network::server::register_rpc_bi_stream_handler(
@@ -288,7 +338,8 @@ fn create_resources(assets: AssetCache) -> Entity {
         unistream_handlers,
     );
 
-    let dgram_handlers = HashMap::new();
+    let mut dgram_handlers = HashMap::new();
+    ambient_network::prediction::register_input_datagram_handler(&mut dgram_handlers);
     server_resources.set(ambient_network::server::datagram_handlers(), dgram_handlers);
 
     server_resources
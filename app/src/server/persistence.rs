@@ -0,0 +1,342 @@
+//! Saves the `Store` components of the server world to disk, and restores them on startup.
+//!
+//! Snapshots are written as versioned JSON. Only entities that belong to the game state are
+//! written; packages, modules and anything marked `dont_store` (players, synced resources)
+//! are recreated by the runtime on every start.
+
+use std::{
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    thread::JoinHandle,
+    time::Duration,
+};
+
+use ambient_core::game_time;
+use ambient_ecs::{
+    components, dont_store,
+    generated::package::components::{is_package, main_package_id},
+    query, DeserWorldWithWarnings, FnSystem, Resource, Store, SystemGroup, World, WorldContext,
+};
+use ambient_network::{
+    is_persistent_resources,
+    server::{ForkingEvent, ShutdownEvent},
+    ServerWorldExt,
+};
+use ambient_wasm::shared::is_module;
+use anyhow::Context;
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+
+use crate::cli::package::HostCli;
+
+/// The version of the snapshot format written by this runtime.
+///
+/// Bump this whenever the layout of a snapshot changes, and teach [`read_snapshot`]
+/// how to handle the older versions.
+pub const WORLD_SNAPSHOT_VERSION: u32 = 1;
+
+/// The default time between autosaves if `--save-world` is used.
+pub const DEFAULT_AUTOSAVE_INTERVAL: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Clone, Default)]
+pub struct WorldPersistence {
+    /// The snapshot to restore the world from at startup.
+    pub load_path: Option<PathBuf>,
+    /// Where snapshots of the world are written.
+    pub save_path: Option<PathBuf>,
+    /// How often to save the world. If `None`, the world is only saved on shutdown.
+    pub autosave_interval: Option<Duration>,
+}
+impl WorldPersistence {
+    pub fn from_cli(host_cli: &HostCli) -> Self {
+        Self {
+            load_path: host_cli.load_world.clone(),
+            save_path: host_cli.save_world.clone(),
+            autosave_interval: match host_cli.autosave_interval_seconds {
+                Some(0) => None,
+                Some(seconds) => Some(Duration::from_secs(seconds)),
+                None => Some(DEFAULT_AUTOSAVE_INTERVAL),
+            },
+        }
+    }
+}
+
+components!("server::persistence", {
+    @[Resource]
+    world_persistence: WorldPersistence,
+    /// A snapshot that has been read from disk, but not spawned yet.
+    ///
+    /// Spawning waits for the main package to load so that its components can be deserialized.
+    @[Resource]
+    pending_world_snapshot: Arc<Vec<u8>>,
+    /// The game time at which the world was last saved.
+    @[Resource]
+    last_world_save: Duration,
+    /// The autosave that is being written to disk.
+    @[Resource]
+    pending_autosave: PendingAutosave,
+});
+
+/// The thread writing an autosave, so that the next save can wait for it to finish.
+#[derive(Debug, Clone, Default)]
+pub struct PendingAutosave(Arc<Mutex<Option<JoinHandle<()>>>>);
+impl PendingAutosave {
+    fn is_pending(&self) -> bool {
+        self.0
+            .lock()
+            .as_ref()
+            .is_some_and(|handle| !handle.is_finished())
+    }
+    fn join(&self) {
+        if let Some(handle) = self.0.lock().take() {
+            handle.join().ok();
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct WorldSnapshotRef<'a> {
+    version: u32,
+    world: &'a World,
+}
+
+#[derive(Deserialize)]
+struct WorldSnapshotHeader {
+    version: u32,
+}
+
+#[derive(Deserialize)]
+struct WorldSnapshot {
+    world: DeserWorldWithWarnings,
+}
+
+/// Reads the snapshot at `path`, if there is one, and checks that it can be loaded by this runtime.
+///
+/// A missing file is not an error, so that the same path can be used for loading and saving.
+pub fn read_snapshot(path: &Path) -> anyhow::Result<Option<Vec<u8>>> {
+    if !path.exists() {
+        tracing::info!("No world snapshot at {path:?}; starting with a fresh world");
+        return Ok(None);
+    }
+
+    let data =
+        std::fs::read(path).with_context(|| format!("Failed to read world snapshot {path:?}"))?;
+    let header: WorldSnapshotHeader = serde_json::from_slice(&data)
+        .with_context(|| format!("World snapshot {path:?} is not valid"))?;
+    anyhow::ensure!(
+        header.version == WORLD_SNAPSHOT_VERSION,
+        "World snapshot {path:?} has version {}, but only version {WORLD_SNAPSHOT_VERSION} is supported",
+        header.version
+    );
+
+    Ok(Some(data))
+}
+
+/// Creates a world containing only the entities and components that should be persisted.
+pub fn snapshot_world(world: &World) -> World {
+    let mut snapshot = World::new_with_config("world_snapshot", WorldContext::Prefab, false);
+    for (id, _) in query(())
+        .excl(dont_store())
+        .excl(is_package())
+        .excl(is_module())
+        .iter(world, None)
+    {
+        if id == world.resource_entity() {
+            continue;
+        }
+
+        let mut entity = world.clone_entity(id).unwrap();
+        entity.filter(&|comp| comp.has_attribute::<Store>());
+        if !entity.is_empty() {
+            snapshot.spawn_with_id(id, entity);
+        }
+    }
+    snapshot
+}
+
+pub fn serialize_snapshot(world: &World) -> anyhow::Result<Vec<u8>> {
+    Ok(serde_json::to_vec(&WorldSnapshotRef {
+        version: WORLD_SNAPSHOT_VERSION,
+        world: &snapshot_world(world),
+    })?)
+}
+
+/// Writes the snapshot next to `path` first, so that a crash mid-write can't corrupt the previous save.
+///
+/// Every write uses its own temporary file, so that writes can't interleave.
+pub fn write_snapshot(path: &Path, data: &[u8]) -> anyhow::Result<()> {
+    static WRITES: AtomicU64 = AtomicU64::new(0);
+
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let mut tmp_name = path
+        .file_name()
+        .context("Invalid world snapshot path")?
+        .to_owned();
+    tmp_name.push(format!(
+        ".{}-{}.tmp",
+        std::process::id(),
+        WRITES.fetch_add(1, Ordering::Relaxed)
+    ));
+    let tmp_path = path.with_file_name(tmp_name);
+    std::fs::write(&tmp_path, data)
+        .with_context(|| format!("Failed to write world snapshot to {tmp_path:?}"))?;
+    std::fs::rename(&tmp_path, path)
+        .with_context(|| format!("Failed to move world snapshot to {path:?}"))?;
+    Ok(())
+}
+
+/// Spawns the entities of a snapshot into `world`, keeping their ids so that references between them stay valid.
+pub fn restore_snapshot(world: &mut World, data: &[u8]) -> anyhow::Result<()> {
+    let WorldSnapshot {
+        world: DeserWorldWithWarnings {
+            world: snapshot,
+            warnings,
+        },
+    } = serde_json::from_slice(data).context("Failed to deserialize world snapshot")?;
+    warnings.log_warnings();
+
+    let entities = snapshot.entities();
+    if entities
+        .iter()
+        .any(|(_, entity)| entity.contains(is_persistent_resources()))
+    {
+        // The snapshot brings its own persistent resources, so drop the ones created at startup
+        if let Some(id) = world.persisted_resource_entity() {
+            world.despawn(id);
+        }
+    }
+
+    let count = entities.len();
+    for (id, entity) in entities {
+        if world.exists(id) {
+            world.add_components(id, entity)?;
+        } else {
+            world.batch_spawn_with_ids(entity, vec![id]);
+        }
+    }
+    tracing::info!("Restored {count} entities from world snapshot");
+
+    Ok(())
+}
+
+fn save(world: &World, path: &Path) -> anyhow::Result<()> {
+    let data = serialize_snapshot(world)?;
+    write_snapshot(path, &data)?;
+    tracing::debug!("Saved world to {path:?}");
+    Ok(())
+}
+
+pub fn systems() -> SystemGroup {
+    SystemGroup::new(
+        "server/persistence",
+        vec![
+            Box::new(FnSystem::new(|world, _| {
+                let Some(data) = world.resource_opt(pending_world_snapshot()).cloned() else {
+                    return;
+                };
+                if world.resource_opt(main_package_id()).is_none() {
+                    return;
+                }
+
+                world
+                    .remove_component(world.resource_entity(), pending_world_snapshot())
+                    .unwrap();
+                if let Err(err) = restore_snapshot(world, &data) {
+                    tracing::error!("Failed to restore world snapshot: {err:?}");
+                }
+            })),
+            Box::new(FnSystem::new(|world, _| {
+                let Some(persistence) = world.resource_opt(world_persistence()) else {
+                    return;
+                };
+                let (Some(path), Some(interval)) =
+                    (persistence.save_path.clone(), persistence.autosave_interval)
+                else {
+                    return;
+                };
+                // Don't overwrite the previous save before it has been restored
+                if world.resource_opt(pending_world_snapshot()).is_some() {
+                    return;
+                }
+                // Skip this autosave if the last one is still being written
+                let pending = world
+                    .resource_opt(pending_autosave())
+                    .cloned()
+                    .unwrap_or_default();
+                if pending.is_pending() {
+                    return;
+                }
+
+                let game_time = *world.resource(game_time());
+                let last_save = world
+                    .resource_opt(last_world_save())
+                    .copied()
+                    .unwrap_or_default();
+                if game_time < last_save + interval {
+                    return;
+                }
+                world.add_resource(last_world_save(), game_time);
+
+                // Serialization needs the world, but the disk write can happen off the tick
+                match serialize_snapshot(world) {
+                    Ok(data) => {
+                        pending.join();
+                        *pending.0.lock() = Some(std::thread::spawn(move || {
+                            if let Err(err) = write_snapshot(&path, &data) {
+                                tracing::error!("Failed to autosave world: {err:?}");
+                            }
+                        }));
+                        world.add_resource(pending_autosave(), pending);
+                    }
+                    Err(err) => tracing::error!("Failed to serialize world: {err:?}"),
+                }
+            })),
+        ],
+    )
+}
+
+pub fn on_shutdown_systems() -> SystemGroup<ShutdownEvent> {
+    SystemGroup::new(
+        "server/persistence/on_shutdown_systems",
+        vec![Box::new(FnSystem::new(|world, _| {
+            let Some(path) = world
+                .resource_opt(world_persistence())
+                .and_then(|p| p.save_path.clone())
+            else {
+                return;
+            };
+            if world.resource_opt(pending_world_snapshot()).is_some() {
+                tracing::warn!("Not saving the world, as its snapshot was never restored");
+                return;
+            }
+            // Let the last autosave finish first, so that it can't replace this newer save
+            if let Some(pending) = world.resource_opt(pending_autosave()) {
+                pending.join();
+            }
+
+            if let Err(err) = save(world, &path) {
+                tracing::error!("Failed to save world on shutdown: {err:?}");
+            }
+        }))],
+    )
+}
+
+pub fn on_forking_systems() -> SystemGroup<ForkingEvent> {
+    SystemGroup::new(
+        "server/persistence/on_forking_systems",
+        vec![Box::new(FnSystem::new(|world, _| {
+            // Only the main instance is saved; forks would overwrite its snapshot
+            let resources = world.resource_entity();
+            world.remove_component(resources, world_persistence()).ok();
+            world
+                .remove_component(resources, pending_world_snapshot())
+                .ok();
+            world.remove_component(resources, pending_autosave()).ok();
+        }))],
+    )
+}
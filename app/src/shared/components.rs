@@ -4,11 +4,13 @@ pub(crate) fn init() -> anyhow::Result<()> {
     ambient_physics::init_all_components();
     ambient_wasm::shared::init_all_components();
     ambient_decals::init_components();
+    ambient_prefab::init_components();
     ambient_world_audio::init_components();
     ambient_primitives::init_components();
     ambient_sky::init_components();
     ambient_water::init_components();
     ambient_package_semantic_native::init_components();
+    crate::server::persistence::init_components();
 
     Ok(())
 }
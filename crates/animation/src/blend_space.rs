@@ -0,0 +1,281 @@
+//! Blend spaces, which blend any number of animations by where a parameter is between their
+//! positions in a 1D or 2D space.
+
+use std::borrow::Cow;
+
+use ambient_ecs::{
+    components,
+    generated::animation::components::{blend_space_parameter, blend_space_positions},
+    query, EntityId, SystemGroup, World,
+};
+use glam::{DVec2, Vec2};
+
+components!("animation", {
+    /// The Delaunay triangulation of the `blend_space_positions` of a 2D blend space, as indices
+    /// into the positions
+    blend_space_triangles: Vec<[usize; 3]>,
+});
+
+pub(crate) fn blend_space_systems() -> SystemGroup {
+    SystemGroup::new(
+        "blend_space_systems",
+        vec![
+            query(blend_space_positions().changed()).to_system(|q, world, qs, _| {
+                for (id, positions) in q.collect_cloned(world, qs) {
+                    world
+                        .add_component(id, blend_space_triangles(), triangulate(&positions))
+                        .ok();
+                }
+            }),
+        ],
+    )
+}
+
+/// Returns the weight of each child of `node` if it's a blend space
+pub(crate) fn blend_space_weights(world: &World, node: EntityId) -> Option<Vec<f32>> {
+    let positions = world.get_ref(node, blend_space_positions()).ok()?;
+    let parameter = world.get(node, blend_space_parameter()).unwrap_or_default();
+    let triangles = match world.get_ref(node, blend_space_triangles()) {
+        Ok(triangles) => Cow::Borrowed(triangles),
+        // The positions were just added, and haven't been triangulated yet
+        Err(_) => Cow::Owned(triangulate(positions)),
+    };
+    Some(weights(positions, &triangles, parameter))
+}
+
+/// The Delaunay triangulation of the positions, using the Bowyer-Watson algorithm.
+///
+/// Returns no triangles if all of the positions are on a line.
+pub(crate) fn triangulate(positions: &[Vec2]) -> Vec<[usize; 3]> {
+    let n = positions.len();
+    if n < 3 {
+        return Vec::new();
+    }
+    let mut points = positions.iter().map(|p| p.as_dvec2()).collect::<Vec<_>>();
+    let (min, max) = points.iter().fold(
+        (DVec2::splat(f64::MAX), DVec2::splat(f64::MIN)),
+        |(min, max), p| (min.min(*p), max.max(*p)),
+    );
+    let center = (min + max) / 2.;
+    let size = (max - min).max_element().max(1.) * 100.;
+    // A triangle which contains all of the points
+    points.push(center + DVec2::new(-size, -size));
+    points.push(center + DVec2::new(size, -size));
+    points.push(center + DVec2::new(0., size));
+
+    let mut triangles = vec![[n, n + 1, n + 2]];
+    for i in 0..n {
+        let p = points[i];
+        let (bad, good): (Vec<_>, Vec<_>) = triangles
+            .into_iter()
+            .partition(|triangle| in_circumcircle(&points, *triangle, p));
+        triangles = good;
+
+        // The edges of the hole left by the bad triangles, which are connected to the new point
+        let edges = bad
+            .iter()
+            .flat_map(|[a, b, c]| [(*a, *b), (*b, *c), (*c, *a)])
+            .collect::<Vec<_>>();
+        for &(a, b) in &edges {
+            let shared = edges
+                .iter()
+                .filter(|(c, d)| (*c, *d) == (a, b) || (*c, *d) == (b, a))
+                .count()
+                > 1;
+            if !shared {
+                triangles.push([a, b, i]);
+            }
+        }
+    }
+
+    triangles.retain(|triangle| {
+        triangle.iter().all(|i| *i < n) && area(&points, *triangle).abs() > 1e-9
+    });
+    triangles
+}
+
+fn area(points: &[DVec2], [a, b, c]: [usize; 3]) -> f64 {
+    (points[b] - points[a]).perp_dot(points[c] - points[a]) / 2.
+}
+
+fn in_circumcircle(points: &[DVec2], [a, b, c]: [usize; 3], p: DVec2) -> bool {
+    let (a, b, c) = (points[a], points[b], points[c]);
+    let d = 2. * (b - a).perp_dot(c - a);
+    if d.abs() < 1e-12 {
+        return false;
+    }
+    let (b, c) = (b - a, c - a);
+    let center = DVec2::new(
+        c.y * b.length_squared() - b.y * c.length_squared(),
+        b.x * c.length_squared() - c.x * b.length_squared(),
+    ) / d;
+    (p - a - center).length_squared() < center.length_squared() * (1. - 1e-9)
+}
+
+/// The weight of each position at `parameter`, which add up to 1
+pub(crate) fn weights(positions: &[Vec2], triangles: &[[usize; 3]], parameter: Vec2) -> Vec<f32> {
+    let mut weights = vec![0.; positions.len()];
+    if positions.is_empty() {
+        return weights;
+    }
+    if triangles.is_empty() {
+        weights_1d(positions, parameter, &mut weights);
+        return weights;
+    }
+
+    // Use the triangle that contains the parameter, or the closest one if it's outside of all
+    let mut closest = (f32::MAX, [0; 3], [0.; 3]);
+    for &[a, b, c] in triangles {
+        let (barycentric, distance) =
+            closest_in_triangle(positions[a], positions[b], positions[c], parameter);
+        if distance < closest.0 {
+            closest = (distance, [a, b, c], barycentric);
+        }
+    }
+    let (_, indices, barycentric) = closest;
+    for (i, weight) in indices.into_iter().zip(barycentric) {
+        weights[i] += weight;
+    }
+    weights
+}
+
+/// Blends between the two positions on either side of the parameter, along the line that the
+/// positions are on
+fn weights_1d(positions: &[Vec2], parameter: Vec2, weights: &mut [f32]) {
+    let origin = positions[0];
+    let direction = positions
+        .iter()
+        .map(|p| *p - origin)
+        .max_by(|a, b| a.length_squared().total_cmp(&b.length_squared()))
+        .unwrap()
+        .normalize_or_zero();
+    let project = |p: Vec2| (p - origin).dot(direction);
+
+    let mut sorted = (0..positions.len()).collect::<Vec<_>>();
+    sorted.sort_by(|a, b| project(positions[*a]).total_cmp(&project(positions[*b])));
+    let x = project(parameter);
+    let first = sorted[0];
+    let last = sorted[sorted.len() - 1];
+    if x <= project(positions[first]) {
+        weights[first] = 1.;
+    } else if x >= project(positions[last]) {
+        weights[last] = 1.;
+    } else {
+        for (&left, &right) in sorted.iter().zip(&sorted[1..]) {
+            let (start, end) = (project(positions[left]), project(positions[right]));
+            if start <= x && x <= end {
+                let t = if end > start {
+                    (x - start) / (end - start)
+                } else {
+                    0.
+                };
+                weights[left] = 1. - t;
+                weights[right] = t;
+                break;
+            }
+        }
+    }
+}
+
+/// Returns the barycentric coordinates of the closest point to `p` in the triangle, and the
+/// squared distance to it
+fn closest_in_triangle(a: Vec2, b: Vec2, c: Vec2, p: Vec2) -> ([f32; 3], f32) {
+    let area = (b - a).perp_dot(c - a);
+    let u = (b - p).perp_dot(c - p) / area;
+    let v = (c - p).perp_dot(a - p) / area;
+    let w = 1. - u - v;
+    if u >= 0. && v >= 0. && w >= 0. {
+        return ([u, v, w], 0.);
+    }
+
+    // Outside of the triangle, so the closest point is on one of its edges
+    let closest_on_edge = |start: Vec2, end: Vec2| {
+        let edge = end - start;
+        let t = ((p - start).dot(edge) / edge.length_squared()).clamp(0., 1.);
+        (t, (start + edge * t).distance_squared(p))
+    };
+    let (ab, ab_distance) = closest_on_edge(a, b);
+    let (bc, bc_distance) = closest_on_edge(b, c);
+    let (ca, ca_distance) = closest_on_edge(c, a);
+    if ab_distance <= bc_distance && ab_distance <= ca_distance {
+        ([1. - ab, ab, 0.], ab_distance)
+    } else if bc_distance <= ca_distance {
+        ([0., 1. - bc, bc], bc_distance)
+    } else {
+        ([ca, 0., 1. - ca], ca_distance)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use glam::vec2;
+
+    use super::*;
+
+    fn assert_weights(actual: Vec<f32>, expected: &[f32]) {
+        assert_eq!(actual.len(), expected.len());
+        for (actual, expected) in actual.iter().zip(expected) {
+            assert!(
+                (actual - expected).abs() < 1e-5,
+                "{actual:?} != {expected:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn blend_space_1d() {
+        let positions = [vec2(0., 0.), vec2(2., 0.), vec2(1., 0.)];
+        let triangles = triangulate(&positions);
+        assert!(triangles.is_empty());
+
+        let weights_at = |x: f32| weights(&positions, &triangles, vec2(x, 0.));
+        assert_weights(weights_at(0.25), &[0.75, 0., 0.25]);
+        assert_weights(weights_at(1.5), &[0., 0.5, 0.5]);
+        assert_weights(weights_at(-1.), &[1., 0., 0.]);
+        assert_weights(weights_at(3.), &[0., 1., 0.]);
+    }
+
+    #[test]
+    fn blend_space_2d() {
+        // A grid of 3x3 points, where the squares can be split along either diagonal
+        let positions = (-1..=1)
+            .flat_map(|x| (-1..=1).map(move |y| vec2(x as f32, y as f32)))
+            .collect::<Vec<_>>();
+        let triangles = triangulate(&positions);
+        assert_eq!(triangles.len(), 8);
+        let area = triangles
+            .iter()
+            .map(|[a, b, c]| {
+                let (a, b, c) = (positions[*a], positions[*b], positions[*c]);
+                (b - a).perp_dot(c - a).abs() / 2.
+            })
+            .sum::<f32>();
+        assert!((area - 4.).abs() < 1e-5);
+
+        let weights_at = |p: Vec2| weights(&positions, &triangles, p);
+        let center = 4;
+        let mut expected = [0.; 9];
+        expected[center] = 1.;
+        assert_weights(weights_at(Vec2::ZERO), &expected);
+
+        // Halfway between the center and (1, 0)
+        let mut expected = [0.; 9];
+        expected[center] = 0.5;
+        expected[7] = 0.5;
+        assert_weights(weights_at(vec2(0.5, 0.)), &expected);
+
+        // Outside of the space, so clamped to the corner
+        let mut expected = [0.; 9];
+        expected[8] = 1.;
+        assert_weights(weights_at(vec2(2., 3.)), &expected);
+
+        let weights = weights_at(vec2(0.3, -0.6));
+        assert!((weights.iter().sum::<f32>() - 1.).abs() < 1e-5);
+        let blended = positions
+            .iter()
+            .zip(&weights)
+            .map(|(p, w)| *p * *w)
+            .sum::<Vec2>();
+        assert!(blended.abs_diff_eq(vec2(0.3, -0.6), 1e-5));
+    }
+}
@@ -0,0 +1,304 @@
+//! Inverse kinematics nodes. They are post-processing steps on the output of their child, which
+//! are applied to the bones of each animated entity after the animation has been sampled and
+//! blended, as they need the world transforms of the bones.
+
+use std::collections::HashMap;
+
+use ambient_core::transform::{
+    get_world_position, local_to_parent, local_to_world, rotation, scale, translation,
+};
+use ambient_ecs::{
+    generated::animation::components::{
+        ik_pole, ik_target, ik_target_entity, ik_weight, look_at_ik_axis, look_at_ik_bind_id,
+        two_bone_ik_bind_ids,
+    },
+    generated::hierarchy::components::{children, parent},
+    EntityId, World,
+};
+use glam::{Mat4, Quat, Vec3};
+
+use crate::player::weighted_children;
+
+#[derive(Debug, Clone)]
+pub enum IkConstraint {
+    TwoBone {
+        /// The root, middle and end bones of the chain
+        bind_ids: [String; 3],
+        target: Vec3,
+        pole: Option<Vec3>,
+        weight: f32,
+    },
+    LookAt {
+        bind_id: String,
+        /// The axis of the bone that is pointed towards the target, in the space of the bone
+        axis: Vec3,
+        target: Vec3,
+        weight: f32,
+    },
+}
+
+pub(crate) fn is_ik_node(world: &World, node: EntityId) -> bool {
+    world.has_component(node, two_bone_ik_bind_ids())
+        || world.has_component(node, look_at_ik_bind_id())
+}
+
+/// Returns the child of `node` if it's an IK node
+pub(crate) fn ik_node_input(world: &World, node: EntityId) -> Option<EntityId> {
+    if is_ik_node(world, node) {
+        world.get_ref(node, children()).ok()?.first().copied()
+    } else {
+        None
+    }
+}
+
+/// Collects the constraints of the IK nodes under `node`, innermost first.
+///
+/// The IK is applied after blending, so the weight of a constraint is scaled by how much its node
+/// contributes to the output of the player.
+pub(crate) fn collect_ik_constraints(
+    world: &World,
+    node: EntityId,
+    weight: f32,
+    constraints: &mut Vec<IkConstraint>,
+) {
+    if weight <= 0. {
+        return;
+    }
+    if let Some(input) = ik_node_input(world, node) {
+        collect_ik_constraints(world, input, weight, constraints);
+        constraints.extend(ik_constraint(world, node, weight));
+    } else if let Some(children) = weighted_children(world, node) {
+        for (child, child_weight) in children {
+            collect_ik_constraints(world, child, weight * child_weight, constraints);
+        }
+    }
+}
+
+fn ik_constraint(world: &World, node: EntityId, weight: f32) -> Option<IkConstraint> {
+    let target = match world.get(node, ik_target_entity()) {
+        Ok(entity) => get_world_position(world, entity).ok()?,
+        Err(_) => world.get(node, ik_target()).ok()?,
+    };
+    let weight = weight * world.get(node, ik_weight()).unwrap_or(1.).clamp(0., 1.);
+    if let Ok(bind_ids) = world.get_ref(node, two_bone_ik_bind_ids()) {
+        Some(IkConstraint::TwoBone {
+            bind_ids: bind_ids.clone().try_into().ok()?,
+            target,
+            pole: world.get(node, ik_pole()).ok(),
+            weight,
+        })
+    } else {
+        Some(IkConstraint::LookAt {
+            bind_id: world.get_cloned(node, look_at_ik_bind_id()).ok()?,
+            axis: world.get(node, look_at_ik_axis()).unwrap_or(Vec3::Z),
+            target,
+            weight,
+        })
+    }
+}
+
+/// Applies the constraints to the bones of an animated entity, in order
+pub(crate) fn apply_ik_constraints(
+    world: &World,
+    binder: &HashMap<String, EntityId>,
+    constraints: &[IkConstraint],
+) {
+    for constraint in constraints {
+        match constraint {
+            IkConstraint::TwoBone {
+                bind_ids: [root, middle, end],
+                target,
+                pole,
+                weight,
+            } => {
+                let (Some(&root), Some(&middle), Some(&end)) =
+                    (binder.get(root), binder.get(middle), binder.get(end))
+                else {
+                    continue;
+                };
+                let (_, root_rotation, a) =
+                    world_transform(world, root).to_scale_rotation_translation();
+                let (_, middle_rotation, b) =
+                    world_transform(world, middle).to_scale_rotation_translation();
+                let c = world_transform(world, end).transform_point3(Vec3::ZERO);
+
+                let (root_delta, middle_delta) = solve_two_bone_ik(a, b, c, *target, *pole);
+                rotate_bone(world, root, root_rotation, root_delta, *weight);
+                rotate_bone(world, middle, middle_rotation, middle_delta, *weight);
+            }
+            IkConstraint::LookAt {
+                bind_id,
+                axis,
+                target,
+                weight,
+            } => {
+                let Some(&bone) = binder.get(bind_id) else {
+                    continue;
+                };
+                let (_, bone_rotation, position) =
+                    world_transform(world, bone).to_scale_rotation_translation();
+                let delta = solve_look_at(position, bone_rotation, *axis, *target);
+                rotate_bone(world, bone, bone_rotation, delta, *weight);
+            }
+        }
+    }
+}
+
+/// The world transform of the entity, from the current local transforms of it and its ancestors.
+///
+/// Unlike `local_to_world`, this includes the animation that was just applied to the bones.
+fn world_transform(world: &World, id: EntityId) -> Mat4 {
+    let has_local_transform = world.has_component(id, translation())
+        || world.has_component(id, rotation())
+        || world.has_component(id, scale());
+    let local = if has_local_transform {
+        Mat4::from_scale_rotation_translation(
+            world.get(id, scale()).unwrap_or(Vec3::ONE),
+            world.get(id, rotation()).unwrap_or(Quat::IDENTITY),
+            world.get(id, translation()).unwrap_or(Vec3::ZERO),
+        )
+    } else if let Ok(local) = world.get(id, local_to_parent()) {
+        local
+    } else {
+        return world.get(id, local_to_world()).unwrap_or_default();
+    };
+    match world.get(id, parent()) {
+        Ok(parent) => world_transform(world, parent) * local,
+        Err(_) => local,
+    }
+}
+
+/// Rotates the bone by `delta` in world space, where `world_rotation` is its current rotation in
+/// world space
+fn rotate_bone(world: &World, bone: EntityId, world_rotation: Quat, delta: Quat, weight: f32) {
+    if let Ok(local) = world.get_mut_unsafe(bone, rotation()) {
+        let rotated = *local * world_rotation.inverse() * delta * world_rotation;
+        *local = local.slerp(rotated, weight).normalize();
+    }
+}
+
+/// Solves the chain with the root at `a`, the middle joint at `b` and the end at `c`, so that the
+/// end reaches `target`, or points towards it if it's out of reach. If there's a `pole`, the middle
+/// joint bends towards it.
+///
+/// Returns the world space rotation of the root bone around `a`, and of the middle bone around `b`.
+pub(crate) fn solve_two_bone_ik(
+    a: Vec3,
+    b: Vec3,
+    c: Vec3,
+    target: Vec3,
+    pole: Option<Vec3>,
+) -> (Quat, Quat) {
+    const EPSILON: f32 = 1e-4;
+    let ab = (b - a).length();
+    let bc = (c - b).length();
+    if ab < EPSILON || bc < EPSILON {
+        return (Quat::IDENTITY, Quat::IDENTITY);
+    }
+    let at = (target - a).length().clamp(EPSILON, ab + bc - EPSILON);
+    let angle = |u: Vec3, v: Vec3| {
+        u.normalize_or_zero()
+            .dot(v.normalize_or_zero())
+            .clamp(-1., 1.)
+            .acos()
+    };
+
+    // The current and desired angles at the root between the end and the middle joint, and at the
+    // middle joint between the root and the end
+    let root_angle = angle(c - a, b - a);
+    let middle_angle = angle(a - b, c - b);
+    let desired_root_angle = ((bc * bc - ab * ab - at * at) / (-2. * ab * at))
+        .clamp(-1., 1.)
+        .acos();
+    let desired_middle_angle = ((at * at - ab * ab - bc * bc) / (-2. * ab * bc))
+        .clamp(-1., 1.)
+        .acos();
+
+    // The chain bends in its own plane. If it's straight, bend it towards the pole instead
+    let mut bend_axis = (c - a).cross(b - a).normalize_or_zero();
+    if bend_axis == Vec3::ZERO {
+        bend_axis = pole
+            .map(|pole| (c - a).cross(pole - a).normalize_or_zero())
+            .filter(|axis| *axis != Vec3::ZERO)
+            .unwrap_or_else(|| (c - a).normalize_or_zero().any_orthonormal_vector());
+    }
+    let bend = Quat::from_axis_angle(bend_axis, desired_root_angle - root_angle);
+    let middle_delta = Quat::from_axis_angle(bend_axis, desired_middle_angle - middle_angle);
+
+    // After bending, the end is still in the same direction from the root, so turn it to the target
+    let mut root_delta = Quat::from_rotation_arc(
+        (c - a).normalize_or_zero(),
+        (target - a).normalize_or_zero(),
+    ) * bend;
+
+    if let Some(pole) = pole {
+        // Twist the chain around the line from the root to the target, so that the middle joint
+        // is on the side of the pole
+        let axis = (target - a).normalize_or_zero();
+        let project = |v: Vec3| v - axis * axis.dot(v);
+        let middle = project(root_delta * (b - a));
+        let pole = project(pole - a);
+        if middle.length_squared() > EPSILON && pole.length_squared() > EPSILON {
+            let twist = axis.dot(middle.cross(pole)).atan2(middle.dot(pole));
+            root_delta = Quat::from_axis_angle(axis, twist) * root_delta;
+        }
+    }
+
+    (root_delta, middle_delta)
+}
+
+/// Returns the world space rotation that points the `axis` of a bone at `position` with
+/// `world_rotation` towards `target`
+pub(crate) fn solve_look_at(
+    position: Vec3,
+    world_rotation: Quat,
+    axis: Vec3,
+    target: Vec3,
+) -> Quat {
+    let current = (world_rotation * axis).normalize_or_zero();
+    let desired = (target - position).normalize_or_zero();
+    if current == Vec3::ZERO || desired == Vec3::ZERO {
+        return Quat::IDENTITY;
+    }
+    Quat::from_rotation_arc(current, desired)
+}
+
+#[cfg(test)]
+mod test {
+    use glam::vec3;
+
+    use super::*;
+
+    #[test]
+    fn two_bone_ik_reaches_target() {
+        let (a, b, c) = (Vec3::ZERO, vec3(0., 0.1, -1.), vec3(0., 0., -2.));
+        let solve = |target: Vec3, pole: Option<Vec3>| {
+            let (root_delta, middle_delta) = solve_two_bone_ik(a, b, c, target, pole);
+            // The middle bone is a child of the root bone, so it is rotated by both
+            let b = a + root_delta * (b - a);
+            let c = b + root_delta * middle_delta * (c - b);
+            (b, c)
+        };
+
+        let target = vec3(0.5, 0.5, -1.2);
+        let (_, end) = solve(target, None);
+        assert!(end.abs_diff_eq(target, 1e-3));
+
+        // The middle joint bends towards the pole
+        let (middle, end) = solve(target, Some(vec3(0., -5., 0.)));
+        assert!(end.abs_diff_eq(target, 1e-3));
+        assert!(middle.y < 0.);
+        let (middle, _) = solve(target, Some(vec3(0., 5., 0.)));
+        assert!(middle.y > 0.);
+
+        // Targets out of reach straighten the chain towards them
+        let (_, end) = solve(vec3(0., 0., -10.), None);
+        assert!(end.abs_diff_eq(vec3(0., 0., -2.01), 1e-3));
+    }
+
+    #[test]
+    fn look_at() {
+        let delta = solve_look_at(Vec3::ZERO, Quat::IDENTITY, Vec3::Z, vec3(1., 0., 0.));
+        assert!((delta * Vec3::Z).abs_diff_eq(Vec3::X, 1e-5));
+    }
+}
@@ -1,19 +1,34 @@
 use ambient_ecs::SystemGroup;
+use blend_space::blend_space_systems;
 use player::animation_player_systems;
+use ragdoll::ragdoll_systems;
 
+mod blend_space;
+mod ik;
 mod player;
+mod ragdoll;
 mod resources;
 mod retargeting;
 
+pub use player::{crossed_animation_events, CrossedAnimationEvent};
+pub use ragdoll::animated_bone_transform;
 pub use resources::*;
 pub use retargeting::*;
 
 pub fn init_all_components() {
     player::init_components();
+    blend_space::init_components();
 }
 
 pub fn animation_systems() -> SystemGroup {
-    animation_player_systems()
+    SystemGroup::new(
+        "animation_systems",
+        vec![
+            Box::new(blend_space_systems()),
+            Box::new(animation_player_systems()),
+            Box::new(ragdoll_systems()),
+        ],
+    )
 }
 
 #[test]
@@ -1,21 +1,28 @@
 use std::{
+    cmp::Ordering,
     collections::{hash_map::Entry, HashMap},
     str::FromStr,
     sync::Arc,
     time::Duration,
 };
 
-use ambient_core::{asset_cache, async_ecs::async_run, epoch_time, runtime};
+use ambient_core::{
+    asset_cache,
+    async_ecs::async_run,
+    epoch_time, runtime,
+    transform::{local_to_world, translation},
+};
 use ambient_ecs::{
     components,
     generated::animation::components::{
-        animation_errors, apply_animation_player, apply_base_pose, bind_ids, blend, clip_duration,
-        clip_load_error, clip_loaded, freeze_at_percentage, freeze_at_time, is_animation_player,
-        looping, mask_bind_ids, mask_weights, play_clip_from_url, retarget_animation_scaled,
-        retarget_model_from_url, speed, start_time,
+        additive_weight, animation_errors, apply_animation_player, apply_base_pose, bind_ids,
+        blend, clip_duration, clip_load_error, clip_loaded, freeze_at_percentage, freeze_at_time,
+        is_animation_player, looping, mask_bind_ids, mask_weights, play_clip_from_url,
+        retarget_animation_scaled, retarget_model_from_url, root_motion_axes, root_motion_bone,
+        root_motion_delta, speed, start_time, two_bone_ik_bind_ids,
     },
-    generated::hierarchy::components::children,
-    query, ComponentDesc, Debuggable, EntityId, SystemGroup, World,
+    generated::hierarchy::components::{children, parent},
+    query, ComponentDesc, Debuggable, Entity, EntityId, SystemGroup, World,
 };
 use ambient_model::{animation_binder, ModelFromUrl};
 use ambient_native_std::{
@@ -28,6 +35,8 @@ use glam::{Quat, Vec3};
 use itertools::Itertools;
 
 use crate::{
+    blend_space::blend_space_weights,
+    ik::{apply_ik_constraints, collect_ik_constraints, ik_node_input, is_ik_node, IkConstraint},
     AnimationClip, AnimationClipRetargetedFromModel, AnimationOutput, AnimationRetargeting,
     AnimationTarget, AnimationTrackInterpolator, Vec3Field,
 };
@@ -39,10 +48,30 @@ components!("animation", {
     mask: HashMap<String, f32>,
     cached_base_pose: HashMap<AnimationOutputKey, AnimationOutput>,
     play_clip: Arc<AnimationClip>,
+    /// The translation of the root motion bone during the last frame, relative to its parent
+    @[Debuggable]
+    root_motion_output: Vec3,
+    /// The time the player was last sampled at
+    sampled_time: Duration,
+    /// The clip events that the player crossed during the last frame
+    @[Debuggable]
+    crossed_animation_events: Vec<CrossedAnimationEvent>,
+    /// The IK nodes of the player, which are applied to the animated entities
+    @[Debuggable]
+    ik_constraints: Vec<IkConstraint>,
 });
 
+#[derive(Debug, Clone)]
+pub struct CrossedAnimationEvent {
+    /// The clip node which has the event
+    pub node: EntityId,
+    pub name: String,
+    /// How much the clip contributes to the output of the player
+    pub weight: f32,
+}
+
 #[derive(Clone)]
-pub struct AnimationOutputs(HashMap<AnimationOutputKey, AnimationOutput>);
+pub struct AnimationOutputs(pub(crate) HashMap<AnimationOutputKey, AnimationOutput>);
 impl std::fmt::Debug for AnimationOutputs {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let mut map = f.debug_map();
@@ -55,9 +84,200 @@ impl std::fmt::Debug for AnimationOutputs {
 
 #[derive(Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub struct AnimationOutputKey {
-    target: AnimationTarget,
-    component: u32,
-    field: Option<Vec3Field>,
+    pub(crate) target: AnimationTarget,
+    pub(crate) component: u32,
+    pub(crate) field: Option<Vec3Field>,
+}
+
+/// The horizontal plane of Y-up skeletons, see `root_motion_axes`
+const DEFAULT_ROOT_MOTION_AXES: Vec3 = Vec3::new(1., 0., 1.);
+
+/// Returns the time in the clip which is played at `time`, and the number of times the clip has
+/// looped
+fn clip_time(world: &World, node: EntityId, clip: &AnimationClip, time: Duration) -> (f64, u64) {
+    if let Ok(freeze_at_time) = world.get(node, freeze_at_time()) {
+        (freeze_at_time as f64, 0)
+    } else if let Ok(freeze_at_percentage) = world.get(node, freeze_at_percentage()) {
+        ((freeze_at_percentage * clip.duration()) as f64, 0)
+    } else {
+        let time = match world.get(node, start_time()) {
+            Ok(st) => time.saturating_sub(st).as_secs_f64(),
+            Err(_) => time.as_secs_f64(),
+        };
+        // The speed scales the time in the clip, so it has to be applied before wrapping
+        let mut time = time * world.get(node, speed()).unwrap_or(1.) as f64;
+        let mut loops = 0;
+        if world.get(node, looping()).unwrap_or(false) {
+            let duration = clip.duration() as f64;
+            loops = (time / duration).floor() as u64;
+            time %= duration;
+        }
+        (time, loops)
+    }
+}
+
+/// Returns the children of a node which combines other nodes, with how much each of them
+/// contributes to its output
+pub(crate) fn weighted_children(world: &World, node: EntityId) -> Option<Vec<(EntityId, f32)>> {
+    if let Ok(blend_weight) = world.get(node, blend()) {
+        let children = world.get_ref(node, children()).ok()?;
+        let [left, right] = children[..] else {
+            return None;
+        };
+        Some(vec![(left, 1. - blend_weight), (right, blend_weight)])
+    } else if let Some(weights) = blend_space_weights(world, node) {
+        let children = world.get_ref(node, children()).ok()?;
+        Some(children.iter().copied().zip(weights).collect())
+    } else if let Ok(weight) = world.get(node, additive_weight()) {
+        // The reference pose is subtracted from the layer, so it doesn't contribute on its own
+        let children = world.get_ref(node, children()).ok()?;
+        let [base, layer, ..] = children[..] else {
+            return None;
+        };
+        Some(vec![(base, 1.), (layer, weight)])
+    } else {
+        ik_node_input(world, node).map(|input| vec![(input, 1.)])
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct RootMotion {
+    /// The translation of the root bone at the start of the clip
+    origin: Vec3,
+    /// How far the root bone moved between two times
+    delta: Vec3,
+}
+
+/// Samples how far the root bone moved from `prev_time` to `time`.
+///
+/// Returns `None` if the node doesn't animate the translation of the root bone.
+fn sample_root_motion(
+    world: &World,
+    node: EntityId,
+    bind_id: &str,
+    prev_time: Duration,
+    time: Duration,
+) -> Option<RootMotion> {
+    if world.get_ref(node, play_clip_from_url()).is_ok() {
+        let clip = world.get_ref(node, play_clip()).ok()?;
+        let track = clip.tracks.iter().find(|track| {
+            track.target.bind_id() == Some(bind_id)
+                && track.outputs.component() == translation().desc()
+                && track.outputs.field().is_none()
+        })?;
+        let position = |time: f64| {
+            *AnimationTrackInterpolator::new()
+                .value(track, time as f32)
+                .as_vec3_value()
+                .unwrap()
+        };
+
+        let (prev_time, prev_loops) = clip_time(world, node, &clip, prev_time);
+        let (time, loops) = clip_time(world, node, &clip, time);
+        let origin = position(0.);
+        let mut delta = position(time) - position(prev_time);
+        if loops != prev_loops {
+            // The distance covered by each full loop of the clip
+            let end = clip.duration() as f64;
+            delta += (position(end) - origin) * (loops as f32 - prev_loops as f32);
+        }
+        Some(RootMotion { origin, delta })
+    } else if let Ok(blend_weight) = world.get(node, blend()) {
+        let children = world.get_ref(node, children()).ok()?;
+        let [left, right] = children[..] else {
+            return None;
+        };
+        let left = sample_root_motion(world, left, bind_id, prev_time, time);
+        let right = sample_root_motion(world, right, bind_id, prev_time, time);
+        let blend_weight = world
+            .get_ref(node, mask())
+            .ok()
+            .and_then(|mask| mask.get(bind_id).copied())
+            .unwrap_or(blend_weight);
+        match (left, right) {
+            (Some(left), Some(right)) => Some(RootMotion {
+                origin: left.origin.lerp(right.origin, blend_weight),
+                delta: left.delta.lerp(right.delta, blend_weight),
+            }),
+            (left, right) => left.or(right),
+        }
+    } else if world.has_component(node, additive_weight()) {
+        // Additive layers are offsets from the base, so only the base moves the root
+        let base = *world.get_ref(node, children()).ok()?.first()?;
+        sample_root_motion(world, base, bind_id, prev_time, time)
+    } else {
+        let mut total = 0.;
+        let mut motion = RootMotion::default();
+        for (child, weight) in weighted_children(world, node)? {
+            if weight <= 0. {
+                continue;
+            }
+            if let Some(child) = sample_root_motion(world, child, bind_id, prev_time, time) {
+                total += weight;
+                motion.origin += child.origin * weight;
+                motion.delta += child.delta * weight;
+            }
+        }
+        (total > 0.).then(|| RootMotion {
+            origin: motion.origin / total,
+            delta: motion.delta / total,
+        })
+    }
+}
+
+/// Collects the events of the clips under `node` that the playhead crossed from `prev_time`
+/// (inclusive) to `time` (exclusive), once for each time they were crossed. On the first frame
+/// there is no `prev_time`, and clips which were started at a `start_time` are crossed from their
+/// start, so that events at the very start of a clip fire too.
+fn collect_crossed_events(
+    world: &World,
+    node: EntityId,
+    prev_time: Option<Duration>,
+    time: Duration,
+    weight: f32,
+    events: &mut Vec<CrossedAnimationEvent>,
+) {
+    if weight <= 0. {
+        return;
+    }
+    if world.get_ref(node, play_clip_from_url()).is_ok() {
+        let Ok(clip) = world.get_ref(node, play_clip()) else {
+            return;
+        };
+        let (time, loops) = clip_time(world, node, clip, time);
+        let plays_from_start = world.has_component(node, start_time())
+            && !world.has_component(node, freeze_at_time())
+            && !world.has_component(node, freeze_at_percentage());
+        let (prev_time, prev_loops) = match prev_time {
+            Some(prev_time) => clip_time(world, node, clip, prev_time),
+            None if plays_from_start => (0., 0),
+            None => (time, loops),
+        };
+        for event in &clip.events {
+            let event_time = event.time as f64;
+            let count = match loops.cmp(&prev_loops) {
+                Ordering::Equal => (prev_time <= event_time && event_time < time) as u64,
+                // Crossed in the rest of the first loop, in every full loop, and in the last loop
+                Ordering::Greater => {
+                    (prev_time <= event_time) as u64
+                        + (loops - prev_loops - 1)
+                        + (event_time < time) as u64
+                }
+                Ordering::Less => 0,
+            };
+            for _ in 0..count {
+                events.push(CrossedAnimationEvent {
+                    node,
+                    name: event.name.clone(),
+                    weight,
+                });
+            }
+        }
+    } else if let Some(children) = weighted_children(world, node) {
+        for (child, child_weight) in children {
+            collect_crossed_events(world, child, prev_time, time, weight * child_weight, events);
+        }
+    }
 }
 
 fn sample_animation_node(
@@ -85,34 +305,8 @@ fn sample_animation_node_inner(
             Ok(clip) => clip,
             Err(_) => return Ok(Default::default()),
         };
-        let time = if let Ok(freeze_at_time) = world.get(node, freeze_at_time()) {
-            freeze_at_time as f64
-        } else if let Ok(freeze_at_percentage) = world.get(node, freeze_at_percentage()) {
-            (freeze_at_percentage * clip.duration()) as f64
-        } else {
-            let mut time = match world.get(node, start_time()) {
-                Ok(st) => time.saturating_sub(st).as_secs_f64(),
-                Err(_) => time.as_secs_f64(),
-            };
-            let speed = world.get(node, speed()).unwrap_or(1.);
-            if world.get(node, looping()).unwrap_or(false) {
-                time %= clip.duration() as f64;
-            }
-            time * speed as f64
-        };
-        let mut output: HashMap<AnimationOutputKey, AnimationOutput> = clip
-            .tracks
-            .iter()
-            .map(|track| {
-                let value = AnimationTrackInterpolator::new().value(track, time as f32);
-                let key = AnimationOutputKey {
-                    target: track.target.clone(),
-                    component: track.outputs.component().index(),
-                    field: track.outputs.field(),
-                };
-                (key, value)
-            })
-            .collect();
+        let (time, _) = clip_time(world, node, &clip, time);
+        let mut output = sample_clip(&clip, time as f32);
         if let Ok(base_pose) = world.get_ref(node, cached_base_pose()) {
             for (key, value) in base_pose.iter() {
                 if !output.contains_key(key) {
@@ -149,11 +343,94 @@ fn sample_animation_node_inner(
             }
         }
         Ok(output)
+    } else if is_ik_node(world, node) {
+        // The IK itself is applied to the animated entities, see `apply_ik_constraints`
+        let children = world.get_ref(node, children())?;
+        if children.len() != 1 {
+            anyhow::bail!("IK node needs to have exactly one child");
+        }
+        if let Ok(bind_ids) = world.get_ref(node, two_bone_ik_bind_ids()) {
+            if bind_ids.len() != 3 {
+                anyhow::bail!("Two-bone IK node needs to have exactly three bind ids");
+            }
+        }
+        Ok(sample_animation_node(world, children[0], time, errors))
+    } else if let Some(weights) = blend_space_weights(world, node) {
+        let children = world.get_ref(node, children())?;
+        if children.len() != weights.len() {
+            anyhow::bail!("Blend space node needs to have a position for each child");
+        }
+        // Blend each output by the total weight of the children that have it so far
+        let mut output: HashMap<AnimationOutputKey, (AnimationOutput, f32)> = HashMap::new();
+        for (child, weight) in children.iter().zip(weights) {
+            if weight <= 0. {
+                continue;
+            }
+            for (key, value) in sample_animation_node(world, *child, time, errors) {
+                match output.entry(key) {
+                    Entry::Occupied(mut o) => {
+                        let (blended, total) = o.get_mut();
+                        *total += weight;
+                        *blended = blended.mix(value, weight / *total);
+                    }
+                    Entry::Vacant(v) => {
+                        v.insert((value, weight));
+                    }
+                }
+            }
+        }
+        Ok(output
+            .into_iter()
+            .map(|(key, (value, _))| (key, value))
+            .collect())
+    } else if let Ok(weight) = world.get(node, additive_weight()) {
+        let children = world.get_ref(node, children())?;
+        let (base, layer, reference) = match children[..] {
+            [base, layer] => (base, layer, None),
+            [base, layer, reference] => (base, layer, Some(reference)),
+            _ => anyhow::bail!("Additive node needs to have two or three children"),
+        };
+        let mut output = sample_animation_node(world, base, time, errors);
+        let reference = match reference {
+            Some(reference) => sample_animation_node(world, reference, time, errors),
+            None => {
+                if world.get_ref(layer, play_clip_from_url()).is_err() {
+                    anyhow::bail!(
+                        "Additive node needs a reference pose as its third child if its layer is not a clip"
+                    );
+                }
+                match world.get_ref(layer, play_clip()) {
+                    Ok(clip) => sample_clip(clip, 0.),
+                    Err(_) => return Ok(output),
+                }
+            }
+        };
+        for (key, value) in sample_animation_node(world, layer, time, errors) {
+            if let (Some(base), Some(reference)) = (output.get_mut(&key), reference.get(&key)) {
+                *base = base.add_difference(&value, reference, weight);
+            }
+        }
+        Ok(output)
     } else {
         anyhow::bail!("Node is not a proper animation node")
     }
 }
 
+fn sample_clip(clip: &AnimationClip, time: f32) -> HashMap<AnimationOutputKey, AnimationOutput> {
+    clip.tracks
+        .iter()
+        .map(|track| {
+            let value = AnimationTrackInterpolator::new().value(track, time);
+            let key = AnimationOutputKey {
+                target: track.target.clone(),
+                component: track.outputs.component().index(),
+                field: track.outputs.field(),
+            };
+            (key, value)
+        })
+        .collect()
+}
+
 fn apply_animation_outputs_to_entity(
     world: &World,
     binder: &HashMap<String, EntityId>,
@@ -305,7 +582,47 @@ pub fn animation_player_systems() -> SystemGroup {
                 let time = *world.resource(epoch_time());
                 for (id, (_, children)) in q.collect_cloned(world, qs) {
                     let mut errors = Default::default();
-                    let output = sample_animation_node(world, children[0], time, &mut errors);
+                    let mut output = sample_animation_node(world, children[0], time, &mut errors);
+                    let prev_time = world.get(id, sampled_time()).ok();
+                    if let Ok(bind_id) = world.get_cloned(id, root_motion_bone()) {
+                        // Keep the root bone at the start of the clip along the extracted axes,
+                        // and apply the motion to the entities instead
+                        let axes = world
+                            .get(id, root_motion_axes())
+                            .unwrap_or(DEFAULT_ROOT_MOTION_AXES);
+                        let motion = sample_root_motion(
+                            world,
+                            children[0],
+                            &bind_id,
+                            prev_time.unwrap_or(time),
+                            time,
+                        )
+                        .unwrap_or_default();
+                        let key = AnimationOutputKey {
+                            target: AnimationTarget::BinderId(bind_id),
+                            component: translation().index(),
+                            field: None,
+                        };
+                        if let Some(AnimationOutput::Vec3 { value, .. }) = output.get_mut(&key) {
+                            *value = motion.origin * axes + *value * (Vec3::ONE - axes);
+                        }
+                        world
+                            .add_component(id, root_motion_output(), motion.delta * axes)
+                            .ok();
+                    }
+                    let mut events = Vec::new();
+                    collect_crossed_events(world, children[0], prev_time, time, 1., &mut events);
+                    let mut constraints = Vec::new();
+                    collect_ik_constraints(world, children[0], 1., &mut constraints);
+                    world
+                        .add_components(
+                            id,
+                            Entity::new()
+                                .with(sampled_time(), time)
+                                .with(crossed_animation_events(), events)
+                                .with(ik_constraints(), constraints),
+                        )
+                        .ok();
                     world
                         .add_component(id, animation_output(), AnimationOutputs(output))
                         .ok();
@@ -321,6 +638,30 @@ pub fn animation_player_systems() -> SystemGroup {
                     if let Ok(outputs) = world.get_ref(*anim_player_id, animation_output()) {
                         apply_animation_outputs_to_entity(world, binder, &outputs.0);
                     }
+                    if let Ok(constraints) = world.get_ref(*anim_player_id, ik_constraints()) {
+                        apply_ik_constraints(world, binder, constraints);
+                    }
+                }
+            }),
+            query((apply_animation_player(), animation_binder())).to_system(|q, world, qs, _| {
+                let mut deltas = Vec::new();
+                for (id, (anim_player_id, binder)) in q.iter(world, qs) {
+                    let (Ok(bind_id), Ok(delta)) = (
+                        world.get_ref(*anim_player_id, root_motion_bone()),
+                        world.get(*anim_player_id, root_motion_output()),
+                    ) else {
+                        continue;
+                    };
+                    // The motion is relative to the parent of the bone, so transform it to world space
+                    let delta = binder
+                        .get(bind_id)
+                        .and_then(|bone| world.get(*bone, parent()).ok())
+                        .and_then(|parent| world.get(parent, local_to_world()).ok())
+                        .map_or(delta, |transform| transform.transform_vector3(delta));
+                    deltas.push((id, delta));
+                }
+                for (id, delta) in deltas {
+                    world.add_component(id, root_motion_delta(), delta).ok();
                 }
             }),
         ],
@@ -369,3 +710,218 @@ fn build_base_pose(
         anyhow::bail!("No anim yet")
     }
 }
+
+#[cfg(test)]
+mod test {
+    use ambient_core::transform;
+    use ambient_ecs::{
+        generated::animation::components::{blend_space_parameter, blend_space_positions},
+        WorldContext,
+    };
+    use glam::{vec3, Vec2};
+
+    use super::*;
+    use crate::{AnimationEvent, AnimationOutputs, AnimationTrack};
+
+    #[test]
+    fn root_motion_across_loops() {
+        ambient_ecs::init_components();
+        transform::init_components();
+        init_components();
+
+        let clip = AnimationClip::from_tracks(vec![AnimationTrack {
+            target: AnimationTarget::BinderId("Hips".to_string()),
+            inputs: vec![0., 1.],
+            outputs: AnimationOutputs::Vec3 {
+                component: translation(),
+                data: vec![vec3(0., 1., 0.), vec3(2., 1., 0.)],
+            },
+        }]);
+        let mut world = World::new("root_motion", WorldContext::Unknown);
+        let node = Entity::new()
+            .with(play_clip_from_url(), String::new())
+            .with(play_clip(), Arc::new(clip))
+            .with(looping(), true)
+            .with(start_time(), Duration::ZERO)
+            .spawn(&mut world);
+
+        let sample = |from: f32, to: f32| {
+            sample_root_motion(
+                &world,
+                node,
+                "Hips",
+                Duration::from_secs_f32(from),
+                Duration::from_secs_f32(to),
+            )
+            .unwrap()
+        };
+
+        let motion = sample(0.25, 0.75);
+        assert_eq!(motion.origin, vec3(0., 1., 0.));
+        assert!(motion.delta.abs_diff_eq(vec3(1., 0., 0.), 1e-5));
+        // Wrapping around the end of the clip keeps moving forward
+        assert!(sample(0.75, 1.25).delta.abs_diff_eq(vec3(1., 0., 0.), 1e-5));
+        assert!(sample(0.5, 2.5).delta.abs_diff_eq(vec3(4., 0., 0.), 1e-5));
+
+        // The speed is applied before wrapping, so a faster clip loops sooner
+        world.add_component(node, speed(), 2.).unwrap();
+        let sample = |from: f32, to: f32| {
+            sample_root_motion(
+                &world,
+                node,
+                "Hips",
+                Duration::from_secs_f32(from),
+                Duration::from_secs_f32(to),
+            )
+            .unwrap()
+        };
+        assert!(sample(0.25, 0.75).delta.abs_diff_eq(vec3(2., 0., 0.), 1e-5));
+        assert!(sample(0.25, 1.25).delta.abs_diff_eq(vec3(4., 0., 0.), 1e-5));
+
+        assert!(
+            sample_root_motion(&world, node, "Spine", Duration::ZERO, Duration::ZERO).is_none()
+        );
+    }
+
+    #[test]
+    fn events_across_loops_and_blends() {
+        ambient_ecs::init_components();
+        transform::init_components();
+        init_components();
+
+        let mut clip = AnimationClip::from_tracks(vec![AnimationTrack {
+            target: AnimationTarget::BinderId("Hips".to_string()),
+            inputs: vec![0., 1.],
+            outputs: AnimationOutputs::Vec3 {
+                component: translation(),
+                data: vec![Vec3::ZERO, Vec3::X],
+            },
+        }]);
+        clip.add_event(AnimationEvent {
+            time: 0.5,
+            name: "step".to_string(),
+        });
+        let clip = Arc::new(clip);
+        let mut world = World::new("animation_events", WorldContext::Unknown);
+        let spawn_node = |world: &mut World| {
+            Entity::new()
+                .with(play_clip_from_url(), String::new())
+                .with(play_clip(), clip.clone())
+                .with(looping(), true)
+                .with(start_time(), Duration::ZERO)
+                .spawn(world)
+        };
+        let left = spawn_node(&mut world);
+        let right = spawn_node(&mut world);
+        let blend_node = Entity::new()
+            .with(blend(), 0.25)
+            .with(children(), vec![left, right])
+            .spawn(&mut world);
+
+        let crossed = |node: EntityId, from: f32, to: f32| {
+            let mut events = Vec::new();
+            collect_crossed_events(
+                &world,
+                node,
+                Some(Duration::from_secs_f32(from)),
+                Duration::from_secs_f32(to),
+                1.,
+                &mut events,
+            );
+            events
+        };
+
+        assert_eq!(crossed(left, 0.25, 0.75).len(), 1);
+        assert_eq!(crossed(left, 0.75, 1.25).len(), 0);
+        // Once when wrapping around, and once in the next loop
+        assert_eq!(crossed(left, 0.25, 1.75).len(), 2);
+        assert_eq!(crossed(left, 0.25, 3.75).len(), 4);
+
+        let events = crossed(blend_node, 0.25, 0.75);
+        assert_eq!(events.len(), 2);
+        assert_eq!((events[0].node, events[0].weight), (left, 0.75));
+        assert_eq!((events[1].node, events[1].weight), (right, 0.25));
+
+        // Events at the start of a clip fire on the first frame, which has no previous time
+        let mut clip = (*clip).clone();
+        clip.add_event(AnimationEvent {
+            time: 0.,
+            name: "start".to_string(),
+        });
+        world.set(left, play_clip(), Arc::new(clip)).unwrap();
+        let mut events = Vec::new();
+        collect_crossed_events(
+            &world,
+            left,
+            None,
+            Duration::from_secs_f32(0.1),
+            1.,
+            &mut events,
+        );
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].name, "start");
+    }
+
+    #[test]
+    fn blend_space_and_additive_layers() {
+        ambient_ecs::init_components();
+        transform::init_components();
+        init_components();
+        crate::blend_space::init_components();
+
+        let mut world = World::new("blend_spaces", WorldContext::Unknown);
+        let spawn_clip = |world: &mut World, from: Vec3, to: Vec3| {
+            let clip = AnimationClip::from_tracks(vec![AnimationTrack {
+                target: AnimationTarget::BinderId("Hips".to_string()),
+                inputs: vec![0., 1.],
+                outputs: AnimationOutputs::Vec3 {
+                    component: translation(),
+                    data: vec![from, to],
+                },
+            }]);
+            Entity::new()
+                .with(play_clip_from_url(), String::new())
+                .with(play_clip(), Arc::new(clip))
+                .with(freeze_at_time(), 0.5)
+                .spawn(world)
+        };
+        let key = AnimationOutputKey {
+            target: AnimationTarget::BinderId("Hips".to_string()),
+            component: translation().index(),
+            field: None,
+        };
+        let sample = |world: &World, node: EntityId| {
+            let mut errors = Vec::new();
+            let output = sample_animation_node(world, node, Duration::ZERO, &mut errors);
+            assert!(errors.is_empty(), "{errors:?}");
+            *output[&key].as_vec3_value().unwrap()
+        };
+
+        let idle = spawn_clip(&mut world, Vec3::ZERO, Vec3::ZERO);
+        let walk = spawn_clip(&mut world, Vec3::X, Vec3::X);
+        let run = spawn_clip(&mut world, Vec3::Y, Vec3::Y);
+        let blend_space = Entity::new()
+            .with(children(), vec![idle, walk, run])
+            .with(
+                blend_space_positions(),
+                vec![Vec2::ZERO, Vec2::new(1., 0.), Vec2::new(2., 0.)],
+            )
+            .with(blend_space_parameter(), Vec2::new(1.5, 0.))
+            .spawn(&mut world);
+        assert!(sample(&world, blend_space).abs_diff_eq(vec3(0.5, 0.5, 0.), 1e-5));
+
+        // The layer moves from 0 to 2 on Z, so at 0.5 it's 1 from its first frame
+        let layer = spawn_clip(&mut world, Vec3::ZERO, vec3(0., 0., 2.));
+        let additive = Entity::new()
+            .with(children(), vec![walk, layer])
+            .with(additive_weight(), 0.5)
+            .spawn(&mut world);
+        assert!(sample(&world, additive).abs_diff_eq(vec3(1., 0., 0.5), 1e-5));
+
+        let reference = spawn_clip(&mut world, vec3(0., 0., -1.), vec3(0., 0., -1.));
+        world
+            .add_component(additive, children(), vec![walk, layer, reference])
+            .unwrap();
+        assert!(sample(&world, additive).abs_diff_eq(vec3(1., 0., 1.), 1e-5));
+    }
+}
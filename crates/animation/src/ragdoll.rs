@@ -0,0 +1,118 @@
+use std::collections::HashMap;
+
+use ambient_core::{
+    hierarchy::parent,
+    transform::{get_world_transform, local_to_parent, rotation, scale, translation},
+};
+use ambient_ecs::{
+    generated::{
+        animation::components::apply_animation_player,
+        physics::components::{ragdoll_bind_ids, ragdoll_blend, ragdoll_link_poses},
+    },
+    query, EntityId, SystemGroup, World,
+};
+use ambient_model::animation_binder;
+use glam::{Mat4, Quat, Vec3};
+
+use crate::{
+    player::{animation_output, AnimationOutputKey},
+    AnimationOutput, AnimationTarget,
+};
+
+/// Blends the animated bones of models with the ragdoll link poses simulated by the server.
+///
+/// Must run after the animation has been applied to the bones.
+pub fn ragdoll_systems() -> SystemGroup {
+    SystemGroup::new(
+        "ragdoll_systems",
+        vec![query((
+            ragdoll_bind_ids(),
+            ragdoll_link_poses(),
+            ragdoll_blend(),
+            animation_binder(),
+        ))
+        .to_system(|q, world, qs, _| {
+            for (_, (bind_ids, poses, blend, binder)) in q.collect_cloned(world, qs) {
+                let link_poses = bind_ids
+                    .iter()
+                    .zip(poses)
+                    .filter_map(|(bind_id, pose)| Some((*binder.get(bind_id)?, pose)))
+                    .collect::<HashMap<_, _>>();
+                apply_link_poses(world, &link_poses, blend.clamp(0., 1.));
+            }
+        })],
+    )
+}
+
+/// Returns the translation and rotation relative to its parent that the animation player of `id`
+/// gives the bone with `bind_id`, where it animates them.
+///
+/// This is for worlds where the bones of the model are not spawned, like on the server.
+pub fn animated_bone_transform(
+    world: &World,
+    id: EntityId,
+    bind_id: &str,
+) -> (Option<Vec3>, Option<Quat>) {
+    let Ok(outputs) = world
+        .get(id, apply_animation_player())
+        .and_then(|player| world.get_ref(player, animation_output()))
+    else {
+        return (None, None);
+    };
+    let output = |component: u32| {
+        outputs.0.get(&AnimationOutputKey {
+            target: AnimationTarget::BinderId(bind_id.to_string()),
+            component,
+            field: None,
+        })
+    };
+    let translation = match output(translation().index()) {
+        Some(AnimationOutput::Vec3 { value, .. }) => Some(*value),
+        _ => None,
+    };
+    let rotation = match output(rotation().index()) {
+        Some(AnimationOutput::Quat { value, .. }) => Some(*value),
+        _ => None,
+    };
+    (translation, rotation)
+}
+
+fn apply_link_poses(world: &mut World, link_poses: &HashMap<EntityId, Mat4>, blend: f32) {
+    for (&bone, pose) in link_poses {
+        let Ok(parent) = world.get(bone, parent()) else {
+            continue;
+        };
+        let local = bone_to_world(world, parent, link_poses).inverse() * *pose;
+        let (_, ragdoll_rotation, ragdoll_translation) = local.to_scale_rotation_translation();
+        if let Ok(translation) = world.get_mut(bone, translation()) {
+            *translation = translation.lerp(ragdoll_translation, blend);
+        }
+        if let Ok(rotation) = world.get_mut(bone, rotation()) {
+            *rotation = rotation.slerp(ragdoll_rotation, blend);
+        }
+    }
+}
+
+/// The world transform of `bone` when the link bones are at their ragdoll poses.
+fn bone_to_world(world: &World, bone: EntityId, link_poses: &HashMap<EntityId, Mat4>) -> Mat4 {
+    if let Some(pose) = link_poses.get(&bone) {
+        return *pose;
+    }
+    let Ok(parent) = world.get(bone, parent()) else {
+        return get_world_transform(world, bone).unwrap_or_default();
+    };
+    // `local_to_parent` is from the last frame for nodes with a translation, rotation or scale
+    let has_trs = world.has_component(bone, translation())
+        || world.has_component(bone, rotation())
+        || world.has_component(bone, scale());
+    let local = if has_trs {
+        Mat4::from_scale_rotation_translation(
+            world.get(bone, scale()).unwrap_or(Vec3::ONE),
+            world.get(bone, rotation()).unwrap_or(Quat::IDENTITY),
+            world.get(bone, translation()).unwrap_or(Vec3::ZERO),
+        )
+    } else {
+        world.get(bone, local_to_parent()).unwrap_or_default()
+    };
+    bone_to_world(world, parent, link_poses) * local
+}
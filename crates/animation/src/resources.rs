@@ -72,6 +72,77 @@ impl AnimationOutput {
             _ => unreachable!(),
         }
     }
+    /// Adds the difference between `layer` and `reference` to this output, scaled by `p`.
+    ///
+    /// Translations are added, while rotations and scales are multiplied.
+    pub fn add_difference(
+        &self,
+        layer: &AnimationOutput,
+        reference: &AnimationOutput,
+        p: f32,
+    ) -> Self {
+        match (self, layer, reference) {
+            (
+                AnimationOutput::Vec3 {
+                    value: base,
+                    component,
+                },
+                AnimationOutput::Vec3 { value: layer, .. },
+                AnimationOutput::Vec3 {
+                    value: reference, ..
+                },
+            ) => AnimationOutput::Vec3 {
+                component: *component,
+                value: if *component == scale() {
+                    *base * Vec3::ONE.lerp(*layer / *reference, p)
+                } else {
+                    *base + (*layer - *reference) * p
+                },
+            },
+
+            (
+                AnimationOutput::Quat {
+                    value: base,
+                    component,
+                },
+                AnimationOutput::Quat { value: layer, .. },
+                AnimationOutput::Quat {
+                    value: reference, ..
+                },
+            ) => {
+                let mut difference = reference.inverse() * *layer;
+                if difference.w < 0. {
+                    difference = difference.neg();
+                }
+                AnimationOutput::Quat {
+                    component: *component,
+                    value: (*base * Quat::IDENTITY.slerp(difference, p)).normalize(),
+                }
+            }
+
+            (
+                AnimationOutput::Vec3Field {
+                    value: base,
+                    field,
+                    component,
+                },
+                AnimationOutput::Vec3Field { value: layer, .. },
+                AnimationOutput::Vec3Field {
+                    value: reference, ..
+                },
+            ) => AnimationOutput::Vec3Field {
+                component: *component,
+                field: *field,
+                value: if *component == scale() {
+                    *base * mix(1., *layer / *reference, p)
+                } else {
+                    *base + (*layer - *reference) * p
+                },
+            },
+
+            _ => unreachable!(),
+        }
+    }
     pub fn as_vec3_value(&self) -> Option<&Vec3> {
         match self {
             AnimationOutput::Vec3 { value, .. } => Some(value),
@@ -246,6 +317,8 @@ pub struct AnimationClip {
     pub tracks: Vec<AnimationTrack>,
     pub start: f32,
     pub end: f32,
+    /// Named markers which are emitted when the playhead crosses them, sorted by time
+    pub events: Vec<AnimationEvent>,
 }
 impl AnimationClip {
     pub fn from_tracks(tracks: Vec<AnimationTrack>) -> Self {
@@ -260,11 +333,17 @@ impl AnimationClip {
             tracks,
             start: 0.,
             end,
+            events: Vec::new(),
         }
     }
     pub fn duration(&self) -> f32 {
         self.end - self.start
     }
+    /// Adds an event to the clip, keeping the events sorted by time
+    pub fn add_event(&mut self, event: AnimationEvent) {
+        let index = self.events.partition_point(|x| x.time <= event.time);
+        self.events.insert(index, event);
+    }
     /// Merge tracks with Vec3Field outputs into Vec3 and Quat tracks
     pub fn merge_field_tracks(&mut self) {
         let mut euler_rotation_tracks = HashMap::new();
@@ -316,6 +395,13 @@ impl AnimationClip {
         }
     }
 }
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct AnimationEvent {
+    /// The time of the event in seconds, relative to the start of the clip
+    pub time: f32,
+    pub name: String,
+}
+
 impl PartialEq for AnimationClip {
     fn eq(&self, other: &Self) -> bool {
         self.id == other.id
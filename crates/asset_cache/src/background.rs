@@ -29,6 +29,10 @@ where
         AssetLoadDropPolicy::KeepLoading
     }
 
+    fn asset_type(&self) -> &'static str {
+        self.0.asset_type()
+    }
+
     async fn load(self, assets: AssetCache) -> T {
         // Short happy path
         // This is needed as JoinHandle does not complete immediately, even if the spawned future
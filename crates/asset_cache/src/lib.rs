@@ -76,6 +76,8 @@ pub(crate) enum ContentState {
     Loaded {
         value: Arc<dyn AssetHolder>,
         check_alive: Arc<dyn Fn() -> bool + Send + Sync>,
+        /// See [`Asset::strong_count`]
+        strong_count: Arc<dyn Fn() -> Option<usize> + Send + Sync>,
     },
     Aborted,
     Expired,
@@ -93,16 +95,36 @@ impl std::fmt::Debug for ContentState {
 }
 
 struct AsyncAssetLoc {
-    #[allow(dead_code)]
     key: AssetKey,
     /// Since there may be multiple tasks for keepalive, keepalive_end should only be called when
     /// **All** keepalive tasks are done.
     keepalive_guard: Weak<KeepaliveGuard>,
     content: ContentState,
     keepalive_task: Option<ChildTask<()>>,
+    /// The keepalive of the last `get`
+    keepalive: AssetKeepalive,
+    asset_type: &'static str,
+    /// Approximate size in bytes of the loaded asset
+    size: u64,
+    last_access: time::Instant,
 }
 
 impl AsyncAssetLoc {
+    /// Returns true if the asset is only loaded because of its keepalive, and may be evicted to
+    /// stay within the memory budget.
+    ///
+    /// Assets which are referenced outside of the cache are not evictable, as evicting them would
+    /// not free any memory.
+    fn is_evictable(&self) -> bool {
+        let ContentState::Loaded { strong_count, .. } = &self.content else {
+            return false;
+        };
+        matches!(self.keepalive, AssetKeepalive::Timeout(_))
+            && self.keepalive_guard.strong_count() > 0
+            // The keepalive task holds the only reference
+            && matches!(strong_count(), Some(count) if count <= 1)
+    }
+
     /// Checks if the resource has been dropped since this method was called last time
     fn state(&mut self) -> AsyncAssetState {
         match &mut self.content {
@@ -167,6 +189,8 @@ pub struct AssetCache {
     pub timeline: Arc<Mutex<AssetsTimeline>>,
     runtime: RuntimeHandle,
     max_keepalive: Option<Duration>,
+    /// Memory budget in bytes per asset type, see [`AsyncAssetKey::asset_type`]
+    budgets: Arc<Mutex<HashMap<String, u64>>>,
     /// stack is used for nested asset loading, to visualize for the timeline who loaded what
     stack: Vec<AssetKey>,
 }
@@ -186,6 +210,7 @@ impl AssetCache {
             timeline: Arc::new(Mutex::new(AssetsTimeline::new())),
             runtime: runtime.clone(),
             max_keepalive,
+            budgets: Default::default(),
             stack: Vec::new(),
         };
         {
@@ -273,6 +298,30 @@ impl AssetCache {
         );
     }
 
+    /// Limits the memory used by assets of `asset_type` which are only kept alive by their
+    /// [`AssetKeepalive::Timeout`]. Once the budget is exceeded, the least recently used assets are
+    /// evicted.
+    ///
+    /// Assets which are referenced outside of the cache, or kept alive
+    /// [`AssetKeepalive::Forever`], are never evicted, and neither are assets whose references
+    /// can't be counted (see [`Asset::strong_count`]). `None` removes the budget.
+    pub fn set_memory_budget(&self, asset_type: impl Into<String>, budget: Option<u64>) {
+        let mut budgets = self.budgets.lock();
+        match budget {
+            Some(budget) => budgets.insert(asset_type.into(), budget),
+            None => budgets.remove(&asset_type.into()),
+        };
+    }
+
+    pub fn memory_budget(&self, asset_type: &str) -> Option<u64> {
+        self.budgets.lock().get(asset_type).copied()
+    }
+
+    /// Returns the cache statistics per asset type
+    pub fn stats(&self) -> HashMap<String, AssetTypeStats> {
+        self.timeline.lock().stats.clone()
+    }
+
     fn clean_up_dropped(&self) {
         let mut async_ = self.async_cache.lock();
         for (key, asset) in &mut *async_ {
@@ -283,6 +332,56 @@ impl AssetCache {
                 _ => {}
             }
         }
+
+        self.evict_over_budget(&mut async_);
+    }
+
+    /// Ends the keepalive of the least recently used assets of each type until the type is within
+    /// its budget, and updates the byte counts of the stats
+    fn evict_over_budget(&self, cache: &mut HashMap<AssetKey, AsyncAssetLoc>) {
+        let budgets = self.budgets.lock();
+        let mut timeline = self.timeline.lock();
+
+        let mut candidates: HashMap<&'static str, Vec<&mut AsyncAssetLoc>> = HashMap::new();
+        for stats in timeline.stats.values_mut() {
+            stats.bytes = 0;
+            stats.keepalive_bytes = 0;
+        }
+        for loc in cache.values_mut() {
+            if !matches!(loc.content, ContentState::Loaded { .. }) {
+                continue;
+            }
+            let stats = timeline
+                .stats
+                .entry(loc.asset_type.to_string())
+                .or_default();
+            stats.bytes += loc.size;
+            if loc.is_evictable() {
+                stats.keepalive_bytes += loc.size;
+                candidates.entry(loc.asset_type).or_default().push(loc);
+            }
+        }
+
+        for (asset_type, mut locs) in candidates {
+            let Some(&budget) = budgets.get(asset_type) else {
+                continue;
+            };
+            let stats = timeline.stats.get_mut(asset_type).unwrap();
+
+            locs.sort_by_key(|loc| loc.last_access);
+            for loc in locs {
+                if stats.keepalive_bytes <= budget {
+                    break;
+                }
+
+                tracing::debug!(key = ?loc.key, size = loc.size, "Evicting asset");
+                // Aborts the keepalive task, which releases its reference to the asset
+                loc.keepalive_task = None;
+                loc.keepalive = AssetKeepalive::None;
+                stats.keepalive_bytes -= loc.size;
+                stats.evictions += 1;
+            }
+        }
     }
 
     /// Returns a snapshot of the current state of the asset
@@ -321,6 +420,7 @@ impl AssetCache {
         let timeline = self.timeline.clone();
 
         let asset_key = AssetKey::new(key.key());
+        let asset_type = key.asset_type();
 
         let load = || {
             // No future loading the value was found.
@@ -329,6 +429,7 @@ impl AssetCache {
             timeline.lock().start_load(
                 asset_key.clone(),
                 key.long_name(),
+                asset_type,
                 self.stack.clone(),
                 keepalive.is_active(),
             );
@@ -364,6 +465,7 @@ impl AssetCache {
         let fut = match cache.entry(asset_key.clone()) {
             Entry::Occupied(mut slot) => {
                 let loc = slot.get_mut();
+                loc.last_access = time::Instant::now();
 
                 match &mut loc.content {
                     ContentState::Loading { fut } => {
@@ -386,6 +488,7 @@ impl AssetCache {
                             .downcast_ref::<<T as Asset>::WeakType>()
                             .unwrap();
                         if let Some(content) = T::from_weak(content) {
+                            timeline.lock().hit(asset_type);
                             return Ok((asset_key, content));
                         }
 
@@ -414,12 +517,17 @@ impl AssetCache {
                     content,
                     keepalive_task,
                     keepalive_guard: Weak::new(),
+                    keepalive: AssetKeepalive::None,
+                    asset_type,
+                    size: 0,
+                    last_access: time::Instant::now(),
                 });
 
                 fut
             }
         };
 
+        timeline.lock().miss(asset_type);
         Err(fut)
     }
 
@@ -445,6 +553,9 @@ impl AssetCache {
             .get_mut(&asset_key)
             .expect("Asset loc was removed during loading");
 
+        loc.last_access = time::Instant::now();
+        loc.keepalive = keepalive.clone();
+
         // Start or replace the keepalive task
 
         let keepalive_ref = value.clone();
@@ -562,6 +673,15 @@ pub trait AsyncAssetKey<T: Asset + Clone + Sync + Send + 'static>:
         AssetLoadDropPolicy::StopLoading
     }
 
+    /// The type of asset used for the stats and memory budgets of the cache, e.g. one of
+    /// [`asset_types`].
+    ///
+    /// Defaults to the type name of the key.
+    fn asset_type(&self) -> &'static str {
+        std::any::type_name::<Self>()
+    }
+
+    /// Approximate size in bytes of the asset in memory, used for the memory budget
     fn cpu_size(&self, _asset: &T) -> Option<u64> {
         None
     }
@@ -569,6 +689,23 @@ pub trait AsyncAssetKey<T: Asset + Clone + Sync + Send + 'static>:
         None
     }
 }
+/// The [`AsyncAssetKey::asset_type`] of the main kinds of assets, which can be given a memory
+/// budget with [`AssetCache::set_memory_budget`]
+pub mod asset_types {
+    /// Downloaded files
+    pub const BYTES: &str = "bytes";
+    /// Decoded images
+    pub const IMAGE: &str = "image";
+    /// Textures on the GPU
+    pub const TEXTURE: &str = "texture";
+    /// Meshes on the GPU
+    pub const MESH: &str = "mesh";
+    /// Models, which reference their meshes and textures
+    pub const MODEL: &str = "model";
+    /// Audio tracks
+    pub const AUDIO: &str = "audio";
+}
+
 #[async_trait]
 pub trait AsyncAssetKeyExt<T: Asset + Clone + Sync + Send + 'static>: AsyncAssetKey<T> {
     fn key(&self) -> String;
@@ -621,6 +758,11 @@ pub trait Asset {
     fn from_weak(weak: &Self::WeakType) -> Option<Self>
     where
         Self: Sized;
+    /// The number of strong references to the asset, or `None` if they can't be counted. Used to
+    /// only evict assets which are not referenced outside of the cache.
+    fn strong_count(_weak: &Self::WeakType) -> Option<usize> {
+        None
+    }
 }
 impl<T: Sync + Send + ?Sized> Asset for Arc<T> {
     type WeakType = Weak<T>;
@@ -630,6 +772,9 @@ impl<T: Sync + Send + ?Sized> Asset for Arc<T> {
     fn from_weak(weak: &Self::WeakType) -> Option<Self> {
         Weak::upgrade(weak)
     }
+    fn strong_count(weak: &Self::WeakType) -> Option<usize> {
+        Some(Weak::strong_count(weak))
+    }
 }
 
 impl<T: Asset + Sync + Send, E: Clone + Sync + Send> Asset for Result<T, E> {
@@ -646,6 +791,12 @@ impl<T: Asset + Sync + Send, E: Clone + Sync + Send> Asset for Result<T, E> {
             Err(err) => Some(Err(err.clone())),
         }
     }
+    fn strong_count(weak: &Self::WeakType) -> Option<usize> {
+        match weak {
+            Ok(val) => T::strong_count(val),
+            Err(_) => Some(0),
+        }
+    }
 }
 
 impl<T: Asset + Sync + Send> Asset for Option<T> {
@@ -659,6 +810,12 @@ impl<T: Asset + Sync + Send> Asset for Option<T> {
             None => Some(None),
         }
     }
+    fn strong_count(weak: &Self::WeakType) -> Option<usize> {
+        match weak {
+            Some(val) => T::strong_count(val),
+            None => Some(0),
+        }
+    }
 }
 
 impl<T0: Asset + Sync + Send, T1: Asset + Sync + Send> Asset for (T0, T1) {
@@ -669,6 +826,9 @@ impl<T0: Asset + Sync + Send, T1: Asset + Sync + Send> Asset for (T0, T1) {
     fn from_weak((a, b): &Self::WeakType) -> Option<Self> {
         Some((T0::from_weak(a)?, T1::from_weak(b)?))
     }
+    fn strong_count((a, b): &Self::WeakType) -> Option<usize> {
+        Some(T0::strong_count(a)?.max(T1::strong_count(b)?))
+    }
 }
 
 impl<T: Asset + Sync + Send> Asset for Vec<T> {
@@ -681,6 +841,11 @@ impl<T: Asset + Sync + Send> Asset for Vec<T> {
             .map(|x| T::from_weak(x))
             .collect::<Option<Vec<_>>>()
     }
+    fn strong_count(v: &Self::WeakType) -> Option<usize> {
+        v.iter()
+            .map(|x| T::strong_count(x))
+            .try_fold(0, |max, count| Some(max.max(count?)))
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -708,6 +873,7 @@ impl AssetLifetime {
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct AssetTimeline {
     pub long_name: String,
+    pub asset_type: String,
     pub stack: Vec<AssetKey>,
     pub cpu_size: Option<u64>,
     pub gpu_size: Option<u64>,
@@ -729,9 +895,46 @@ impl AssetTimeline {
     }
 }
 
+/// Cache statistics for one type of asset
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AssetTypeStats {
+    /// Requests for an asset which was already loaded
+    pub hits: u64,
+    /// Requests which had to wait for the asset to load
+    pub misses: u64,
+    /// Approximate size in bytes of the loaded assets
+    pub bytes: u64,
+    /// Approximate size in bytes of the assets which are only loaded because of their keepalive
+    pub keepalive_bytes: u64,
+    /// Assets whose keepalive was ended early to stay within the memory budget
+    pub evictions: u64,
+    pub loads: u64,
+    pub total_load_time: Duration,
+    pub max_load_time: Duration,
+}
+impl AssetTypeStats {
+    pub fn hit_rate(&self) -> f32 {
+        let total = self.hits + self.misses;
+        if total == 0 {
+            0.
+        } else {
+            self.hits as f32 / total as f32
+        }
+    }
+    pub fn mean_load_time(&self) -> Duration {
+        if self.loads == 0 {
+            Duration::ZERO
+        } else {
+            self.total_load_time / self.loads as u32
+        }
+    }
+}
+
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct AssetsTimeline {
     pub assets: HashMap<AssetKey, AssetTimeline>,
+    /// Statistics per asset type, see [`AsyncAssetKey::asset_type`]
+    pub stats: HashMap<String, AssetTypeStats>,
     pub start_time: chrono::DateTime<chrono::Utc>,
 }
 
@@ -739,6 +942,7 @@ impl AssetsTimeline {
     pub fn new() -> Self {
         Self {
             assets: Default::default(),
+            stats: Default::default(),
             start_time: chrono::Utc::now(),
         }
     }
@@ -750,11 +954,13 @@ impl AssetsTimeline {
         &mut self,
         key: AssetKey,
         long_name: String,
+        asset_type: &str,
         stack: Vec<AssetKey>,
         keepalive: bool,
     ) {
         let asset = self.assets.entry(key).or_default();
         asset.long_name = long_name;
+        asset.asset_type = asset_type.to_string();
         asset.stack = stack;
         asset.is_alive = true;
         asset.lifetimes.push(AssetLifetime {
@@ -777,9 +983,24 @@ impl AssetsTimeline {
     }
     fn end_load(&mut self, key: &AssetKey, cpu_size: Option<u64>, gpu_size: Option<u64>) {
         let asset = self.assets.get_mut(key).unwrap();
-        asset.lifetimes.last_mut().unwrap().end_load = Some(chrono::Utc::now());
+        let lifetime = asset.lifetimes.last_mut().unwrap();
+        let now = chrono::Utc::now();
+        lifetime.end_load = Some(now);
         asset.cpu_size = cpu_size;
         asset.gpu_size = gpu_size;
+
+        let load_time = (now - lifetime.start_load).to_std().unwrap_or_default();
+        let stats = self.stats.entry(asset.asset_type.clone()).or_default();
+        stats.loads += 1;
+        stats.total_load_time += load_time;
+        stats.max_load_time = stats.max_load_time.max(load_time);
+    }
+
+    fn hit(&mut self, asset_type: &str) {
+        self.stats.entry(asset_type.to_string()).or_default().hits += 1;
+    }
+    fn miss(&mut self, asset_type: &str) {
+        self.stats.entry(asset_type.to_string()).or_default().misses += 1;
     }
 
     fn keepalive_end(&mut self, key: &AssetKey) {
@@ -841,6 +1062,7 @@ where
             let cpu_size = p.key.cpu_size(&res);
             let gpu_size = p.key.gpu_size(&res);
             p.timeline.lock().end_load(p.asset_key, cpu_size, gpu_size);
+            let size = cpu_size.unwrap_or_default() + gpu_size.unwrap_or_default();
 
             let weak_res = Arc::new(T::to_weak(&res)) as Arc<dyn AssetHolder>;
 
@@ -848,6 +1070,10 @@ where
                 let weak_res = T::to_weak(&res);
                 move || T::from_weak(&weak_res).is_some()
             });
+            let strong_count = Arc::new({
+                let weak_res = T::to_weak(&res);
+                move || T::strong_count(&weak_res)
+            });
 
             // Type erase
             let value = Arc::new(res) as Arc<dyn AssetHolder>;
@@ -863,7 +1089,9 @@ where
             loc.content = ContentState::Loaded {
                 value: weak_res,
                 check_alive,
+                strong_count,
             };
+            loc.size = size;
 
             Poll::Ready(LoadPayload {
                 asset_key: p.asset_key.clone(),
@@ -1000,4 +1228,50 @@ mod test {
             assert_eq!(val, 3);
         }
     }
+
+    #[tokio::test]
+    async fn memory_budget() {
+        #[derive(Debug, Clone)]
+        struct SizedKey(u32);
+        #[async_trait]
+        impl AsyncAssetKey<Arc<u32>> for SizedKey {
+            async fn load(self, _assets: AssetCache) -> Arc<u32> {
+                Arc::new(self.0)
+            }
+            fn asset_type(&self) -> &'static str {
+                "sized"
+            }
+            fn cpu_size(&self, _asset: &Arc<u32>) -> Option<u64> {
+                Some(100)
+            }
+        }
+
+        let assets = AssetCache::new(tokio::runtime::Handle::current());
+        assets.set_memory_budget("sized", Some(150));
+
+        // Referenced assets are not evicted, as that would not free their memory
+        let referenced = SizedKey(0).get(&assets).await;
+        for i in 1..4 {
+            SizedKey(i).get(&assets).await;
+        }
+
+        assets.clean_up_dropped();
+        // Let the aborted keepalive tasks release their references
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assets.clean_up_dropped();
+
+        assert!(SizedKey(0).is_loaded(&assets).is_some());
+        assert!(SizedKey(1).is_loaded(&assets).is_none());
+        assert!(SizedKey(2).is_loaded(&assets).is_none());
+        assert!(SizedKey(3).is_loaded(&assets).is_some());
+
+        let stats = &assets.stats()["sized"];
+        assert_eq!(stats.loads, 4);
+        assert_eq!(stats.misses, 4);
+        assert_eq!(stats.hits, 0);
+        assert_eq!(stats.evictions, 2);
+        assert_eq!(stats.bytes, 200);
+        assert_eq!(stats.keepalive_bytes, 100);
+        drop(referenced);
+    }
 }
@@ -55,6 +55,24 @@ impl ElementComponent for AssetTimelineVisualizer {
             }
         }
         let total_roots_gpu_size = roots.values().map(|x| x.1).sum::<u64>();
+        let stats = self
+            .timeline
+            .stats
+            .iter()
+            .sorted_by_key(|(_, stats)| std::cmp::Reverse(stats.bytes))
+            .map(|(asset_type, stats)| {
+                Text::el(format!(
+                    "{asset_type}: {} ({} kept alive), {} hits, {} misses, {} evictions, {} mean load",
+                    to_byte_unit(stats.bytes),
+                    to_byte_unit(stats.keepalive_bytes),
+                    stats.hits,
+                    stats.misses,
+                    stats.evictions,
+                    pretty_duration(stats.mean_load_time())
+                ))
+                .small_style()
+            })
+            .collect_vec();
         let timeline = Arc::new(self.timeline);
         let mut children = roots
             .into_iter()
@@ -166,6 +184,7 @@ impl ElementComponent for AssetTimelineVisualizer {
             ])
             .keyboard(),
         );
+        children.insert(1, FlowColumn::el(stats));
         FlowColumn::el(children).with(fit_horizontal(), Fit::Parent)
     }
 }
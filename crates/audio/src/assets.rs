@@ -1,13 +1,14 @@
 use std::sync::Arc;
 
 use ambient_native_std::{
-    asset_cache::{AsyncAssetKey, AsyncAssetKeyExt},
+    asset_cache::{asset_types, AsyncAssetKey, AsyncAssetKeyExt},
     asset_url::{AbsAssetUrl, AssetType, GetAssetType},
     download_asset::BytesFromUrl,
 };
 use async_trait::*;
 
 use crate::{
+    streaming_source::StreamingTrack,
     track::{AudioFormat, Track},
     vorbis::VorbisTrack,
     Error,
@@ -26,15 +27,7 @@ impl AsyncAssetKey<Result<Arc<Track>, Arc<Error>>> for AudioFromUrl {
     where
         Self: 'async_trait,
     {
-        let format = match self.url.extension().as_ref().map(|x| x as &str) {
-            Some("wav") => AudioFormat::Wav,
-            Some("ogg") => AudioFormat::Vorbis,
-            v => {
-                return Err(Arc::new(Error::UnsupportedFormat(
-                    v.unwrap_or_default().to_string(),
-                )))
-            }
-        };
+        let format = audio_format(&self.url)?;
         let bytes: Arc<[u8]> = BytesFromUrl::new(self.url.clone(), true)
             .get(&assets)
             .await
@@ -43,6 +36,60 @@ impl AsyncAssetKey<Result<Arc<Track>, Arc<Error>>> for AudioFromUrl {
 
         Ok(Arc::new(Track::from_format(bytes, format)?))
     }
+
+    fn asset_type(&self) -> &'static str {
+        asset_types::AUDIO
+    }
+
+    fn cpu_size(&self, asset: &Result<Arc<Track>, Arc<Error>>) -> Option<u64> {
+        asset.as_ref().ok().map(|x| x.size_in_bytes() as u64)
+    }
+}
+
+fn audio_format(url: &AbsAssetUrl) -> Result<AudioFormat, Arc<Error>> {
+    match url.extension().as_ref().map(|x| x as &str) {
+        Some("wav") => Ok(AudioFormat::Wav),
+        Some("ogg") => Ok(AudioFormat::Vorbis),
+        v => Err(Arc::new(Error::UnsupportedFormat(
+            v.unwrap_or_default().to_string(),
+        ))),
+    }
+}
+
+/// Like [`AudioFromUrl`], but the track is streamed while it plays rather than downloaded up
+/// front. This is meant for long tracks like music.
+///
+/// Streaming is not supported on the web, where the whole track is downloaded instead.
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct StreamingAudioFromUrl {
+    pub url: AbsAssetUrl,
+}
+
+#[async_trait]
+impl AsyncAssetKey<Result<Arc<Track>, Arc<Error>>> for StreamingAudioFromUrl {
+    async fn load(
+        self,
+        assets: ambient_native_std::asset_cache::AssetCache,
+    ) -> Result<Arc<Track>, Arc<Error>>
+    where
+        Self: 'async_trait,
+    {
+        if cfg!(target_os = "unknown") {
+            return AudioFromUrl { url: self.url }.get(&assets).await;
+        }
+
+        let format = audio_format(&self.url)?;
+        let track = StreamingTrack::open(&assets, self.url, format).await?;
+        Ok(Arc::new(Track::Streaming(track)))
+    }
+
+    fn asset_type(&self) -> &'static str {
+        asset_types::AUDIO
+    }
+
+    fn cpu_size(&self, asset: &Result<Arc<Track>, Arc<Error>>) -> Option<u64> {
+        asset.as_ref().ok().map(|x| x.size_in_bytes() as u64)
+    }
 }
 
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
@@ -67,6 +114,14 @@ impl AsyncAssetKey<Result<Arc<VorbisTrack>, Arc<Error>>> for VorbisFromUrl {
 
         Ok(Arc::new(VorbisTrack::new(bytes)?))
     }
+
+    fn asset_type(&self) -> &'static str {
+        asset_types::AUDIO
+    }
+
+    fn cpu_size(&self, asset: &Result<Arc<VorbisTrack>, Arc<Error>>) -> Option<u64> {
+        asset.as_ref().ok().map(|x| x.size_in_bytes() as u64)
+    }
 }
 
 impl GetAssetType for VorbisTrack {
@@ -112,6 +112,115 @@ impl TransferFunction for Bpf {
     }
 }
 
+/// Boosts or cuts the frequencies below `freq`
+#[derive(Debug, Clone, PartialEq)]
+pub struct LowShelf {
+    pub freq: f32,
+    // In dB
+    pub gain: f32,
+}
+
+impl TransferFunction for LowShelf {
+    fn get_coeffs(&self, sample_freq: SampleRate) -> BltCoeffs {
+        let w0 = TAU * self.freq / sample_freq as f32;
+        let re = w0.cos();
+        let a = 10f32.powf(self.gain / 40.0);
+        // Shelf slope of 1
+        let alpha = w0.sin() * 0.5 * 2f32.sqrt();
+        let k = 2.0 * a.sqrt() * alpha;
+
+        let b0 = a * ((a + 1.0) - (a - 1.0) * re + k);
+        let b1 = 2.0 * a * ((a - 1.0) - (a + 1.0) * re);
+        let b2 = a * ((a + 1.0) - (a - 1.0) * re - k);
+
+        let a0 = (a + 1.0) + (a - 1.0) * re + k;
+        let a1 = -2.0 * ((a - 1.0) + (a + 1.0) * re);
+        let a2 = (a + 1.0) + (a - 1.0) * re - k;
+
+        // Normalization step
+        BltCoeffs {
+            b0: b0 / a0,
+            b1: b1 / a0,
+            b2: b2 / a0,
+            a1: a1 / a0,
+            a2: a2 / a0,
+        }
+    }
+}
+
+/// Boosts or cuts the frequencies above `freq`
+#[derive(Debug, Clone, PartialEq)]
+pub struct HighShelf {
+    pub freq: f32,
+    // In dB
+    pub gain: f32,
+}
+
+impl TransferFunction for HighShelf {
+    fn get_coeffs(&self, sample_freq: SampleRate) -> BltCoeffs {
+        let w0 = TAU * self.freq / sample_freq as f32;
+        let re = w0.cos();
+        let a = 10f32.powf(self.gain / 40.0);
+        // Shelf slope of 1
+        let alpha = w0.sin() * 0.5 * 2f32.sqrt();
+        let k = 2.0 * a.sqrt() * alpha;
+
+        let b0 = a * ((a + 1.0) + (a - 1.0) * re + k);
+        let b1 = -2.0 * a * ((a - 1.0) + (a + 1.0) * re);
+        let b2 = a * ((a + 1.0) + (a - 1.0) * re - k);
+
+        let a0 = (a + 1.0) - (a - 1.0) * re + k;
+        let a1 = 2.0 * ((a - 1.0) - (a + 1.0) * re);
+        let a2 = (a + 1.0) - (a - 1.0) * re - k;
+
+        // Normalization step
+        BltCoeffs {
+            b0: b0 / a0,
+            b1: b1 / a0,
+            b2: b2 / a0,
+            a1: a1 / a0,
+            a2: a2 / a0,
+        }
+    }
+}
+
+/// Boosts or cuts the frequencies around `freq`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Peak {
+    pub freq: f32,
+    // In octaves
+    pub bandwidth: f32,
+    // In dB
+    pub gain: f32,
+}
+
+impl TransferFunction for Peak {
+    fn get_coeffs(&self, sample_freq: SampleRate) -> BltCoeffs {
+        let w0 = TAU * self.freq / sample_freq as f32;
+        let re = w0.cos();
+        let a = 10f32.powf(self.gain / 40.0);
+
+        let alpha = w0.sin() * (2f32.ln() / 2.0 * self.bandwidth * w0 / w0.sin()).sinh();
+
+        let b0 = 1.0 + alpha * a;
+        let b1 = -2.0 * re;
+        let b2 = 1.0 - alpha * a;
+
+        let a0 = 1.0 + alpha / a;
+        let a1 = -2.0 * re;
+        let a2 = 1.0 - alpha / a;
+
+        // Normalization step
+        BltCoeffs {
+            b0: b0 / a0,
+            b1: b1 / a0,
+            b2: b2 / a0,
+            a1: a1 / a0,
+            a2: a2 / a0,
+        }
+    }
+}
+
 pub trait TransferFunction {
     fn get_coeffs(&self, sample_freq: SampleRate) -> BltCoeffs;
 }
@@ -0,0 +1,469 @@
+use std::{fmt::Display, str::FromStr, sync::Arc};
+
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    blt::{HighShelf, Hpf, LowShelf, Lpf, Peak},
+    error::Error,
+    source::compressor::time_to_coeff,
+    value::Constant,
+    Frame, SampleRate, Source,
+};
+
+/// Time it takes for volume changes of a bus to be applied, in seconds
+const VOLUME_SMOOTHING: f32 = 0.01;
+
+/// A named group of sounds which share a volume, effects and ducking.
+///
+/// Every bus other than `Master` is mixed into `Master`, which is played to the output.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum BusId {
+    #[default]
+    Master,
+    Music,
+    Sfx,
+    Voice,
+    Ui,
+}
+
+impl BusId {
+    pub const ALL: [BusId; 5] = [
+        BusId::Master,
+        BusId::Music,
+        BusId::Sfx,
+        BusId::Voice,
+        BusId::Ui,
+    ];
+    pub const COUNT: usize = Self::ALL.len();
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            BusId::Master => "master",
+            BusId::Music => "music",
+            BusId::Sfx => "sfx",
+            BusId::Voice => "voice",
+            BusId::Ui => "ui",
+        }
+    }
+
+    #[inline]
+    pub(crate) fn index(self) -> usize {
+        self as usize
+    }
+}
+
+impl Display for BusId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for BusId {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        BusId::ALL
+            .into_iter()
+            .find(|bus| bus.name().eq_ignore_ascii_case(s))
+            .ok_or_else(|| Error::UnknownBus(s.to_string()))
+    }
+}
+
+/// An effect in the effect chain of a bus
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum BusEffect {
+    /// `room_size` and `damping` are in `[0, 1]`. `wet` is the amount of reverb mixed with the
+    /// dry signal.
+    Reverb {
+        room_size: f32,
+        damping: f32,
+        wet: f32,
+    },
+    /// `threshold` is in dB. `attack` and `release` are in seconds.
+    Compressor {
+        threshold: f32,
+        ratio: f32,
+        attack: f32,
+        release: f32,
+    },
+    /// Three band equalizer. The gains are in dB, around 250Hz, 1kHz and 4kHz.
+    Equalizer {
+        low: f32,
+        mid: f32,
+        high: f32,
+    },
+    /// `time` is in seconds. `feedback` is the gain of each successive echo.
+    Delay {
+        time: f32,
+        feedback: f32,
+        wet: f32,
+    },
+    LowPass {
+        freq: f32,
+        bandwidth: f32,
+    },
+    HighPass {
+        freq: f32,
+        bandwidth: f32,
+    },
+}
+
+impl BusEffect {
+    fn apply(self, source: Box<dyn Source>) -> Box<dyn Source> {
+        match self {
+            BusEffect::Reverb {
+                room_size,
+                damping,
+                wet,
+            } => Box::new(source.reverb(room_size, damping, wet)),
+            BusEffect::Compressor {
+                threshold,
+                ratio,
+                attack,
+                release,
+            } => Box::new(source.compressor(threshold, ratio, attack, release)),
+            BusEffect::Equalizer { low, mid, high } => Box::new(
+                source
+                    .blt(Constant(LowShelf {
+                        freq: 250.0,
+                        gain: low,
+                    }))
+                    .blt(Constant(Peak {
+                        freq: 1000.0,
+                        bandwidth: 2.0,
+                        gain: mid,
+                    }))
+                    .blt(Constant(HighShelf {
+                        freq: 4000.0,
+                        gain: high,
+                    })),
+            ),
+            BusEffect::Delay {
+                time,
+                feedback,
+                wet,
+            } => Box::new(source.delay(time, feedback, wet)),
+            BusEffect::LowPass { freq, bandwidth } => {
+                Box::new(source.blt(Constant(Lpf { freq, bandwidth })))
+            }
+            BusEffect::HighPass { freq, bandwidth } => {
+                Box::new(source.blt(Constant(Hpf { freq, bandwidth })))
+            }
+        }
+    }
+}
+
+/// Lowers the volume of a bus while another bus is playing, e.g. to keep dialogue audible over
+/// music.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Ducking {
+    /// The bus which ducks this bus when it plays
+    pub key: BusId,
+    /// How much the volume is lowered when fully ducked, in `[0, 1]`
+    pub amount: f32,
+    /// The amplitude of the key bus at which this bus is fully ducked
+    pub threshold: f32,
+    /// Time to duck, in seconds
+    pub attack: f32,
+    /// Time to recover once the key bus is quiet, in seconds
+    pub release: f32,
+}
+
+impl Default for Ducking {
+    fn default() -> Self {
+        Self {
+            key: BusId::Voice,
+            amount: 0.7,
+            threshold: 0.05,
+            attack: 0.05,
+            release: 0.5,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BusSettings {
+    pub volume: f32,
+    pub muted: bool,
+    /// Applied in order, before the volume
+    pub effects: Vec<BusEffect>,
+    /// Ignored for the master bus
+    pub ducking: Option<Ducking>,
+}
+
+impl Default for BusSettings {
+    fn default() -> Self {
+        Self {
+            volume: 1.0,
+            muted: false,
+            effects: Vec::new(),
+            ducking: None,
+        }
+    }
+}
+
+/// Feeds the sounds of a bus into its effect chain, one frame at a time
+struct BusInput {
+    frame: Arc<Mutex<Frame>>,
+    sample_rate: SampleRate,
+}
+
+impl Source for BusInput {
+    fn next_sample(&mut self) -> Option<Frame> {
+        Some(*self.frame.lock())
+    }
+
+    fn sample_rate(&self) -> SampleRate {
+        self.sample_rate
+    }
+
+    fn sample_count(&self) -> Option<u64> {
+        None
+    }
+}
+
+struct Bus {
+    settings: BusSettings,
+    input: Arc<Mutex<Frame>>,
+    chain: Box<dyn Source>,
+    gain: f32,
+    /// Smoothed amplitude of the output, which ducks other buses
+    envelope: f32,
+    duck_gain: f32,
+    duck_attack: f32,
+    duck_release: f32,
+    output: Frame,
+}
+
+impl Bus {
+    fn new(sample_rate: SampleRate) -> Self {
+        let input = Arc::new(Mutex::new(Frame::ZERO));
+        Self {
+            settings: BusSettings::default(),
+            chain: Box::new(BusInput {
+                frame: input.clone(),
+                sample_rate,
+            }),
+            input,
+            gain: 1.0,
+            envelope: 0.0,
+            duck_gain: 1.0,
+            duck_attack: 0.0,
+            duck_release: 0.0,
+            output: Frame::ZERO,
+        }
+    }
+
+    fn configure(&mut self, sample_rate: SampleRate, settings: BusSettings) {
+        if settings.effects != self.settings.effects {
+            let input: Box<dyn Source> = Box::new(BusInput {
+                frame: self.input.clone(),
+                sample_rate,
+            });
+            self.chain = settings
+                .effects
+                .iter()
+                .fold(input, |source, effect| effect.apply(source));
+        }
+        if let Some(ducking) = &settings.ducking {
+            self.duck_attack = time_to_coeff(ducking.attack, sample_rate);
+            self.duck_release = time_to_coeff(ducking.release, sample_rate);
+        }
+        self.settings = settings;
+    }
+
+    fn process(&mut self, input: Frame, volume_coeff: f32, envelope_coeff: f32) {
+        *self.input.lock() = input;
+        let output = self.chain.next_sample().unwrap_or_default();
+
+        let target = if self.settings.muted {
+            0.0
+        } else {
+            self.settings.volume
+        };
+        self.gain = volume_coeff * self.gain + (1.0 - volume_coeff) * target;
+        self.output = output * self.gain;
+
+        let level = self.output.abs().max_element();
+        self.envelope = if level > self.envelope {
+            level
+        } else {
+            envelope_coeff * self.envelope + (1.0 - envelope_coeff) * level
+        };
+    }
+
+    fn duck(&mut self, key_envelope: f32) {
+        let Some(ducking) = &self.settings.ducking else {
+            self.duck_gain = 1.0;
+            return;
+        };
+        let keyed = (key_envelope / ducking.threshold.max(1e-6)).clamp(0.0, 1.0);
+        let target = 1.0 - ducking.amount.clamp(0.0, 1.0) * keyed;
+        let coeff = if target < self.duck_gain {
+            self.duck_attack
+        } else {
+            self.duck_release
+        };
+        self.duck_gain = coeff * self.duck_gain + (1.0 - coeff) * target;
+    }
+}
+
+/// The buses of a mixer
+pub(crate) struct Buses {
+    sample_rate: SampleRate,
+    buses: Vec<Bus>,
+    volume_coeff: f32,
+    envelope_coeff: f32,
+    /// Reused buffers of the sounds of each bus
+    inputs: Vec<Vec<Frame>>,
+}
+
+impl Buses {
+    pub fn new(sample_rate: SampleRate) -> Self {
+        Self {
+            sample_rate,
+            buses: BusId::ALL.iter().map(|_| Bus::new(sample_rate)).collect(),
+            volume_coeff: time_to_coeff(VOLUME_SMOOTHING, sample_rate),
+            envelope_coeff: time_to_coeff(VOLUME_SMOOTHING, sample_rate),
+            inputs: vec![Vec::new(); BusId::COUNT],
+        }
+    }
+
+    pub fn settings(&self, bus: BusId) -> &BusSettings {
+        &self.buses[bus.index()].settings
+    }
+
+    pub fn configure(&mut self, bus: BusId, settings: BusSettings) {
+        self.buses[bus.index()].configure(self.sample_rate, settings);
+    }
+
+    /// Takes a zeroed buffer of `len` frames for each bus, which must be given back with
+    /// [`Buses::put_inputs`]
+    pub fn take_inputs(&mut self, len: usize) -> Vec<Vec<Frame>> {
+        let mut inputs = std::mem::take(&mut self.inputs);
+        inputs.resize(BusId::COUNT, Vec::new());
+        for input in &mut inputs {
+            input.clear();
+            input.resize(len, Frame::ZERO);
+        }
+        inputs
+    }
+
+    pub fn put_inputs(&mut self, inputs: Vec<Vec<Frame>>) {
+        self.inputs = inputs;
+    }
+
+    /// Mixes a frame of the sounds of each bus into the output frame of the master bus
+    pub fn mix(&mut self, inputs: [Frame; BusId::COUNT]) -> Frame {
+        let (master, children) = self.buses.split_first_mut().unwrap();
+        for (bus, input) in children.iter_mut().zip(&inputs[1..]) {
+            bus.process(*input, self.volume_coeff, self.envelope_coeff);
+        }
+
+        let mut master_input = inputs[BusId::Master.index()];
+        for i in 0..children.len() {
+            let key_envelope = children[i]
+                .settings
+                .ducking
+                .filter(|ducking| ducking.key != BusId::Master)
+                .map(|ducking| children[ducking.key.index() - 1].envelope)
+                .unwrap_or_default();
+            let bus = &mut children[i];
+            bus.duck(key_envelope);
+            master_input += bus.output * bus.duck_gain;
+        }
+
+        master.process(master_input, self.volume_coeff, self.envelope_coeff);
+        master.output
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const SAMPLE_RATE: SampleRate = 1000;
+
+    fn mix_constant(buses: &mut Buses, inputs: [Frame; BusId::COUNT], frames: usize) -> Frame {
+        (0..frames).fold(Frame::ZERO, |_, _| buses.mix(inputs))
+    }
+
+    fn on(bus: BusId, frame: Frame) -> [Frame; BusId::COUNT] {
+        let mut inputs = [Frame::ZERO; BusId::COUNT];
+        inputs[bus.index()] = frame;
+        inputs
+    }
+
+    #[test]
+    fn volume_and_mute() {
+        let mut buses = Buses::new(SAMPLE_RATE);
+        buses.configure(
+            BusId::Music,
+            BusSettings {
+                volume: 0.5,
+                ..Default::default()
+            },
+        );
+        buses.configure(
+            BusId::Master,
+            BusSettings {
+                volume: 0.5,
+                ..Default::default()
+            },
+        );
+        let out = mix_constant(&mut buses, on(BusId::Music, Frame::ONE), 1000);
+        assert!(out.abs_diff_eq(Frame::splat(0.25), 1e-4), "{out}");
+
+        buses.configure(
+            BusId::Music,
+            BusSettings {
+                muted: true,
+                ..Default::default()
+            },
+        );
+        let out = mix_constant(&mut buses, on(BusId::Music, Frame::ONE), 1000);
+        assert!(out.abs_diff_eq(Frame::ZERO, 1e-4), "{out}");
+    }
+
+    #[test]
+    fn ducking() {
+        let mut buses = Buses::new(SAMPLE_RATE);
+        buses.configure(
+            BusId::Music,
+            BusSettings {
+                ducking: Some(Ducking {
+                    key: BusId::Voice,
+                    amount: 0.5,
+                    threshold: 0.5,
+                    attack: 0.01,
+                    release: 0.01,
+                }),
+                ..Default::default()
+            },
+        );
+
+        let music = Frame::splat(0.1);
+        let out = mix_constant(&mut buses, on(BusId::Music, music), 1000);
+        assert!(out.abs_diff_eq(music, 1e-4), "{out}");
+
+        let mut inputs = on(BusId::Music, music);
+        inputs[BusId::Voice.index()] = Frame::ONE;
+        let out = mix_constant(&mut buses, inputs, 1000);
+        assert!(out.abs_diff_eq(music * 0.5 + Frame::ONE, 1e-4), "{out}");
+
+        let out = mix_constant(&mut buses, on(BusId::Music, music), 1000);
+        assert!(out.abs_diff_eq(music, 1e-4), "{out}");
+    }
+
+    #[test]
+    fn bus_names() {
+        for bus in BusId::ALL {
+            assert_eq!(bus.name().parse::<BusId>().unwrap(), bus);
+        }
+        assert!("drums".parse::<BusId>().is_err());
+    }
+}
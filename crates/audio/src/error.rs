@@ -33,6 +33,8 @@ pub enum Error {
     InvalidChannelCount(u16),
     #[error("Failed to load IR sphere for spatial audio")]
     IrSphere(hrtf::IrSphereError),
+    #[error("Unknown audio bus {0:?}. Expected one of master, music, sfx, voice or ui")]
+    UnknownBus(String),
 
     #[error("Too many channels in ogg stream. Expected a maximum of 2 channels, found {0}")]
     TooManyOggChannels(usize),
@@ -1,6 +1,9 @@
 mod assets;
+mod bus;
 mod error;
 mod mixer;
+mod offline;
+mod ranged;
 // mod sink;
 mod stream;
 
@@ -18,8 +21,10 @@ pub mod vorbis;
 pub mod wav;
 
 pub use assets::*;
+pub use bus::{BusEffect, BusId, BusSettings, Ducking};
 pub use error::*;
 pub use mixer::*;
+pub use offline::*;
 // pub use sink::*;
 pub use source::*;
 pub use spatial::*;
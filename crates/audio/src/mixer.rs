@@ -10,8 +10,9 @@ use parking_lot::Mutex;
 use slotmap::{new_key_type, SlotMap};
 
 use crate::{
+    bus::Buses,
     signal::{AsyncSignal, BlockingSignal, Signal},
-    Frame, SampleConversion, SampleRate, Source,
+    BusId, BusSettings, Frame, SampleConversion, SampleRate, Source,
 };
 
 new_key_type! {
@@ -24,6 +25,7 @@ struct PlayingSound {
     #[allow(dead_code)]
     cursor: usize,
     source: Box<dyn Source>,
+    bus: BusId,
 }
 
 /// Handle to a playing sound
@@ -104,6 +106,7 @@ pub struct AudioMixerInner {
     pub sample_rate: SampleRate,
     waiters: Mutex<SignalVec>,
     sources: Mutex<SlotMap<SoundId, PlayingSound>>,
+    buses: Mutex<Buses>,
 }
 
 impl std::fmt::Debug for AudioMixerInner {
@@ -122,6 +125,7 @@ impl AudioMixer {
                 sample_rate,
                 sources: Mutex::default(),
                 waiters: Default::default(),
+                buses: Mutex::new(Buses::new(sample_rate)),
             }),
         }
     }
@@ -132,8 +136,14 @@ impl AudioMixer {
         }
     }
 
-    /// Play a source on the mixer, returning a handle which can be used to control it
+    /// Play a source on the master bus of the mixer, returning a handle which can be used to
+    /// control it
     pub fn play<S: Source + 'static>(&self, source: S) -> Sound {
+        self.play_on(BusId::Master, source)
+    }
+
+    /// Play a source on a bus of the mixer, returning a handle which can be used to control it
+    pub fn play_on<S: Source + 'static>(&self, bus: BusId, source: S) -> Sound {
         let sample_rate = source.sample_rate();
 
         let source = if sample_rate == self.inner.sample_rate {
@@ -142,11 +152,11 @@ impl AudioMixer {
             Box::new(SampleConversion::new(source, self.inner.sample_rate as _)) as Box<dyn Source>
         };
 
-        let id = self
-            .inner
-            .sources
-            .lock()
-            .insert(PlayingSound { cursor: 0, source });
+        let id = self.inner.sources.lock().insert(PlayingSound {
+            cursor: 0,
+            source,
+            bus,
+        });
         Sound {
             id,
             mixer: self.clone(),
@@ -157,6 +167,37 @@ impl AudioMixer {
         self.inner.sources.lock().remove(key);
     }
 
+    /// Returns the volume, effects and ducking of a bus
+    pub fn bus(&self, bus: BusId) -> BusSettings {
+        self.inner.buses.lock().settings(bus).clone()
+    }
+
+    /// Sets the volume, effects and ducking of a bus.
+    ///
+    /// The effect chain is only rebuilt if the effects changed, so volume changes don't cut off
+    /// e.g. a reverb tail.
+    pub fn set_bus(&self, bus: BusId, settings: BusSettings) {
+        self.inner.buses.lock().configure(bus, settings);
+    }
+
+    pub fn set_bus_volume(&self, bus: BusId, volume: f32) {
+        let mut buses = self.inner.buses.lock();
+        let settings = BusSettings {
+            volume,
+            ..buses.settings(bus).clone()
+        };
+        buses.configure(bus, settings);
+    }
+
+    pub fn set_bus_muted(&self, bus: BusId, muted: bool) {
+        let mut buses = self.inner.buses.lock();
+        let settings = BusSettings {
+            muted,
+            ..buses.settings(bus).clone()
+        };
+        buses.configure(bus, settings);
+    }
+
     fn notify_sound_waiters(&self, id: SoundId) {
         // Wake the wakers which are parked on this id, and remove them from the waiting list
         self.inner.waiters.lock().retain_mut(|(sound_id, signal)| {
@@ -178,7 +219,7 @@ impl AudioMixer {
 impl Source for AudioMixer {
     fn next_sample(&mut self) -> Option<crate::Frame> {
         let mut sources = self.inner.sources.lock();
-        let mut res = Frame::ZERO;
+        let mut inputs = [Frame::ZERO; BusId::COUNT];
         sources.retain(|id, source| {
             let sample = match source.source.next_sample() {
                 Some(v) => v,
@@ -187,12 +228,12 @@ impl Source for AudioMixer {
                     return false;
                 }
             };
-            res += sample;
+            inputs[source.bus.index()] += sample;
 
             true
         });
 
-        Some(res)
+        Some(self.inner.buses.lock().mix(inputs))
     }
 
     fn sample_rate(&self) -> crate::SampleRate {
@@ -201,8 +242,12 @@ impl Source for AudioMixer {
 
     fn sample_buffered(&mut self, output: &mut [Frame]) -> usize {
         let mut sources = self.inner.sources.lock();
+        let mut buses = self.inner.buses.lock();
+        let mut inputs = buses.take_inputs(output.len());
         sources.retain(|id, source| {
-            let written = source.source.sample_buffered(output);
+            let written = source
+                .source
+                .sample_buffered(&mut inputs[source.bus.index()]);
 
             // No more samples in source
             if written != output.len() {
@@ -213,6 +258,11 @@ impl Source for AudioMixer {
             true
         });
 
+        for (i, frame) in output.iter_mut().enumerate() {
+            *frame += buses.mix(std::array::from_fn(|bus| inputs[bus][i]));
+        }
+        buses.put_inputs(inputs);
+
         output.len()
     }
 
@@ -0,0 +1,260 @@
+use std::{fs::File, io::BufWriter, path::Path, time::Duration};
+
+use hound::{SampleFormat, WavSpec, WavWriter};
+
+use crate::{error::Result, source::to_sample_index, AudioMixer, Error, Frame, SampleRate, Source};
+
+enum Output {
+    Buffer(Vec<Frame>),
+    Wav(WavWriter<BufWriter<File>>),
+}
+
+/// How many blocks [`OfflineAudioStream::advance_block`] renders per second by default
+pub const DEFAULT_BLOCKS_PER_SECOND: u32 = 60;
+
+/// Renders a mixer without an audio device, e.g. on CI or a headless server.
+///
+/// Nothing is rendered until the stream is advanced, so the stream has its own virtual clock
+/// which can be driven by the game time rather than the sound card. Advancing it by fixed blocks
+/// makes the output independent of how long each frame took.
+pub struct OfflineAudioStream {
+    mixer: AudioMixer,
+    output: Output,
+    /// Frames rendered so far
+    rendered: u64,
+    /// The virtual time that has been advanced to
+    time: Duration,
+    /// The number of frames rendered by [`OfflineAudioStream::advance_block`]
+    block_size: usize,
+    buf: Vec<Frame>,
+}
+
+impl std::fmt::Debug for OfflineAudioStream {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OfflineAudioStream")
+            .field("mixer", &self.mixer)
+            .field("rendered", &self.rendered)
+            .field("time", &self.time)
+            .field("block_size", &self.block_size)
+            .finish()
+    }
+}
+
+impl OfflineAudioStream {
+    /// Renders into a buffer, see [`OfflineAudioStream::samples`]
+    pub fn new(sample_rate: SampleRate) -> Self {
+        Self::with_output(sample_rate, Output::Buffer(Vec::new()))
+    }
+
+    /// Renders into a stereo 32-bit float WAV file at `path`
+    pub fn to_wav(path: impl AsRef<Path>, sample_rate: SampleRate) -> Result<Self> {
+        let path = path.as_ref();
+        let file = File::create(path).map_err(|err| Error::Io(err, path.to_path_buf()))?;
+        let spec = WavSpec {
+            channels: 2,
+            sample_rate: sample_rate as _,
+            bits_per_sample: 32,
+            sample_format: SampleFormat::Float,
+        };
+        let writer = WavWriter::new(BufWriter::new(file), spec)?;
+        Ok(Self::with_output(sample_rate, Output::Wav(writer)))
+    }
+
+    fn with_output(sample_rate: SampleRate, output: Output) -> Self {
+        Self {
+            mixer: AudioMixer::new(sample_rate),
+            output,
+            rendered: 0,
+            time: Duration::ZERO,
+            block_size: (sample_rate / DEFAULT_BLOCKS_PER_SECOND as SampleRate).max(1) as usize,
+            buf: Vec::new(),
+        }
+    }
+
+    /// Sets the number of frames rendered by each [`OfflineAudioStream::advance_block`]
+    pub fn with_block_size(mut self, block_size: usize) -> Self {
+        self.block_size = block_size.max(1);
+        self
+    }
+
+    #[must_use]
+    pub fn mixer(&self) -> &AudioMixer {
+        &self.mixer
+    }
+
+    pub fn sample_rate(&self) -> SampleRate {
+        self.mixer.inner.sample_rate
+    }
+
+    /// The virtual time that has been rendered
+    pub fn time(&self) -> Duration {
+        self.time
+    }
+
+    /// Renders one block of frames and advances the virtual clock by its duration
+    pub fn advance_block(&mut self) -> Result<()> {
+        self.render(self.block_size)?;
+        self.time = self.time.max(Duration::from_secs_f64(
+            self.rendered as f64 / self.sample_rate() as f64,
+        ));
+        Ok(())
+    }
+
+    /// Renders the mixer until the virtual clock has advanced by `dt`
+    pub fn advance(&mut self, dt: Duration) -> Result<()> {
+        self.advance_to(self.time + dt)
+    }
+
+    /// Renders the mixer until the virtual clock reaches `time`.
+    ///
+    /// The number of frames is computed from the total time, so advancing in small steps
+    /// renders as many frames as advancing in one go.
+    pub fn advance_to(&mut self, time: Duration) -> Result<()> {
+        if time <= self.time {
+            return Ok(());
+        }
+        self.time = time;
+        let target = to_sample_index(self.sample_rate(), time);
+        let frames = target.saturating_sub(self.rendered) as usize;
+        self.render(frames)
+    }
+
+    /// Renders `frames` frames, without regard to the virtual clock
+    fn render(&mut self, frames: usize) -> Result<()> {
+        self.buf.clear();
+        self.buf.resize(frames, Frame::ZERO);
+        self.mixer.sample_buffered(&mut self.buf);
+        self.rendered += frames as u64;
+
+        match &mut self.output {
+            Output::Buffer(samples) => samples.extend_from_slice(&self.buf),
+            Output::Wav(writer) => {
+                for frame in &self.buf {
+                    writer.write_sample(frame.x)?;
+                    writer.write_sample(frame.y)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// The frames rendered so far. Empty when rendering to a WAV file.
+    pub fn samples(&self) -> &[Frame] {
+        match &self.output {
+            Output::Buffer(samples) => samples,
+            Output::Wav(_) => &[],
+        }
+    }
+
+    /// Takes the frames rendered so far. Empty when rendering to a WAV file.
+    pub fn take_samples(&mut self) -> Vec<Frame> {
+        match &mut self.output {
+            Output::Buffer(samples) => std::mem::take(samples),
+            Output::Wav(_) => Vec::new(),
+        }
+    }
+
+    /// Writes the header of the WAV file for the frames rendered so far, and flushes it to disk.
+    /// Rendering can continue afterwards.
+    pub fn flush(&mut self) -> Result<()> {
+        if let Output::Wav(writer) = &mut self.output {
+            writer.flush()?;
+        }
+        Ok(())
+    }
+
+    /// Writes the header of the WAV file and closes it
+    pub fn finish(self) -> Result<()> {
+        if let Output::Wav(writer) = self.output {
+            writer.finalize()?;
+        }
+        Ok(())
+    }
+}
+
+/// Renders `source` on its own for `duration` at `sample_rate`, e.g. for golden audio tests
+pub fn render_offline<S: Source + 'static>(
+    source: S,
+    sample_rate: SampleRate,
+    duration: Duration,
+) -> Vec<Frame> {
+    let mut stream = OfflineAudioStream::new(sample_rate);
+    stream.mixer().play(source);
+    stream
+        .advance(duration)
+        .expect("Rendering to a buffer can't fail");
+    stream.take_samples()
+}
+
+#[cfg(test)]
+mod test {
+    use itertools::Itertools;
+
+    use super::*;
+    use crate::{BusId, SineWave};
+
+    #[test]
+    fn virtual_clock() {
+        let mut stream = OfflineAudioStream::new(8000);
+        stream.mixer().play(SineWave::new(440.0));
+        for _ in 0..25 {
+            stream.advance(Duration::from_millis(40)).unwrap();
+        }
+        assert_eq!(stream.time(), Duration::from_secs(1));
+        assert_eq!(stream.samples().len(), 8000);
+        assert!(stream.samples().iter().any(|v| v.x.abs() > 0.5));
+    }
+
+    #[test]
+    fn rendering_is_deterministic() {
+        let render = || {
+            let mut stream = OfflineAudioStream::new(44100);
+            stream.mixer().set_bus_volume(BusId::Master, 0.5);
+            stream
+                .mixer()
+                .play(SineWave::new(440.0).reverb(0.5, 0.5, 0.3));
+            stream.advance(Duration::from_millis(250)).unwrap();
+            stream.take_samples()
+        };
+        assert_eq!(render(), render());
+    }
+
+    #[test]
+    fn fixed_blocks() {
+        let mut stream = OfflineAudioStream::new(48000).with_block_size(800);
+        stream.mixer().play(SineWave::new(440.0));
+        for _ in 0..60 {
+            stream.advance_block().unwrap();
+        }
+        assert_eq!(stream.time(), Duration::from_secs(1));
+        assert_eq!(stream.samples().len(), 48000);
+    }
+
+    #[test]
+    fn wav_output() {
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        let path = std::env::temp_dir().join(format!(
+            "ambient_audio_offline_test_{}_{nanos}.wav",
+            std::process::id()
+        ));
+        let mut stream = OfflineAudioStream::to_wav(&path, 44100).unwrap();
+        stream.mixer().play(SineWave::new(440.0));
+        stream.advance(Duration::from_millis(100)).unwrap();
+        stream.finish().unwrap();
+
+        let samples = hound::WavReader::open(&path)
+            .unwrap()
+            .samples::<f32>()
+            .map(|v| v.unwrap())
+            .tuples()
+            .map(|(l, r)| Frame::new(l, r))
+            .collect_vec();
+        std::fs::remove_file(&path).ok();
+
+        let expected = render_offline(SineWave::new(440.0), 44100, Duration::from_millis(100));
+        assert_eq!(samples, expected);
+    }
+}
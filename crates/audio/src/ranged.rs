@@ -0,0 +1,191 @@
+use std::{
+    collections::VecDeque,
+    io::{self, Read, Seek, SeekFrom},
+    ops::Range,
+    sync::Arc,
+};
+
+use ambient_native_std::{
+    asset_cache::AssetCache,
+    asset_url::AbsAssetUrl,
+    download_asset::{download_uncached_byte_range, AssetResult},
+};
+use parking_lot::Mutex;
+
+/// The size of the ranges which are downloaded
+const CHUNK_SIZE: u64 = 64 * 1024;
+/// The number of chunks of a file which are kept in memory
+const MAX_CHUNKS: usize = 16;
+
+/// A file which is downloaded in chunks as it is read.
+///
+/// Only the most recently used chunks are kept, so a file of any length uses at most
+/// `CHUNK_SIZE * MAX_CHUNKS` bytes of memory. If the server does not support range requests, the
+/// whole file is downloaded once and kept in memory instead.
+pub(crate) struct RangedFile {
+    assets: AssetCache,
+    url: AbsAssetUrl,
+    len: u64,
+    /// The whole file, if the server ignored the range of the first request
+    whole: Option<Arc<[u8]>>,
+    /// The most recently used chunk is last
+    chunks: Mutex<VecDeque<(u64, Arc<[u8]>)>>,
+}
+
+impl std::fmt::Debug for RangedFile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RangedFile")
+            .field("url", &self.url)
+            .field("len", &self.len)
+            .finish()
+    }
+}
+
+impl RangedFile {
+    /// Downloads the first chunk, which also gives the length of the file
+    pub async fn open(assets: &AssetCache, url: AbsAssetUrl) -> AssetResult<Self> {
+        let first = download_uncached_byte_range(assets, url.clone(), 0..CHUNK_SIZE).await?;
+        let (whole, chunks) = if first.partial {
+            (None, VecDeque::from([(0, Arc::from(first.bytes))]))
+        } else {
+            tracing::debug!("{url} does not support range requests, keeping the whole file");
+            (Some(Arc::from(first.bytes)), VecDeque::new())
+        };
+        Ok(Self {
+            assets: assets.clone(),
+            url,
+            len: first.total_len,
+            whole,
+            chunks: Mutex::new(chunks),
+        })
+    }
+
+    pub fn url(&self) -> &AbsAssetUrl {
+        &self.url
+    }
+
+    pub fn len(&self) -> u64 {
+        self.len
+    }
+
+    /// Returns chunk `index`, downloading it if it is not in memory.
+    ///
+    /// This blocks the current thread while downloading, so it must not be used in async code.
+    fn chunk(&self, index: u64) -> io::Result<Arc<[u8]>> {
+        let start = index * CHUNK_SIZE;
+        if let Some(whole) = &self.whole {
+            let range = start.min(self.len) as usize..(start + CHUNK_SIZE).min(self.len) as usize;
+            return Ok(whole[range].into());
+        }
+        if let Some(chunk) = self.cached(index) {
+            return Ok(chunk);
+        }
+
+        let chunk: Arc<[u8]> = self
+            .download(start..(start + CHUNK_SIZE).min(self.len))?
+            .into();
+
+        let mut chunks = self.chunks.lock();
+        if chunks.len() >= MAX_CHUNKS {
+            chunks.pop_front();
+        }
+        chunks.push_back((index, chunk.clone()));
+        Ok(chunk)
+    }
+
+    fn cached(&self, index: u64) -> Option<Arc<[u8]>> {
+        let mut chunks = self.chunks.lock();
+        let i = chunks.iter().position(|(v, _)| *v == index)?;
+        let entry = chunks.remove(i)?;
+        let chunk = entry.1.clone();
+        chunks.push_back(entry);
+        Some(chunk)
+    }
+
+    #[cfg(not(target_os = "unknown"))]
+    fn download(&self, range: Range<u64>) -> io::Result<Vec<u8>> {
+        self.assets
+            .runtime()
+            .block_on(download_uncached_byte_range(
+                &self.assets,
+                self.url.clone(),
+                range.clone(),
+            ))
+            .map(|v| {
+                if v.partial {
+                    v.bytes
+                } else {
+                    let start = (range.start as usize).min(v.bytes.len());
+                    let end = (range.end as usize).min(v.bytes.len());
+                    v.bytes[start..end].to_vec()
+                }
+            })
+            .map_err(|err| io::Error::new(io::ErrorKind::Other, err))
+    }
+
+    #[cfg(target_os = "unknown")]
+    fn download(&self, _range: Range<u64>) -> io::Result<Vec<u8>> {
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "Streaming audio is not supported on the web",
+        ))
+    }
+}
+
+/// Reads a [`RangedFile`], downloading the chunks as they are needed
+pub(crate) struct RangedReader {
+    file: Arc<RangedFile>,
+    pos: u64,
+    current: Option<(u64, Arc<[u8]>)>,
+}
+
+impl RangedReader {
+    pub fn new(file: Arc<RangedFile>) -> Self {
+        Self {
+            file,
+            pos: 0,
+            current: None,
+        }
+    }
+}
+
+impl Read for RangedReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if buf.is_empty() || self.pos >= self.file.len {
+            return Ok(0);
+        }
+
+        let index = self.pos / CHUNK_SIZE;
+        let chunk = match &self.current {
+            Some((i, chunk)) if *i == index => chunk.clone(),
+            _ => {
+                let chunk = self.file.chunk(index)?;
+                self.current = Some((index, chunk.clone()));
+                chunk
+            }
+        };
+
+        let offset = (self.pos - index * CHUNK_SIZE) as usize;
+        let len = chunk.len().saturating_sub(offset).min(buf.len());
+        buf[..len].copy_from_slice(&chunk[offset..offset + len]);
+        self.pos += len as u64;
+        Ok(len)
+    }
+}
+
+impl Seek for RangedReader {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        let pos = match pos {
+            SeekFrom::Start(pos) => Some(pos),
+            SeekFrom::End(offset) => self.file.len.checked_add_signed(offset),
+            SeekFrom::Current(offset) => self.pos.checked_add_signed(offset),
+        };
+        self.pos = pos.ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "Seek to a negative or overflowing position",
+            )
+        })?;
+        Ok(self.pos)
+    }
+}
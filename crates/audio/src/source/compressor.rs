@@ -0,0 +1,62 @@
+use crate::{Frame, SampleRate, Source};
+
+/// Converts a time constant in seconds to the coefficient of a one pole smoother
+pub(crate) fn time_to_coeff(secs: f32, sample_rate: SampleRate) -> f32 {
+    if secs <= 0.0 {
+        0.0
+    } else {
+        (-1.0 / (secs * sample_rate as f32)).exp()
+    }
+}
+
+/// Reduces the dynamic range of a source by attenuating it when it is louder than a threshold
+#[derive(Debug, Clone)]
+pub struct Compressor<S> {
+    source: S,
+    threshold: f32,
+    ratio: f32,
+    attack: f32,
+    release: f32,
+    /// Smoothed level in dB
+    envelope: f32,
+}
+
+impl<S: Source> Compressor<S> {
+    /// `threshold` is in dB. `attack` and `release` are in seconds.
+    pub fn new(source: S, threshold: f32, ratio: f32, attack: f32, release: f32) -> Self {
+        let sample_rate = source.sample_rate();
+        Self {
+            source,
+            threshold,
+            ratio: ratio.max(1.0),
+            attack: time_to_coeff(attack, sample_rate),
+            release: time_to_coeff(release, sample_rate),
+            envelope: -120.0,
+        }
+    }
+}
+
+impl<S: Source> Source for Compressor<S> {
+    fn next_sample(&mut self) -> Option<Frame> {
+        let sample = self.source.next_sample()?;
+        let level = 20.0 * sample.abs().max_element().max(1e-6).log10();
+        let coeff = if level > self.envelope {
+            self.attack
+        } else {
+            self.release
+        };
+        self.envelope = coeff * self.envelope + (1.0 - coeff) * level;
+
+        let over = (self.envelope - self.threshold).max(0.0);
+        let reduction = over * (1.0 - 1.0 / self.ratio);
+        Some(sample * 10f32.powf(-reduction / 20.0))
+    }
+
+    fn sample_rate(&self) -> SampleRate {
+        self.source.sample_rate()
+    }
+
+    fn sample_count(&self) -> Option<u64> {
+        self.source.sample_count()
+    }
+}
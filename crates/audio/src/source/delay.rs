@@ -0,0 +1,48 @@
+use crate::{Frame, SampleRate, Source};
+
+/// The longest delay time in seconds, which bounds the memory used by the delay line
+pub const MAX_DELAY_TIME: f32 = 5.0;
+
+/// Echoes a source after a fixed time, feeding the echoes back into the delay line
+#[derive(Debug, Clone)]
+pub struct Delay<S> {
+    source: S,
+    buffer: Vec<Frame>,
+    index: usize,
+    feedback: f32,
+    wet: f32,
+}
+
+impl<S: Source> Delay<S> {
+    /// `time` is in seconds, up to [`MAX_DELAY_TIME`]. `feedback` is the gain of each successive
+    /// echo.
+    pub fn new(source: S, time: f32, feedback: f32, wet: f32) -> Self {
+        let len = (time.clamp(0.0, MAX_DELAY_TIME) * source.sample_rate() as f32) as usize;
+        Self {
+            source,
+            buffer: vec![Frame::ZERO; len.max(1)],
+            index: 0,
+            feedback: feedback.clamp(0.0, 0.99),
+            wet: wet.clamp(0.0, 1.0),
+        }
+    }
+}
+
+impl<S: Source> Source for Delay<S> {
+    fn next_sample(&mut self) -> Option<Frame> {
+        let dry = self.source.next_sample()?;
+        let delayed = self.buffer[self.index];
+        self.buffer[self.index] = dry + delayed * self.feedback;
+        self.index = (self.index + 1) % self.buffer.len();
+
+        Some(dry + delayed * self.wet)
+    }
+
+    fn sample_rate(&self) -> SampleRate {
+        self.source.sample_rate()
+    }
+
+    fn sample_count(&self) -> Option<u64> {
+        self.source.sample_count()
+    }
+}
@@ -87,3 +87,47 @@ impl Source for DynamicMix {
             .unwrap_or_default()
     }
 }
+
+/// Mixes any number of sources together until all of them have finished. The sources which
+/// finish first are replaced by silence while the others are still playing.
+pub struct MixAll {
+    sources: Vec<Option<Uniform<Box<dyn Source>>>>,
+    sample_rate: crate::SampleRate,
+}
+
+impl MixAll {
+    pub fn new(sources: Vec<Box<dyn Source>>) -> Self {
+        let sample_rate = sources.iter().map(|v| v.sample_rate()).max().unwrap_or(1);
+        Self {
+            sources: uniform_n(sources).map(Some).collect_vec(),
+            sample_rate,
+        }
+    }
+}
+
+impl Source for MixAll {
+    fn next_sample(&mut self) -> Option<crate::Frame> {
+        let mut sample = None;
+        for source in &mut self.sources {
+            match source.as_mut().map(|v| v.next_sample()) {
+                Some(Some(v)) => *sample.get_or_insert(Frame::ZERO) += v,
+                Some(None) => *source = None,
+                None => {}
+            }
+        }
+
+        sample
+    }
+
+    fn sample_rate(&self) -> crate::SampleRate {
+        self.sample_rate
+    }
+
+    fn sample_count(&self) -> Option<u64> {
+        self.sources
+            .iter()
+            .flatten()
+            .map(|v| v.sample_count())
+            .try_fold(0, |acc, v| Some(acc.max(v?)))
+    }
+}
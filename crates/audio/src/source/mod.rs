@@ -1,6 +1,8 @@
 mod buffered;
 mod chain;
+pub(crate) mod compressor;
 mod crossfade;
+mod delay;
 pub(crate) mod dynamic_delay;
 pub mod gain;
 pub mod history;
@@ -10,9 +12,12 @@ mod oscilloscope;
 mod pad_to;
 mod pan;
 mod peek;
+mod pitch;
 mod repeat;
+mod reverb;
 mod sample_bufferer;
 mod sample_rate;
+mod silence;
 mod slice;
 mod spatial;
 pub mod streaming_source;
@@ -29,17 +34,23 @@ use std::{
 pub use buffered::*;
 pub use chain::*;
 use circular_queue::CircularQueue;
+pub use compressor::*;
 pub use crossfade::*;
+pub use delay::*;
 pub use gain::*;
 pub use mix::*;
 pub use onepole::*;
 pub use pan::*;
 use parking_lot::Mutex;
 pub use peek::*;
+pub use pitch::*;
 pub use repeat::*;
+pub use reverb::*;
 pub use sample_rate::*;
+pub use silence::*;
 pub use slice::*;
 pub use spatial::*;
+pub use streaming_source::{StreamHandle, StreamingDecodeStream, StreamingTrack};
 pub use uniform::*;
 
 use self::{history::History, oscilloscope::Oscilloscope, pad_to::PadTo};
@@ -213,6 +224,35 @@ pub trait Source: Send {
         BilinearTransform::new(self, Constant(Lpf { freq, bandwidth }))
     }
 
+    /// Changes the pitch and speed by `ratio`, e.g. `2.0` is an octave higher and twice as fast
+    fn pitch(self, ratio: f32) -> Pitch<Self>
+    where
+        Self: Sized,
+    {
+        Pitch::new(self, ratio)
+    }
+
+    fn reverb(self, room_size: f32, damping: f32, wet: f32) -> Reverb<Self>
+    where
+        Self: Sized,
+    {
+        Reverb::new(self, room_size, damping, wet)
+    }
+
+    fn compressor(self, threshold: f32, ratio: f32, attack: f32, release: f32) -> Compressor<Self>
+    where
+        Self: Sized,
+    {
+        Compressor::new(self, threshold, ratio, attack, release)
+    }
+
+    fn delay(self, time: f32, feedback: f32, wet: f32) -> Delay<Self>
+    where
+        Self: Sized,
+    {
+        Delay::new(self, time, feedback, wet)
+    }
+
     fn onepole<P>(self, freq: P) -> Box<dyn Source + Send>
     where
         Self: Sized + 'static,
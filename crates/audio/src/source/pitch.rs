@@ -0,0 +1,34 @@
+use crate::{Frame, SampleRate, Source};
+
+/// Changes the pitch and speed of a source by `ratio`, by changing the sample rate it reports.
+///
+/// The source is resampled to the rate of the output when played.
+#[derive(Debug, Clone)]
+pub struct Pitch<S> {
+    source: S,
+    sample_rate: SampleRate,
+}
+
+impl<S: Source> Pitch<S> {
+    pub fn new(source: S, ratio: f32) -> Self {
+        let sample_rate = (source.sample_rate() as f32 * ratio).round().max(1.0) as SampleRate;
+        Self {
+            source,
+            sample_rate,
+        }
+    }
+}
+
+impl<S: Source> Source for Pitch<S> {
+    fn next_sample(&mut self) -> Option<Frame> {
+        self.source.next_sample()
+    }
+
+    fn sample_rate(&self) -> SampleRate {
+        self.sample_rate
+    }
+
+    fn sample_count(&self) -> Option<u64> {
+        self.source.sample_count()
+    }
+}
@@ -0,0 +1,137 @@
+use crate::{Frame, SampleRate, Source};
+
+/// Comb filter delays in samples at 44.1kHz, from Freeverb
+const COMB_TUNING: [usize; 8] = [1116, 1188, 1277, 1356, 1422, 1491, 1557, 1617];
+/// All pass filter delays in samples at 44.1kHz, from Freeverb
+const ALLPASS_TUNING: [usize; 4] = [556, 441, 341, 225];
+/// Extra delay of the right channel, to decorrelate it from the left
+const STEREO_SPREAD: usize = 23;
+const INPUT_GAIN: f32 = 0.015;
+const WET_GAIN: f32 = 3.0;
+
+#[derive(Debug, Clone)]
+struct Comb {
+    buffer: Vec<f32>,
+    index: usize,
+    filter_store: f32,
+}
+
+impl Comb {
+    fn new(len: usize) -> Self {
+        Self {
+            buffer: vec![0.0; len.max(1)],
+            index: 0,
+            filter_store: 0.0,
+        }
+    }
+
+    #[inline]
+    fn process(&mut self, input: f32, feedback: f32, damping: f32) -> f32 {
+        let output = self.buffer[self.index];
+        self.filter_store = output * (1.0 - damping) + self.filter_store * damping;
+        self.buffer[self.index] = input + self.filter_store * feedback;
+        self.index = (self.index + 1) % self.buffer.len();
+        output
+    }
+}
+
+#[derive(Debug, Clone)]
+struct AllPass {
+    buffer: Vec<f32>,
+    index: usize,
+}
+
+impl AllPass {
+    fn new(len: usize) -> Self {
+        Self {
+            buffer: vec![0.0; len.max(1)],
+            index: 0,
+        }
+    }
+
+    #[inline]
+    fn process(&mut self, input: f32) -> f32 {
+        let delayed = self.buffer[self.index];
+        self.buffer[self.index] = input + delayed * 0.5;
+        self.index = (self.index + 1) % self.buffer.len();
+        delayed - input
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Channel {
+    combs: Vec<Comb>,
+    allpasses: Vec<AllPass>,
+}
+
+impl Channel {
+    fn new(sample_rate: SampleRate, spread: usize) -> Self {
+        let scale = |len: usize| (len + spread) * sample_rate as usize / 44100;
+        Self {
+            combs: COMB_TUNING.iter().map(|&v| Comb::new(scale(v))).collect(),
+            allpasses: ALLPASS_TUNING
+                .iter()
+                .map(|&v| AllPass::new(scale(v)))
+                .collect(),
+        }
+    }
+
+    fn process(&mut self, input: f32, feedback: f32, damping: f32) -> f32 {
+        let out = self
+            .combs
+            .iter_mut()
+            .map(|comb| comb.process(input, feedback, damping))
+            .sum();
+        self.allpasses
+            .iter_mut()
+            .fold(out, |acc, allpass| allpass.process(acc))
+    }
+}
+
+/// Freeverb style reverb, made of parallel comb filters followed by all pass filters
+#[derive(Debug, Clone)]
+pub struct Reverb<S> {
+    source: S,
+    feedback: f32,
+    damping: f32,
+    wet: f32,
+    left: Channel,
+    right: Channel,
+}
+
+impl<S: Source> Reverb<S> {
+    /// `room_size` and `damping` are in `[0, 1]`. `wet` is the amount of reverb mixed with the
+    /// dry signal.
+    pub fn new(source: S, room_size: f32, damping: f32, wet: f32) -> Self {
+        let sample_rate = source.sample_rate();
+        Self {
+            source,
+            feedback: room_size.clamp(0.0, 1.0) * 0.28 + 0.7,
+            damping: damping.clamp(0.0, 1.0) * 0.4,
+            wet: wet.clamp(0.0, 1.0),
+            left: Channel::new(sample_rate, 0),
+            right: Channel::new(sample_rate, STEREO_SPREAD),
+        }
+    }
+}
+
+impl<S: Source> Source for Reverb<S> {
+    fn next_sample(&mut self) -> Option<Frame> {
+        let dry = self.source.next_sample()?;
+        let input = (dry.x + dry.y) * INPUT_GAIN;
+        let wet = Frame::new(
+            self.left.process(input, self.feedback, self.damping),
+            self.right.process(input, self.feedback, self.damping),
+        );
+
+        Some(dry * (1.0 - self.wet) + wet * self.wet * WET_GAIN)
+    }
+
+    fn sample_rate(&self) -> SampleRate {
+        self.source.sample_rate()
+    }
+
+    fn sample_count(&self) -> Option<u64> {
+        self.source.sample_count()
+    }
+}
@@ -0,0 +1,47 @@
+use std::time::Duration;
+
+use crate::{to_sample_index, Frame, SampleRate, Source};
+
+/// A source which plays nothing, for a duration or forever
+#[derive(Debug, Clone)]
+pub struct Silence {
+    remaining: Option<u64>,
+    sample_rate: SampleRate,
+}
+
+impl Silence {
+    pub fn new(dur: Duration, sample_rate: SampleRate) -> Self {
+        Self {
+            remaining: Some(to_sample_index(sample_rate, dur)),
+            sample_rate,
+        }
+    }
+
+    pub fn forever(sample_rate: SampleRate) -> Self {
+        Self {
+            remaining: None,
+            sample_rate,
+        }
+    }
+}
+
+impl Source for Silence {
+    fn next_sample(&mut self) -> Option<Frame> {
+        match &mut self.remaining {
+            Some(0) => None,
+            Some(remaining) => {
+                *remaining -= 1;
+                Some(Frame::ZERO)
+            }
+            None => Some(Frame::ZERO),
+        }
+    }
+
+    fn sample_rate(&self) -> SampleRate {
+        self.sample_rate
+    }
+
+    fn sample_count(&self) -> Option<u64> {
+        self.remaining
+    }
+}
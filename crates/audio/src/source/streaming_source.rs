@@ -1,6 +1,20 @@
+use std::{collections::VecDeque, io::Read, sync::Arc, time::Duration};
+
+use ambient_native_std::{asset_cache::AssetCache, asset_url::AbsAssetUrl};
 use glam::Vec2;
+use hound::WavReader;
+use lewton::inside_ogg::OggStreamReader;
+use parking_lot::{Condvar, Mutex};
 
-use crate::{Frame, SampleRate, Source};
+use crate::{
+    error::Result,
+    ranged::{RangedFile, RangedReader},
+    to_sample_index,
+    track::AudioFormat,
+    vorbis::FramedSamples,
+    wav::read_block,
+    Frame, SampleRate, Source,
+};
 
 /// A source backed by a buffer of samples
 #[derive(Clone)]
@@ -46,3 +60,478 @@ where
         Some(self.iter.len().try_into().unwrap())
     }
 }
+
+/// How far ahead of playback a stream is decoded
+const STREAM_BUFFER: Duration = Duration::from_secs(2);
+/// How much of a stream is decoded before playback starts, or resumes after running out
+const STREAM_PREBUFFER: Duration = Duration::from_millis(250);
+
+/// A track which is downloaded and decoded while it plays, rather than loaded into memory up
+/// front.
+///
+/// Cloning the track does not duplicate the downloaded data.
+#[derive(Debug, Clone)]
+pub struct StreamingTrack {
+    file: Arc<RangedFile>,
+    format: AudioFormat,
+    sample_rate: SampleRate,
+    sample_count: Option<u64>,
+}
+
+impl StreamingTrack {
+    /// Starts downloading `url` and reads the header of the track
+    pub async fn open(assets: &AssetCache, url: AbsAssetUrl, format: AudioFormat) -> Result<Self> {
+        let file = Arc::new(RangedFile::open(assets, url).await?);
+
+        // Reading the header can download more of the file, which blocks
+        assets
+            .runtime()
+            .spawn_blocking(move || -> Result<Self> {
+                let (sample_rate, sample_count) = match format {
+                    AudioFormat::Wav => {
+                        let reader = WavReader::new(RangedReader::new(file.clone()))?;
+                        (
+                            reader.spec().sample_rate as SampleRate,
+                            Some(reader.duration() as u64),
+                        )
+                    }
+                    AudioFormat::Vorbis => {
+                        let reader = OggStreamReader::new(RangedReader::new(file.clone()))?;
+                        (
+                            reader.ident_hdr.audio_sample_rate as SampleRate,
+                            last_granule_position(&file),
+                        )
+                    }
+                };
+                Ok(Self {
+                    file,
+                    format,
+                    sample_rate,
+                    sample_count,
+                })
+            })
+            .await
+            .expect("Reading the header of the stream panicked")
+    }
+
+    pub fn url(&self) -> &AbsAssetUrl {
+        self.file.url()
+    }
+
+    /// Starts decoding the track on a background thread
+    pub fn decode(&self) -> StreamingDecodeStream {
+        let prebuffer = to_sample_index(self.sample_rate, STREAM_PREBUFFER) as usize;
+        let capacity = to_sample_index(self.sample_rate, STREAM_BUFFER) as usize;
+        let shared = Arc::new(StreamShared {
+            state: Mutex::new(StreamState {
+                frames: VecDeque::with_capacity(capacity),
+                ..Default::default()
+            }),
+            cond: Condvar::new(),
+        });
+
+        std::thread::Builder::new()
+            .name("audio_stream".to_string())
+            .spawn({
+                let shared = shared.clone();
+                let file = self.file.clone();
+                let format = self.format.clone();
+                move || decode_stream(file, format, capacity, &shared)
+            })
+            .expect("Failed to spawn audio stream thread");
+
+        StreamingDecodeStream {
+            track: self.clone(),
+            shared,
+            prebuffer,
+            looping: false,
+        }
+    }
+}
+
+/// Finds the granule position of the last page of an Ogg file, which is its length in frames
+fn last_granule_position(file: &Arc<RangedFile>) -> Option<u64> {
+    use std::io::{Seek, SeekFrom};
+
+    // An Ogg page is at most 65307 bytes long
+    let mut reader = RangedReader::new(file.clone());
+    reader
+        .seek(SeekFrom::Start(file.len().saturating_sub(65307)))
+        .ok()?;
+    let mut tail = Vec::new();
+    reader.read_to_end(&mut tail).ok()?;
+
+    let page = tail.windows(4).rposition(|v| v == b"OggS")?;
+    let granule = i64::from_le_bytes(tail.get(page + 6..page + 14)?.try_into().ok()?);
+    u64::try_from(granule).ok()
+}
+
+enum StreamDecoder {
+    Vorbis(OggStreamReader<RangedReader>),
+    Wav(WavReader<RangedReader>),
+}
+
+impl StreamDecoder {
+    fn new(file: Arc<RangedFile>, format: AudioFormat) -> Result<Self> {
+        let reader = RangedReader::new(file);
+        Ok(match format {
+            AudioFormat::Vorbis => Self::Vorbis(OggStreamReader::new(reader)?),
+            AudioFormat::Wav => Self::Wav(WavReader::new(reader)?),
+        })
+    }
+
+    /// Decodes the next frames into `output`. Returns false at the end of the track
+    fn decode(&mut self, output: &mut Vec<Frame>) -> Result<bool> {
+        match self {
+            StreamDecoder::Vorbis(reader) => {
+                match reader.read_dec_packet_generic::<FramedSamples>()? {
+                    Some(packet) => {
+                        output.extend(packet.samples?);
+                        Ok(true)
+                    }
+                    None => Ok(false),
+                }
+            }
+            StreamDecoder::Wav(reader) => {
+                read_block(reader, output)?;
+                Ok(!output.is_empty())
+            }
+        }
+    }
+
+    /// Seeks to the frame `frame`. Ogg files are seeked to the start of the page containing it
+    fn seek(&mut self, frame: u64) -> Result<()> {
+        match self {
+            StreamDecoder::Vorbis(reader) => reader.seek_absgp_pg(frame)?,
+            StreamDecoder::Wav(reader) => reader
+                .seek(frame.try_into().unwrap_or(u32::MAX))
+                .map_err(hound::Error::from)?,
+        }
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+struct StreamState {
+    /// The decoded frames which have not been played yet
+    frames: VecDeque<Frame>,
+    /// The frame of the track at the front of `frames`
+    position: u64,
+    /// Cleared until the prebuffer has been decoded
+    playing: bool,
+    /// A seek which has not been done by the decoder yet
+    seek: Option<u64>,
+    /// The decoder has reached the end of the track, or failed
+    finished: bool,
+    /// The stream has been dropped
+    stopped: bool,
+}
+
+struct StreamShared {
+    state: Mutex<StreamState>,
+    /// Wakes the decoder when frames have been played, or the stream is seeked or stopped
+    cond: Condvar,
+}
+
+/// Decodes a track into `shared` until the stream is stopped
+fn decode_stream(
+    file: Arc<RangedFile>,
+    format: AudioFormat,
+    capacity: usize,
+    shared: &StreamShared,
+) {
+    let url = file.url().clone();
+    let mut decoder = match StreamDecoder::new(file, format) {
+        Ok(decoder) => Some(decoder),
+        Err(err) => {
+            tracing::error!("Failed to open audio stream {url}: {err}");
+            None
+        }
+    };
+
+    let mut packet = Vec::new();
+    loop {
+        let seek = {
+            let mut state = shared.state.lock();
+            if decoder.is_none() {
+                state.finished = true;
+            }
+            while (state.finished || state.frames.len() >= capacity)
+                && state.seek.is_none()
+                && !state.stopped
+            {
+                shared.cond.wait(&mut state);
+            }
+            if state.stopped {
+                return;
+            }
+            state.seek.take()
+        };
+        let Some(decoder) = &mut decoder else {
+            continue;
+        };
+
+        if let Some(frame) = seek {
+            let result = decoder.seek(frame);
+            let mut state = shared.state.lock();
+            if let Err(err) = result {
+                tracing::error!("Failed to seek audio stream {url}: {err}");
+                state.finished = true;
+            }
+            continue;
+        }
+
+        packet.clear();
+        let result = decoder.decode(&mut packet);
+        let mut state = shared.state.lock();
+        // The packet is from before a seek which has not been done yet
+        if state.seek.is_some() {
+            continue;
+        }
+        match result {
+            Ok(true) => state.frames.extend(packet.drain(..)),
+            Ok(false) => state.finished = true,
+            Err(err) => {
+                tracing::error!("Failed to decode audio stream {url}: {err}");
+                state.finished = true;
+            }
+        }
+    }
+}
+
+/// Plays a [`StreamingTrack`] while it is decoded on a background thread.
+///
+/// Silence is played until enough of the track has been decoded, and whenever decoding falls
+/// behind. Cloning the stream starts playing the track again from the beginning.
+pub struct StreamingDecodeStream {
+    track: StreamingTrack,
+    shared: Arc<StreamShared>,
+    prebuffer: usize,
+    looping: bool,
+}
+
+impl std::fmt::Debug for StreamingDecodeStream {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StreamingDecodeStream")
+            .field("track", &self.track)
+            .finish()
+    }
+}
+
+impl Clone for StreamingDecodeStream {
+    fn clone(&self) -> Self {
+        let mut stream = self.track.decode();
+        stream.looping = self.looping;
+        stream
+    }
+}
+
+impl StreamingDecodeStream {
+    /// Returns a handle which can seek the stream while it is played by the mixer
+    pub fn handle(&self) -> StreamHandle {
+        StreamHandle {
+            shared: self.shared.clone(),
+            sample_rate: self.track.sample_rate,
+            prebuffer: self.prebuffer,
+        }
+    }
+
+    /// Plays the track again from the beginning whenever it ends.
+    ///
+    /// Unlike [`Source::repeat`], this keeps decoding into the same stream, so a [`StreamHandle`]
+    /// keeps working after the first loop.
+    pub fn looped(mut self) -> Self {
+        self.looping = true;
+        self
+    }
+}
+
+impl Drop for StreamingDecodeStream {
+    fn drop(&mut self) {
+        self.shared.state.lock().stopped = true;
+        self.shared.cond.notify_all();
+    }
+}
+
+impl Source for StreamingDecodeStream {
+    fn next_sample(&mut self) -> Option<Frame> {
+        let mut output = [Frame::ZERO];
+        (self.sample_buffered(&mut output) == 1).then_some(output[0])
+    }
+
+    fn sample_buffered(&mut self, output: &mut [Frame]) -> usize {
+        let mut state = self.shared.state.lock();
+        if !state.playing {
+            if state.frames.len() >= self.prebuffer || state.finished {
+                state.playing = true;
+            } else {
+                // Play silence while buffering
+                return output.len();
+            }
+        }
+
+        let len = output.len().min(state.frames.len());
+        for (output, frame) in output.iter_mut().zip(state.frames.drain(..len)) {
+            *output += frame;
+        }
+        state.position += len as u64;
+        self.shared.cond.notify_all();
+
+        if len == output.len() {
+            len
+        } else if state.finished && self.looping && state.position > 0 {
+            // Play silence while the start is decoded again
+            state.seek = Some(0);
+            state.position = 0;
+            state.playing = false;
+            state.finished = false;
+            self.shared.cond.notify_all();
+            output.len()
+        } else if state.finished {
+            len
+        } else {
+            // Decoding fell behind, so wait for the prebuffer again
+            state.playing = false;
+            output.len()
+        }
+    }
+
+    fn sample_rate(&self) -> SampleRate {
+        self.track.sample_rate
+    }
+
+    fn sample_count(&self) -> Option<u64> {
+        self.track.sample_count
+    }
+}
+
+/// Controls a [`StreamingDecodeStream`] while it is played
+#[derive(Clone)]
+pub struct StreamHandle {
+    shared: Arc<StreamShared>,
+    sample_rate: SampleRate,
+    prebuffer: usize,
+}
+
+impl std::fmt::Debug for StreamHandle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StreamHandle")
+            .field("position", &self.position())
+            .finish()
+    }
+}
+
+impl StreamHandle {
+    /// Continues playback from `time`, after the prebuffer has been decoded from there.
+    ///
+    /// Ogg files are seeked to the start of the page containing `time`, which is a few
+    /// milliseconds before it.
+    pub fn seek(&self, time: Duration) {
+        let frame = to_sample_index(self.sample_rate, time);
+        let mut state = self.shared.state.lock();
+        state.seek = Some(frame);
+        state.frames.clear();
+        state.position = frame;
+        state.playing = false;
+        state.finished = false;
+        self.shared.cond.notify_all();
+    }
+
+    /// The time in the track which is being played
+    pub fn position(&self) -> Duration {
+        let position = self.shared.state.lock().position;
+        Duration::from_secs_f64(position as f64 / self.sample_rate as f64)
+    }
+
+    /// Whether playback is waiting for more of the track to be decoded
+    pub fn is_buffering(&self) -> bool {
+        let state = self.shared.state.lock();
+        !state.playing && !state.finished && state.frames.len() < self.prebuffer
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use hound::{SampleFormat, WavSpec, WavWriter};
+    use itertools::Itertools;
+
+    use super::*;
+    use crate::{track::Track, SineWave};
+
+    /// Waits until the prebuffer of the stream has been decoded
+    async fn wait_for_prebuffer(handle: &StreamHandle) {
+        while handle.is_buffering() {
+            tokio::time::sleep(Duration::from_millis(1)).await;
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn stream_wav() {
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        let path = std::env::temp_dir().join(format!(
+            "ambient_audio_streaming_test_{}_{nanos}.wav",
+            std::process::id()
+        ));
+        let spec = WavSpec {
+            channels: 2,
+            sample_rate: 8000,
+            bits_per_sample: 16,
+            sample_format: SampleFormat::Int,
+        };
+        let mut writer = WavWriter::create(&path, spec).unwrap();
+        // Shorter than the prebuffer, so the whole track is decoded before it plays
+        for frame in SineWave::new(440.0).samples_iter().take(1500) {
+            writer.write_sample((frame.x * 16000.0) as i16).unwrap();
+            writer.write_sample((frame.y * 16000.0) as i16).unwrap();
+        }
+        writer.finalize().unwrap();
+
+        let expected = Track::from_wav(std::fs::read(&path).unwrap())
+            .unwrap()
+            .decode()
+            .samples_iter()
+            .collect_vec();
+
+        let assets = AssetCache::new(tokio::runtime::Handle::current());
+        let track = StreamingTrack::open(
+            &assets,
+            AbsAssetUrl::from_file_path(&path),
+            AudioFormat::Wav,
+        )
+        .await
+        .unwrap();
+        let stream = track.decode();
+        assert_eq!(stream.sample_count(), Some(1500));
+
+        let handle = stream.handle();
+        wait_for_prebuffer(&handle).await;
+        let played = stream.samples_iter().collect_vec();
+        assert_eq!(played, expected);
+
+        let stream = track.decode();
+        let handle = stream.handle();
+        handle.seek(Duration::from_millis(100));
+        wait_for_prebuffer(&handle).await;
+        assert_eq!(handle.position(), Duration::from_millis(100));
+        let played = stream.samples_iter().collect_vec();
+        assert_eq!(played, expected[800..]);
+
+        // The end of a looped stream starts it again, instead of ending it
+        let mut stream = track.decode().looped();
+        let handle = stream.handle();
+        for _ in 0..2 {
+            wait_for_prebuffer(&handle).await;
+            let mut played = vec![Frame::ZERO; expected.len()];
+            assert_eq!(stream.sample_buffered(&mut played), expected.len());
+            assert_eq!(played, expected);
+            let mut rest = [Frame::ZERO];
+            assert_eq!(stream.sample_buffered(&mut rest), 1);
+            assert_eq!(handle.position(), Duration::ZERO);
+        }
+
+        std::fs::remove_file(&path).ok();
+    }
+}
@@ -4,6 +4,7 @@ use derive_more::From;
 
 use crate::{
     error::Result,
+    streaming_source::{StreamHandle, StreamingDecodeStream, StreamingTrack},
     vorbis::{VorbisDecodeStream, VorbisTrack},
     wav::{WavDecodeStream, WavTrack},
     Source,
@@ -22,6 +23,8 @@ pub enum AudioFormat {
 pub enum Track {
     Vorbis(VorbisTrack),
     Wav(WavTrack),
+    /// A track which is downloaded and decoded while it plays
+    Streaming(StreamingTrack),
 }
 
 impl Track {
@@ -40,10 +43,21 @@ impl Track {
         }
     }
 
+    /// The size of the track in memory. Streaming tracks only keep a few chunks of the file in
+    /// memory while they play, which are not counted.
+    pub fn size_in_bytes(&self) -> usize {
+        match self {
+            Track::Vorbis(v) => v.size_in_bytes(),
+            Track::Wav(v) => v.size_in_bytes(),
+            Track::Streaming(_) => 0,
+        }
+    }
+
     pub fn decode(&self) -> TrackDecodeStream {
         match self {
             Track::Vorbis(v) => TrackDecodeStream::Vorbis(Box::new(v.decode())),
             Track::Wav(v) => TrackDecodeStream::Wav(v.decode()),
+            Track::Streaming(v) => TrackDecodeStream::Streaming(Box::new(v.decode())),
         }
     }
 }
@@ -52,6 +66,17 @@ impl Track {
 pub enum TrackDecodeStream {
     Vorbis(Box<VorbisDecodeStream>),
     Wav(WavDecodeStream),
+    Streaming(Box<StreamingDecodeStream>),
+}
+
+impl TrackDecodeStream {
+    /// The handle to seek with, if this is a streaming track
+    pub fn stream_handle(&self) -> Option<StreamHandle> {
+        match self {
+            TrackDecodeStream::Streaming(v) => Some(v.handle()),
+            _ => None,
+        }
+    }
 }
 
 impl Source for TrackDecodeStream {
@@ -59,6 +84,7 @@ impl Source for TrackDecodeStream {
         match self {
             TrackDecodeStream::Vorbis(v) => v.next_sample(),
             TrackDecodeStream::Wav(v) => v.next_sample(),
+            TrackDecodeStream::Streaming(v) => v.next_sample(),
         }
     }
 
@@ -66,6 +92,7 @@ impl Source for TrackDecodeStream {
         match self {
             TrackDecodeStream::Vorbis(v) => v.sample_buffered(output),
             TrackDecodeStream::Wav(v) => v.sample_buffered(output),
+            TrackDecodeStream::Streaming(v) => v.sample_buffered(output),
         }
     }
 
@@ -73,6 +100,7 @@ impl Source for TrackDecodeStream {
         match self {
             TrackDecodeStream::Vorbis(v) => v.sample_rate(),
             TrackDecodeStream::Wav(v) => v.sample_rate(),
+            TrackDecodeStream::Streaming(v) => v.sample_rate(),
         }
     }
 
@@ -80,6 +108,7 @@ impl Source for TrackDecodeStream {
         match self {
             TrackDecodeStream::Vorbis(v) => v.sample_count(),
             TrackDecodeStream::Wav(v) => v.sample_count(),
+            TrackDecodeStream::Streaming(v) => v.sample_count(),
         }
     }
 }
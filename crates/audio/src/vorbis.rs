@@ -7,7 +7,7 @@ use lewton::inside_ogg::OggStreamReader;
 use crate::{ChannelCount, Error, Frame, Result, SampleRate, Source};
 
 /// A packet of multi-channel interleaved samples
-struct FramedSamples {
+pub(crate) struct FramedSamples {
     pub samples: Result<Vec<Frame>>,
     pub channel_count: usize,
 }
@@ -105,6 +105,11 @@ impl VorbisTrack {
             bytes: self.bytes.clone(),
         }
     }
+
+    /// The size of the encoded track in memory
+    pub fn size_in_bytes(&self) -> usize {
+        self.bytes.len()
+    }
 }
 
 /// Audio source which decodes a compressed ogg stream
@@ -1,4 +1,7 @@
-use std::{io::Cursor, sync::Arc};
+use std::{
+    io::{Cursor, Read},
+    sync::Arc,
+};
 
 use cpal::{ChannelCount, Sample};
 use derivative::Derivative;
@@ -78,6 +81,11 @@ impl WavTrack {
             bytes: self.bytes.clone(),
         }
     }
+
+    /// The size of the encoded track in memory
+    pub fn size_in_bytes(&self) -> usize {
+        self.bytes.len()
+    }
 }
 
 pub const WAV_BLOCK_SIZE: usize = 512;
@@ -135,39 +143,49 @@ fn append_frames(
     Ok(())
 }
 
+/// Reads the next block of at most [`WAV_BLOCK_SIZE`] frames from `reader` into `res`
+pub(crate) fn read_block<R: Read>(reader: &mut WavReader<R>, res: &mut Vec<Frame>) -> Result<()> {
+    let WavSpec {
+        channels,
+        bits_per_sample,
+        sample_format,
+        ..
+    } = reader.spec();
+
+    // Convert samples as appropriate
+    match (sample_format, bits_per_sample) {
+        (SampleFormat::Int, 16) => append_frames(
+            reader.samples::<i16>().map_ok(|v| v.to_f32()),
+            res,
+            channels,
+        ),
+        (SampleFormat::Int, 24) => append_frames(
+            reader
+                .samples::<i32>()
+                .map_ok(|v| ((v >> 8) as i16).to_f32()),
+            res,
+            channels,
+        ),
+        (SampleFormat::Int, 32) => append_frames(
+            reader
+                .samples::<i32>()
+                .map_ok(|v| ((v >> 16) as i16).to_f32()),
+            res,
+            channels,
+        ),
+        (SampleFormat::Float, 32) => {
+            append_frames(reader.samples::<f32>().take(WAV_BLOCK_SIZE), res, channels)
+        }
+        _ => {
+            panic!("Unsupported wav format")
+        }
+    }
+}
+
 impl WavDecodeStream {
     fn read_next_block(&mut self) -> Result<&[Frame]> {
         self.current_block.clear();
-        // Convert samples as appropriate
-        match (self.format, self.bits_per_sample) {
-            (SampleFormat::Int, 16) => append_frames(
-                self.streamer.samples::<i16>().map_ok(|v| v.to_f32()),
-                &mut self.current_block,
-                self.channels,
-            ),
-            (SampleFormat::Int, 24) => append_frames(
-                self.streamer
-                    .samples::<i32>()
-                    .map_ok(|v| ((v >> 8) as i16).to_f32()),
-                &mut self.current_block,
-                self.channels,
-            ),
-            (SampleFormat::Int, 32) => append_frames(
-                self.streamer
-                    .samples::<i32>()
-                    .map_ok(|v| ((v >> 16) as i16).to_f32()),
-                &mut self.current_block,
-                self.channels,
-            ),
-            (SampleFormat::Float, 32) => append_frames(
-                self.streamer.samples::<f32>().take(WAV_BLOCK_SIZE),
-                &mut self.current_block,
-                self.channels,
-            ),
-            _ => {
-                panic!("Unsupported wav format")
-            }
-        }?;
+        read_block(&mut self.streamer, &mut self.current_block)?;
 
         self.cursor = 0;
 
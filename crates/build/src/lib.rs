@@ -177,7 +177,10 @@ pub async fn build_package(
         .map(|s| s.as_str())
         .unwrap_or("missing ID");
 
-    if last_build_settings.as_ref() == Some(settings) && last_modified_before_build {
+    if !settings.force
+        && last_build_settings.as_ref() == Some(settings)
+        && last_modified_before_build
+    {
         tracing::info!("Skipping unmodified package \"{package_name}\" ({package_id})");
         return Ok(BuildResult {
             build_path,
@@ -193,8 +196,29 @@ pub async fn build_package(
         .await
         .context("Failed to create build directory")?;
 
+    // Kept outside of the build directory, as everything in it is deployed
+    let pipeline_cache_path = root_build_path.join(".pipeline_cache").join(
+        build_path
+            .file_name()
+            .context("the build path has no name")?,
+    );
+    if settings.force {
+        match tokio::fs::remove_dir_all(&pipeline_cache_path).await {
+            Ok(_) => {}
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
+            Err(err) => return Err(err).context("Failed to remove pipeline cache"),
+        }
+    }
+
     let assets = if !settings.wasm_only {
-        build_assets(assets, &assets_path, &build_path, false).await?
+        build_assets(
+            assets,
+            &assets_path,
+            &build_path,
+            Some(&pipeline_cache_path),
+            false,
+        )
+        .await?
     } else {
         vec![]
     };
@@ -241,6 +265,7 @@ pub async fn build_assets(
     assets: &AssetCache,
     assets_path: &Path,
     build_path: &Path,
+    pipeline_cache_path: Option<&Path>,
     for_import_only: bool,
 ) -> anyhow::Result<Vec<OutAsset>> {
     let files = get_files_in_path(assets_path).map(Into::into).collect_vec();
@@ -259,6 +284,7 @@ pub async fn build_assets(
         out_root: AbsAssetUrl::from_directory_path(build_path.join("assets")),
         input_file_filter: None,
         package_name: "".to_string(),
+        pipeline_cache_path: pipeline_cache_path.map(Path::to_path_buf),
         write_file: Arc::new({
             let build_path = build_path.to_owned();
             let file_write_semaphore = file_write_semaphore.clone();
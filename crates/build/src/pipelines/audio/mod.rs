@@ -26,6 +26,7 @@ pub async fn pipeline(ctx: &PipelineCtx, config: AudioPipeline) -> Vec<OutAsset>
 
             let rel_path = ctx.in_root().relative_path(file.decoded_path());
 
+            let mut is_wav = false;
             let content_url = match file.extension().as_deref() {
                 Some("wav") => {
                     if config.convert {
@@ -34,6 +35,7 @@ pub async fn pipeline(ctx: &PipelineCtx, config: AudioPipeline) -> Vec<OutAsset>
                         ctx.write_file(rel_path.with_extension("ogg"), contents)
                             .await
                     } else {
+                        is_wav = true;
                         ctx.write_file(&rel_path, contents).await
                     }
                 }
@@ -52,8 +54,11 @@ pub async fn pipeline(ctx: &PipelineCtx, config: AudioPipeline) -> Vec<OutAsset>
                 ),
             };
 
-            let root_node = AudioNode::Vorbis {
-                url: content_url.to_string(),
+            let url = content_url.to_string();
+            let root_node = if is_wav {
+                AudioNode::Wav { url }
+            } else {
+                AudioNode::Vorbis { url }
             };
             let graph_url = ctx
                 .write_file(
@@ -0,0 +1,98 @@
+use std::path::PathBuf;
+
+use ambient_native_std::AmbientVersion;
+use anyhow::Context;
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use super::{context::PipelineCtx, out_asset::OutAsset};
+
+/// Caches the outputs of a pipeline, keyed by a hash of its inputs.
+///
+/// The inputs of a pipeline are its settings and the contents of every file in its directory. If
+/// none of them changed since the last build, the outputs of that build are used instead of
+/// processing the pipeline again.
+pub(crate) struct PipelineCache {
+    path: PathBuf,
+    hash: String,
+}
+
+#[derive(Serialize, Deserialize)]
+struct CacheEntry {
+    hash: String,
+    assets: Vec<OutAsset>,
+}
+
+impl PipelineCache {
+    /// Hashes the inputs of the pipeline.
+    ///
+    /// Returns `None` if the build is not cached, or the inputs are not local files.
+    pub async fn new(ctx: &PipelineCtx) -> anyhow::Result<Option<Self>> {
+        let Some(cache_path) = &ctx.process_ctx.pipeline_cache_path else {
+            return Ok(None);
+        };
+
+        let AmbientVersion { version, revision } = AmbientVersion::default();
+        let mut hasher = Sha256::new();
+        hasher.update(format!("{version} {revision}\n"));
+        hasher.update(serde_json::to_vec(&*ctx.pipeline)?);
+        hasher.update(ctx.pipeline_file.0.as_str());
+
+        for file in ctx.files.0.iter().sorted_by(|a, b| a.0.cmp(&b.0)) {
+            let Some(path) = file.to_file_path()? else {
+                return Ok(None);
+            };
+            let contents = tokio::fs::read(&path)
+                .await
+                .with_context(|| format!("Failed to read {path:?}"))?;
+
+            hasher.update(file.0.as_str());
+            hasher.update((contents.len() as u64).to_le_bytes());
+            hasher.update(contents);
+        }
+
+        // One entry per pipeline, so that stale entries are replaced rather than accumulated
+        let name = Sha256::digest(ctx.pipeline_path().as_str());
+        Ok(Some(Self {
+            path: cache_path.join(format!("{name:x}.json")),
+            hash: format!("{:x}", hasher.finalize()),
+        }))
+    }
+
+    /// Returns the outputs of the last build of the pipeline if its inputs are unchanged, and all
+    /// of the outputs still exist.
+    pub async fn load(&self) -> Option<Vec<OutAsset>> {
+        let contents = tokio::fs::read(&self.path).await.ok()?;
+        let entry: CacheEntry = serde_json::from_slice(&contents).ok()?;
+        if entry.hash != self.hash {
+            return None;
+        }
+
+        for asset in &entry.assets {
+            if let Some(path) = asset
+                .content
+                .as_content()
+                .and_then(|url| url.to_file_path().ok().flatten())
+            {
+                if !path.exists() {
+                    return None;
+                }
+            }
+        }
+
+        Some(entry.assets)
+    }
+
+    pub async fn store(&self, assets: &[OutAsset]) -> anyhow::Result<()> {
+        let entry = CacheEntry {
+            hash: self.hash.clone(),
+            assets: assets.to_vec(),
+        };
+
+        tokio::fs::create_dir_all(self.path.parent().unwrap()).await?;
+        tokio::fs::write(&self.path, serde_json::to_vec(&entry)?)
+            .await
+            .with_context(|| format!("Failed to write pipeline cache {:?}", self.path))
+    }
+}
@@ -1,9 +1,17 @@
-use std::{collections::HashSet, sync::Arc};
+use std::{
+    collections::HashSet,
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
 
 use ambient_asset_cache::SyncAssetKey;
 use ambient_native_std::{asset_cache::AssetCache, asset_url::AbsAssetUrl};
 use ambient_pipeline_types::{models::ModelsPipeline, Pipeline, PipelineProcessor, PipelinesFile};
 use anyhow::Context;
+use cache::PipelineCache;
 use context::PipelineCtx;
 use futures::{
     future::{ready, BoxFuture},
@@ -13,6 +21,7 @@ use image::ImageFormat;
 use out_asset::{OutAsset, OutAssetContent, OutAssetPreview};
 
 pub mod audio;
+mod cache;
 pub mod context;
 pub mod importer;
 pub mod materials;
@@ -85,18 +94,46 @@ pub async fn process_pipelines(ctx: &ProcessCtx) -> anyhow::Result<Vec<OutAsset>
         .try_flatten()
         .map_ok(|(pipeline_file, pipeline): (AbsAssetUrl, Pipeline)| {
             let root = pipeline_file.join(".").unwrap();
+
+            // Only pipelines which finished without errors are cached
+            let has_errored = Arc::new(AtomicBool::new(false));
+            let mut process_ctx = ctx.clone();
+            process_ctx.on_error = Arc::new({
+                let on_error = ctx.on_error.clone();
+                let has_errored = has_errored.clone();
+                move |err| {
+                    has_errored.store(true, Ordering::SeqCst);
+                    on_error(err)
+                }
+            });
+
             let ctx = PipelineCtx {
                 files: ctx.files.sub_directory(root.decoded_path().as_str()),
-                process_ctx: ctx.clone(),
+                process_ctx,
                 pipeline: Arc::new(pipeline.clone()),
                 pipeline_file,
                 root_path: ctx.in_root.relative_path(root.decoded_path()),
             };
 
             async move {
-                tokio::spawn(async move { process_pipeline(&pipeline, ctx).await })
+                let cache = PipelineCache::new(&ctx).await?;
+                if let Some(cache) = &cache {
+                    if let Some(assets) = cache.load().await {
+                        tracing::debug!("Skipping unmodified pipeline: {:?}", ctx.pipeline_path());
+                        return Ok(assets);
+                    }
+                }
+
+                let assets = tokio::spawn(async move { process_pipeline(&pipeline, ctx).await })
                     .await
-                    .context("Pipeline processing panicked")
+                    .context("Pipeline processing panicked")?;
+
+                if let Some(cache) = cache {
+                    if !has_errored.load(Ordering::SeqCst) {
+                        cache.store(&assets).await?;
+                    }
+                }
+                anyhow::Ok(assets)
             }
         })
         .try_buffered(30)
@@ -118,6 +155,8 @@ pub struct ProcessCtx {
     pub(crate) package_name: String,
     pub(crate) in_root: AbsAssetUrl,
     pub(crate) out_root: AbsAssetUrl,
+    /// Where the outputs of each pipeline are cached between builds, if they are
+    pub(crate) pipeline_cache_path: Option<PathBuf>,
     pub(crate) write_file:
         Arc<dyn Fn(String, Vec<u8>) -> BoxFuture<'static, AbsAssetUrl> + Sync + Send>,
     pub(crate) on_status: Arc<dyn Fn(String) -> BoxFuture<'static, ()> + Sync + Send>,
@@ -133,6 +172,7 @@ impl std::fmt::Debug for ProcessCtx {
             .field("package_name", &self.package_name)
             .field("in_root", &self.in_root)
             .field("out_root", &self.out_root)
+            .field("pipeline_cache_path", &self.pipeline_cache_path)
             .finish_non_exhaustive()
     }
 }
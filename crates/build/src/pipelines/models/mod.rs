@@ -2,11 +2,15 @@ use std::{path::PathBuf, sync::Arc};
 
 use ambient_core::hierarchy::children;
 use ambient_model_import::{apply_model_transform, model_crate::ModelCrate, TextureResolver};
-use ambient_native_std::asset_url::AssetType;
+use ambient_native_std::asset_url::{AbsAssetUrl, AssetType};
 use ambient_physics::collider::collider_type;
-use ambient_pipeline_types::models::{Collider, ModelImporter, ModelsPipeline};
+use ambient_pipeline_types::models::{
+    AnimationEventMarker, Collider, ModelImporter, ModelsPipeline,
+};
+use anyhow::Context;
 use futures::FutureExt;
 use relative_path::RelativePath;
+use serde::Deserialize;
 
 use super::{
     context::PipelineCtx,
@@ -111,6 +115,39 @@ async fn apply(
     Ok(())
 }
 
+/// A `<model>.events.toml` file next to a model, which adds events to its animations
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct AnimationEventsFile {
+    #[serde(default)]
+    events: Vec<AnimationEventMarker>,
+}
+
+/// Adds the animation events of the pipeline, and of the events file next to `file` if there is
+/// one, to the animations of the model
+async fn apply_animation_events(
+    pipeline: &ModelsPipeline,
+    ctx: &PipelineCtx,
+    file: &AbsAssetUrl,
+    model_crate: &mut ModelCrate,
+) -> anyhow::Result<()> {
+    for marker in &pipeline.animation_events {
+        model_crate.add_animation_event(marker);
+    }
+
+    let events_path = file.decoded_path().with_extension("events.toml");
+    if let Some(events_file) = ctx.files.0.iter().find(|x| x.decoded_path() == events_path) {
+        let events = events_file
+            .download_toml::<AnimationEventsFile>(ctx.assets())
+            .await
+            .with_context(|| format!("Failed to read animation events at {events_path}"))?;
+        for marker in &events.events {
+            model_crate.add_animation_event(marker);
+        }
+    }
+    Ok(())
+}
+
 fn create_texture_resolver(ctx: &PipelineCtx) -> TextureResolver {
     let ctx = ctx.clone();
     Arc::new(move |path| {
@@ -8,7 +8,7 @@ use super::{
         out_asset::{OutAssetContent, OutAssetPreview},
         ModelsPipeline,
     },
-    apply, create_texture_resolver,
+    apply, apply_animation_events, create_texture_resolver,
 };
 use crate::pipelines::{out_asset::asset_id_from_url, OutAsset};
 
@@ -42,6 +42,7 @@ pub async fn pipeline(ctx: &PipelineCtx, config: ModelsPipeline) -> Vec<OutAsset
 
                 let out_model_path = ctx.in_root().relative_path(file.decoded_path());
                 apply(&config, &ctx, &mut model_crate, &out_model_path).await?;
+                apply_animation_events(&config, &ctx, &file, &mut model_crate).await?;
 
                 let model_crate_url = ctx.write_model_crate(&model_crate, &out_model_path).await;
 
@@ -1,8 +1,9 @@
 use std::{collections::HashSet, sync::Arc};
 
 use ambient_native_std::asset_url::{AbsAssetUrl, AssetType};
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum OutAssetContent {
     Content(AbsAssetUrl),
     Collection(Vec<String>),
@@ -20,14 +21,19 @@ impl OutAssetContent {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub enum OutAssetPreview {
+    #[default]
     None,
-    FromModel { url: AbsAssetUrl },
-    Image { image: Arc<image::RgbaImage> },
+    FromModel {
+        url: AbsAssetUrl,
+    },
+    Image {
+        image: Arc<image::RgbaImage>,
+    },
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OutAsset {
     /// A unique id identifying this asset
     pub id: String,
@@ -40,6 +46,8 @@ pub struct OutAsset {
     /// `self.categories[0].insert("Vehicles");`
     /// `self.categories[1].insert("Vehicles > Cars");`
     pub categories: [HashSet<String>; 3],
+    /// Not kept when the asset is restored from the pipeline cache
+    #[serde(skip)]
     pub preview: OutAssetPreview,
     pub content: OutAssetContent,
     pub source: Option<AbsAssetUrl>,
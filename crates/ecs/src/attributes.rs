@@ -238,6 +238,38 @@ where
     }
 }
 
+/// Predicted by the client from the local player's input, and reconciled with the server's value
+///
+/// Provides `Networked`
+#[derive(Debug, Clone, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Predicted;
+impl ComponentAttribute for Predicted {}
+impl<T> AttributeConstructor<T, ()> for Predicted
+where
+    T: ComponentValue + Serialize + for<'de> Deserialize<'de>,
+{
+    fn construct(store: &mut AttributeStore, params: ()) {
+        <Networked as AttributeConstructor<T, ()>>::construct(store, params);
+        store.set(Self);
+    }
+}
+
+/// Smoothed on the client by interpolating between the values received from the server
+///
+/// Provides `Networked`
+#[derive(Debug, Clone, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Interpolated;
+impl ComponentAttribute for Interpolated {}
+impl<T> AttributeConstructor<T, ()> for Interpolated
+where
+    T: ComponentValue + Serialize + for<'de> Deserialize<'de>,
+{
+    fn construct(store: &mut AttributeStore, params: ()) {
+        <Networked as AttributeConstructor<T, ()>>::construct(store, params);
+        store.set(Self);
+    }
+}
+
 pub(crate) struct ComponentPath(pub String);
 impl ComponentAttribute for ComponentPath {}
 
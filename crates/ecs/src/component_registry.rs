@@ -116,6 +116,8 @@ define_external_component_attribute! {
     standard: {
         debuggable: Debuggable,
         networked: Networked,
+        interpolated: Interpolated,
+        predicted: Predicted,
         resource: Resource,
         store: Store,
         maybe_resource: MaybeResource
@@ -21,8 +21,8 @@ mod raw {
             #[doc = r" Auto-generated component definitions."]
             pub mod components {
                 use crate::{
-                    components, Debuggable, Description, EntityId, Enum, MaybeResource, Name,
-                    Networked, Resource, Store,
+                    components, Debuggable, Description, EntityId, Enum, Interpolated,
+                    MaybeResource, Name, Networked, Predicted, Resource, Store,
                 };
                 use ambient_shared_types::{
                     ProceduralMaterialHandle, ProceduralMeshHandle, ProceduralSamplerHandle,
@@ -30,15 +30,15 @@ mod raw {
                 };
                 use glam::{Mat4, Quat, UVec2, UVec3, UVec4, Vec2, Vec3, Vec4};
                 use std::time::Duration;
-                components ! ("animation" , { # [doc = "**Is animation player**: This entity is treated as an animation player. Attach an animation node as a child for it to play.\n\n*Attributes*: Debuggable, Networked"] @ [Debuggable , Networked , Name ["Is animation player"] , Description ["This entity is treated as an animation player. Attach an animation node as a child for it to play."]] is_animation_player : () , # [doc = "**Animation errors**: A list of errors that were produced trying to play the animation.\n\n*Attributes*: Debuggable"] @ [Debuggable , Name ["Animation errors"] , Description ["A list of errors that were produced trying to play the animation."]] animation_errors : Vec :: < String > , # [doc = "**Apply animation player**: Apply the designated animation player to this entity and its sub-tree.\n\n*Attributes*: Debuggable, Networked"] @ [Debuggable , Networked , Name ["Apply animation player"] , Description ["Apply the designated animation player to this entity and its sub-tree."]] apply_animation_player : EntityId , # [doc = "**Play clip from URL**: Make this entity a 'play animation clip' node. The value is the URL to the clip we'd like to play.\n\n*Attributes*: Debuggable, Networked"] @ [Debuggable , Networked , Name ["Play clip from URL"] , Description ["Make this entity a 'play animation clip' node. The value is the URL to the clip we'd like to play."]] play_clip_from_url : String , # [doc = "**Looping**: When this is true, the animation clip will repeat infinitely.\n\n*Attributes*: Debuggable, Networked"] @ [Debuggable , Networked , Name ["Looping"] , Description ["When this is true, the animation clip will repeat infinitely."]] looping : bool , # [doc = "**Speed**: Animation playback speed. Default is 1, higher values speeds up the animation.\n\n*Attributes*: Debuggable, Networked"] @ [Debuggable , Networked , Name ["Speed"] , Description ["Animation playback speed. Default is 1, higher values speeds up the animation."]] speed : f32 , # [doc = "**Start time**: Start time of an animation node.\n\n*Attributes*: Debuggable, Networked"] @ [Debuggable , Networked , Name ["Start time"] , Description ["Start time of an animation node."]] start_time : Duration , # [doc = "**Freeze at percentage**: Sample the input animation at a certain percentage of the animation track length.\n\n*Attributes*: Debuggable, Networked"] @ [Debuggable , Networked , Name ["Freeze at percentage"] , Description ["Sample the input animation at a certain percentage of the animation track length."]] freeze_at_percentage : f32 , # [doc = "**Freeze at time**: Sample the input animation at a certain time (in seconds).\n\n*Attributes*: Debuggable, Networked"] @ [Debuggable , Networked , Name ["Freeze at time"] , Description ["Sample the input animation at a certain time (in seconds)."]] freeze_at_time : f32 , # [doc = "**Clip duration**: The clip duration is loaded from the clip, and then applied to the entity.\n\n*Attributes*: Debuggable"] @ [Debuggable , Name ["Clip duration"] , Description ["The clip duration is loaded from the clip, and then applied to the entity."]] clip_duration : f32 , # [doc = "**Clip loaded**: The clip has been loaded.\n\n*Attributes*: Debuggable"] @ [Debuggable , Name ["Clip loaded"] , Description ["The clip has been loaded."]] clip_loaded : () , # [doc = "**Clip load error**: There was an error loading the clip.\n\n*Attributes*: Debuggable"] @ [Debuggable , Name ["Clip load error"] , Description ["There was an error loading the clip."]] clip_load_error : String , # [doc = "**Blend**: Blend two animations together. The values is the blend weight. Use `children` to set the animations. Blend 0 means we only sample from the first animation, 1 means only the second one, and values in between blend between them.\n\n*Attributes*: Debuggable, Networked"] @ [Debuggable , Networked , Name ["Blend"] , Description ["Blend two animations together. The values is the blend weight. Use `children` to set the animations. Blend 0 means we only sample from the first animation, 1 means only the second one, and values in between blend between them."]] blend : f32 , # [doc = "**Mask bind ids**: List of bind ids that will be masked.\n\n*Attributes*: Debuggable, Networked"] @ [Debuggable , Networked , Name ["Mask bind ids"] , Description ["List of bind ids that will be masked."]] mask_bind_ids : Vec :: < String > , # [doc = "**Mask weights**: Weights for each bind id in `mask_bind_ids`.\n\n*Attributes*: Debuggable, Networked"] @ [Debuggable , Networked , Name ["Mask weights"] , Description ["Weights for each bind id in `mask_bind_ids`."]] mask_weights : Vec :: < f32 > , # [doc = "**Retarget Model from URL**: Retarget the animation using the model at the given URL.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Retarget Model from URL"] , Description ["Retarget the animation using the model at the given URL."]] retarget_model_from_url : String , # [doc = "**Retarget animation scaled**: Retarget animation scaled. True means normalize hip.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Retarget animation scaled"] , Description ["Retarget animation scaled. True means normalize hip."]] retarget_animation_scaled : bool , # [doc = "**Apply base pose**: Apply the base pose to this clip.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Apply base pose"] , Description ["Apply the base pose to this clip."]] apply_base_pose : () , # [doc = "**Bind id**: Animation bind ID.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Bind id"] , Description ["Animation bind ID."]] bind_id : String , # [doc = "**Bind ids**: Animation bind IDs.\n\n*Attributes*: Debuggable, Store"] @ [Debuggable , Store , Name ["Bind ids"] , Description ["Animation bind IDs."]] bind_ids : Vec :: < String > , });
+                components ! ("animation" , { # [doc = "**Is animation player**: This entity is treated as an animation player. Attach an animation node as a child for it to play.\n\n*Attributes*: Debuggable, Networked"] @ [Debuggable , Networked , Name ["Is animation player"] , Description ["This entity is treated as an animation player. Attach an animation node as a child for it to play."]] is_animation_player : () , # [doc = "**Animation errors**: A list of errors that were produced trying to play the animation.\n\n*Attributes*: Debuggable"] @ [Debuggable , Name ["Animation errors"] , Description ["A list of errors that were produced trying to play the animation."]] animation_errors : Vec :: < String > , # [doc = "**Apply animation player**: Apply the designated animation player to this entity and its sub-tree.\n\n*Attributes*: Debuggable, Networked"] @ [Debuggable , Networked , Name ["Apply animation player"] , Description ["Apply the designated animation player to this entity and its sub-tree."]] apply_animation_player : EntityId , # [doc = "**Play clip from URL**: Make this entity a 'play animation clip' node. The value is the URL to the clip we'd like to play.\n\n*Attributes*: Debuggable, Networked"] @ [Debuggable , Networked , Name ["Play clip from URL"] , Description ["Make this entity a 'play animation clip' node. The value is the URL to the clip we'd like to play."]] play_clip_from_url : String , # [doc = "**Looping**: When this is true, the animation clip will repeat infinitely.\n\n*Attributes*: Debuggable, Networked"] @ [Debuggable , Networked , Name ["Looping"] , Description ["When this is true, the animation clip will repeat infinitely."]] looping : bool , # [doc = "**Speed**: Animation playback speed. Default is 1, higher values speeds up the animation.\n\n*Attributes*: Debuggable, Networked"] @ [Debuggable , Networked , Name ["Speed"] , Description ["Animation playback speed. Default is 1, higher values speeds up the animation."]] speed : f32 , # [doc = "**Start time**: Start time of an animation node.\n\n*Attributes*: Debuggable, Networked"] @ [Debuggable , Networked , Name ["Start time"] , Description ["Start time of an animation node."]] start_time : Duration , # [doc = "**Freeze at percentage**: Sample the input animation at a certain percentage of the animation track length.\n\n*Attributes*: Debuggable, Networked"] @ [Debuggable , Networked , Name ["Freeze at percentage"] , Description ["Sample the input animation at a certain percentage of the animation track length."]] freeze_at_percentage : f32 , # [doc = "**Freeze at time**: Sample the input animation at a certain time (in seconds).\n\n*Attributes*: Debuggable, Networked"] @ [Debuggable , Networked , Name ["Freeze at time"] , Description ["Sample the input animation at a certain time (in seconds)."]] freeze_at_time : f32 , # [doc = "**Clip duration**: The clip duration is loaded from the clip, and then applied to the entity.\n\n*Attributes*: Debuggable"] @ [Debuggable , Name ["Clip duration"] , Description ["The clip duration is loaded from the clip, and then applied to the entity."]] clip_duration : f32 , # [doc = "**Clip loaded**: The clip has been loaded.\n\n*Attributes*: Debuggable"] @ [Debuggable , Name ["Clip loaded"] , Description ["The clip has been loaded."]] clip_loaded : () , # [doc = "**Clip load error**: There was an error loading the clip.\n\n*Attributes*: Debuggable"] @ [Debuggable , Name ["Clip load error"] , Description ["There was an error loading the clip."]] clip_load_error : String , # [doc = "**Blend**: Blend two animations together. The values is the blend weight. Use `children` to set the animations. Blend 0 means we only sample from the first animation, 1 means only the second one, and values in between blend between them.\n\n*Attributes*: Debuggable, Networked"] @ [Debuggable , Networked , Name ["Blend"] , Description ["Blend two animations together. The values is the blend weight. Use `children` to set the animations. Blend 0 means we only sample from the first animation, 1 means only the second one, and values in between blend between them."]] blend : f32 , # [doc = "**Mask bind ids**: List of bind ids that will be masked.\n\n*Attributes*: Debuggable, Networked"] @ [Debuggable , Networked , Name ["Mask bind ids"] , Description ["List of bind ids that will be masked."]] mask_bind_ids : Vec :: < String > , # [doc = "**Mask weights**: Weights for each bind id in `mask_bind_ids`.\n\n*Attributes*: Debuggable, Networked"] @ [Debuggable , Networked , Name ["Mask weights"] , Description ["Weights for each bind id in `mask_bind_ids`."]] mask_weights : Vec :: < f32 > , # [doc = "**Retarget Model from URL**: Retarget the animation using the model at the given URL.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Retarget Model from URL"] , Description ["Retarget the animation using the model at the given URL."]] retarget_model_from_url : String , # [doc = "**Retarget animation scaled**: Retarget animation scaled. True means normalize hip.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Retarget animation scaled"] , Description ["Retarget animation scaled. True means normalize hip."]] retarget_animation_scaled : bool , # [doc = "**Apply base pose**: Apply the base pose to this clip.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Apply base pose"] , Description ["Apply the base pose to this clip."]] apply_base_pose : () , # [doc = "**Bind id**: Animation bind ID.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Bind id"] , Description ["Animation bind ID."]] bind_id : String , # [doc = "**Bind ids**: Animation bind IDs.\n\n*Attributes*: Debuggable, Store"] @ [Debuggable , Store , Name ["Bind ids"] , Description ["Animation bind IDs."]] bind_ids : Vec :: < String > , # [doc = "**Root motion bone**: Enables root motion on this animation player. The translation of the bone with this bind id along the `root_motion_axes` is removed from the animation, and the entities the player is applied to get a `root_motion_delta` instead.\n\n*Attributes*: Debuggable, Networked"] @ [Debuggable , Networked , Name ["Root motion bone"] , Description ["Enables root motion on this animation player. The translation of the bone with this bind id along the `root_motion_axes` is removed from the animation, and the entities the player is applied to get a `root_motion_delta` instead."]] root_motion_bone : String , # [doc = "**Root motion delta**: The world-space translation of the root motion bone during the last frame. Set on entities with an `apply_animation_player` whose player has a `root_motion_bone`.\n\n*Attributes*: Debuggable"] @ [Debuggable , Name ["Root motion delta"] , Description ["The world-space translation of the root motion bone during the last frame. Set on entities with an `apply_animation_player` whose player has a `root_motion_bone`."]] root_motion_delta : Vec3 , # [doc = "**Two-bone IK bind ids**: If attached, this animation node is a two-bone IK node. It takes the output of its only child, and rotates the bones with the bind ids `[root, middle, end]` (e.g. upper leg, lower leg and foot) so that the end reaches `ik_target`.\n\n*Attributes*: Debuggable, Networked"] @ [Debuggable , Networked , Name ["Two-bone IK bind ids"] , Description ["If attached, this animation node is a two-bone IK node. It takes the output of its only child, and rotates the bones with the bind ids `[root, middle, end]` (e.g. upper leg, lower leg and foot) so that the end reaches `ik_target`."]] two_bone_ik_bind_ids : Vec :: < String > , # [doc = "**Look-at IK bind id**: If attached, this animation node is a look-at IK node. It takes the output of its only child, and rotates the bone with this bind id so that its `look_at_ik_axis` points towards `ik_target`.\n\n*Attributes*: Debuggable, Networked"] @ [Debuggable , Networked , Name ["Look-at IK bind id"] , Description ["If attached, this animation node is a look-at IK node. It takes the output of its only child, and rotates the bone with this bind id so that its `look_at_ik_axis` points towards `ik_target`."]] look_at_ik_bind_id : String , # [doc = "**Look-at IK axis**: The axis of the bone of a look-at IK node that is pointed towards the target, in the space of the bone. Defaults to the Z axis.\n\n*Attributes*: Debuggable, Networked"] @ [Debuggable , Networked , Name ["Look-at IK axis"] , Description ["The axis of the bone of a look-at IK node that is pointed towards the target, in the space of the bone. Defaults to the Z axis."]] look_at_ik_axis : Vec3 , # [doc = "**IK target**: The world-space position that an IK node reaches for or looks at.\n\n*Attributes*: Debuggable, Networked"] @ [Debuggable , Networked , Name ["IK target"] , Description ["The world-space position that an IK node reaches for or looks at."]] ik_target : Vec3 , # [doc = "**IK target entity**: If attached, an IK node uses the world-space position of this entity as its target instead of `ik_target`.\n\n*Attributes*: Debuggable, Networked"] @ [Debuggable , Networked , Name ["IK target entity"] , Description ["If attached, an IK node uses the world-space position of this entity as its target instead of `ik_target`."]] ik_target_entity : EntityId , # [doc = "**IK pole**: The world-space position that the middle bone of a two-bone IK node bends towards, like the direction of a knee or an elbow. If not attached, the chain keeps bending the way it does in the animation.\n\n*Attributes*: Debuggable, Networked"] @ [Debuggable , Networked , Name ["IK pole"] , Description ["The world-space position that the middle bone of a two-bone IK node bends towards, like the direction of a knee or an elbow. If not attached, the chain keeps bending the way it does in the animation."]] ik_pole : Vec3 , # [doc = "**IK weight**: How much an IK node changes the output of its child, from 0 to 1. Defaults to 1.\n\n*Attributes*: Debuggable, Networked"] @ [Debuggable , Networked , Name ["IK weight"] , Description ["How much an IK node changes the output of its child, from 0 to 1. Defaults to 1."]] ik_weight : f32 , # [doc = "**Blend space positions**: If attached, this animation node is a blend space, which blends its `children` by where `blend_space_parameter` is between them. The value is the position of each child in the parameter space. If the positions are on a line, the blend space is 1D; otherwise, the children are blended by the triangle of the Delaunay triangulation of the positions that the parameter is in.\n\n*Attributes*: Debuggable, Networked"] @ [Debuggable , Networked , Name ["Blend space positions"] , Description ["If attached, this animation node is a blend space, which blends its `children` by where `blend_space_parameter` is between them. The value is the position of each child in the parameter space. If the positions are on a line, the blend space is 1D; otherwise, the children are blended by the triangle of the Delaunay triangulation of the positions that the parameter is in."]] blend_space_positions : Vec :: < Vec2 > , # [doc = "**Blend space parameter**: The position in the parameter space of a blend space to blend its children at, such as the velocity of a character. Positions outside of the children are clamped to the closest point between them.\n\n*Attributes*: Debuggable, Networked"] @ [Debuggable , Networked , Name ["Blend space parameter"] , Description ["The position in the parameter space of a blend space to blend its children at, such as the velocity of a character. Positions outside of the children are clamped to the closest point between them."]] blend_space_parameter : Vec2 , # [doc = "**Additive weight**: If attached, this animation node is an additive layer, which adds the difference between its second child and a reference pose to its first child, scaled by this weight. The reference pose is the third child if there is one, and otherwise the first frame of the second child, which must then be a clip.\n\n*Attributes*: Debuggable, Networked"] @ [Debuggable , Networked , Name ["Additive weight"] , Description ["If attached, this animation node is an additive layer, which adds the difference between its second child and a reference pose to its first child, scaled by this weight. The reference pose is the third child if there is one, and otherwise the first frame of the second child, which must then be a clip."]] additive_weight : f32 , # [doc = "**Root motion axes**: Which axes of the translation of the `root_motion_bone`, relative to its parent, are extracted as root motion. 1 extracts the axis and 0 leaves it in the animation. Defaults to `[1, 0, 1]`, the horizontal plane of Y-up skeletons, so that vertical motion like the bob of a walk stays in the animation.\n\n*Attributes*: Debuggable, Networked"] @ [Debuggable , Networked , Name ["Root motion axes"] , Description ["Which axes of the translation of the `root_motion_bone`, relative to its parent, are extracted as root motion. 1 extracts the axis and 0 leaves it in the animation. Defaults to `[1, 0, 1]`, the horizontal plane of Y-up skeletons, so that vertical motion like the bob of a walk stays in the animation."]] root_motion_axes : Vec3 , });
             }
         }
         pub mod app {
             #[doc = r" Auto-generated component definitions."]
             pub mod components {
                 use crate::{
-                    components, Debuggable, Description, EntityId, Enum, MaybeResource, Name,
-                    Networked, Resource, Store,
+                    components, Debuggable, Description, EntityId, Enum, Interpolated,
+                    MaybeResource, Name, Networked, Predicted, Resource, Store,
                 };
                 use ambient_shared_types::{
                     ProceduralMaterialHandle, ProceduralMeshHandle, ProceduralSamplerHandle,
@@ -53,8 +53,8 @@ mod raw {
             #[doc = r" Auto-generated component definitions."]
             pub mod components {
                 use crate::{
-                    components, Debuggable, Description, EntityId, Enum, MaybeResource, Name,
-                    Networked, Resource, Store,
+                    components, Debuggable, Description, EntityId, Enum, Interpolated,
+                    MaybeResource, Name, Networked, Predicted, Resource, Store,
                 };
                 use ambient_shared_types::{
                     ProceduralMaterialHandle, ProceduralMeshHandle, ProceduralSamplerHandle,
@@ -62,15 +62,15 @@ mod raw {
                 };
                 use glam::{Mat4, Quat, UVec2, UVec3, UVec4, Vec2, Vec3, Vec4};
                 use std::time::Duration;
-                components ! ("audio" , { # [doc = "**Is audio player**: The entity is an audio player.\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Is audio player"] , Description ["The entity is an audio player."]] is_audio_player : () , # [doc = "**Is spatial audio player**: The entity is a spatial audio player.\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Is spatial audio player"] , Description ["The entity is a spatial audio player."]] is_spatial_audio_player : () , # [doc = "**Spatial audio emitter**: The entity is a spatial audio emitter.\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Spatial audio emitter"] , Description ["The entity is a spatial audio emitter."]] spatial_audio_emitter : EntityId , # [doc = "**Spatial audio listener**: The entity is a spatial audio listener.\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Spatial audio listener"] , Description ["The entity is a spatial audio listener."]] spatial_audio_listener : EntityId , # [doc = "**Looping**: Whether or not the audio should loop.\n\n\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Looping"] , Description ["Whether or not the audio should loop.\n"]] looping : bool , # [doc = "**One pole low pass filter**: With this component, the audio will be filtered with a one pole low pass filter.\n\n\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["One pole low pass filter"] , Description ["With this component, the audio will be filtered with a one pole low pass filter.\n"]] onepole_lpf : f32 , # [doc = "**Playing sound**: The entity with this comp is a playing sound.\n\nWe can attach other components to it to control the sound parameters.\n\n\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Playing sound"] , Description ["The entity with this comp is a playing sound.\nWe can attach other components to it to control the sound parameters.\n"]] playing_sound : () , # [doc = "**Amplitude**: The amplitude of the audio.\n\n\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Amplitude"] , Description ["The amplitude of the audio.\n"]] amplitude : f32 , # [doc = "**Panning**: The panning of the audio.\n\n\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Panning"] , Description ["The panning of the audio.\n"]] panning : f32 , # [doc = "**Low_pass filter**: Low pass filter. The first value is the cutoff frequency, the second is the bandwidth.\n\n\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Low_pass filter"] , Description ["Low pass filter. The first value is the cutoff frequency, the second is the bandwidth.\n"]] lpf : Vec2 , # [doc = "**High_pass filter**: High pass filter. The first value is the cutoff frequency, the second is the bandwidth.\n\n\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["High_pass filter"] , Description ["High pass filter. The first value is the cutoff frequency, the second is the bandwidth.\n"]] hpf : Vec2 , # [doc = "**Audio URL**: The URL of the assets.\n\n\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Audio URL"] , Description ["The URL of the assets.\n"]] audio_url : String , # [doc = "**Trigger at this frame**: The system will watch for this component and PLAY the audio at this frame,\n\nusing the other components as parameters.\n\nThen set it back to false.\n\n\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Trigger at this frame"] , Description ["The system will watch for this component and PLAY the audio at this frame,\nusing the other components as parameters.\nThen set it back to false.\n"]] play_now : () , # [doc = "**Stop at this frame**: The system will watch for this component and STOP the audio at this frame,\n\nusing the other components as parameters.\n\nThen set it back to false.\n\n\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Stop at this frame"] , Description ["The system will watch for this component and STOP the audio at this frame,\nusing the other components as parameters.\nThen set it back to false.\n"]] stop_now : () , });
+                components ! ("audio" , { # [doc = "**Is audio player**: The entity is an audio player.\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Is audio player"] , Description ["The entity is an audio player."]] is_audio_player : () , # [doc = "**Is spatial audio player**: The entity is a spatial audio player.\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Is spatial audio player"] , Description ["The entity is a spatial audio player."]] is_spatial_audio_player : () , # [doc = "**Spatial audio emitter**: The entity is a spatial audio emitter.\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Spatial audio emitter"] , Description ["The entity is a spatial audio emitter."]] spatial_audio_emitter : EntityId , # [doc = "**Spatial audio listener**: The entity is a spatial audio listener.\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Spatial audio listener"] , Description ["The entity is a spatial audio listener."]] spatial_audio_listener : EntityId , # [doc = "**Looping**: Whether or not the audio should loop.\n\n\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Looping"] , Description ["Whether or not the audio should loop.\n"]] looping : bool , # [doc = "**One pole low pass filter**: With this component, the audio will be filtered with a one pole low pass filter.\n\n\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["One pole low pass filter"] , Description ["With this component, the audio will be filtered with a one pole low pass filter.\n"]] onepole_lpf : f32 , # [doc = "**Playing sound**: The entity with this comp is a playing sound.\n\nWe can attach other components to it to control the sound parameters.\n\n\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Playing sound"] , Description ["The entity with this comp is a playing sound.\nWe can attach other components to it to control the sound parameters.\n"]] playing_sound : () , # [doc = "**Amplitude**: The amplitude of the audio.\n\n\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Amplitude"] , Description ["The amplitude of the audio.\n"]] amplitude : f32 , # [doc = "**Panning**: The panning of the audio.\n\n\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Panning"] , Description ["The panning of the audio.\n"]] panning : f32 , # [doc = "**Low_pass filter**: Low pass filter. The first value is the cutoff frequency, the second is the bandwidth.\n\n\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Low_pass filter"] , Description ["Low pass filter. The first value is the cutoff frequency, the second is the bandwidth.\n"]] lpf : Vec2 , # [doc = "**High_pass filter**: High pass filter. The first value is the cutoff frequency, the second is the bandwidth.\n\n\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["High_pass filter"] , Description ["High pass filter. The first value is the cutoff frequency, the second is the bandwidth.\n"]] hpf : Vec2 , # [doc = "**Audio URL**: The URL of the assets.\n\n\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Audio URL"] , Description ["The URL of the assets.\n"]] audio_url : String , # [doc = "**Trigger at this frame**: The system will watch for this component and PLAY the audio at this frame,\n\nusing the other components as parameters.\n\nThen set it back to false.\n\n\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Trigger at this frame"] , Description ["The system will watch for this component and PLAY the audio at this frame,\nusing the other components as parameters.\nThen set it back to false.\n"]] play_now : () , # [doc = "**Stop at this frame**: The system will watch for this component and STOP the audio at this frame,\n\nusing the other components as parameters.\n\nThen set it back to false.\n\n\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Stop at this frame"] , Description ["The system will watch for this component and STOP the audio at this frame,\nusing the other components as parameters.\nThen set it back to false.\n"]] stop_now : () , # [doc = "**Audio bus**: The bus the audio is played on: master, music, sfx, voice or ui. Defaults to master. On an entity with `is_audio_bus`, the bus that the entity configures.\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Audio bus"] , Description ["The bus the audio is played on: master, music, sfx, voice or ui. Defaults to master. On an entity with `is_audio_bus`, the bus that the entity configures."]] audio_bus : String , # [doc = "**Is audio bus**: The entity configures the volume, effects and ducking of the bus in its `audio_bus` component. The effects are applied in the order high-pass, low-pass, EQ, compressor, delay, reverb.\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Is audio bus"] , Description ["The entity configures the volume, effects and ducking of the bus in its `audio_bus` component. The effects are applied in the order high-pass, low-pass, EQ, compressor, delay, reverb."]] is_audio_bus : () , # [doc = "**Audio bus volume**: The volume of the bus. Defaults to 1.\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Audio bus volume"] , Description ["The volume of the bus. Defaults to 1."]] audio_bus_volume : f32 , # [doc = "**Audio bus muted**: Whether or not the bus is muted.\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Audio bus muted"] , Description ["Whether or not the bus is muted."]] audio_bus_muted : bool , # [doc = "**Audio bus ducked by**: The bus which lowers the volume of this bus while it plays, e.g. voice to duck music under dialogue.\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Audio bus ducked by"] , Description ["The bus which lowers the volume of this bus while it plays, e.g. voice to duck music under dialogue."]] audio_bus_ducked_by : String , # [doc = "**Audio bus ducking**: How the bus is ducked by `audio_bus_ducked_by`. The values are the amount the volume is lowered in [0, 1], the amplitude of the other bus at which this bus is fully ducked, and the attack and release times in seconds.\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Audio bus ducking"] , Description ["How the bus is ducked by `audio_bus_ducked_by`. The values are the amount the volume is lowered in [0, 1], the amplitude of the other bus at which this bus is fully ducked, and the attack and release times in seconds."]] audio_bus_ducking : Vec4 , # [doc = "**Audio bus EQ**: Three band equalizer on the bus. The values are the gains in dB of the lows, mids and highs.\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Audio bus EQ"] , Description ["Three band equalizer on the bus. The values are the gains in dB of the lows, mids and highs."]] audio_bus_eq : Vec3 , # [doc = "**Audio bus compressor**: Compressor on the bus. The values are the threshold in dB, the ratio, and the attack and release times in seconds.\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Audio bus compressor"] , Description ["Compressor on the bus. The values are the threshold in dB, the ratio, and the attack and release times in seconds."]] audio_bus_compressor : Vec4 , # [doc = "**Audio bus delay**: Delay on the bus. The values are the delay time in seconds (at most 5), the feedback and the wet amount.\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Audio bus delay"] , Description ["Delay on the bus. The values are the delay time in seconds (at most 5), the feedback and the wet amount."]] audio_bus_delay : Vec3 , # [doc = "**Audio bus reverb**: Reverb on the bus. The values are the room size and damping in [0, 1], and the wet amount.\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Audio bus reverb"] , Description ["Reverb on the bus. The values are the room size and damping in [0, 1], and the wet amount."]] audio_bus_reverb : Vec3 , # [doc = "**Audio graph URL**: The URL of an audio graph asset (a `.toml` file describing a tree of audio nodes) to play instead of `audio_url`.\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Audio graph URL"] , Description ["The URL of an audio graph asset (a `.toml` file describing a tree of audio nodes) to play instead of `audio_url`."]] audio_graph_url : String , # [doc = "**Audio graph seed**: The seed for the random nodes of the audio graph. If not set, a random seed is used every time the graph is played.\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Audio graph seed"] , Description ["The seed for the random nodes of the audio graph. If not set, a random seed is used every time the graph is played."]] audio_graph_seed : u64 , # [doc = "**Audio streaming**: If true, the `audio_url` is downloaded and decoded while it plays instead of being loaded up front. Recommended for long tracks like music.\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Audio streaming"] , Description ["If true, the `audio_url` is downloaded and decoded while it plays instead of being loaded up front. Recommended for long tracks like music."]] audio_streaming : bool , # [doc = "**Audio bus high-pass**: High-pass filter on the bus. The values are the cutoff frequency in Hz and the bandwidth in octaves.\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Audio bus high-pass"] , Description ["High-pass filter on the bus. The values are the cutoff frequency in Hz and the bandwidth in octaves."]] audio_bus_high_pass : Vec2 , # [doc = "**Audio bus low-pass**: Low-pass filter on the bus. The values are the cutoff frequency in Hz and the bandwidth in octaves.\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Audio bus low-pass"] , Description ["Low-pass filter on the bus. The values are the cutoff frequency in Hz and the bandwidth in octaves."]] audio_bus_low_pass : Vec2 , # [doc = "**Audio seek**: Seeks the streaming sound of this entity to this time in the track. This is the playing sound entity of an audio player, or the emitter of a spatial audio player. The component is removed once the stream has started and the seek has been applied. Only has an effect on sounds played with `audio_streaming`.\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Audio seek"] , Description ["Seeks the streaming sound of this entity to this time in the track. This is the playing sound entity of an audio player, or the emitter of a spatial audio player. The component is removed once the stream has started and the seek has been applied. Only has an effect on sounds played with `audio_streaming`."]] audio_seek : Duration , });
             }
         }
         pub mod camera {
             #[doc = r" Auto-generated component definitions."]
             pub mod components {
                 use crate::{
-                    components, Debuggable, Description, EntityId, Enum, MaybeResource, Name,
-                    Networked, Resource, Store,
+                    components, Debuggable, Description, EntityId, Enum, Interpolated,
+                    MaybeResource, Name, Networked, Predicted, Resource, Store,
                 };
                 use ambient_shared_types::{
                     ProceduralMaterialHandle, ProceduralMeshHandle, ProceduralSamplerHandle,
@@ -85,8 +85,8 @@ mod raw {
             #[doc = r" Auto-generated component definitions."]
             pub mod components {
                 use crate::{
-                    components, Debuggable, Description, EntityId, Enum, MaybeResource, Name,
-                    Networked, Resource, Store,
+                    components, Debuggable, Description, EntityId, Enum, Interpolated,
+                    MaybeResource, Name, Networked, Predicted, Resource, Store,
                 };
                 use ambient_shared_types::{
                     ProceduralMaterialHandle, ProceduralMeshHandle, ProceduralSamplerHandle,
@@ -101,8 +101,8 @@ mod raw {
             #[doc = r" Auto-generated component definitions."]
             pub mod components {
                 use crate::{
-                    components, Debuggable, Description, EntityId, Enum, MaybeResource, Name,
-                    Networked, Resource, Store,
+                    components, Debuggable, Description, EntityId, Enum, Interpolated,
+                    MaybeResource, Name, Networked, Predicted, Resource, Store,
                 };
                 use ambient_shared_types::{
                     ProceduralMaterialHandle, ProceduralMeshHandle, ProceduralSamplerHandle,
@@ -117,8 +117,8 @@ mod raw {
             #[doc = r" Auto-generated component definitions."]
             pub mod components {
                 use crate::{
-                    components, Debuggable, Description, EntityId, Enum, MaybeResource, Name,
-                    Networked, Resource, Store,
+                    components, Debuggable, Description, EntityId, Enum, Interpolated,
+                    MaybeResource, Name, Networked, Predicted, Resource, Store,
                 };
                 use ambient_shared_types::{
                     ProceduralMaterialHandle, ProceduralMeshHandle, ProceduralSamplerHandle,
@@ -212,8 +212,8 @@ mod raw {
             #[doc = r" Auto-generated component definitions."]
             pub mod components {
                 use crate::{
-                    components, Debuggable, Description, EntityId, Enum, MaybeResource, Name,
-                    Networked, Resource, Store,
+                    components, Debuggable, Description, EntityId, Enum, Interpolated,
+                    MaybeResource, Name, Networked, Predicted, Resource, Store,
                 };
                 use ambient_shared_types::{
                     ProceduralMaterialHandle, ProceduralMeshHandle, ProceduralSamplerHandle,
@@ -525,8 +525,8 @@ mod raw {
             #[doc = r" Auto-generated component definitions."]
             pub mod components {
                 use crate::{
-                    components, Debuggable, Description, EntityId, Enum, MaybeResource, Name,
-                    Networked, Resource, Store,
+                    components, Debuggable, Description, EntityId, Enum, Interpolated,
+                    MaybeResource, Name, Networked, Predicted, Resource, Store,
                 };
                 use ambient_shared_types::{
                     ProceduralMaterialHandle, ProceduralMeshHandle, ProceduralSamplerHandle,
@@ -541,8 +541,8 @@ mod raw {
             #[doc = r" Auto-generated component definitions."]
             pub mod components {
                 use crate::{
-                    components, Debuggable, Description, EntityId, Enum, MaybeResource, Name,
-                    Networked, Resource, Store,
+                    components, Debuggable, Description, EntityId, Enum, Interpolated,
+                    MaybeResource, Name, Networked, Predicted, Resource, Store,
                 };
                 use ambient_shared_types::{
                     ProceduralMaterialHandle, ProceduralMeshHandle, ProceduralSamplerHandle,
@@ -550,15 +550,15 @@ mod raw {
                 };
                 use glam::{Mat4, Quat, UVec2, UVec3, UVec4, Vec2, Vec3, Vec4};
                 use std::time::Duration;
-                components ! ("network" , { # [doc = "**Is remote entity**: If attached, this entity was not spawned locally (e.g. if this is the client, it was spawned by the server).\n\n*Attributes*: Debuggable, Networked"] @ [Debuggable , Networked , Name ["Is remote entity"] , Description ["If attached, this entity was not spawned locally (e.g. if this is the client, it was spawned by the server)."]] is_remote_entity : () , # [doc = "**Is persistent resources**: If attached, this entity contains global resources that are persisted to disk and synchronized to clients.\n\n*Attributes*: Debuggable, Networked"] @ [Debuggable , Networked , Name ["Is persistent resources"] , Description ["If attached, this entity contains global resources that are persisted to disk and synchronized to clients."]] is_persistent_resources : () , # [doc = "**Is synchronized resources**: If attached, this entity contains global resources that are synchronized to clients, but not persisted.\n\n*Attributes*: Debuggable, Networked"] @ [Debuggable , Networked , Name ["Is synchronized resources"] , Description ["If attached, this entity contains global resources that are synchronized to clients, but not persisted."]] is_synced_resources : () , # [doc = "**No sync**: If attached, this entity will not be synchronized to clients.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["No sync"] , Description ["If attached, this entity will not be synchronized to clients."]] no_sync : () , });
+                components ! ("network" , { # [doc = "**Is remote entity**: If attached, this entity was not spawned locally (e.g. if this is the client, it was spawned by the server).\n\n*Attributes*: Debuggable, Networked"] @ [Debuggable , Networked , Name ["Is remote entity"] , Description ["If attached, this entity was not spawned locally (e.g. if this is the client, it was spawned by the server)."]] is_remote_entity : () , # [doc = "**Is persistent resources**: If attached, this entity contains global resources that are persisted to disk and synchronized to clients.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Is persistent resources"] , Description ["If attached, this entity contains global resources that are persisted to disk and synchronized to clients."]] is_persistent_resources : () , # [doc = "**Is synchronized resources**: If attached, this entity contains global resources that are synchronized to clients, but not persisted.\n\n*Attributes*: Debuggable, Networked"] @ [Debuggable , Networked , Name ["Is synchronized resources"] , Description ["If attached, this entity contains global resources that are synchronized to clients, but not persisted."]] is_synced_resources : () , # [doc = "**No sync**: If attached, this entity will not be synchronized to clients.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["No sync"] , Description ["If attached, this entity will not be synchronized to clients."]] no_sync : () , # [doc = "**Always relevant**: If attached, this entity is synchronized to every player, regardless of the relevancy rules.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Always relevant"] , Description ["If attached, this entity is synchronized to every player, regardless of the relevancy rules."]] always_relevant : () , # [doc = "**Visible to**: If attached, this entity is only synchronized to the players in this list.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Visible to"] , Description ["If attached, this entity is only synchronized to the players in this list."]] visible_to : Vec :: < EntityId > , # [doc = "**Relevancy distance**: The maximum distance from a player's viewpoint at which entities are synchronized to that player. Attach it to the resource entity to use it for all entities, or to an entity to override it for that entity.\n\n*Attributes*: MaybeResource, Debuggable, Networked, Store"] @ [MaybeResource , Debuggable , Networked , Store , Name ["Relevancy distance"] , Description ["The maximum distance from a player's viewpoint at which entities are synchronized to that player. Attach it to the resource entity to use it for all entities, or to an entity to override it for that entity."]] relevancy_distance : f32 , # [doc = "**Last input tick**: The tick of the last input from this player that the server has simulated. Used by the client to reconcile its predicted components.\n\n*Attributes*: Debuggable, Networked"] @ [Debuggable , Networked , Name ["Last input tick"] , Description ["The tick of the last input from this player that the server has simulated. Used by the client to reconcile its predicted components."]] last_input_tick : u64 , # [doc = "**Instance ID**: The ID of the world instance that this world belongs to. The main instance, which players join when they connect, is `main`.\n\n*Attributes*: Debuggable, Resource"] @ [Debuggable , Resource , Name ["Instance ID"] , Description ["The ID of the world instance that this world belongs to. The main instance, which players join when they connect, is `main`."]] instance_id : String , });
             }
         }
         pub mod package {
             #[doc = r" Auto-generated component definitions."]
             pub mod components {
                 use crate::{
-                    components, Debuggable, Description, EntityId, Enum, MaybeResource, Name,
-                    Networked, Resource, Store,
+                    components, Debuggable, Description, EntityId, Enum, Interpolated,
+                    MaybeResource, Name, Networked, Predicted, Resource, Store,
                 };
                 use ambient_shared_types::{
                     ProceduralMaterialHandle, ProceduralMeshHandle, ProceduralSamplerHandle,
@@ -715,8 +715,8 @@ mod raw {
             #[doc = r" Auto-generated component definitions."]
             pub mod components {
                 use crate::{
-                    components, Debuggable, Description, EntityId, Enum, MaybeResource, Name,
-                    Networked, Resource, Store,
+                    components, Debuggable, Description, EntityId, Enum, Interpolated,
+                    MaybeResource, Name, Networked, Predicted, Resource, Store,
                 };
                 use ambient_shared_types::{
                     ProceduralMaterialHandle, ProceduralMeshHandle, ProceduralSamplerHandle,
@@ -724,15 +724,94 @@ mod raw {
                 };
                 use glam::{Mat4, Quat, UVec2, UVec3, UVec4, Vec2, Vec3, Vec4};
                 use std::time::Duration;
-                components ! ("physics" , { # [doc = "**Angular velocity**: Angular velocity (radians/second) of this entity in the physics scene.\n\nUpdating this component will update the entity's angular velocity in the physics scene.\n\n\n\nNote that changing this component will forcibly set the velocity; changing the velocity every frame may lead to unexpected behavior, like improper physics or collisions failing.\n\n\n\nIf you need to adjust the velocity each frame, consider applying an impulse using `physics` functions instead.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Angular velocity"] , Description ["Angular velocity (radians/second) of this entity in the physics scene.\nUpdating this component will update the entity's angular velocity in the physics scene.\n\nNote that changing this component will forcibly set the velocity; changing the velocity every frame may lead to unexpected behavior, like improper physics or collisions failing.\n\nIf you need to adjust the velocity each frame, consider applying an impulse using `physics` functions instead."]] angular_velocity : Vec3 , # [doc = "**Cube collider**: If attached, this entity will have a cube physics collider.\n\n`x, y, z` is the size of the cube.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Cube collider"] , Description ["If attached, this entity will have a cube physics collider.\n`x, y, z` is the size of the cube."]] cube_collider : Vec3 , # [doc = "**Character controller height**: The height of the physics character controller attached to this entity.\n\nIf an entity has both this and a `character_controller_radius`, it will be given a physical character collider.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Character controller height"] , Description ["The height of the physics character controller attached to this entity.\nIf an entity has both this and a `character_controller_radius`, it will be given a physical character collider."]] character_controller_height : f32 , # [doc = "**Character controller radius**: The radius of the physics character controller attached to this entity.\n\nIf an entity has both this and a `character_controller_height`, it will be given a physical character collider.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Character controller radius"] , Description ["The radius of the physics character controller attached to this entity.\nIf an entity has both this and a `character_controller_height`, it will be given a physical character collider."]] character_controller_radius : f32 , # [doc = "**Collider from URL**: This entity will load its physics collider from the URL.\n\nThe value is the URL to load from.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Collider from URL"] , Description ["This entity will load its physics collider from the URL.\nThe value is the URL to load from."]] collider_from_url : String , # [doc = "**Collider loaded**: This component is automatically attached to an entity once the collider has been loaded (through e.g. `collider_from_url`).\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Collider loaded"] , Description ["This component is automatically attached to an entity once the collider has been loaded (through e.g. `collider_from_url`)."]] collider_loaded : () , # [doc = "**Collider loads**: Contains all colliders that were loaded in this physics tick.\n\n*Attributes*: Debuggable, Networked, Resource, Store"] @ [Debuggable , Networked , Resource , Store , Name ["Collider loads"] , Description ["Contains all colliders that were loaded in this physics tick."]] collider_loads : Vec :: < EntityId > , # [doc = "**Contact offset**: Contact offset (in meters) of this entity in the physics scene.\n\nUpdating this component will update the entity's contact offset for each attached shape in the physics scene.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Contact offset"] , Description ["Contact offset (in meters) of this entity in the physics scene.\nUpdating this component will update the entity's contact offset for each attached shape in the physics scene."]] contact_offset : f32 , # [doc = "**Density**: The density of this entity.\n\nThis is used to update the `mass` when the entity is rescaled.\n\n*Attributes*: Debuggable, Networked, Store\n\n*Suggested Default*: 1.0"] @ [Debuggable , Networked , Store , Name ["Density"] , Description ["The density of this entity.\nThis is used to update the `mass` when the entity is rescaled."]] density : f32 , # [doc = "**Dynamic**: If this is true, the entity will be dynamic (i.e. be able to move). Otherwise, it will be static.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Dynamic"] , Description ["If this is true, the entity will be dynamic (i.e. be able to move). Otherwise, it will be static."]] dynamic : bool , # [doc = "**Kinematic**: If attached, and this entity is dynamic, this entity will also be kinematic (i.e. unable to be affected by other entities motion). Otherwise, it will receive forces normally.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Kinematic"] , Description ["If attached, and this entity is dynamic, this entity will also be kinematic (i.e. unable to be affected by other entities motion). Otherwise, it will receive forces normally."]] kinematic : () , # [doc = "**Linear velocity**: Linear velocity (meters/second) of this entity in the physics scene.\n\nUpdating this component will update the entity's linear velocity in the physics scene.\n\n\n\nNote that changing this component will forcibly set the velocity; changing the velocity every frame may lead to unexpected behavior, like gravity not working or collisions failing.\n\n\n\nIf you need to adjust the velocity each frame, consider applying a force using `physics` functions instead.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Linear velocity"] , Description ["Linear velocity (meters/second) of this entity in the physics scene.\nUpdating this component will update the entity's linear velocity in the physics scene.\n\nNote that changing this component will forcibly set the velocity; changing the velocity every frame may lead to unexpected behavior, like gravity not working or collisions failing.\n\nIf you need to adjust the velocity each frame, consider applying a force using `physics` functions instead."]] linear_velocity : Vec3 , # [doc = "**Make physics static**: All physics objects will be made static when loaded.\n\n*Attributes*: Debuggable, Networked, Resource, Store"] @ [Debuggable , Networked , Resource , Store , Name ["Make physics static"] , Description ["All physics objects will be made static when loaded."]] make_physics_static : bool , # [doc = "**Mass**: The mass of this entity, measured in kilograms.\n\n*Attributes*: Debuggable, Networked, Store\n\n*Suggested Default*: 1.0"] @ [Debuggable , Networked , Store , Name ["Mass"] , Description ["The mass of this entity, measured in kilograms."]] mass : f32 , # [doc = "**Physics controlled**: If attached, this entity will be controlled by physics.\n\nNote that this requires the entity to have a collider.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Physics controlled"] , Description ["If attached, this entity will be controlled by physics.\nNote that this requires the entity to have a collider."]] physics_controlled : () , # [doc = "**Plane collider**: If attached, this entity will have a plane physics collider. A plane is an infinite, flat surface. If you need a bounded flat surface, consider using a cube collider instead.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Plane collider"] , Description ["If attached, this entity will have a plane physics collider. A plane is an infinite, flat surface. If you need a bounded flat surface, consider using a cube collider instead."]] plane_collider : () , # [doc = "**Rest offset**: Rest offset (in meters) of this entity in the physics scene.\n\nUpdating this component will update the entity's rest offset for each attached shape in the physics scene.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Rest offset"] , Description ["Rest offset (in meters) of this entity in the physics scene.\nUpdating this component will update the entity's rest offset for each attached shape in the physics scene."]] rest_offset : f32 , # [doc = "**Sphere collider**: If attached, this entity will have a sphere physics collider.\n\nThe value corresponds to the radius of the sphere.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Sphere collider"] , Description ["If attached, this entity will have a sphere physics collider.\nThe value corresponds to the radius of the sphere."]] sphere_collider : f32 , # [doc = "**Unit mass**: The mass of a character/unit.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Unit mass"] , Description ["The mass of a character/unit."]] unit_mass : f32 , # [doc = "**Unit velocity**: The velocity of a character/unit.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Unit velocity"] , Description ["The velocity of a character/unit."]] unit_velocity : Vec3 , # [doc = "**Unit yaw**: The yaw of a character/unit.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Unit yaw"] , Description ["The yaw of a character/unit."]] unit_yaw : f32 , # [doc = "**Visualize collider**: If attached, the collider will be rendered.\n\n\n\n**Note**: this will continuously overwrite the `local_gizmos` component.\n\n\n\n*Attributes*: Debuggable, Networked"] @ [Debuggable , Networked , Name ["Visualize collider"] , Description ["If attached, the collider will be rendered.\n\n**Note**: this will continuously overwrite the `local_gizmos` component.\n"]] visualize_collider : () , });
+                components ! ("physics" , { # [doc = "**Angular velocity**: Angular velocity (radians/second) of this entity in the physics scene.\n\nUpdating this component will update the entity's angular velocity in the physics scene.\n\n\n\nNote that changing this component will forcibly set the velocity; changing the velocity every frame may lead to unexpected behavior, like improper physics or collisions failing.\n\n\n\nIf you need to adjust the velocity each frame, consider applying an impulse using `physics` functions instead.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Angular velocity"] , Description ["Angular velocity (radians/second) of this entity in the physics scene.\nUpdating this component will update the entity's angular velocity in the physics scene.\n\nNote that changing this component will forcibly set the velocity; changing the velocity every frame may lead to unexpected behavior, like improper physics or collisions failing.\n\nIf you need to adjust the velocity each frame, consider applying an impulse using `physics` functions instead."]] angular_velocity : Vec3 , # [doc = "**Cube collider**: If attached, this entity will have a cube physics collider.\n\n`x, y, z` is the size of the cube.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Cube collider"] , Description ["If attached, this entity will have a cube physics collider.\n`x, y, z` is the size of the cube."]] cube_collider : Vec3 , # [doc = "**Character controller height**: The height of the physics character controller attached to this entity.\n\nIf an entity has both this and a `character_controller_radius`, it will be given a physical character collider.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Character controller height"] , Description ["The height of the physics character controller attached to this entity.\nIf an entity has both this and a `character_controller_radius`, it will be given a physical character collider."]] character_controller_height : f32 , # [doc = "**Character controller radius**: The radius of the physics character controller attached to this entity.\n\nIf an entity has both this and a `character_controller_height`, it will be given a physical character collider.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Character controller radius"] , Description ["The radius of the physics character controller attached to this entity.\nIf an entity has both this and a `character_controller_height`, it will be given a physical character collider."]] character_controller_radius : f32 , # [doc = "**Collider from URL**: This entity will load its physics collider from the URL.\n\nThe value is the URL to load from.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Collider from URL"] , Description ["This entity will load its physics collider from the URL.\nThe value is the URL to load from."]] collider_from_url : String , # [doc = "**Collider loaded**: This component is automatically attached to an entity once the collider has been loaded (through e.g. `collider_from_url`).\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Collider loaded"] , Description ["This component is automatically attached to an entity once the collider has been loaded (through e.g. `collider_from_url`)."]] collider_loaded : () , # [doc = "**Collider loads**: Contains all colliders that were loaded in this physics tick.\n\n*Attributes*: Debuggable, Networked, Resource, Store"] @ [Debuggable , Networked , Resource , Store , Name ["Collider loads"] , Description ["Contains all colliders that were loaded in this physics tick."]] collider_loads : Vec :: < EntityId > , # [doc = "**Collides with**: The collision layers this collider collides with, as a bitmask. Two colliders only collide if each is on a layer that the other collides with. Colliders without this component collide with every layer. Also used to filter what character controllers collide with.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Collides with"] , Description ["The collision layers this collider collides with, as a bitmask. Two colliders only collide if each is on a layer that the other collides with. Colliders without this component collide with every layer. Also used to filter what character controllers collide with."]] collides_with : u32 , # [doc = "**Collision layer**: The collision layers this collider is on, as a bitmask. Colliders without this component, or with a value of `0`, are on the default layer, `1`. Updating this component will update the filtering of each attached shape in the physics scene.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Collision layer"] , Description ["The collision layers this collider is on, as a bitmask. Colliders without this component, or with a value of `0`, are on the default layer, `1`. Updating this component will update the filtering of each attached shape in the physics scene."]] collision_layer : u32 , # [doc = "**Contact offset**: Contact offset (in meters) of this entity in the physics scene.\n\nUpdating this component will update the entity's contact offset for each attached shape in the physics scene.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Contact offset"] , Description ["Contact offset (in meters) of this entity in the physics scene.\nUpdating this component will update the entity's contact offset for each attached shape in the physics scene."]] contact_offset : f32 , # [doc = "**Density**: The density of this entity.\n\nThis is used to update the `mass` when the entity is rescaled.\n\n*Attributes*: Debuggable, Networked, Store\n\n*Suggested Default*: 1.0"] @ [Debuggable , Networked , Store , Name ["Density"] , Description ["The density of this entity.\nThis is used to update the `mass` when the entity is rescaled."]] density : f32 , # [doc = "**Dynamic**: If this is true, the entity will be dynamic (i.e. be able to move). Otherwise, it will be static.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Dynamic"] , Description ["If this is true, the entity will be dynamic (i.e. be able to move). Otherwise, it will be static."]] dynamic : bool , # [doc = "**Is trigger**: If attached, the collider of this entity is a trigger volume: it does not collide with anything, and `TriggerEnter` and `TriggerExit` messages are sent when other colliders enter or leave it. On the client, these messages are approximated for sphere and cube triggers, so that clientside modules can preview them.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Is trigger"] , Description ["If attached, the collider of this entity is a trigger volume: it does not collide with anything, and `TriggerEnter` and `TriggerExit` messages are sent when other colliders enter or leave it. On the client, these messages are approximated for sphere and cube triggers, so that clientside modules can preview them."]] is_trigger : () , # [doc = "**Joint actor 0**: The first entity connected by this joint. The joint is created once this entity has a collider.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Joint actor 0"] , Description ["The first entity connected by this joint. The joint is created once this entity has a collider."]] joint_actor_0 : EntityId , # [doc = "**Joint actor 1**: The second entity connected by this joint. If not attached, the first entity is jointed to its current place in the world.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Joint actor 1"] , Description ["The second entity connected by this joint. If not attached, the first entity is jointed to its current place in the world."]] joint_actor_1 : EntityId , # [doc = "**Joint break force**: The force at which this joint breaks. If not attached, the joint can not be broken by force.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Joint break force"] , Description ["The force at which this joint breaks. If not attached, the joint can not be broken by force."]] joint_break_force : f32 , # [doc = "**Joint break torque**: The torque at which this joint breaks. If not attached, the joint can not be broken by torque.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Joint break torque"] , Description ["The torque at which this joint breaks. If not attached, the joint can not be broken by torque."]] joint_break_torque : f32 , # [doc = "**Joint broken**: Attached by the physics system when this joint has broken. Removing it recreates the joint.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Joint broken"] , Description ["Attached by the physics system when this joint has broken. Removing it recreates the joint."]] joint_broken : () , # [doc = "**Joint drive damping**: The damping of the drive of this joint. For distance joints, the damping of the spring. For revolute and spherical joints, the damping of a spring towards the rest orientation. For D6 joints, the damping of the drives of the free and limited axes.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Joint drive damping"] , Description ["The damping of the drive of this joint. For distance joints, the damping of the spring. For revolute and spherical joints, the damping of a spring towards the rest orientation. For D6 joints, the damping of the drives of the free and limited axes."]] joint_drive_damping : f32 , # [doc = "**Joint drive force limit**: The maximum force the drive of this joint can apply. Applies to revolute and D6 joints.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Joint drive force limit"] , Description ["The maximum force the drive of this joint can apply. Applies to revolute and D6 joints."]] joint_drive_force_limit : f32 , # [doc = "**Joint drive stiffness**: The stiffness of the drive of this joint. For distance joints, attaching it makes the joint a spring. For revolute and spherical joints, the stiffness of a spring towards the rest orientation. For D6 joints, the stiffness of the drives of the free and limited axes.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Joint drive stiffness"] , Description ["The stiffness of the drive of this joint. For distance joints, attaching it makes the joint a spring. For revolute and spherical joints, the stiffness of a spring towards the rest orientation. For D6 joints, the stiffness of the drives of the free and limited axes."]] joint_drive_stiffness : f32 , # [doc = "**Joint drive velocity**: The target velocity of the motor of this joint. For revolute joints, attaching it enables the motor, in radians/second. For D6 joints, the target angular velocity around the twist axis.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Joint drive velocity"] , Description ["The target velocity of the motor of this joint. For revolute joints, attaching it enables the motor, in radians/second. For D6 joints, the target angular velocity around the twist axis."]] joint_drive_velocity : f32 , # [doc = "**Joint free axes**: For D6 joints, a bitmask of the axes that can move freely: 1 = X, 2 = Y, 4 = Z, 8 = twist, 16 = swing 1, 32 = swing 2. Axes that are neither free nor limited are locked.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Joint free axes"] , Description ["For D6 joints, a bitmask of the axes that can move freely: 1 = X, 2 = Y, 4 = Z, 8 = twist, 16 = swing 1, 32 = swing 2. Axes that are neither free nor limited are locked."]] joint_free_axes : u32 , # [doc = "**Joint kind**: If attached, this entity is a joint connecting `joint_actor_0` to `joint_actor_1`, and this is its kind.\n\n*Attributes*: Debuggable, Networked, Store, Enum"] @ [Debuggable , Networked , Store , Enum , Name ["Joint kind"] , Description ["If attached, this entity is a joint connecting `joint_actor_0` to `joint_actor_1`, and this is its kind."]] joint_kind : crate :: generated :: raw :: ambient_core :: physics :: types :: JointKind , # [doc = "**Joint limited axes**: For D6 joints, a bitmask of the axes that are limited by `joint_limits`, using the same bits as `joint_free_axes`.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Joint limited axes"] , Description ["For D6 joints, a bitmask of the axes that are limited by `joint_limits`, using the same bits as `joint_free_axes`."]] joint_limited_axes : u32 , # [doc = "**Joint limits**: The lower and upper limits of this joint. For revolute joints, the angle limits in radians. For prismatic joints, the translation limits along the X axis. For distance joints, the minimum and maximum distance. For spherical joints, the half-angles of the limit cone around the Y and Z axes. For D6 joints, the twist angle limits; limited swing axes use the magnitudes of the lower and upper limits as the half-angles of the cone around the Y and Z axes, and limited linear axes use the upper limit as distance.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Joint limits"] , Description ["The lower and upper limits of this joint. For revolute joints, the angle limits in radians. For prismatic joints, the translation limits along the X axis. For distance joints, the minimum and maximum distance. For spherical joints, the half-angles of the limit cone around the Y and Z axes. For D6 joints, the twist angle limits; limited swing axes use the magnitudes of the lower and upper limits as the half-angles of the cone around the Y and Z axes, and limited linear axes use the upper limit as distance."]] joint_limits : Vec2 , # [doc = "**Joint local frame 0**: The frame of this joint relative to `joint_actor_0`. Defaults to the identity.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Joint local frame 0"] , Description ["The frame of this joint relative to `joint_actor_0`. Defaults to the identity."]] joint_local_frame_0 : Mat4 , # [doc = "**Joint local frame 1**: The frame of this joint relative to `joint_actor_1`, or to the world if there is no second actor. Defaults to the identity if there is a second actor, and to the current world frame of the joint otherwise.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Joint local frame 1"] , Description ["The frame of this joint relative to `joint_actor_1`, or to the world if there is no second actor. Defaults to the identity if there is a second actor, and to the current world frame of the joint otherwise."]] joint_local_frame_1 : Mat4 , # [doc = "**Kinematic**: If attached, and this entity is dynamic, this entity will also be kinematic (i.e. unable to be affected by other entities motion). Otherwise, it will receive forces normally.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Kinematic"] , Description ["If attached, and this entity is dynamic, this entity will also be kinematic (i.e. unable to be affected by other entities motion). Otherwise, it will receive forces normally."]] kinematic : () , # [doc = "**Linear velocity**: Linear velocity (meters/second) of this entity in the physics scene.\n\nUpdating this component will update the entity's linear velocity in the physics scene.\n\n\n\nNote that changing this component will forcibly set the velocity; changing the velocity every frame may lead to unexpected behavior, like gravity not working or collisions failing.\n\n\n\nIf you need to adjust the velocity each frame, consider applying a force using `physics` functions instead.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Linear velocity"] , Description ["Linear velocity (meters/second) of this entity in the physics scene.\nUpdating this component will update the entity's linear velocity in the physics scene.\n\nNote that changing this component will forcibly set the velocity; changing the velocity every frame may lead to unexpected behavior, like gravity not working or collisions failing.\n\nIf you need to adjust the velocity each frame, consider applying a force using `physics` functions instead."]] linear_velocity : Vec3 , # [doc = "**Make physics static**: All physics objects will be made static when loaded.\n\n*Attributes*: Debuggable, Networked, Resource, Store"] @ [Debuggable , Networked , Resource , Store , Name ["Make physics static"] , Description ["All physics objects will be made static when loaded."]] make_physics_static : bool , # [doc = "**Mass**: The mass of this entity, measured in kilograms.\n\n*Attributes*: Debuggable, Networked, Store\n\n*Suggested Default*: 1.0"] @ [Debuggable , Networked , Store , Name ["Mass"] , Description ["The mass of this entity, measured in kilograms."]] mass : f32 , # [doc = "**Physics controlled**: If attached, this entity will be controlled by physics.\n\nNote that this requires the entity to have a collider.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Physics controlled"] , Description ["If attached, this entity will be controlled by physics.\nNote that this requires the entity to have a collider."]] physics_controlled : () , # [doc = "**Plane collider**: If attached, this entity will have a plane physics collider. A plane is an infinite, flat surface. If you need a bounded flat surface, consider using a cube collider instead.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Plane collider"] , Description ["If attached, this entity will have a plane physics collider. A plane is an infinite, flat surface. If you need a bounded flat surface, consider using a cube collider instead."]] plane_collider : () , # [doc = "**Ragdoll bind IDs**: The bind IDs of the bones of this entity's `model_from_url` that get a ragdoll link, starting with the root of the ragdoll.\n\nEach link is attached to the link of its closest ancestor bone in the list.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Ragdoll bind IDs"] , Description ["The bind IDs of the bones of this entity's `model_from_url` that get a ragdoll link, starting with the root of the ragdoll.\nEach link is attached to the link of its closest ancestor bone in the list."]] ragdoll_bind_ids : Vec :: < String > , # [doc = "**Ragdoll blend**: How much the bones in `ragdoll_bind_ids` are driven by the ragdoll instead of animation, from 0 to 1.\n\nThe ragdoll is simulated on the server while this is more than 0, starting from the current pose of the model and the velocities of its bones.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Ragdoll blend"] , Description ["How much the bones in `ragdoll_bind_ids` are driven by the ragdoll instead of animation, from 0 to 1.\nThe ragdoll is simulated on the server while this is more than 0, starting from the current pose of the model and the velocities of its bones."]] ragdoll_blend : f32 , # [doc = "**Ragdoll joint limits**: The twist and swing limits, in radians, of the joint connecting each ragdoll link to its parent link. Defaults to 45 degrees for links without a limit.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Ragdoll joint limits"] , Description ["The twist and swing limits, in radians, of the joint connecting each ragdoll link to its parent link. Defaults to 45 degrees for links without a limit."]] ragdoll_joint_limits : Vec :: < Vec2 > , # [doc = "**Ragdoll link poses**: The world transforms of the ragdoll links, in the order of `ragdoll_bind_ids`. Updated by the server every frame while the ragdoll is simulated, so it is not stored.\n\n*Attributes*: Debuggable, Networked"] @ [Debuggable , Networked , Name ["Ragdoll link poses"] , Description ["The world transforms of the ragdoll links, in the order of `ragdoll_bind_ids`. Updated by the server every frame while the ragdoll is simulated, so it is not stored."]] ragdoll_link_poses : Vec :: < Mat4 > , # [doc = "**Ragdoll link radii**: The radius of the capsule collider of each ragdoll link. Defaults to 0.1 for links without a radius.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Ragdoll link radii"] , Description ["The radius of the capsule collider of each ragdoll link. Defaults to 0.1 for links without a radius."]] ragdoll_link_radii : Vec :: < f32 > , # [doc = "**Report contacts**: If attached, the contacts of this collider with other colliders are reported with `ContactBegin`, `ContactPersist` and `ContactEnd` messages, including the contact points, normals, impulse and relative velocity. This has a cost, so it should only be attached to colliders that need it.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Report contacts"] , Description ["If attached, the contacts of this collider with other colliders are reported with `ContactBegin`, `ContactPersist` and `ContactEnd` messages, including the contact points, normals, impulse and relative velocity. This has a cost, so it should only be attached to colliders that need it."]] report_contacts : () , # [doc = "**Rest offset**: Rest offset (in meters) of this entity in the physics scene.\n\nUpdating this component will update the entity's rest offset for each attached shape in the physics scene.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Rest offset"] , Description ["Rest offset (in meters) of this entity in the physics scene.\nUpdating this component will update the entity's rest offset for each attached shape in the physics scene."]] rest_offset : f32 , # [doc = "**Sphere collider**: If attached, this entity will have a sphere physics collider.\n\nThe value corresponds to the radius of the sphere.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Sphere collider"] , Description ["If attached, this entity will have a sphere physics collider.\nThe value corresponds to the radius of the sphere."]] sphere_collider : f32 , # [doc = "**Unit mass**: The mass of a character/unit.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Unit mass"] , Description ["The mass of a character/unit."]] unit_mass : f32 , # [doc = "**Unit velocity**: The velocity of a character/unit.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Unit velocity"] , Description ["The velocity of a character/unit."]] unit_velocity : Vec3 , # [doc = "**Unit yaw**: The yaw of a character/unit.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Unit yaw"] , Description ["The yaw of a character/unit."]] unit_yaw : f32 , # [doc = "**Visualize collider**: If attached, the collider will be rendered.\n\n\n\n**Note**: this will continuously overwrite the `local_gizmos` component.\n\n\n\n*Attributes*: Debuggable, Networked"] @ [Debuggable , Networked , Name ["Visualize collider"] , Description ["If attached, the collider will be rendered.\n\n**Note**: this will continuously overwrite the `local_gizmos` component.\n"]] visualize_collider : () , # [doc = "**Character movement direction**: The direction in the XY plane that the local player wants to move their character controller in, with a length of up to 1. Set it on the player entity on the client to have the movement predicted by the client and simulated by the server.\n\n*Attributes*: Debuggable"] @ [Debuggable , Name ["Character movement direction"] , Description ["The direction in the XY plane that the local player wants to move their character controller in, with a length of up to 1. Set it on the player entity on the client to have the movement predicted by the client and simulated by the server."]] character_movement_direction : Vec2 , # [doc = "**Character movement speed**: The maximum speed, in meters per second, at which the server moves a player with `character_movement_direction`. Defaults to 5.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Character movement speed"] , Description ["The maximum speed, in meters per second, at which the server moves a player with `character_movement_direction`. Defaults to 5."]] character_movement_speed : f32 , });
+            }
+            #[doc = r" Auto-generated type definitions."]
+            pub mod types {
+                use ambient_package_rt::message_serde::*;
+                use serde;
+                #[derive(
+                    Copy,
+                    Clone,
+                    Debug,
+                    PartialEq,
+                    Eq,
+                    serde :: Serialize,
+                    serde :: Deserialize,
+                    Default,
+                )]
+                #[serde(crate = "self::serde")]
+                #[doc = "**JointKind**: The kind of a joint."]
+                pub enum JointKind {
+                    #[default]
+                    #[doc = "Rigidly connects the actors."]
+                    Fixed,
+                    #[doc = "Allows rotation around the X axis of the joint frames."]
+                    Revolute,
+                    #[doc = "Allows rotation around any axis, like a ball-and-socket."]
+                    Spherical,
+                    #[doc = "Allows translation along the X axis of the joint frames."]
+                    Prismatic,
+                    #[doc = "Keeps the distance between the joint frames within the limits."]
+                    Distance,
+                    #[doc = "Configurable joint: each of its six axes can be locked, limited or free."]
+                    D6,
+                }
+                impl crate::EnumComponent for JointKind {
+                    fn to_u32(&self) -> u32 {
+                        match self {
+                            Self::Fixed => JointKind::Fixed as u32,
+                            Self::Revolute => JointKind::Revolute as u32,
+                            Self::Spherical => JointKind::Spherical as u32,
+                            Self::Prismatic => JointKind::Prismatic as u32,
+                            Self::Distance => JointKind::Distance as u32,
+                            Self::D6 => JointKind::D6 as u32,
+                        }
+                    }
+                    fn from_u32(value: u32) -> Option<Self> {
+                        if value == JointKind::Fixed as u32 {
+                            return Some(Self::Fixed);
+                        }
+                        if value == JointKind::Revolute as u32 {
+                            return Some(Self::Revolute);
+                        }
+                        if value == JointKind::Spherical as u32 {
+                            return Some(Self::Spherical);
+                        }
+                        if value == JointKind::Prismatic as u32 {
+                            return Some(Self::Prismatic);
+                        }
+                        if value == JointKind::Distance as u32 {
+                            return Some(Self::Distance);
+                        }
+                        if value == JointKind::D6 as u32 {
+                            return Some(Self::D6);
+                        }
+                        None
+                    }
+                }
+                impl MessageSerde for JointKind {
+                    fn serialize_message_part(
+                        &self,
+                        output: &mut Vec<u8>,
+                    ) -> Result<(), MessageSerdeError> {
+                        crate::EnumComponent::to_u32(self).serialize_message_part(output)
+                    }
+                    fn deserialize_message_part(
+                        input: &mut dyn std::io::Read,
+                    ) -> Result<Self, MessageSerdeError> {
+                        crate::EnumComponent::from_u32(u32::deserialize_message_part(input)?)
+                            .ok_or(MessageSerdeError::InvalidValue)
+                    }
+                }
             }
         }
         pub mod player {
             #[doc = r" Auto-generated component definitions."]
             pub mod components {
                 use crate::{
-                    components, Debuggable, Description, EntityId, Enum, MaybeResource, Name,
-                    Networked, Resource, Store,
+                    components, Debuggable, Description, EntityId, Enum, Interpolated,
+                    MaybeResource, Name, Networked, Predicted, Resource, Store,
                 };
                 use ambient_shared_types::{
                     ProceduralMaterialHandle, ProceduralMeshHandle, ProceduralSamplerHandle,
@@ -747,8 +826,8 @@ mod raw {
             #[doc = r" Auto-generated component definitions."]
             pub mod components {
                 use crate::{
-                    components, Debuggable, Description, EntityId, Enum, MaybeResource, Name,
-                    Networked, Resource, Store,
+                    components, Debuggable, Description, EntityId, Enum, Interpolated,
+                    MaybeResource, Name, Networked, Predicted, Resource, Store,
                 };
                 use ambient_shared_types::{
                     ProceduralMaterialHandle, ProceduralMeshHandle, ProceduralSamplerHandle,
@@ -756,15 +835,15 @@ mod raw {
                 };
                 use glam::{Mat4, Quat, UVec2, UVec3, UVec4, Vec2, Vec3, Vec4};
                 use std::time::Duration;
-                components ! ("prefab" , { # [doc = "**Prefab from URL**: Load and attach a prefab from a URL or relative path.\n\nWhen loaded, the components from this prefab will add to or replace the existing components for the entity.\n\n*Attributes*: Debuggable, Store"] @ [Debuggable , Store , Name ["Prefab from URL"] , Description ["Load and attach a prefab from a URL or relative path.\nWhen loaded, the components from this prefab will add to or replace the existing components for the entity."]] prefab_from_url : String , # [doc = "**Spawned**: If attached, this entity was built from a prefab that has finished spawning.\n\n*Attributes*: Debuggable"] @ [Debuggable , Name ["Spawned"] , Description ["If attached, this entity was built from a prefab that has finished spawning."]] spawned : () , });
+                components ! ("prefab" , { # [doc = "**Prefab from URL**: Load and attach a prefab from a URL or relative path.\n\nWhen loaded, the components of the root of this prefab will add to or replace the existing components for the entity, and the rest of its entities are spawned as descendants of the entity. Setting this again reloads the prefab, keeping the components in `prefab_overrides`.\n\n*Attributes*: Debuggable, Store"] @ [Debuggable , Store , Name ["Prefab from URL"] , Description ["Load and attach a prefab from a URL or relative path.\nWhen loaded, the components of the root of this prefab will add to or replace the existing components for the entity, and the rest of its entities are spawned as descendants of the entity. Setting this again reloads the prefab, keeping the components in `prefab_overrides`."]] prefab_from_url : String , # [doc = "**Spawned**: If attached, this entity was built from a prefab that has finished spawning.\n\n*Attributes*: Debuggable"] @ [Debuggable , Name ["Spawned"] , Description ["If attached, this entity was built from a prefab that has finished spawning."]] spawned : () , # [doc = "**Prefab overrides**: The components of this entity that were set for this instance of a prefab, as a list of component paths (e.g. `ambient_core::transform::translation`). When the prefab is reloaded, these components keep their values instead of being replaced by the ones in the prefab. This can be attached to the root of the instance, or any of the entities spawned from the prefab.\n\n*Attributes*: Debuggable, Store"] @ [Debuggable , Store , Name ["Prefab overrides"] , Description ["The components of this entity that were set for this instance of a prefab, as a list of component paths (e.g. `ambient_core::transform::translation`). When the prefab is reloaded, these components keep their values instead of being replaced by the ones in the prefab. This can be attached to the root of the instance, or any of the entities spawned from the prefab."]] prefab_overrides : Vec :: < String > , });
             }
         }
         pub mod primitives {
             #[doc = r" Auto-generated component definitions."]
             pub mod components {
                 use crate::{
-                    components, Debuggable, Description, EntityId, Enum, MaybeResource, Name,
-                    Networked, Resource, Store,
+                    components, Debuggable, Description, EntityId, Enum, Interpolated,
+                    MaybeResource, Name, Networked, Predicted, Resource, Store,
                 };
                 use ambient_shared_types::{
                     ProceduralMaterialHandle, ProceduralMeshHandle, ProceduralSamplerHandle,
@@ -779,8 +858,8 @@ mod raw {
             #[doc = r" Auto-generated component definitions."]
             pub mod components {
                 use crate::{
-                    components, Debuggable, Description, EntityId, Enum, MaybeResource, Name,
-                    Networked, Resource, Store,
+                    components, Debuggable, Description, EntityId, Enum, Interpolated,
+                    MaybeResource, Name, Networked, Predicted, Resource, Store,
                 };
                 use ambient_shared_types::{
                     ProceduralMaterialHandle, ProceduralMeshHandle, ProceduralSamplerHandle,
@@ -795,8 +874,8 @@ mod raw {
             #[doc = r" Auto-generated component definitions."]
             pub mod components {
                 use crate::{
-                    components, Debuggable, Description, EntityId, Enum, MaybeResource, Name,
-                    Networked, Resource, Store,
+                    components, Debuggable, Description, EntityId, Enum, Interpolated,
+                    MaybeResource, Name, Networked, Predicted, Resource, Store,
                 };
                 use ambient_shared_types::{
                     ProceduralMaterialHandle, ProceduralMeshHandle, ProceduralSamplerHandle,
@@ -811,8 +890,8 @@ mod raw {
             #[doc = r" Auto-generated component definitions."]
             pub mod components {
                 use crate::{
-                    components, Debuggable, Description, EntityId, Enum, MaybeResource, Name,
-                    Networked, Resource, Store,
+                    components, Debuggable, Description, EntityId, Enum, Interpolated,
+                    MaybeResource, Name, Networked, Predicted, Resource, Store,
                 };
                 use ambient_shared_types::{
                     ProceduralMaterialHandle, ProceduralMeshHandle, ProceduralSamplerHandle,
@@ -827,8 +906,8 @@ mod raw {
             #[doc = r" Auto-generated component definitions."]
             pub mod components {
                 use crate::{
-                    components, Debuggable, Description, EntityId, Enum, MaybeResource, Name,
-                    Networked, Resource, Store,
+                    components, Debuggable, Description, EntityId, Enum, Interpolated,
+                    MaybeResource, Name, Networked, Predicted, Resource, Store,
                 };
                 use ambient_shared_types::{
                     ProceduralMaterialHandle, ProceduralMeshHandle, ProceduralSamplerHandle,
@@ -934,8 +1013,8 @@ mod raw {
             #[doc = r" Auto-generated component definitions."]
             pub mod components {
                 use crate::{
-                    components, Debuggable, Description, EntityId, Enum, MaybeResource, Name,
-                    Networked, Resource, Store,
+                    components, Debuggable, Description, EntityId, Enum, Interpolated,
+                    MaybeResource, Name, Networked, Predicted, Resource, Store,
                 };
                 use ambient_shared_types::{
                     ProceduralMaterialHandle, ProceduralMeshHandle, ProceduralSamplerHandle,
@@ -943,15 +1022,15 @@ mod raw {
                 };
                 use glam::{Mat4, Quat, UVec2, UVec3, UVec4, Vec2, Vec3, Vec4};
                 use std::time::Duration;
-                components ! ("transform" , { # [doc = "**Cylindrical billboard Z**: If attached, this ensures this entity is always aligned with the camera, except on the Z-axis.\n\nThis is useful for decorations that the player will be looking at from roughly the same altitude.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Cylindrical billboard Z"] , Description ["If attached, this ensures this entity is always aligned with the camera, except on the Z-axis.\nThis is useful for decorations that the player will be looking at from roughly the same altitude."]] cylindrical_billboard_z : () , # [doc = "**Euler rotation**: The Euler rotation of this entity in ZYX order.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Euler rotation"] , Description ["The Euler rotation of this entity in ZYX order."]] euler_rotation : Vec3 , # [doc = "**Inverse Local to World**: Converts a world position to a local position.\n\nThis is automatically updated.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Inverse Local to World"] , Description ["Converts a world position to a local position.\nThis is automatically updated."]] inv_local_to_world : Mat4 , # [doc = "**Local to Parent**: Transformation from the entity's local space to the parent's space.\n\n*Attributes*: Debuggable, Networked, Store, MaybeResource"] @ [Debuggable , Networked , Store , MaybeResource , Name ["Local to Parent"] , Description ["Transformation from the entity's local space to the parent's space."]] local_to_parent : Mat4 , # [doc = "**Local to World**: Transformation from the entity's local space to worldspace.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Local to World"] , Description ["Transformation from the entity's local space to worldspace."]] local_to_world : Mat4 , # [doc = "**Look-at target**: The position that this entity should be looking at.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Look-at target"] , Description ["The position that this entity should be looking at."]] lookat_target : Vec3 , # [doc = "**Look-at up**: When combined with `lookat_target`, the up vector for this entity.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Look-at up"] , Description ["When combined with `lookat_target`, the up vector for this entity."]] lookat_up : Vec3 , # [doc = "**Mesh to Local**: Transformation from mesh-space to the entity's local space.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Mesh to Local"] , Description ["Transformation from mesh-space to the entity's local space."]] mesh_to_local : Mat4 , # [doc = "**Mesh to World**: Transformation from mesh-space to world space.\n\nThis is automatically updated when `mesh_to_local` and `local_to_world` change.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Mesh to World"] , Description ["Transformation from mesh-space to world space.\nThis is automatically updated when `mesh_to_local` and `local_to_world` change."]] mesh_to_world : Mat4 , # [doc = "**Reset scale**: If attached to a transform hierarchy, the scale will be reset at that point, with only rotation/translation considered.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Reset scale"] , Description ["If attached to a transform hierarchy, the scale will be reset at that point, with only rotation/translation considered."]] reset_scale : () , # [doc = "**Rotation**: The rotation of this entity.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Rotation"] , Description ["The rotation of this entity."]] rotation : Quat , # [doc = "**Scale**: The scale of this entity.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Scale"] , Description ["The scale of this entity."]] scale : Vec3 , # [doc = "**Spherical billboard**: If attached, this ensures that this entity is always aligned with the camera.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Spherical billboard"] , Description ["If attached, this ensures that this entity is always aligned with the camera."]] spherical_billboard : () , # [doc = "**Translation**: The translation/position of this entity.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Translation"] , Description ["The translation/position of this entity."]] translation : Vec3 , });
+                components ! ("transform" , { # [doc = "**Cylindrical billboard Z**: If attached, this ensures this entity is always aligned with the camera, except on the Z-axis.\n\nThis is useful for decorations that the player will be looking at from roughly the same altitude.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Cylindrical billboard Z"] , Description ["If attached, this ensures this entity is always aligned with the camera, except on the Z-axis.\nThis is useful for decorations that the player will be looking at from roughly the same altitude."]] cylindrical_billboard_z : () , # [doc = "**Euler rotation**: The Euler rotation of this entity in ZYX order.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Euler rotation"] , Description ["The Euler rotation of this entity in ZYX order."]] euler_rotation : Vec3 , # [doc = "**Inverse Local to World**: Converts a world position to a local position.\n\nThis is automatically updated.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Inverse Local to World"] , Description ["Converts a world position to a local position.\nThis is automatically updated."]] inv_local_to_world : Mat4 , # [doc = "**Local to Parent**: Transformation from the entity's local space to the parent's space.\n\n*Attributes*: Debuggable, Networked, Store, MaybeResource"] @ [Debuggable , Networked , Store , MaybeResource , Name ["Local to Parent"] , Description ["Transformation from the entity's local space to the parent's space."]] local_to_parent : Mat4 , # [doc = "**Local to World**: Transformation from the entity's local space to worldspace.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Local to World"] , Description ["Transformation from the entity's local space to worldspace."]] local_to_world : Mat4 , # [doc = "**Look-at target**: The position that this entity should be looking at.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Look-at target"] , Description ["The position that this entity should be looking at."]] lookat_target : Vec3 , # [doc = "**Look-at up**: When combined with `lookat_target`, the up vector for this entity.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Look-at up"] , Description ["When combined with `lookat_target`, the up vector for this entity."]] lookat_up : Vec3 , # [doc = "**Mesh to Local**: Transformation from mesh-space to the entity's local space.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Mesh to Local"] , Description ["Transformation from mesh-space to the entity's local space."]] mesh_to_local : Mat4 , # [doc = "**Mesh to World**: Transformation from mesh-space to world space.\n\nThis is automatically updated when `mesh_to_local` and `local_to_world` change.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Mesh to World"] , Description ["Transformation from mesh-space to world space.\nThis is automatically updated when `mesh_to_local` and `local_to_world` change."]] mesh_to_world : Mat4 , # [doc = "**Reset scale**: If attached to a transform hierarchy, the scale will be reset at that point, with only rotation/translation considered.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Reset scale"] , Description ["If attached to a transform hierarchy, the scale will be reset at that point, with only rotation/translation considered."]] reset_scale : () , # [doc = "**Rotation**: The rotation of this entity.\n\n*Attributes*: Debuggable, Interpolated, Networked, Store"] @ [Debuggable , Interpolated , Networked , Store , Name ["Rotation"] , Description ["The rotation of this entity."]] rotation : Quat , # [doc = "**Scale**: The scale of this entity.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Scale"] , Description ["The scale of this entity."]] scale : Vec3 , # [doc = "**Spherical billboard**: If attached, this ensures that this entity is always aligned with the camera.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Spherical billboard"] , Description ["If attached, this ensures that this entity is always aligned with the camera."]] spherical_billboard : () , # [doc = "**Translation**: The translation/position of this entity.\n\n*Attributes*: Debuggable, Interpolated, Networked, Predicted, Store"] @ [Debuggable , Interpolated , Networked , Predicted , Store , Name ["Translation"] , Description ["The translation/position of this entity."]] translation : Vec3 , });
             }
         }
         pub mod ui {
             #[doc = r" Auto-generated component definitions."]
             pub mod components {
                 use crate::{
-                    components, Debuggable, Description, EntityId, Enum, MaybeResource, Name,
-                    Networked, Resource, Store,
+                    components, Debuggable, Description, EntityId, Enum, Interpolated,
+                    MaybeResource, Name, Networked, Predicted, Resource, Store,
                 };
                 use ambient_shared_types::{
                     ProceduralMaterialHandle, ProceduralMeshHandle, ProceduralSamplerHandle,
@@ -1008,8 +1087,8 @@ mod raw {
             #[doc = r" Auto-generated component definitions."]
             pub mod components {
                 use crate::{
-                    components, Debuggable, Description, EntityId, Enum, MaybeResource, Name,
-                    Networked, Resource, Store,
+                    components, Debuggable, Description, EntityId, Enum, Interpolated,
+                    MaybeResource, Name, Networked, Predicted, Resource, Store,
                 };
                 use ambient_shared_types::{
                     ProceduralMaterialHandle, ProceduralMeshHandle, ProceduralSamplerHandle,
@@ -1109,6 +1188,263 @@ mod raw {
             }
             impl RuntimeMessage for ColliderLoads {}
             #[derive(Clone, Debug)]
+            #[doc = "**TriggerEnter**: Sent when a collider enters a trigger (an entity with `is_trigger`)."]
+            pub struct TriggerEnter {
+                pub trigger: EntityId,
+                pub other: EntityId,
+            }
+            impl TriggerEnter {
+                #[allow(clippy::too_many_arguments)]
+                pub fn new(trigger: impl Into<EntityId>, other: impl Into<EntityId>) -> Self {
+                    Self {
+                        trigger: trigger.into(),
+                        other: other.into(),
+                    }
+                }
+            }
+            impl Message for TriggerEnter {
+                fn id() -> &'static str {
+                    "ambient_core::TriggerEnter"
+                }
+                fn serialize_message(&self) -> Result<Vec<u8>, MessageSerdeError> {
+                    let mut output = vec![];
+                    self.trigger.serialize_message_part(&mut output)?;
+                    self.other.serialize_message_part(&mut output)?;
+                    Ok(output)
+                }
+                fn deserialize_message(mut input: &[u8]) -> Result<Self, MessageSerdeError> {
+                    Ok(Self {
+                        trigger: EntityId::deserialize_message_part(&mut input)?,
+                        other: EntityId::deserialize_message_part(&mut input)?,
+                    })
+                }
+            }
+            impl RuntimeMessage for TriggerEnter {}
+            #[derive(Clone, Debug)]
+            #[doc = "**TriggerExit**: Sent when a collider leaves a trigger (an entity with `is_trigger`)."]
+            pub struct TriggerExit {
+                pub trigger: EntityId,
+                pub other: EntityId,
+            }
+            impl TriggerExit {
+                #[allow(clippy::too_many_arguments)]
+                pub fn new(trigger: impl Into<EntityId>, other: impl Into<EntityId>) -> Self {
+                    Self {
+                        trigger: trigger.into(),
+                        other: other.into(),
+                    }
+                }
+            }
+            impl Message for TriggerExit {
+                fn id() -> &'static str {
+                    "ambient_core::TriggerExit"
+                }
+                fn serialize_message(&self) -> Result<Vec<u8>, MessageSerdeError> {
+                    let mut output = vec![];
+                    self.trigger.serialize_message_part(&mut output)?;
+                    self.other.serialize_message_part(&mut output)?;
+                    Ok(output)
+                }
+                fn deserialize_message(mut input: &[u8]) -> Result<Self, MessageSerdeError> {
+                    Ok(Self {
+                        trigger: EntityId::deserialize_message_part(&mut input)?,
+                        other: EntityId::deserialize_message_part(&mut input)?,
+                    })
+                }
+            }
+            impl RuntimeMessage for TriggerExit {}
+            #[derive(Clone, Debug)]
+            #[doc = "**ContactBegin**: Sent when two colliders start touching, if either has `report_contacts`."]
+            pub struct ContactBegin {
+                pub a: EntityId,
+                pub b: EntityId,
+                pub points: Vec<Vec3>,
+                pub normals: Vec<Vec3>,
+                pub impulse: Vec3,
+                pub relative_velocity: Vec3,
+            }
+            impl ContactBegin {
+                #[allow(clippy::too_many_arguments)]
+                pub fn new(
+                    a: impl Into<EntityId>,
+                    b: impl Into<EntityId>,
+                    points: impl Into<Vec<Vec3>>,
+                    normals: impl Into<Vec<Vec3>>,
+                    impulse: impl Into<Vec3>,
+                    relative_velocity: impl Into<Vec3>,
+                ) -> Self {
+                    Self {
+                        a: a.into(),
+                        b: b.into(),
+                        points: points.into(),
+                        normals: normals.into(),
+                        impulse: impulse.into(),
+                        relative_velocity: relative_velocity.into(),
+                    }
+                }
+            }
+            impl Message for ContactBegin {
+                fn id() -> &'static str {
+                    "ambient_core::ContactBegin"
+                }
+                fn serialize_message(&self) -> Result<Vec<u8>, MessageSerdeError> {
+                    let mut output = vec![];
+                    self.a.serialize_message_part(&mut output)?;
+                    self.b.serialize_message_part(&mut output)?;
+                    self.points.serialize_message_part(&mut output)?;
+                    self.normals.serialize_message_part(&mut output)?;
+                    self.impulse.serialize_message_part(&mut output)?;
+                    self.relative_velocity.serialize_message_part(&mut output)?;
+                    Ok(output)
+                }
+                fn deserialize_message(mut input: &[u8]) -> Result<Self, MessageSerdeError> {
+                    Ok(Self {
+                        a: EntityId::deserialize_message_part(&mut input)?,
+                        b: EntityId::deserialize_message_part(&mut input)?,
+                        points: Vec::<Vec3>::deserialize_message_part(&mut input)?,
+                        normals: Vec::<Vec3>::deserialize_message_part(&mut input)?,
+                        impulse: Vec3::deserialize_message_part(&mut input)?,
+                        relative_velocity: Vec3::deserialize_message_part(&mut input)?,
+                    })
+                }
+            }
+            impl RuntimeMessage for ContactBegin {}
+            #[derive(Clone, Debug)]
+            #[doc = "**ContactPersist**: Sent every frame while two colliders keep touching, if either has `report_contacts`."]
+            pub struct ContactPersist {
+                pub a: EntityId,
+                pub b: EntityId,
+                pub points: Vec<Vec3>,
+                pub normals: Vec<Vec3>,
+                pub impulse: Vec3,
+                pub relative_velocity: Vec3,
+            }
+            impl ContactPersist {
+                #[allow(clippy::too_many_arguments)]
+                pub fn new(
+                    a: impl Into<EntityId>,
+                    b: impl Into<EntityId>,
+                    points: impl Into<Vec<Vec3>>,
+                    normals: impl Into<Vec<Vec3>>,
+                    impulse: impl Into<Vec3>,
+                    relative_velocity: impl Into<Vec3>,
+                ) -> Self {
+                    Self {
+                        a: a.into(),
+                        b: b.into(),
+                        points: points.into(),
+                        normals: normals.into(),
+                        impulse: impulse.into(),
+                        relative_velocity: relative_velocity.into(),
+                    }
+                }
+            }
+            impl Message for ContactPersist {
+                fn id() -> &'static str {
+                    "ambient_core::ContactPersist"
+                }
+                fn serialize_message(&self) -> Result<Vec<u8>, MessageSerdeError> {
+                    let mut output = vec![];
+                    self.a.serialize_message_part(&mut output)?;
+                    self.b.serialize_message_part(&mut output)?;
+                    self.points.serialize_message_part(&mut output)?;
+                    self.normals.serialize_message_part(&mut output)?;
+                    self.impulse.serialize_message_part(&mut output)?;
+                    self.relative_velocity.serialize_message_part(&mut output)?;
+                    Ok(output)
+                }
+                fn deserialize_message(mut input: &[u8]) -> Result<Self, MessageSerdeError> {
+                    Ok(Self {
+                        a: EntityId::deserialize_message_part(&mut input)?,
+                        b: EntityId::deserialize_message_part(&mut input)?,
+                        points: Vec::<Vec3>::deserialize_message_part(&mut input)?,
+                        normals: Vec::<Vec3>::deserialize_message_part(&mut input)?,
+                        impulse: Vec3::deserialize_message_part(&mut input)?,
+                        relative_velocity: Vec3::deserialize_message_part(&mut input)?,
+                    })
+                }
+            }
+            impl RuntimeMessage for ContactPersist {}
+            #[derive(Clone, Debug)]
+            #[doc = "**ContactEnd**: Sent when two colliders stop touching, if either has `report_contacts`."]
+            pub struct ContactEnd {
+                pub a: EntityId,
+                pub b: EntityId,
+            }
+            impl ContactEnd {
+                #[allow(clippy::too_many_arguments)]
+                pub fn new(a: impl Into<EntityId>, b: impl Into<EntityId>) -> Self {
+                    Self {
+                        a: a.into(),
+                        b: b.into(),
+                    }
+                }
+            }
+            impl Message for ContactEnd {
+                fn id() -> &'static str {
+                    "ambient_core::ContactEnd"
+                }
+                fn serialize_message(&self) -> Result<Vec<u8>, MessageSerdeError> {
+                    let mut output = vec![];
+                    self.a.serialize_message_part(&mut output)?;
+                    self.b.serialize_message_part(&mut output)?;
+                    Ok(output)
+                }
+                fn deserialize_message(mut input: &[u8]) -> Result<Self, MessageSerdeError> {
+                    Ok(Self {
+                        a: EntityId::deserialize_message_part(&mut input)?,
+                        b: EntityId::deserialize_message_part(&mut input)?,
+                    })
+                }
+            }
+            impl RuntimeMessage for ContactEnd {}
+            #[derive(Clone, Debug)]
+            #[doc = "**AnimationEvent**: Sent to server-side modules when an animation player crosses an event of one of its clips. `weight` is how much the clip contributes to the output of the player, from 0 to 1."]
+            pub struct AnimationEvent {
+                pub player: EntityId,
+                pub node: EntityId,
+                pub name: String,
+                pub weight: f32,
+            }
+            impl AnimationEvent {
+                #[allow(clippy::too_many_arguments)]
+                pub fn new(
+                    player: impl Into<EntityId>,
+                    node: impl Into<EntityId>,
+                    name: impl Into<String>,
+                    weight: impl Into<f32>,
+                ) -> Self {
+                    Self {
+                        player: player.into(),
+                        node: node.into(),
+                        name: name.into(),
+                        weight: weight.into(),
+                    }
+                }
+            }
+            impl Message for AnimationEvent {
+                fn id() -> &'static str {
+                    "ambient_core::AnimationEvent"
+                }
+                fn serialize_message(&self) -> Result<Vec<u8>, MessageSerdeError> {
+                    let mut output = vec![];
+                    self.player.serialize_message_part(&mut output)?;
+                    self.node.serialize_message_part(&mut output)?;
+                    self.name.serialize_message_part(&mut output)?;
+                    self.weight.serialize_message_part(&mut output)?;
+                    Ok(output)
+                }
+                fn deserialize_message(mut input: &[u8]) -> Result<Self, MessageSerdeError> {
+                    Ok(Self {
+                        player: EntityId::deserialize_message_part(&mut input)?,
+                        node: EntityId::deserialize_message_part(&mut input)?,
+                        name: String::deserialize_message_part(&mut input)?,
+                        weight: f32::deserialize_message_part(&mut input)?,
+                    })
+                }
+            }
+            impl RuntimeMessage for AnimationEvent {}
+            #[derive(Clone, Debug)]
             #[doc = "**ModuleLoad**: Sent to a module when it loads."]
             pub struct ModuleLoad;
             impl ModuleLoad {
@@ -1439,24 +1775,30 @@ mod raw {
             }
             impl RuntimeMessage for WindowCursorLockChange {}
             #[derive(Clone, Debug)]
-            #[doc = "**HttpResponse**: Sent when an HTTP response is received."]
+            #[doc = "**HttpResponse**: Sent when an HTTP response is received, or the request failed. `id` is the ID returned when the request was sent, and each header is formatted as `name: value`."]
             pub struct HttpResponse {
+                pub id: u64,
                 pub url: String,
                 pub status: u32,
+                pub headers: Vec<String>,
                 pub body: Vec<u8>,
                 pub error: Option<String>,
             }
             impl HttpResponse {
                 #[allow(clippy::too_many_arguments)]
                 pub fn new(
+                    id: impl Into<u64>,
                     url: impl Into<String>,
                     status: impl Into<u32>,
+                    headers: impl Into<Vec<String>>,
                     body: impl Into<Vec<u8>>,
                     error: impl Into<Option<String>>,
                 ) -> Self {
                     Self {
+                        id: id.into(),
                         url: url.into(),
                         status: status.into(),
+                        headers: headers.into(),
                         body: body.into(),
                         error: error.into(),
                     }
@@ -1468,16 +1810,20 @@ mod raw {
                 }
                 fn serialize_message(&self) -> Result<Vec<u8>, MessageSerdeError> {
                     let mut output = vec![];
+                    self.id.serialize_message_part(&mut output)?;
                     self.url.serialize_message_part(&mut output)?;
                     self.status.serialize_message_part(&mut output)?;
+                    self.headers.serialize_message_part(&mut output)?;
                     self.body.serialize_message_part(&mut output)?;
                     self.error.serialize_message_part(&mut output)?;
                     Ok(output)
                 }
                 fn deserialize_message(mut input: &[u8]) -> Result<Self, MessageSerdeError> {
                     Ok(Self {
+                        id: u64::deserialize_message_part(&mut input)?,
                         url: String::deserialize_message_part(&mut input)?,
                         status: u32::deserialize_message_part(&mut input)?,
+                        headers: Vec::<String>::deserialize_message_part(&mut input)?,
                         body: Vec::<u8>::deserialize_message_part(&mut input)?,
                         error: Option::<String>::deserialize_message_part(&mut input)?,
                     })
@@ -240,6 +240,10 @@ impl WorldStreamFilter {
                 .collect_vec(),
         }
     }
+    /// Returns true if the entity `id` is streamed by this filter
+    pub fn matches_entity(&self, world: &World, id: EntityId) -> bool {
+        self.arch_filter.matches_entity(world, id)
+    }
     pub fn all_entities<'a>(&self, world: &'a World) -> impl Iterator<Item = EntityId> + 'a {
         Query::all()
             .filter(&self.arch_filter)
@@ -254,7 +258,7 @@ impl WorldStreamFilter {
             .filter(|&comp| (self.component_filter)(comp, WorldStreamCompEvent::Init))
             .collect_vec()
     }
-    fn read_entity_components(&self, world: &World, id: EntityId) -> Vec<ComponentEntry> {
+    pub fn read_entity_components(&self, world: &World, id: EntityId) -> Vec<ComponentEntry> {
         self.get_entity_components(world, id)
             .into_iter()
             .map(|comp| world.get_entry(id, comp).unwrap())
@@ -8,7 +8,9 @@ use std::{
 };
 
 use ambient_native_std::{
-    asset_cache::{AssetCache, AsyncAssetKey, AsyncAssetKeyExt, SyncAssetKey, SyncAssetKeyExt},
+    asset_cache::{
+        asset_types, AssetCache, AsyncAssetKey, AsyncAssetKeyExt, SyncAssetKey, SyncAssetKeyExt,
+    },
     asset_url::AbsAssetUrl,
     download_asset::{AssetResult, MeshFromUrl},
     mesh::Mesh,
@@ -91,6 +93,12 @@ impl GpuMeshFromUrl {
 
 #[async_trait]
 impl AsyncAssetKey<AssetResult<Arc<GpuMesh>>> for GpuMeshFromUrl {
+    fn asset_type(&self) -> &'static str {
+        asset_types::MESH
+    }
+    fn gpu_size(&self, asset: &AssetResult<Arc<GpuMesh>>) -> Option<u64> {
+        asset.as_ref().ok().map(|x| x.size_in_bytes() as u64)
+    }
     async fn load(self, assets: AssetCache) -> AssetResult<Arc<GpuMesh>> {
         let gpu = GpuKey.get(&assets);
         let mesh = MeshFromUrl::new(self.url, self.cache_on_disk)
@@ -1,7 +1,7 @@
 use std::{borrow::Cow, fmt, io::Cursor, sync::Arc};
 
 use ambient_native_std::{
-    asset_cache::{AssetCache, AsyncAssetKey, AsyncAssetKeyExt, SyncAssetKeyExt},
+    asset_cache::{asset_types, AssetCache, AsyncAssetKey, AsyncAssetKeyExt, SyncAssetKeyExt},
     asset_url::AbsAssetUrl,
     download_asset::{AssetError, AssetResult, BytesFromUrl},
     CowStr,
@@ -19,6 +19,12 @@ pub struct ImageFromUrl {
 }
 #[async_trait]
 impl AsyncAssetKey<Result<Arc<DynamicImage>, AssetError>> for ImageFromUrl {
+    fn asset_type(&self) -> &'static str {
+        asset_types::IMAGE
+    }
+    fn cpu_size(&self, asset: &Result<Arc<DynamicImage>, AssetError>) -> Option<u64> {
+        asset.as_ref().ok().map(|x| x.as_bytes().len() as u64)
+    }
     async fn load(self, assets: AssetCache) -> Result<Arc<DynamicImage>, AssetError> {
         image_from_url(assets, self.url).await.map(Arc::new)
     }
@@ -30,6 +36,12 @@ pub struct Rgba8ImageFromUrl {
 }
 #[async_trait]
 impl AsyncAssetKey<Result<Arc<image::RgbaImage>, AssetError>> for Rgba8ImageFromUrl {
+    fn asset_type(&self) -> &'static str {
+        asset_types::IMAGE
+    }
+    fn cpu_size(&self, asset: &Result<Arc<image::RgbaImage>, AssetError>) -> Option<u64> {
+        asset.as_ref().ok().map(|x| x.as_raw().len() as u64)
+    }
     async fn load(self, assets: AssetCache) -> Result<Arc<image::RgbaImage>, AssetError> {
         image_from_url(assets, self.url)
             .await
@@ -62,6 +74,9 @@ pub struct TextureFromUrl {
 }
 #[async_trait]
 impl AsyncAssetKey<Result<Arc<Texture>, AssetError>> for TextureFromUrl {
+    fn asset_type(&self) -> &'static str {
+        asset_types::TEXTURE
+    }
     fn gpu_size(&self, asset: &Result<Arc<Texture>, AssetError>) -> Option<u64> {
         asset.as_ref().ok().map(|asset| asset.size_in_bytes)
     }
@@ -88,6 +103,9 @@ pub struct TextureFromRgba8Image {
 }
 #[async_trait]
 impl AsyncAssetKey<Result<Arc<Texture>, AssetError>> for TextureFromRgba8Image {
+    fn asset_type(&self) -> &'static str {
+        asset_types::TEXTURE
+    }
     fn gpu_size(&self, asset: &Result<Arc<Texture>, AssetError>) -> Option<u64> {
         asset.as_ref().ok().map(|x| x.size_in_bytes)
     }
@@ -125,6 +143,9 @@ use anyhow::Context;
 
 #[async_trait]
 impl AsyncAssetKey<Result<Arc<Texture>, AssetError>> for TextureFromBytes {
+    fn asset_type(&self) -> &'static str {
+        asset_types::TEXTURE
+    }
     fn gpu_size(&self, asset: &Result<Arc<Texture>, AssetError>) -> Option<u64> {
         asset.as_ref().ok().map(|asset| asset.size_in_bytes)
     }
@@ -196,6 +217,9 @@ pub struct SplitTextureFromUrl {
 }
 #[async_trait]
 impl AsyncAssetKey<Result<Arc<Texture>, AssetError>> for SplitTextureFromUrl {
+    fn asset_type(&self) -> &'static str {
+        asset_types::TEXTURE
+    }
     fn gpu_size(&self, asset: &Result<Arc<Texture>, AssetError>) -> Option<u64> {
         asset.as_ref().ok().map(|asset| asset.size_in_bytes)
     }
@@ -318,6 +342,9 @@ pub struct TextureArrayFromUrls {
 }
 #[async_trait]
 impl AsyncAssetKey<Result<Arc<Texture>, AssetError>> for TextureArrayFromUrls {
+    fn asset_type(&self) -> &'static str {
+        asset_types::TEXTURE
+    }
     fn gpu_size(&self, asset: &Result<Arc<Texture>, AssetError>) -> Option<u64> {
         asset.as_ref().ok().map(|asset| asset.size_in_bytes)
     }
@@ -15,7 +15,9 @@ use ambient_ecs::{
 };
 use ambient_gpu::mesh_buffer::GpuMeshFromUrl;
 use ambient_native_std::{
-    asset_cache::{AssetCache, AsyncAssetKey, AsyncAssetKeyExt, SyncAssetKey, SyncAssetKeyExt},
+    asset_cache::{
+        asset_types, AssetCache, AsyncAssetKey, AsyncAssetKeyExt, SyncAssetKey, SyncAssetKeyExt,
+    },
     asset_url::{AbsAssetUrl, AssetUrl, ModelAssetType, TypedAssetUrl},
     cb,
     download_asset::{AssetError, BytesFromUrl},
@@ -280,6 +282,11 @@ impl ModelFromUrl {
 }
 #[async_trait]
 impl AsyncAssetKey<Result<Arc<Model>, AssetError>> for ModelFromUrl {
+    // The meshes and textures of the model are loaded with their own keys, so they are counted
+    // there rather than in the size of the model
+    fn asset_type(&self) -> &'static str {
+        asset_types::MODEL
+    }
     async fn load(self, assets: AssetCache) -> Result<Arc<Model>, AssetError> {
         let url = self
             .0
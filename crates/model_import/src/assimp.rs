@@ -442,6 +442,7 @@ fn import_sync(
             tracks,
             start: 0.,
             end: animation.duration as f32 / animation.ticks_per_second as f32,
+            events: Vec::new(),
         };
         model_crate.animations.insert(&animation.name, clip);
     }
@@ -7,7 +7,9 @@ use ambient_native_std::{
     asset_url::AbsAssetUrl,
     download_asset::AssetsCacheDir,
 };
-use ambient_pipeline_types::models::{MaterialFilter, ModelTextureSize, ModelTransform};
+use ambient_pipeline_types::models::{
+    AnimationEventMarker, MaterialFilter, ModelTextureSize, ModelTransform,
+};
 use ambient_renderer::materials::pbr_material::PbrMaterialDesc;
 use anyhow::{anyhow, Context};
 use async_recursion::async_recursion;
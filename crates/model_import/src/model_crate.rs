@@ -1,6 +1,6 @@
 use std::{collections::HashMap, io::Cursor, path::PathBuf, sync::Arc};
 
-use ambient_animation::AnimationClip;
+use ambient_animation::{AnimationClip, AnimationEvent};
 use ambient_core::{
     bounding::local_bounding_aabb,
     hierarchy::children,
@@ -46,7 +46,9 @@ use physxx::{
 };
 use relative_path::RelativePathBuf;
 
-use crate::{dotdot_path, material_filter_matches, MaterialFilter, TextureResolver};
+use crate::{
+    dotdot_path, material_filter_matches, AnimationEventMarker, MaterialFilter, TextureResolver,
+};
 
 #[derive(Debug, Clone)]
 pub struct AssetLoc {
@@ -557,6 +559,21 @@ impl ModelCrate {
             }
         }
     }
+    /// Adds the event to the animation it names, or to all animations if it doesn't name one
+    pub fn add_animation_event(&mut self, marker: &AnimationEventMarker) {
+        for (key, clip) in self.animations.content.iter_mut() {
+            if marker
+                .clip
+                .as_ref()
+                .map_or(true, |name| name == key || name == &clip.id)
+            {
+                clip.add_event(AnimationEvent {
+                    time: marker.time,
+                    name: marker.name.clone(),
+                });
+            }
+        }
+    }
     pub fn cap_texture_sizes(&mut self, max_size: u32) {
         for image in self.images.content.values_mut() {
             cap_texture_size(image, max_size);
@@ -1,7 +1,9 @@
-use std::{marker::PhantomData, path::PathBuf, sync::Arc, time::Duration};
+use std::{marker::PhantomData, ops::Range, path::PathBuf, sync::Arc, time::Duration};
 
 use crate::{
-    asset_cache::{AssetCache, AsyncAssetKey, AsyncAssetKeyExt, SyncAssetKey, SyncAssetKeyExt},
+    asset_cache::{
+        asset_types, AssetCache, AsyncAssetKey, AsyncAssetKeyExt, SyncAssetKey, SyncAssetKeyExt,
+    },
     asset_url::AbsAssetUrl,
     mesh::Mesh,
     RUNTIME_USER_AGENT,
@@ -82,6 +84,16 @@ pub(crate) async fn download<T: 'static + Send, F: Future<Output = anyhow::Resul
     assets: &AssetCache,
     url: impl reqwest::IntoUrl,
     map: impl 'static + Send + Fn(reqwest::Response) -> F,
+) -> anyhow::Result<T> {
+    download_range(assets, url, None, map).await
+}
+
+/// Like [download], but only requests the bytes in `range` if it is set
+async fn download_range<T: 'static + Send, F: Future<Output = anyhow::Result<T>>>(
+    assets: &AssetCache,
+    url: impl reqwest::IntoUrl,
+    range: Option<Range<u64>>,
+    map: impl 'static + Send + Fn(reqwest::Response) -> F,
 ) -> anyhow::Result<T> {
     let url_str = url.as_str().to_string();
     let url = url.into_url()?;
@@ -102,8 +114,14 @@ pub(crate) async fn download<T: 'static + Send, F: Future<Output = anyhow::Resul
             tracing::debug!("Download [pending ] {}", url_short);
             let _permit = semaphore.acquire().await.unwrap();
             tracing::debug!("Download [download] {}", url_short);
-            let resp = client
-                .get(url.clone())
+            let mut request = client.get(url.clone());
+            if let Some(range) = &range {
+                request = request.header(
+                    reqwest::header::RANGE,
+                    format!("bytes={}-{}", range.start, range.end.saturating_sub(1)),
+                );
+            }
+            let resp = request
                 .send()
                 .await
                 .with_context(|| format!("Failed to download {url_str}"))?;
@@ -178,6 +196,80 @@ pub async fn download_uncached_bytes(
     Ok(body)
 }
 
+/// A part of a file, see [download_uncached_byte_range]
+#[derive(Debug, Clone)]
+pub struct ByteRange {
+    /// The bytes that were read. This is shorter than the requested range at the end of the file,
+    /// and is the whole file if the server does not support ranges
+    pub bytes: Vec<u8>,
+    /// The length of the whole file
+    pub total_len: u64,
+    /// False if the server ignored the range and sent the whole file
+    pub partial: bool,
+}
+
+/// Reads the bytes in `range` of a file, without caching them.
+///
+/// Local files are read directly from disk, and other urls are downloaded with an HTTP range
+/// request. Servers which ignore the range send the whole file, which is then returned as is.
+pub async fn download_uncached_byte_range(
+    assets: &AssetCache,
+    url: AbsAssetUrl,
+    range: Range<u64>,
+) -> AssetResult<ByteRange> {
+    #[cfg(not(target_os = "unknown"))]
+    if let Some(path) = url.to_file_path()? {
+        use tokio::io::{AsyncReadExt, AsyncSeekExt};
+        let read = async {
+            let mut file = tokio::fs::File::open(&path).await?;
+            let total_len = file.metadata().await?.len();
+            file.seek(std::io::SeekFrom::Start(range.start)).await?;
+            let mut bytes = Vec::new();
+            file.take(range.end.saturating_sub(range.start))
+                .read_to_end(&mut bytes)
+                .await?;
+            std::io::Result::Ok(ByteRange {
+                bytes,
+                total_len,
+                partial: true,
+            })
+        };
+        return Ok(read
+            .await
+            .context(format!("Failed to read file at: {:}", url.0))?);
+    }
+
+    let download_url = url.to_download_url(assets).map_err(anyhow::Error::new)?.0;
+    Ok(
+        download_range(assets, download_url, Some(range), |resp| async move {
+            if resp.status() == reqwest::StatusCode::PARTIAL_CONTENT {
+                // Content-Range: bytes <start>-<end>/<total>
+                let total_len = resp
+                    .headers()
+                    .get(reqwest::header::CONTENT_RANGE)
+                    .and_then(|v| v.to_str().ok())
+                    .and_then(|v| v.rsplit_once('/'))
+                    .and_then(|(_, total)| total.parse().ok())
+                    .context("Missing the total length in the Content-Range")?;
+                let bytes = resp.bytes().await?.to_vec();
+                Ok(ByteRange {
+                    bytes,
+                    total_len,
+                    partial: true,
+                })
+            } else {
+                let bytes = resp.bytes().await?.to_vec();
+                Ok(ByteRange {
+                    total_len: bytes.len() as u64,
+                    bytes,
+                    partial: false,
+                })
+            }
+        })
+        .await?,
+    )
+}
+
 #[async_trait]
 impl AsyncAssetKey<AssetResult<Arc<Vec<u8>>>> for BytesFromUrl {
     async fn load(self, assets: AssetCache) -> AssetResult<Arc<Vec<u8>>> {
@@ -202,6 +294,10 @@ impl AsyncAssetKey<AssetResult<Arc<Vec<u8>>>> for BytesFromUrl {
             .map(Arc::new)
     }
 
+    fn asset_type(&self) -> &'static str {
+        asset_types::BYTES
+    }
+
     fn cpu_size(&self, value: &AssetResult<Arc<Vec<u8>>>) -> Option<u64> {
         // NOTE: on wasm bytes is limited to 4gb
         value.as_ref().ok().map(|v| v.len() as u64)
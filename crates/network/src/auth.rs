@@ -0,0 +1,283 @@
+//! Authentication of connecting clients.
+//!
+//! Clients claim a user id when connecting. By default the server trusts that claim; hosts that
+//! need to prevent impersonation can set an [`Authenticator`] on the game server, which verifies
+//! the token sent alongside the user id. Rejected clients are disconnected with the reason.
+
+use thiserror::Error;
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum AuthError {
+    #[error("This server requires an authentication token")]
+    MissingToken,
+    #[error("The authentication token is invalid: {0}")]
+    InvalidToken(String),
+    #[error("The authentication token has expired")]
+    Expired,
+    #[error("The authentication token is not valid for user {0:?}")]
+    WrongUser(String),
+    #[error("{0}")]
+    Other(String),
+}
+
+/// Decides whether a client may connect with the user id it claims.
+pub trait Authenticator: Send + Sync {
+    /// Returns an error if the client should not be allowed to connect as `user_id`.
+    ///
+    /// The error is sent back to the client as the reason for being disconnected.
+    fn authenticate(&self, user_id: &str, token: Option<&str>) -> Result<(), AuthError>;
+}
+
+/// Accepts every client with the user id it claims. This is the default.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AllowAnonymous;
+impl Authenticator for AllowAnonymous {
+    fn authenticate(&self, _user_id: &str, _token: Option<&str>) -> Result<(), AuthError> {
+        Ok(())
+    }
+}
+
+#[cfg(not(target_os = "unknown"))]
+pub use native::*;
+
+#[cfg(not(target_os = "unknown"))]
+mod native {
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    use anyhow::Context;
+    use ring::{
+        hmac,
+        signature::{UnparsedPublicKey, ED25519},
+    };
+    use serde::Deserialize;
+
+    use super::{AuthError, Authenticator};
+
+    /// The DER prefix of an Ed25519 `SubjectPublicKeyInfo`, which is followed by the raw 32 byte key.
+    const ED25519_SPKI_PREFIX: [u8; 12] = [
+        0x30, 0x2a, 0x30, 0x05, 0x06, 0x03, 0x2b, 0x65, 0x70, 0x03, 0x21, 0x00,
+    ];
+
+    fn decode_base64url(data: &str) -> Result<Vec<u8>, AuthError> {
+        base64::decode_config(data, base64::URL_SAFE_NO_PAD)
+            .map_err(|err| AuthError::InvalidToken(err.to_string()))
+    }
+
+    /// Accepts clients whose token is the HMAC-SHA256 of their user id, keyed with a secret shared
+    /// with whatever service hands out tokens.
+    ///
+    /// Use [`SharedSecretAuthenticator::token_for`] to create tokens.
+    pub struct SharedSecretAuthenticator {
+        key: hmac::Key,
+    }
+    impl SharedSecretAuthenticator {
+        pub fn new(secret: &[u8]) -> Self {
+            Self {
+                key: hmac::Key::new(hmac::HMAC_SHA256, secret),
+            }
+        }
+
+        /// Creates the token that `user_id` has to connect with.
+        pub fn token_for(&self, user_id: &str) -> String {
+            base64::encode_config(
+                hmac::sign(&self.key, user_id.as_bytes()),
+                base64::URL_SAFE_NO_PAD,
+            )
+        }
+    }
+    impl Authenticator for SharedSecretAuthenticator {
+        fn authenticate(&self, user_id: &str, token: Option<&str>) -> Result<(), AuthError> {
+            let tag = decode_base64url(token.ok_or(AuthError::MissingToken)?)?;
+            hmac::verify(&self.key, user_id.as_bytes(), &tag)
+                .map_err(|_| AuthError::WrongUser(user_id.to_string()))
+        }
+    }
+
+    #[derive(Deserialize)]
+    struct JwtHeader {
+        alg: String,
+    }
+
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum JwtAudience {
+        One(String),
+        Many(Vec<String>),
+    }
+    impl JwtAudience {
+        fn contains(&self, audience: &str) -> bool {
+            match self {
+                Self::One(aud) => aud == audience,
+                Self::Many(auds) => auds.iter().any(|aud| aud == audience),
+            }
+        }
+    }
+
+    #[derive(Deserialize)]
+    struct JwtClaims {
+        sub: String,
+        exp: Option<u64>,
+        nbf: Option<u64>,
+        aud: Option<JwtAudience>,
+    }
+
+    /// Accepts clients with a JWT signed by a trusted issuer, whose `sub` claim is their user id.
+    ///
+    /// Only `EdDSA` (Ed25519) signatures are supported. `exp` and `nbf` are checked if present.
+    pub struct JwtAuthenticator {
+        public_key: Vec<u8>,
+        /// If set, the token's `aud` claim must contain this audience.
+        pub audience: Option<String>,
+    }
+    impl JwtAuthenticator {
+        /// Uses a raw 32 byte Ed25519 public key.
+        pub fn from_ed25519_raw(public_key: &[u8]) -> anyhow::Result<Self> {
+            anyhow::ensure!(
+                public_key.len() == 32,
+                "Ed25519 public keys are 32 bytes, got {}",
+                public_key.len()
+            );
+            Ok(Self {
+                public_key: public_key.to_vec(),
+                audience: None,
+            })
+        }
+
+        /// Uses a PEM encoded Ed25519 public key, such as the one written by `openssl pkey -pubout`.
+        pub fn from_ed25519_pem(pem: &str) -> anyhow::Result<Self> {
+            let body = pem
+                .lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty() && !line.starts_with("-----"))
+                .collect::<String>();
+            let der = base64::decode(body).context("Public key is not valid PEM")?;
+            let public_key = der
+                .strip_prefix(&ED25519_SPKI_PREFIX)
+                .context("Public key is not an Ed25519 key")?;
+            Self::from_ed25519_raw(public_key)
+        }
+
+        pub fn with_audience(mut self, audience: impl Into<String>) -> Self {
+            self.audience = Some(audience.into());
+            self
+        }
+    }
+    impl Authenticator for JwtAuthenticator {
+        fn authenticate(&self, user_id: &str, token: Option<&str>) -> Result<(), AuthError> {
+            let token = token.ok_or(AuthError::MissingToken)?;
+            let invalid = |msg: &str| AuthError::InvalidToken(msg.to_string());
+
+            let (message, signature) =
+                token.rsplit_once('.').ok_or_else(|| invalid("not a JWT"))?;
+            let (header, claims) = message
+                .split_once('.')
+                .ok_or_else(|| invalid("not a JWT"))?;
+
+            let header: JwtHeader = serde_json::from_slice(&decode_base64url(header)?)
+                .map_err(|err| AuthError::InvalidToken(err.to_string()))?;
+            if header.alg != "EdDSA" {
+                return Err(AuthError::InvalidToken(format!(
+                    "unsupported algorithm {:?}",
+                    header.alg
+                )));
+            }
+
+            UnparsedPublicKey::new(&ED25519, &self.public_key)
+                .verify(message.as_bytes(), &decode_base64url(signature)?)
+                .map_err(|_| invalid("bad signature"))?;
+
+            let claims: JwtClaims = serde_json::from_slice(&decode_base64url(claims)?)
+                .map_err(|err| AuthError::InvalidToken(err.to_string()))?;
+
+            let now = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs();
+            if claims.exp.is_some_and(|exp| now >= exp) {
+                return Err(AuthError::Expired);
+            }
+            if claims.nbf.is_some_and(|nbf| now < nbf) {
+                return Err(invalid("not valid yet"));
+            }
+            if let Some(audience) = &self.audience {
+                if !claims.aud.is_some_and(|aud| aud.contains(audience)) {
+                    return Err(invalid("wrong audience"));
+                }
+            }
+            if claims.sub != user_id {
+                return Err(AuthError::WrongUser(user_id.to_string()));
+            }
+
+            Ok(())
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use ring::{
+            rand::SystemRandom,
+            signature::{Ed25519KeyPair, KeyPair},
+        };
+
+        use super::*;
+
+        fn encode(data: &[u8]) -> String {
+            base64::encode_config(data, base64::URL_SAFE_NO_PAD)
+        }
+
+        fn sign_jwt(key: &Ed25519KeyPair, claims: &str) -> String {
+            let message = format!(
+                "{}.{}",
+                encode(br#"{"alg":"EdDSA","typ":"JWT"}"#),
+                encode(claims.as_bytes())
+            );
+            let signature = key.sign(message.as_bytes());
+            format!("{message}.{}", encode(signature.as_ref()))
+        }
+
+        #[test]
+        fn shared_secret() {
+            let auth = SharedSecretAuthenticator::new(b"secret");
+            let token = auth.token_for("alice");
+
+            assert_eq!(auth.authenticate("alice", Some(&token)), Ok(()));
+            assert_eq!(
+                auth.authenticate("bob", Some(&token)),
+                Err(AuthError::WrongUser("bob".into()))
+            );
+            assert_eq!(
+                auth.authenticate("alice", None),
+                Err(AuthError::MissingToken)
+            );
+        }
+
+        #[test]
+        fn jwt() {
+            let pkcs8 = Ed25519KeyPair::generate_pkcs8(&SystemRandom::new()).unwrap();
+            let key = Ed25519KeyPair::from_pkcs8(pkcs8.as_ref()).unwrap();
+            let auth = JwtAuthenticator::from_ed25519_raw(key.public_key().as_ref())
+                .unwrap()
+                .with_audience("game");
+
+            let token = sign_jwt(&key, r#"{"sub":"alice","aud":"game"}"#);
+            assert_eq!(auth.authenticate("alice", Some(&token)), Ok(()));
+            assert_eq!(
+                auth.authenticate("bob", Some(&token)),
+                Err(AuthError::WrongUser("bob".into()))
+            );
+
+            let expired = sign_jwt(&key, r#"{"sub":"alice","aud":"game","exp":1}"#);
+            assert_eq!(
+                auth.authenticate("alice", Some(&expired)),
+                Err(AuthError::Expired)
+            );
+
+            let other_audience = sign_jwt(&key, r#"{"sub":"alice","aud":["other"]}"#);
+            assert!(auth.authenticate("alice", Some(&other_audience)).is_err());
+
+            let (message, _) = token.rsplit_once('.').unwrap();
+            let forged = format!("{message}.{}", encode(&[0; 64]));
+            assert!(auth.authenticate("alice", Some(&forged)).is_err());
+        }
+    }
+}
@@ -0,0 +1,367 @@
+//! Client-side interpolation of networked components.
+//!
+//! Diffs from the server arrive at the server's tick rate, and applying them as they arrive makes
+//! remote entities stutter. Instead, the client buffers the values of components with the
+//! [`Interpolated`] attribute as they arrive, and shows them [`InterpolationSettings::delay`]
+//! in the past, interpolating between the two values around that time. If no newer value has
+//! arrived yet, the last two values are extrapolated for up to
+//! [`InterpolationSettings::max_extrapolation`], after which the component settles at the last value.
+//!
+//! `Vec2`, `Vec3`, `Vec4`, `Quat` and `f32` components are interpolated; components of other types
+//! change to the next value once it is reached. Components predicted for the local player (see
+//! [`crate::prediction`]) are not interpolated, and a component that is changed on the client stops
+//! being interpolated until the server sends a new value for it.
+
+use std::{
+    collections::{HashMap, VecDeque},
+    time::Duration,
+};
+
+use ambient_core::FIXED_SERVER_TICK_TIME;
+use ambient_ecs::{
+    components, ComponentDesc, ComponentEntry, EntityId, FnSystem, Interpolated, Predicted,
+    Resource, SystemGroup, World, WorldChange, WorldDiff,
+};
+use ambient_sys::time::Instant;
+use glam::{Quat, Vec2, Vec3, Vec4};
+
+use crate::prediction::{is_predicting, local_player};
+
+components!("network::interpolation", {
+    /// How the client interpolates `Interpolated` components
+    @[Resource]
+    interpolation_settings: InterpolationSettings,
+    /// The values of `Interpolated` components received from the server
+    @[Resource]
+    interpolation_buffer: InterpolationBuffer,
+});
+
+#[derive(Debug, Clone, Copy)]
+pub struct InterpolationSettings {
+    /// How far in the past interpolated components are shown. Should cover at least two server
+    /// updates, or entities will be extrapolated most of the time.
+    pub delay: Duration,
+    /// For how long the last received values are extrapolated when no newer value has arrived.
+    ///
+    /// The server only sends components when they change, so an entity that stops moving is
+    /// extrapolated for this long before it settles at its final value. Keep this short.
+    pub max_extrapolation: Duration,
+}
+impl Default for InterpolationSettings {
+    fn default() -> Self {
+        Self {
+            delay: Duration::from_millis(100),
+            max_extrapolation: Duration::from_millis(50),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct InterpolationBuffer {
+    entities: HashMap<EntityId, HashMap<ComponentDesc, Track>>,
+}
+impl InterpolationBuffer {
+    fn push(&mut self, id: EntityId, entry: ComponentEntry, received: Instant) {
+        let track = self
+            .entities
+            .entry(id)
+            .or_default()
+            .entry(entry.desc())
+            .or_default();
+        if track.settled {
+            // The component hasn't changed for a while, so it was most likely still at its last
+            // value one server tick ago
+            if let Some(last) = track.samples.back_mut() {
+                last.received = last
+                    .received
+                    .max(received - FIXED_SERVER_TICK_TIME)
+                    .min(received);
+            }
+        }
+        // The diff is about to write this value to the world
+        track.written = Some(entry.clone());
+        track.samples.push_back(Sample {
+            received,
+            value: entry,
+        });
+        track.settled = false;
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+struct Track {
+    samples: VecDeque<Sample>,
+    /// The world has the last value, and there is nothing left to interpolate
+    settled: bool,
+    /// The last value that was written to the world, to detect changes made on the client
+    written: Option<ComponentEntry>,
+}
+
+#[derive(Debug, Clone)]
+struct Sample {
+    received: Instant,
+    value: ComponentEntry,
+}
+
+fn is_interpolated(world: &World, id: EntityId, desc: ComponentDesc) -> bool {
+    if !desc.has_attribute::<Interpolated>() {
+        return false;
+    }
+    let predicted = desc.has_attribute::<Predicted>()
+        && is_predicting(world)
+        && local_player(world) == Some(id);
+    !predicted
+}
+
+/// Buffers the interpolated components of a diff that was just received, before it's applied.
+pub fn record_diff(world: &mut World, diff: &WorldDiff) {
+    let now = Instant::now();
+    let mut buffer = world
+        .resource_mut_opt(interpolation_buffer())
+        .map(std::mem::take)
+        .unwrap_or_default();
+
+    for change in &diff.changes {
+        match change {
+            WorldChange::Spawn(id, entity) => {
+                buffer.entities.remove(id);
+                for entry in entity.iter() {
+                    if is_interpolated(world, *id, entry.desc()) {
+                        buffer.push(*id, entry.clone(), now);
+                    }
+                }
+            }
+            WorldChange::AddComponents(id, entity) | WorldChange::SetComponents(id, entity) => {
+                for entry in entity.iter() {
+                    if is_interpolated(world, *id, entry.desc()) {
+                        buffer.push(*id, entry.clone(), now);
+                    }
+                }
+            }
+            WorldChange::Despawn(id) => {
+                buffer.entities.remove(id);
+            }
+            WorldChange::RemoveComponents(id, components) => {
+                if let Some(tracks) = buffer.entities.get_mut(id) {
+                    for desc in components {
+                        tracks.remove(desc);
+                    }
+                }
+            }
+        }
+    }
+
+    world.add_resource(interpolation_buffer(), buffer);
+}
+
+/// Returns false if the values of the same component are known to be different.
+fn same_value(a: &ComponentEntry, b: &ComponentEntry) -> bool {
+    if let (Some(a), Some(b)) = (a.try_downcast_ref::<Vec3>(), b.try_downcast_ref::<Vec3>()) {
+        a == b
+    } else if let (Some(a), Some(b)) = (a.try_downcast_ref::<Quat>(), b.try_downcast_ref::<Quat>())
+    {
+        a == b
+    } else if let (Some(a), Some(b)) = (a.try_downcast_ref::<Vec2>(), b.try_downcast_ref::<Vec2>())
+    {
+        a == b
+    } else if let (Some(a), Some(b)) = (a.try_downcast_ref::<Vec4>(), b.try_downcast_ref::<Vec4>())
+    {
+        a == b
+    } else if let (Some(a), Some(b)) = (a.try_downcast_ref::<f32>(), b.try_downcast_ref::<f32>()) {
+        a == b
+    } else {
+        true
+    }
+}
+
+/// Interpolates between two values of the same component; `t` may be greater than 1 to extrapolate.
+fn interpolate(a: &ComponentEntry, b: &ComponentEntry, t: f32) -> ComponentEntry {
+    let desc = a.desc();
+    if let (Some(a), Some(b)) = (a.try_downcast_ref::<Vec3>(), b.try_downcast_ref::<Vec3>()) {
+        ComponentEntry::from_raw_parts(desc, a.lerp(*b, t))
+    } else if let (Some(a), Some(b)) = (a.try_downcast_ref::<Quat>(), b.try_downcast_ref::<Quat>())
+    {
+        ComponentEntry::from_raw_parts(desc, a.slerp(*b, t).normalize())
+    } else if let (Some(a), Some(b)) = (a.try_downcast_ref::<Vec2>(), b.try_downcast_ref::<Vec2>())
+    {
+        ComponentEntry::from_raw_parts(desc, a.lerp(*b, t))
+    } else if let (Some(a), Some(b)) = (a.try_downcast_ref::<Vec4>(), b.try_downcast_ref::<Vec4>())
+    {
+        ComponentEntry::from_raw_parts(desc, a.lerp(*b, t))
+    } else if let (Some(a), Some(b)) = (a.try_downcast_ref::<f32>(), b.try_downcast_ref::<f32>()) {
+        ComponentEntry::from_raw_parts(desc, a + (b - a) * t)
+    } else if t < 1. {
+        a.clone()
+    } else {
+        b.clone()
+    }
+}
+
+impl Track {
+    /// Samples the component `delay` in the past. Samples that are no longer needed are removed.
+    fn sample(&mut self, now: Instant, settings: &InterpolationSettings) -> Option<ComponentEntry> {
+        if self.settled {
+            return None;
+        }
+        let age = |sample: &Sample| now.duration_since(sample.received);
+
+        // Keep the newest sample that is older than the delay, as the start of the interpolation
+        while self.samples.len() > 2 && age(&self.samples[1]) >= settings.delay {
+            self.samples.pop_front();
+        }
+
+        let (from, to) = match (self.samples.front(), self.samples.get(1)) {
+            (Some(from), Some(to)) => (from, to),
+            (Some(only), None) => {
+                self.settled = true;
+                return Some(only.value.clone());
+            }
+            _ => return None,
+        };
+        // The delay hasn't caught up with the oldest sample yet
+        if age(from) < settings.delay {
+            return Some(from.value.clone());
+        }
+
+        let span = to.received.duration_since(from.received);
+        let elapsed = age(from) - settings.delay;
+        if span.is_zero() || elapsed > span + settings.max_extrapolation {
+            // Nothing new arrived in time; the entity has most likely stopped changing
+            self.samples.pop_front();
+            self.settled = true;
+            return self.samples.front().map(|sample| sample.value.clone());
+        }
+
+        Some(interpolate(
+            &from.value,
+            &to.value,
+            elapsed.as_secs_f32() / span.as_secs_f32(),
+        ))
+    }
+}
+
+/// Writes the interpolated values of the buffered components to the world every frame.
+pub fn client_systems() -> SystemGroup {
+    SystemGroup::new(
+        "network/interpolation",
+        vec![Box::new(FnSystem::new(|world, _| {
+            let Some(buffer) = world.resource_mut_opt(interpolation_buffer()) else {
+                return;
+            };
+            let mut buffer = std::mem::take(buffer);
+            let settings = world
+                .resource_opt(interpolation_settings())
+                .copied()
+                .unwrap_or_default();
+            let now = Instant::now();
+
+            buffer.entities.retain(|id, _| world.exists(*id));
+            for (id, tracks) in &mut buffer.entities {
+                // Something on the client changed the component, like a teleport; keep its value
+                // instead of interpolating over it
+                tracks.retain(
+                    |desc, track| match (world.get_entry(*id, *desc), &track.written) {
+                        (Ok(current), Some(written)) => same_value(&current, written),
+                        _ => true,
+                    },
+                );
+                for track in tracks.values_mut() {
+                    if let Some(value) = track.sample(now, &settings) {
+                        track.written = Some(value.clone());
+                        world.set_entry(*id, value).ok();
+                    }
+                }
+            }
+
+            world.add_resource(interpolation_buffer(), buffer);
+        }))],
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use ambient_ecs::{
+        components, ComponentEntry, Entity, FrameEvent, Interpolated, System, World, WorldContext,
+    };
+    use ambient_sys::time::Instant;
+    use glam::Vec3;
+    use std::time::Duration;
+
+    use super::{client_systems, interpolation_buffer, InterpolationBuffer, InterpolationSettings};
+
+    components!("test", {
+        @[Interpolated]
+        interpolated_position: Vec3,
+    });
+
+    #[test]
+    fn interpolates_and_settles() {
+        init_components();
+        let id = ambient_ecs::EntityId::new();
+        let start = Instant::now();
+        let settings = InterpolationSettings {
+            delay: Duration::from_millis(100),
+            max_extrapolation: Duration::from_millis(50),
+        };
+        let mut buffer = InterpolationBuffer::default();
+        for (ms, x) in [(0, 0.), (100, 10.)] {
+            let value = ComponentEntry::new(interpolated_position(), Vec3::X * x);
+            buffer.push(id, value, start + Duration::from_millis(ms));
+        }
+        let track = buffer
+            .entities
+            .get_mut(&id)
+            .and_then(|tracks| tracks.get_mut(&interpolated_position().desc()))
+            .unwrap();
+        let mut sample_at = |ms| {
+            track
+                .sample(start + Duration::from_millis(ms), &settings)
+                .and_then(|value| value.try_downcast_cloned::<Vec3>())
+                .map(|value| value.x)
+        };
+        let close = |value: Option<f32>, expected: f32| (value.unwrap() - expected).abs() < 1e-4;
+
+        assert!(close(sample_at(50), 0.));
+        assert!(close(sample_at(150), 5.));
+        // Extrapolated past the last value
+        assert!(close(sample_at(230), 13.));
+        // Nothing arrived in time, so it settles at the last value
+        assert!(close(sample_at(300), 10.));
+        assert_eq!(sample_at(310), None);
+    }
+
+    #[test]
+    fn local_changes_stop_interpolation() {
+        ambient_ecs::init_components();
+        super::init_components();
+        init_components();
+        let mut world = World::new("interpolation", WorldContext::Client);
+        let id = Entity::new()
+            .with(interpolated_position(), Vec3::X * 10.)
+            .spawn(&mut world);
+
+        let now = Instant::now();
+        let mut buffer = InterpolationBuffer::default();
+        for (ms, x) in [(150, 0.), (50, 10.)] {
+            let value = ComponentEntry::new(interpolated_position(), Vec3::X * x);
+            buffer.push(id, value, now - Duration::from_millis(ms));
+        }
+        world.add_resource(interpolation_buffer(), buffer);
+
+        let mut systems = client_systems();
+        systems.run(&mut world, &FrameEvent);
+        let position = world.get(id, interpolated_position()).unwrap();
+        assert!(position.x < 10.);
+
+        // Teleported on the client
+        world
+            .set(id, interpolated_position(), Vec3::X * 100.)
+            .unwrap();
+        systems.run(&mut world, &FrameEvent);
+        assert_eq!(
+            world.get(id, interpolated_position()).unwrap(),
+            Vec3::X * 100.
+        );
+        assert!(world.resource(interpolation_buffer()).entities[&id].is_empty());
+    }
+}
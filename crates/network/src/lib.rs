@@ -16,13 +16,17 @@ pub use ambient_ecs::generated::network::components::{
 
 pub type AsyncMutex<T> = tokio::sync::Mutex<T>;
 
+pub mod auth;
 pub mod bytes_ext;
 pub mod client;
 pub mod client_game_state;
 pub mod codec;
 pub mod diff_serialization;
 pub mod hooks;
+pub mod interpolation;
+pub mod prediction;
 pub mod proto;
+pub mod relevancy;
 pub mod rpc;
 pub mod serialization;
 pub mod server;
@@ -53,6 +57,9 @@ const MAX_FRAME_SIZE: usize = 1024 * 1024 * 1024;
 pub fn init_all_components() {
     client::init_components();
     server::init_components();
+    relevancy::init_components();
+    prediction::init_components();
+    interpolation::init_components();
     client_game_state::init_components();
 }
 
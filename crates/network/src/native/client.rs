@@ -73,6 +73,8 @@ pub struct ClientView {
     pub server_addr: ResolvedAddr,
     pub cert: Option<Vec<u8>>,
     pub user_id: String,
+    /// Sent to the server to prove that this client may use `user_id`
+    pub auth_token: Option<String>,
     pub fail_on_version_mismatch: bool,
     pub systems_and_resources: Cb<dyn Fn() -> (SystemGroup, Entity) + Sync + Send>,
     pub on_loaded: LoadedFunc,
@@ -85,6 +87,7 @@ impl ElementComponent for ClientView {
         let Self {
             server_addr,
             user_id,
+            auth_token,
             fail_on_version_mismatch,
             systems_and_resources,
             create_rpc_registry,
@@ -164,6 +167,7 @@ impl ElementComponent for ClientView {
                     conn.clone(),
                     &assets,
                     user_id,
+                    auth_token,
                     fail_on_version_mismatch,
                     move |args| {
                         let OnConnectionState {
@@ -279,6 +283,7 @@ async fn handle_connection(
     conn: quinn::Connection,
     assets: &AssetCache,
     user_id: String,
+    auth_token: Option<String>,
     fail_on_version_mismatch: bool,
     mut on_loaded: impl FnMut(OnConnectionState) -> anyhow::Result<(SharedClientGameState, CleanupFunc)>
         + Send
@@ -294,7 +299,10 @@ async fn handle_connection(
     tracing::info!("Attempting to connect using {user_id:?}");
 
     request_send
-        .send(ClientRequest::Connect(user_id.clone()))
+        .send(ClientRequest::Connect {
+            user_id: user_id.clone(),
+            auth_token,
+        })
         .await?;
 
     let mut client = ClientProtoState::Pending(user_id.clone());
@@ -316,12 +324,24 @@ async fn handle_connection(
         return Ok(());
     };
 
+    // The server opens the diff stream once it has accepted us, or tells us why it didn't
+    let mut diff_stream = loop {
+        tokio::select! {
+            stream = conn.accept_uni() => break RawFramedRecvStream::new(stream?),
+            Some(frame) = push_recv.next() => {
+                client.process_push(assets, fail_on_version_mismatch, frame?)?;
+                if !client.is_connected() {
+                    tracing::warn!("Connection failed or was denied");
+                    return Ok(());
+                }
+            }
+        }
+    };
+
     tracing::info!("Connection successfully established");
 
     // Create the game client
 
-    let mut diff_stream = RawFramedRecvStream::new(conn.accept_uni().await?);
-
     let (shared_client_state, cleanup) = on_loaded(OnConnectionState {
         assets,
         user_id: &user_id,
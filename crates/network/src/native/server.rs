@@ -29,6 +29,7 @@ use tokio::time::{interval, MissedTickBehavior};
 use uuid::Uuid;
 
 use crate::{
+    auth::{AllowAnonymous, Authenticator},
     native::{
         client_connection::ConnectionKind, load_root_certs, webtransport::handle_h3_connection,
     },
@@ -56,6 +57,8 @@ pub struct GameServer {
     /// Shuts down the server if there are no players
     pub inactivity_shutdown: Option<Duration>,
     proxy_settings: Option<ProxySettings>,
+    /// Verifies the user id of connecting clients. Defaults to [`AllowAnonymous`]
+    pub authenticator: Arc<dyn Authenticator>,
 }
 
 impl GameServer {
@@ -72,6 +75,7 @@ impl GameServer {
             endpoint,
             inactivity_shutdown,
             proxy_settings,
+            authenticator: Arc::new(AllowAnonymous),
         })
     }
 
@@ -115,13 +119,14 @@ impl GameServer {
         let Self {
             endpoint,
             proxy_settings,
+            authenticator,
             ..
         } = self;
 
         let assets = world.resource(asset_cache()).clone();
         let world_stream_filter =
             WorldStreamFilter::new(ArchetypeFilter::new().excl(no_sync()), is_sync_component);
-        let state = Arc::new(Mutex::new(ServerState::new(
+        let mut state = ServerState::new(
             assets.clone(),
             [(
                 MAIN_INSTANCE_ID.to_string(),
@@ -136,7 +141,9 @@ impl GameServer {
             create_server_systems,
             create_on_forking_systems,
             create_shutdown_systems,
-        )));
+        );
+        state.authenticator = authenticator;
+        let state = Arc::new(Mutex::new(state));
         on_server_state_created(state.clone());
 
         let mut fps_counter = FpsCounter::new();
@@ -326,6 +333,15 @@ async fn handle_quinn_connection(
         }
     }
 
+    if let ServerProtoState::Rejected(reason) = &server {
+        push_send
+            .send(ServerPush::Disconnect(Some(reason.clone())))
+            .await?;
+        // Make sure the reason arrives before the connection is dropped
+        SinkExt::<ServerPush>::close(&mut push_send).await?;
+        return Ok(());
+    }
+
     tokio::spawn(handle_diffs(
         FramedSendStream::new(conn.open_uni().await?),
         diffs_rx,
@@ -121,6 +121,15 @@ async fn handle_webtransport_session(
         }
     }
 
+    if let proto::server::ServerProtoState::Rejected(reason) = &server {
+        push_send
+            .send(ServerPush::Disconnect(Some(reason.clone())))
+            .await?;
+        // Make sure the reason arrives before the connection is dropped
+        SinkExt::<ServerPush>::close(&mut push_send).await?;
+        return Ok(());
+    }
+
     tokio::spawn(handle_diffs(
         FramedSendStream::new(conn.open_uni(sid).await?),
         diffs_rx,
@@ -0,0 +1,359 @@
+//! Client-side prediction of the local player, reconciled with the server.
+//!
+//! When a [`prediction_simulator`] resource is present, the client samples the local player's input
+//! every [`FIXED_SERVER_TICK_TIME`], stamps it with a tick, simulates it immediately, and sends it to
+//! the server. The server simulates the same inputs with its own simulator, and acknowledges the last
+//! one it processed through [`last_input_tick`].
+//!
+//! Only components with the [`Predicted`] attribute on the local player are predicted. When a diff
+//! arrives, the client compares the server's values with what it predicted for the acknowledged tick.
+//! If they match, the client keeps its newer predictions; otherwise it rewinds to the server's values
+//! and replays the inputs the server has not processed yet.
+
+use std::{collections::VecDeque, sync::Arc, time::Duration};
+
+use ambient_core::{
+    delta_time,
+    player::{get_by_user_id, local_user_id},
+    runtime, FIXED_SERVER_TICK_TIME,
+};
+use ambient_ecs::{
+    components, query, Entity, EntityId, FnSystem, Predicted, Resource, SystemGroup, World,
+    WorldChange, WorldDiff,
+};
+use ambient_native_std::asset_cache::AssetCache;
+use bytes::Bytes;
+use serde::{Deserialize, Serialize};
+
+pub use ambient_ecs::generated::network::components::last_input_tick;
+
+use crate::{
+    client::client_state, log_network_result, server::SharedServerState, PLAYER_INPUT_DATAGRAM_ID,
+};
+
+/// How many of the most recent unacknowledged inputs are sent with every datagram, so that a lost
+/// datagram doesn't lose its input.
+const REDUNDANT_INPUTS: usize = 8;
+/// The client stops predicting ahead once this many inputs are unacknowledged.
+const MAX_PENDING_INPUTS: usize = 128;
+/// The server drops the oldest inputs of a player once this many are waiting to be simulated.
+const MAX_QUEUED_INPUTS: usize = 32;
+
+components!("network::prediction", {
+    /// Simulates the predicted components; must be set in both the server and the client world
+    @[Resource]
+    prediction_simulator: Arc<dyn PredictionSimulator>,
+    /// The inputs of the local player that the server has not acknowledged yet. Only present
+    /// once the local player has started sending inputs.
+    @[Resource]
+    prediction_state: PredictionState,
+
+    /// The inputs received from this player that the server has not simulated yet
+    player_input_queue: VecDeque<TickedInput>,
+});
+
+/// The input of a player for a single tick.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TickedInput {
+    pub tick: u64,
+    pub data: Vec<u8>,
+}
+
+/// Simulates the [`Predicted`] components of a player from its input.
+///
+/// The same simulator must be registered on the server and the client.
+pub trait PredictionSimulator: Send + Sync {
+    /// Samples the input of the local player for the next tick. Only called on the client.
+    fn sample_input(&self, world: &World, player: EntityId) -> Option<Vec<u8>>;
+    /// Advances the predicted components of `player` by one tick.
+    ///
+    /// Called on both the server and the client; for the same input and state, both should arrive
+    /// at the same result, or the client will be corrected.
+    fn simulate(&self, world: &mut World, player: EntityId, input: &[u8], dt: Duration);
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct PredictionState {
+    next_tick: u64,
+    accumulated: Duration,
+    pending: VecDeque<PendingInput>,
+    /// The latest values of the predicted components according to the server
+    server_state: Entity,
+}
+
+#[derive(Debug, Clone)]
+struct PendingInput {
+    input: TickedInput,
+    /// The predicted components after simulating `input`
+    predicted: Entity,
+}
+
+/// Returns true if the local player's predicted components are being predicted
+pub(crate) fn is_predicting(world: &World) -> bool {
+    world.resource_opt(prediction_state()).is_some()
+}
+
+pub(crate) fn local_player(world: &World) -> Option<EntityId> {
+    get_by_user_id(world, world.resource_opt(local_user_id())?)
+}
+
+fn predicted_components(world: &World, id: EntityId) -> Entity {
+    let mut entity = world.clone_entity(id).unwrap_or_default();
+    entity.filter(&|desc| desc.has_attribute::<Predicted>());
+    entity
+}
+
+fn same_state(a: &Entity, b: &Entity) -> bool {
+    match (bincode::serialize(a), bincode::serialize(b)) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => false,
+    }
+}
+
+/// Samples, predicts and sends the local player's input at the server's tick rate.
+pub fn client_systems() -> SystemGroup {
+    SystemGroup::new(
+        "network/prediction/client",
+        vec![Box::new(FnSystem::new(|world, _| {
+            let Some(simulator) = world.resource_opt(prediction_simulator()).cloned() else {
+                return;
+            };
+            let Some(player) = local_player(world) else {
+                return;
+            };
+            let Some(transport) = world
+                .resource_opt(client_state())
+                .and_then(|state| state.as_ref())
+                .map(|state| state.transport.clone())
+            else {
+                return;
+            };
+
+            if world.resource_opt(prediction_state()).is_none() {
+                // Only start predicting once the player has input to predict
+                if simulator.sample_input(world, player).is_none() {
+                    return;
+                }
+                // Continue from the server's count, in case this player reconnected
+                let next_tick = world.get(player, last_input_tick()).unwrap_or_default() + 1;
+                world.add_resource(
+                    prediction_state(),
+                    PredictionState {
+                        next_tick,
+                        ..Default::default()
+                    },
+                );
+            }
+
+            let dt = Duration::from_secs_f32(*world.resource(delta_time()));
+            let mut state = std::mem::take(world.resource_mut(prediction_state()));
+            state.accumulated += dt;
+
+            let mut sampled = false;
+            while state.accumulated >= FIXED_SERVER_TICK_TIME {
+                state.accumulated -= FIXED_SERVER_TICK_TIME;
+                if state.pending.len() >= MAX_PENDING_INPUTS {
+                    continue;
+                }
+                let Some(data) = simulator.sample_input(world, player) else {
+                    continue;
+                };
+
+                let input = TickedInput {
+                    tick: state.next_tick,
+                    data,
+                };
+                state.next_tick += 1;
+
+                simulator.simulate(world, player, &input.data, FIXED_SERVER_TICK_TIME);
+                state.pending.push_back(PendingInput {
+                    input,
+                    predicted: predicted_components(world, player),
+                });
+                sampled = true;
+            }
+
+            if sampled {
+                let inputs = state
+                    .pending
+                    .iter()
+                    .rev()
+                    .take(REDUNDANT_INPUTS)
+                    .rev()
+                    .map(|pending| &pending.input)
+                    .collect::<Vec<_>>();
+
+                match bincode::serialize(&inputs) {
+                    Ok(data) => {
+                        world.resource(runtime()).spawn(async move {
+                            log_network_result!(
+                                transport
+                                    .send_datagram(PLAYER_INPUT_DATAGRAM_ID, data.into())
+                                    .await
+                            );
+                        });
+                    }
+                    Err(err) => tracing::error!("Failed to serialize player input: {err:?}"),
+                }
+            }
+
+            world.add_resource(prediction_state(), state);
+        }))],
+    )
+}
+
+/// Collects the predicted components that `diff` changes, so that they can be reconciled after the diff is applied.
+pub fn predicted_changes(diff: &WorldDiff) -> Vec<(EntityId, Entity)> {
+    diff.changes
+        .iter()
+        .filter_map(|change| match change {
+            WorldChange::Spawn(id, entity)
+            | WorldChange::AddComponents(id, entity)
+            | WorldChange::SetComponents(id, entity) => {
+                let mut entity = entity.clone();
+                entity.filter(&|desc| desc.has_attribute::<Predicted>());
+                (!entity.is_empty()).then_some((*id, entity))
+            }
+            WorldChange::Despawn(_) | WorldChange::RemoveComponents(_, _) => None,
+        })
+        .collect()
+}
+
+/// Reconciles the local player's predictions with a diff from the server that has just been applied.
+///
+/// `changes` are the [`predicted_changes`] of the diff.
+pub fn reconcile(world: &mut World, changes: Vec<(EntityId, Entity)>) {
+    let Some(simulator) = world.resource_opt(prediction_simulator()).cloned() else {
+        return;
+    };
+    let Some(player) = local_player(world) else {
+        return;
+    };
+    let Some(state) = world.resource_mut_opt(prediction_state()) else {
+        return;
+    };
+    let mut state = std::mem::take(state);
+
+    let mut server_changed = false;
+    for (_, entity) in changes.into_iter().filter(|(id, _)| *id == player) {
+        state.server_state.merge(entity);
+        server_changed = true;
+    }
+
+    let acknowledged = world.get(player, last_input_tick()).unwrap_or_default();
+    let mut predicted_at_ack = None;
+    while state
+        .pending
+        .front()
+        .is_some_and(|pending| pending.input.tick <= acknowledged)
+    {
+        predicted_at_ack = state.pending.pop_front().map(|pending| pending.predicted);
+    }
+
+    let mispredicted = match &predicted_at_ack {
+        Some(predicted) => !same_state(predicted, &state.server_state),
+        // The server changed the predicted components without processing any new input
+        None => server_changed,
+    };
+
+    if mispredicted {
+        tracing::debug!(
+            acknowledged,
+            replayed = state.pending.len(),
+            "Misprediction, rewinding"
+        );
+        world
+            .add_components(player, state.server_state.clone())
+            .ok();
+        for pending in state.pending.iter_mut() {
+            simulator.simulate(world, player, &pending.input.data, FIXED_SERVER_TICK_TIME);
+            pending.predicted = predicted_components(world, player);
+        }
+    } else if server_changed {
+        // The diff overwrote the predictions with older values from the server; restore the latest ones
+        let latest = state
+            .pending
+            .back()
+            .map(|pending| pending.predicted.clone())
+            .or(predicted_at_ack);
+        if let Some(latest) = latest {
+            world.add_components(player, latest).ok();
+        }
+    }
+
+    world.add_resource(prediction_state(), state);
+}
+
+/// Registers the handler that receives the input of players on the server.
+pub fn register_input_datagram_handler(handlers: &mut crate::server::DatagramHandlers) {
+    handlers.insert(
+        PLAYER_INPUT_DATAGRAM_ID,
+        ("server_player_input", Arc::new(on_input_datagram)),
+    );
+}
+
+fn on_input_datagram(state: SharedServerState, _assets: AssetCache, user_id: &str, data: Bytes) {
+    let inputs: Vec<TickedInput> = match bincode::deserialize(&data) {
+        Ok(inputs) => inputs,
+        Err(err) => {
+            tracing::warn!(user_id, "Received malformed player input: {err:?}");
+            return;
+        }
+    };
+
+    let mut state = state.lock();
+    let Some(world) = state.get_player_world_mut(user_id) else {
+        return;
+    };
+    let Some(player) = get_by_user_id(world, user_id) else {
+        return;
+    };
+
+    if !world.has_component(player, player_input_queue()) {
+        world
+            .add_component(player, player_input_queue(), Default::default())
+            .unwrap();
+    }
+    let processed = world.get(player, last_input_tick()).unwrap_or_default();
+    let queue = world.get_mut(player, player_input_queue()).unwrap();
+
+    // Inputs are sent more than once, so only take the ones that haven't been seen before
+    let latest = queue
+        .back()
+        .map_or(processed, |input| input.tick.max(processed));
+    queue.extend(inputs.into_iter().filter(|input| input.tick > latest));
+    while queue.len() > MAX_QUEUED_INPUTS {
+        queue.pop_front();
+    }
+}
+
+/// Simulates one queued input per player each tick.
+pub fn server_systems() -> SystemGroup {
+    SystemGroup::new(
+        "network/prediction/server",
+        vec![Box::new(FnSystem::new(|world, _| {
+            let Some(simulator) = world.resource_opt(prediction_simulator()).cloned() else {
+                return;
+            };
+
+            let players = query(player_input_queue())
+                .iter(world, None)
+                .filter(|(_, queue)| !queue.is_empty())
+                .map(|(id, _)| id)
+                .collect::<Vec<_>>();
+
+            for player in players {
+                let input = world
+                    .get_mut(player, player_input_queue())
+                    .unwrap()
+                    .pop_front()
+                    .unwrap();
+
+                simulator.simulate(world, player, &input.data, FIXED_SERVER_TICK_TIME);
+                world
+                    .add_component(player, last_input_tick(), input.tick)
+                    .unwrap();
+            }
+        }))],
+    )
+}
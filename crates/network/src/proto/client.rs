@@ -89,9 +89,12 @@ impl ClientProtoState {
                 tracing::warn!("Received server info while already connected");
                 Ok(())
             }
-            (ServerPush::Disconnect, _) => {
+            (ServerPush::Disconnect(reason), _) => {
                 self.process_disconnect();
-                Ok(())
+                match reason {
+                    Some(reason) => anyhow::bail!("Disconnected by the server: {reason}"),
+                    None => Ok(()),
+                }
             }
         }
     }
@@ -136,7 +139,10 @@ impl ConnectedClient {
         let diff = self.diff_serializer.deserialize(diff)?;
         let mut gs = state.lock();
         tracing::trace!(diff=?diff.len(), "Applying diff");
+        let predicted = crate::prediction::predicted_changes(&diff);
+        crate::interpolation::record_diff(&mut gs.world, &diff);
         diff.apply(&mut gs.world, Entity::new().with(is_remote_entity(), ()));
+        crate::prediction::reconcile(&mut gs.world, predicted);
         Ok(())
     }
 
@@ -8,7 +8,11 @@ pub mod server;
 /// Request sent by the client to the server
 pub enum ClientRequest {
     /// Connect to the server with the specified user id
-    Connect(String),
+    Connect {
+        user_id: String,
+        /// Proof that the client is allowed to use `user_id`, checked by the server's authenticator
+        auth_token: Option<String>,
+    },
     /// Client wants to disconnect
     Disconnect,
 }
@@ -17,8 +21,8 @@ pub enum ClientRequest {
 /// Frame used by the server to send information to the client
 pub enum ServerPush {
     ServerInfo(ServerInfo),
-    /// Graceful disconnect
-    Disconnect,
+    /// Graceful disconnect, with the reason if the server closed the connection because of an error
+    Disconnect(Option<String>),
 }
 
 /// Miscellaneous information about the server that needs to be sent to the client during the handshake.
@@ -1,6 +1,5 @@
 use std::{collections::HashMap, sync::Arc};
 
-use ambient_core::player::get_by_user_id;
 use ambient_ecs::{
     ComponentRegistry, Entity, EntityId, FrozenWorldDiff, WorldChange, WorldDiff, WorldStreamFilter,
 };
@@ -18,6 +17,7 @@ use crate::{
     diff_serialization::{DiffSerializer, WorldDiffDeduplicator},
     log_network_result, log_task_result,
     proto::ServerPush,
+    relevancy::relevancy_filter,
     server::{
         bi_stream_handlers, create_player_entity_data, datagram_handlers, uni_stream_handlers,
     },
@@ -36,6 +36,8 @@ pub enum ServerProtoState {
     #[default]
     PendingConnection,
     Connected(ConnectedClient),
+    /// The client failed to authenticate, and should be disconnected with the reason
+    Rejected(String),
     Disconnected,
 }
 
@@ -100,7 +102,7 @@ impl Player {
 
     /// Notifies the existing connection handler to shut down
     pub fn abort(&self) {
-        self.control_tx.send(ServerPush::Disconnect).ok();
+        self.control_tx.send(ServerPush::Disconnect(None)).ok();
     }
 }
 
@@ -112,17 +114,30 @@ impl ServerProtoState {
         frame: ClientRequest,
     ) -> anyhow::Result<()> {
         match (frame, &self) {
-            (_, Self::Disconnected) => {
+            (_, Self::Disconnected | Self::Rejected(_)) => {
                 tracing::debug!("Client is disconnected, ignoring control frame");
                 Ok(())
             }
-            (ClientRequest::Connect(user_id), Self::PendingConnection) => {
+            (
+                ClientRequest::Connect {
+                    user_id,
+                    auth_token,
+                },
+                Self::PendingConnection,
+            ) => {
+                let authenticator = data.state.lock().authenticator.clone();
+                if let Err(err) = authenticator.authenticate(&user_id, auth_token.as_deref()) {
+                    tracing::warn!(user_id, "Rejected connection: {err}");
+                    *self = Self::Rejected(err.to_string());
+                    return Ok(());
+                }
+
                 // Connect the user
                 tracing::debug!("User connected");
                 self.process_connect(data, user_id);
                 Ok(())
             }
-            (ClientRequest::Connect(_), Self::Connected(_)) => {
+            (ClientRequest::Connect { .. }, Self::Connected(_)) => {
                 tracing::warn!("Client already connected");
                 Ok(())
             }
@@ -139,8 +154,8 @@ impl ServerProtoState {
 
         let (control_tx, control_rx) = flume::unbounded();
 
-        let old_player = state.players.insert(
-            user_id.clone(),
+        let old_player = state.connect_player(
+            &user_id,
             Player {
                 instance: MAIN_INSTANCE_ID.to_string(),
                 control_tx,
@@ -148,17 +163,21 @@ impl ServerProtoState {
             },
         );
 
-        let instance = state.instances.get_mut(MAIN_INSTANCE_ID).unwrap();
+        let instance_id = state.players[&user_id].instance.clone();
+        let instance = state.instances.get_mut(&instance_id).unwrap();
 
         // Bring world stream up to the current time
         tracing::debug!("[{}] Broadcasting diffs", user_id);
         instance.broadcast_diffs();
         tracing::debug!("[{}] Creating init diff", user_id);
 
-        let diff = data.world_stream_filter.initial_diff(&instance.world);
+        // With relevancy, the player's view is built up by the following broadcasts instead
+        if instance.world.resource_opt(relevancy_filter()).is_none() {
+            let diff = data.world_stream_filter.initial_diff(&instance.world);
 
-        log_result!(data.diff_tx.send(diff.into()));
-        tracing::debug!("[{}] Init diff sent", user_id);
+            log_result!(data.diff_tx.send(diff.into()));
+            tracing::debug!("[{}] Init diff sent", user_id);
+        }
 
         let entity_data = create_player_entity_data(
             data.conn.clone(),
@@ -167,16 +186,11 @@ impl ServerProtoState {
             data.connection_id,
         );
 
+        let id = instance.spawn_or_reconnect_player(&user_id, entity_data);
         if let Some(old_player) = old_player {
-            old_player.control_tx.send(ServerPush::Disconnect).ok();
-
-            let id = get_by_user_id(&instance.world, &user_id).unwrap();
-
-            instance.world.add_components(id, entity_data).unwrap();
-
-            tracing::debug!(user_id, ?id, "Player reconnected");
+            old_player.abort();
+            tracing::debug!(user_id, ?id, instance_id, "Player reconnected");
         } else {
-            let id = instance.spawn_player(entity_data);
             tracing::debug!(user_id, ?id, "Player connected");
         }
 
@@ -0,0 +1,348 @@
+//! Per-player relevancy: decides which networked entities are synchronized to each player.
+//!
+//! When a [`relevancy_filter`] resource is present on a server world, every player gets their
+//! own view of the world. Entities are spawned on the client when they become relevant to the
+//! player and despawned when they stop being relevant.
+//!
+//! Without it, the same diff is broadcast to every player. The [`DefaultRelevancyFilter`] is
+//! installed the first time one of the relevancy components is used in the world.
+//!
+//! Only the entities that changed are checked again every tick, unless the viewpoint of a player
+//! moved more than [`RELEVANCY_VIEWPOINT_TOLERANCE`] or the default [`relevancy_distance`]
+//! changed, in which case every entity is checked again for that player.
+
+use std::{collections::HashSet, sync::Arc};
+
+use ambient_core::{
+    camera::get_active_camera,
+    hierarchy::{children, parent},
+    main_scene,
+    player::user_id,
+    transform::{local_to_world, translation},
+};
+use ambient_ecs::{
+    components, EntityId, Resource, World, WorldChange, WorldDiff, WorldStreamFilter,
+};
+use glam::Vec3;
+
+pub use ambient_ecs::generated::network::components::{
+    always_relevant, relevancy_distance, visible_to,
+};
+
+components!("network::relevancy", {
+    @[Resource]
+    relevancy_filter: Arc<dyn RelevancyFilter>,
+    /// The entities that have been synchronized to this player
+    player_relevant_entities: HashSet<EntityId>,
+    /// The viewpoint from which every entity was last checked for relevancy to this player
+    player_relevancy_viewpoint: Option<Vec3>,
+    /// The default relevancy distance when every entity was last checked for relevancy
+    @[Resource]
+    evaluated_relevancy_distance: Option<f32>,
+});
+
+/// How far the viewpoint of a player can move before every entity is checked for relevancy to
+/// them again. In between, entities at the edge of the relevancy distance can enter or leave it
+/// up to this far late.
+pub const RELEVANCY_VIEWPOINT_TOLERANCE: f32 = 1.0;
+
+/// Returns true if `diff` adds any of the relevancy components
+pub fn adds_relevancy(diff: &WorldDiff) -> bool {
+    diff.changes.iter().any(|change| match change {
+        WorldChange::Spawn(_, entity) | WorldChange::AddComponents(_, entity) => {
+            entity.contains(relevancy_distance())
+                || entity.contains(visible_to())
+                || entity.contains(always_relevant())
+        }
+        _ => false,
+    })
+}
+
+/// Returns the entities whose relevancy may have changed with `diff`, including the descendants of
+/// the changed entities as their relevancy depends on their root, and the entities that were
+/// despawned.
+pub fn changed_entities(
+    world: &World,
+    stream_filter: &WorldStreamFilter,
+    diff: &WorldDiff,
+) -> (HashSet<EntityId>, Vec<EntityId>) {
+    let mut changed = HashSet::new();
+    let mut despawned = Vec::new();
+    let mut stack = Vec::new();
+    for change in &diff.changes {
+        match change {
+            WorldChange::Despawn(id) => despawned.push(*id),
+            WorldChange::Spawn(id, _)
+            | WorldChange::AddComponents(id, _)
+            | WorldChange::RemoveComponents(id, _)
+            | WorldChange::SetComponents(id, _) => stack.push(*id),
+        }
+    }
+    while let Some(id) = stack.pop() {
+        if !stream_filter.matches_entity(world, id) || !changed.insert(id) {
+            continue;
+        }
+        if let Ok(entity_children) = world.get_ref(id, children()) {
+            stack.extend(entity_children.iter().copied());
+        }
+    }
+    (changed, despawned)
+}
+
+/// Returns true if every entity has to be checked for relevancy again for a viewer at `position`,
+/// whose entities were last all checked from `last`
+pub fn viewpoint_moved(last: Option<Option<Vec3>>, position: Option<Vec3>) -> bool {
+    match (last, position) {
+        (Some(Some(last)), Some(position)) => {
+            last.distance_squared(position)
+                > RELEVANCY_VIEWPOINT_TOLERANCE * RELEVANCY_VIEWPOINT_TOLERANCE
+        }
+        (Some(None), None) => false,
+        _ => true,
+    }
+}
+
+/// The point of view of a player when deciding what is relevant to them
+#[derive(Debug, Clone)]
+pub struct RelevancyViewer {
+    pub player: EntityId,
+    /// Where the player is looking from, if known
+    pub position: Option<Vec3>,
+}
+impl RelevancyViewer {
+    /// Uses the player's active camera as the viewpoint, falling back to the player entity itself
+    pub fn new(world: &World, player: EntityId) -> Self {
+        let camera = world
+            .get_ref(player, user_id())
+            .ok()
+            .and_then(|user_id| get_active_camera(world, main_scene(), Some(user_id)));
+
+        Self {
+            player,
+            position: camera
+                .and_then(|camera| world_position(world, camera))
+                .or_else(|| world_position(world, player)),
+        }
+    }
+}
+
+pub trait RelevancyFilter: Send + Sync {
+    /// Returns true if `entity` should be synchronized to `viewer`.
+    ///
+    /// This is only called again for an entity when it or one of its ancestors changes, or when
+    /// the viewpoint moves, so it should only depend on those.
+    fn is_relevant(&self, world: &World, viewer: &RelevancyViewer, entity: EntityId) -> bool;
+}
+
+/// Filters entities using the [`always_relevant`], [`visible_to`] and [`relevancy_distance`] components.
+///
+/// Children are relevant if their root is, so that clients never see half a hierarchy.
+/// Entities without a position, and all entities when the player has no viewpoint, are relevant.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DefaultRelevancyFilter;
+impl RelevancyFilter for DefaultRelevancyFilter {
+    fn is_relevant(&self, world: &World, viewer: &RelevancyViewer, entity: EntityId) -> bool {
+        let root = root_of(world, entity);
+        if root == viewer.player || world.has_component(root, always_relevant()) {
+            return true;
+        }
+
+        if let Ok(players) = world.get_ref(root, visible_to()) {
+            if !players.contains(&viewer.player) {
+                return false;
+            }
+        }
+
+        let Some(max_distance) = world
+            .get(root, relevancy_distance())
+            .ok()
+            .or_else(|| world.resource_opt(relevancy_distance()).copied())
+        else {
+            return true;
+        };
+        match (viewer.position, world_position(world, root)) {
+            (Some(viewpoint), Some(position)) => {
+                viewpoint.distance_squared(position) <= max_distance * max_distance
+            }
+            _ => true,
+        }
+    }
+}
+
+fn root_of(world: &World, mut entity: EntityId) -> EntityId {
+    while let Ok(parent) = world.get(entity, parent()) {
+        entity = parent;
+    }
+    entity
+}
+
+fn world_position(world: &World, entity: EntityId) -> Option<Vec3> {
+    world
+        .get(entity, local_to_world())
+        .map(|transform| transform.w_axis.truncate())
+        .or_else(|_| world.get(entity, translation()))
+        .ok()
+}
+
+/// Creates the diff for a player whose relevant entities are now `relevant`, of which `entered`
+/// just became relevant, and which just stopped knowing about the `left` entities.
+///
+/// Entities that left relevance are despawned, entities that entered it are spawned with all their
+/// synchronized components, and `diff` is forwarded for the entities the player already knew about.
+pub fn relevant_diff(
+    world: &World,
+    stream_filter: &WorldStreamFilter,
+    diff: &WorldDiff,
+    relevant: &HashSet<EntityId>,
+    entered: &HashSet<EntityId>,
+    left: &[EntityId],
+) -> WorldDiff {
+    let mut changes = left
+        .iter()
+        .map(|&id| WorldChange::Despawn(id))
+        .collect::<Vec<_>>();
+
+    changes.extend(diff.changes.iter().filter_map(|change| {
+        let id = match change {
+            WorldChange::Spawn(id, _) | WorldChange::Despawn(id) => return None,
+            WorldChange::AddComponents(id, _)
+            | WorldChange::RemoveComponents(id, _)
+            | WorldChange::SetComponents(id, _) => id,
+        };
+        (relevant.contains(id) && !entered.contains(id)).then(|| change.clone())
+    }));
+
+    changes.extend(
+        entered.iter().map(|&id| {
+            WorldChange::Spawn(id, stream_filter.read_entity_components(world, id).into())
+        }),
+    );
+
+    WorldDiff { changes }
+}
+
+#[cfg(test)]
+mod tests {
+    use ambient_ecs::{Entity, WorldContext};
+    use glam::vec3;
+
+    use super::*;
+
+    fn init() -> World {
+        ambient_ecs::init_components();
+        init_components();
+        World::new("relevancy_test", WorldContext::Server)
+    }
+
+    #[test]
+    fn visible_to_and_distance() {
+        let mut world = init();
+        world.add_resource(relevancy_distance(), 10.);
+        let player = Entity::new()
+            .with(translation(), Vec3::ZERO)
+            .spawn(&mut world);
+        let other_player = Entity::new().spawn(&mut world);
+
+        let near = Entity::new()
+            .with(translation(), vec3(5., 0., 0.))
+            .spawn(&mut world);
+        let far = Entity::new()
+            .with(translation(), vec3(50., 0., 0.))
+            .spawn(&mut world);
+        let far_child = Entity::new().with(parent(), far).spawn(&mut world);
+        let far_always = Entity::new()
+            .with(translation(), vec3(50., 0., 0.))
+            .with(always_relevant(), ())
+            .spawn(&mut world);
+        let hidden = Entity::new()
+            .with(translation(), vec3(1., 0., 0.))
+            .with(visible_to(), vec![other_player])
+            .spawn(&mut world);
+
+        let viewer = RelevancyViewer::new(&world, player);
+        assert_eq!(viewer.position, Some(Vec3::ZERO));
+
+        let filter = DefaultRelevancyFilter;
+        assert!(filter.is_relevant(&world, &viewer, player));
+        assert!(filter.is_relevant(&world, &viewer, near));
+        assert!(!filter.is_relevant(&world, &viewer, far));
+        assert!(!filter.is_relevant(&world, &viewer, far_child));
+        assert!(filter.is_relevant(&world, &viewer, far_always));
+        assert!(!filter.is_relevant(&world, &viewer, hidden));
+    }
+
+    #[test]
+    fn diff_spawns_and_despawns_on_relevance_change() {
+        let mut world = init();
+        let staying = Entity::new()
+            .with(translation(), Vec3::ZERO)
+            .spawn(&mut world);
+        let leaving = Entity::new().spawn(&mut world);
+        let entering = Entity::new()
+            .with(translation(), Vec3::ONE)
+            .spawn(&mut world);
+
+        let diff = WorldDiff::new()
+            .set(staying, translation(), Vec3::X)
+            .set(leaving, translation(), Vec3::Y)
+            .set(entering, translation(), Vec3::Z);
+        assert!(!adds_relevancy(&diff));
+        let relevant = HashSet::from([staying, entering]);
+        let entered = HashSet::from([entering]);
+
+        let player_diff = relevant_diff(
+            &world,
+            &WorldStreamFilter::default(),
+            &diff,
+            &relevant,
+            &entered,
+            &[leaving],
+        );
+
+        assert_eq!(player_diff.changes.len(), 3);
+        assert!(matches!(player_diff.changes[0], WorldChange::Despawn(id) if id == leaving));
+        assert!(
+            matches!(&player_diff.changes[1], WorldChange::SetComponents(id, _) if *id == staying)
+        );
+        assert!(
+            matches!(&player_diff.changes[2], WorldChange::Spawn(id, data) if *id == entering && data.contains(translation()))
+        );
+    }
+
+    #[test]
+    fn only_changed_entities_are_checked_again() {
+        let mut world = init();
+        let root = Entity::new()
+            .with(translation(), Vec3::ZERO)
+            .spawn(&mut world);
+        let child = Entity::new().with(parent(), root).spawn(&mut world);
+        world.add_component(root, children(), vec![child]).unwrap();
+        let unchanged = Entity::new()
+            .with(translation(), Vec3::ZERO)
+            .spawn(&mut world);
+        let despawned = Entity::new().spawn(&mut world);
+        world.despawn(despawned);
+
+        let diff = WorldDiff::new()
+            .set(root, translation(), Vec3::X)
+            .despawn(vec![despawned]);
+        let (changed, despawned_ids) =
+            changed_entities(&world, &WorldStreamFilter::default(), &diff);
+        assert_eq!(changed, HashSet::from([root, child]));
+        assert_eq!(despawned_ids, vec![despawned]);
+
+        assert!(adds_relevancy(&WorldDiff::new().add_component(
+            unchanged,
+            always_relevant(),
+            ()
+        )));
+
+        assert!(viewpoint_moved(None, Some(Vec3::ZERO)));
+        assert!(!viewpoint_moved(
+            Some(Some(Vec3::ZERO)),
+            Some(Vec3::X * 0.5)
+        ));
+        assert!(viewpoint_moved(Some(Some(Vec3::ZERO)), Some(Vec3::X * 2.)));
+        assert!(!viewpoint_moved(Some(None), None));
+    }
+}
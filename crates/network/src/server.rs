@@ -1,8 +1,20 @@
-use std::{collections::HashMap, fmt::Debug, sync::Arc, time::Duration};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Debug,
+    sync::Arc,
+    time::Duration,
+};
 
 use crate::{
-    client::NetworkTransport, proto::server::Player, DynRecv, DynSend, NetworkError,
-    RPC_BISTREAM_ID,
+    auth::{AllowAnonymous, Authenticator},
+    client::NetworkTransport,
+    proto::server::Player,
+    relevancy::{
+        adds_relevancy, changed_entities, evaluated_relevancy_distance, player_relevancy_viewpoint,
+        player_relevant_entities, relevancy_distance, relevancy_filter, relevant_diff,
+        viewpoint_moved, DefaultRelevancyFilter, RelevancyFilter, RelevancyViewer,
+    },
+    DynRecv, DynSend, NetworkError, ServerWorldExt, RPC_BISTREAM_ID,
 };
 use ambient_core::{
     app_start_time, name,
@@ -11,13 +23,14 @@ use ambient_core::{
 };
 use ambient_ecs::{
     components, dont_store, query, ArchetypeFilter, Entity, EntityId, FrameEvent, FrozenWorldDiff,
-    Networked, Resource, System, SystemGroup, World, WorldStream, WorldStreamFilter,
+    Networked, Resource, System, SystemGroup, World, WorldDiff, WorldStream, WorldStreamFilter,
 };
 use ambient_native_std::{
     asset_cache::AssetCache, asset_url::AbsAssetUrl, fps_counter::FpsSample, log_result,
 };
 use ambient_rpc::RpcRegistry;
 use ambient_sys::time::Instant;
+use anyhow::Context;
 use bytes::Bytes;
 use flume::Sender;
 use parking_lot::Mutex;
@@ -38,8 +51,14 @@ components!("network::server", {
     // synced resource
     @[Networked]
     server_stats: FpsSample,
+
+    /// Changes to the world instances requested from this world, applied at the end of the tick
+    @[Resource]
+    instance_commands: Vec<InstanceCommand>,
 });
 
+pub use ambient_ecs::generated::network::components::instance_id;
+
 pub type BiStreamHandler =
     Arc<dyn Fn(SharedServerState, AssetCache, &str, DynSend, DynRecv) + Sync + Send>;
 pub type UniStreamHandler = Arc<dyn Fn(SharedServerState, AssetCache, &str, DynRecv) + Sync + Send>;
@@ -64,6 +83,31 @@ pub struct WorldInstance {
     pub systems: SystemGroup,
 }
 
+/// A change to the world instances of the server, requested from within one of them.
+///
+/// See [`request_instance_command`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InstanceCommand {
+    /// Creates a new instance by forking the main instance
+    Create(String),
+    /// Moves the players of an instance to the main instance, and removes it
+    Destroy(String),
+    /// Moves a player to another instance
+    TransferPlayer {
+        user_id: String,
+        instance_id: String,
+    },
+}
+
+/// Requests a change to the world instances of the server. As the instances can't be changed while
+/// one of them is running, the change is applied after all of them have been stepped.
+pub fn request_instance_command(world: &mut World, command: InstanceCommand) {
+    match world.resource_mut_opt(instance_commands()) {
+        Some(commands) => commands.push(command),
+        None => world.add_resource(instance_commands(), vec![command]),
+    }
+}
+
 #[derive(Clone)]
 pub struct RpcArgs {
     pub state: SharedServerState,
@@ -88,6 +132,7 @@ pub fn create_player_entity_data(
         .with(player_transport(), transport)
         .with(player_entity_stream(), entities_tx)
         .with(player_connection_id(), connection_id)
+        .with(player_relevant_entities(), Default::default())
         .with(dont_store(), ())
 }
 
@@ -128,22 +173,149 @@ impl WorldInstance {
     pub fn spawn_player(&mut self, ed: Entity) -> EntityId {
         ed.spawn(&mut self.world)
     }
+    /// Spawns the entity of a connected player, or adds the data of the new connection to the
+    /// entity the player already has if they reconnected
+    pub fn spawn_or_reconnect_player(&mut self, user_id: &str, ed: Entity) -> EntityId {
+        match get_by_user_id(&self.world, user_id) {
+            Some(id) => {
+                self.world.add_components(id, ed).unwrap();
+                id
+            }
+            None => self.spawn_player(ed),
+        }
+    }
     pub fn despawn_player(&mut self, user_id: &str) -> Option<Entity> {
         let id = get_by_user_id(&self.world, user_id)?;
         ambient_core::hierarchy::despawn_recursive(&mut self.world, id)
     }
     pub fn broadcast_diffs(&mut self) {
         let diff = self.world_stream.next_diff(&self.world);
-        if diff.is_empty() {
+        if let Some(filter) = self.world.resource_opt(relevancy_filter()).cloned() {
+            self.send_relevant_diffs(&*filter, &diff);
             return;
         }
-        let diff: FrozenWorldDiff = diff.into();
+        let uses_relevancy =
+            self.world.resource_opt(relevancy_distance()).is_some() || adds_relevancy(&diff);
+        if !diff.is_empty() {
+            let diff: FrozenWorldDiff = diff.into();
+
+            profiling::scope!("Send MsgEntities");
+
+            for (_, (entity_stream,)) in query((player_entity_stream(),)).iter(&self.world, None) {
+                if let Err(err) = entity_stream.send(diff.clone()) {
+                    tracing::warn!("Failed to broadcast diff to player: {err:?}");
+                }
+            }
+        }
+        if uses_relevancy {
+            self.enable_relevancy(Arc::new(DefaultRelevancyFilter));
+        }
+    }
+    /// Switches from broadcasting the same diff to every player to sending each player only what
+    /// is relevant to them. Every player has been sent every entity up to this point.
+    fn enable_relevancy(&mut self, filter: Arc<dyn RelevancyFilter>) {
+        let entities: HashSet<EntityId> = self
+            .world_stream
+            .filter()
+            .all_entities(&self.world)
+            .collect();
+        let players: Vec<EntityId> = query((player_relevant_entities(),))
+            .iter(&self.world, None)
+            .map(|(id, _)| id)
+            .collect();
+        for player in players {
+            self.world
+                .set(player, player_relevant_entities(), entities.clone())
+                .unwrap();
+        }
+        self.world.add_resource(relevancy_filter(), filter);
+    }
+    /// Sends each player only the part of `diff` that is relevant to them
+    fn send_relevant_diffs(&mut self, filter: &dyn RelevancyFilter, diff: &WorldDiff) {
+        profiling::scope!("Send relevant MsgEntities");
+
+        let stream_filter = self.world_stream.filter().clone();
+        let (changed, despawned) = changed_entities(&self.world, &stream_filter, diff);
+        // The default distance applies to most entities, so they all have to be checked again
+        let distance = self.world.resource_opt(relevancy_distance()).copied();
+        let distance_changed =
+            self.world.resource_opt(evaluated_relevancy_distance()) != Some(&distance);
+        self.world
+            .add_resource(evaluated_relevancy_distance(), distance);
+        let mut all_entities = None;
+
+        let players: Vec<(EntityId, Sender<FrozenWorldDiff>)> = query((player_entity_stream(),))
+            .iter(&self.world, None)
+            .map(|(id, (entity_stream,))| (id, entity_stream.clone()))
+            .collect();
+
+        for (player, entity_stream) in players {
+            let viewer = RelevancyViewer::new(&self.world, player);
+            let Ok(known) = self.world.get_ref(player, player_relevant_entities()) else {
+                continue;
+            };
+            let last_viewpoint = self.world.get(player, player_relevancy_viewpoint()).ok();
+            let check_all = distance_changed || viewpoint_moved(last_viewpoint, viewer.position);
 
-        profiling::scope!("Send MsgEntities");
+            let mut entered = HashSet::new();
+            let mut left = Vec::new();
+            if check_all {
+                let all_entities = all_entities.get_or_insert_with(|| {
+                    stream_filter
+                        .all_entities(&self.world)
+                        .collect::<HashSet<_>>()
+                });
+                for &id in all_entities.iter() {
+                    if filter.is_relevant(&self.world, &viewer, id) {
+                        if !known.contains(&id) {
+                            entered.insert(id);
+                        }
+                    } else if known.contains(&id) {
+                        left.push(id);
+                    }
+                }
+                left.extend(known.iter().filter(|id| !all_entities.contains(id)));
+            } else {
+                left.extend(despawned.iter().filter(|id| known.contains(id)));
+                for &id in &changed {
+                    match (
+                        known.contains(&id),
+                        filter.is_relevant(&self.world, &viewer, id),
+                    ) {
+                        (false, true) => {
+                            entered.insert(id);
+                        }
+                        (true, false) => left.push(id),
+                        _ => {}
+                    }
+                }
+            }
 
-        for (_, (entity_stream,)) in query((player_entity_stream(),)).iter(&self.world, None) {
-            if let Err(err) = entity_stream.send(diff.clone()) {
-                tracing::warn!("Failed to broadcast diff to player: {err:?}");
+            if check_all {
+                self.world
+                    .add_component(player, player_relevancy_viewpoint(), viewer.position)
+                    .unwrap();
+            }
+            let known = self
+                .world
+                .get_mut(player, player_relevant_entities())
+                .unwrap();
+            for id in &left {
+                known.remove(id);
+            }
+            known.extend(entered.iter().copied());
+            let relevant = self
+                .world
+                .get_ref(player, player_relevant_entities())
+                .unwrap();
+
+            let player_diff =
+                relevant_diff(&self.world, &stream_filter, diff, relevant, &entered, &left);
+            if player_diff.is_empty() {
+                continue;
+            }
+            if let Err(err) = entity_stream.send(player_diff.into()) {
+                tracing::warn!("Failed to send diff to player: {err:?}");
             }
         }
     }
@@ -177,6 +349,8 @@ pub struct ServerState {
     pub create_server_systems: Arc<dyn Fn(&mut World) -> SystemGroup + Sync + Send>,
     pub create_on_forking_systems: Arc<dyn Fn() -> SystemGroup<ForkingEvent> + Sync + Send>,
     pub create_shutdown_systems: Arc<dyn Fn() -> SystemGroup<ShutdownEvent> + Sync + Send>,
+    /// Verifies the user id of connecting clients
+    pub authenticator: Arc<dyn Authenticator>,
 }
 
 impl ServerState {
@@ -198,15 +372,19 @@ impl ServerState {
             create_server_systems: Arc::new(|_| SystemGroup::new("", vec![])),
             create_on_forking_systems: Arc::new(|| SystemGroup::new("", vec![])),
             create_shutdown_systems: Arc::new(|| SystemGroup::new("", vec![])),
+            authenticator: Arc::new(AllowAnonymous),
         }
     }
     pub fn new(
         assets: AssetCache,
-        instances: HashMap<String, WorldInstance>,
+        mut instances: HashMap<String, WorldInstance>,
         create_server_systems: Arc<dyn Fn(&mut World) -> SystemGroup + Sync + Send>,
         create_on_forking_systems: Arc<dyn Fn() -> SystemGroup<ForkingEvent> + Sync + Send>,
         create_shutdown_systems: Arc<dyn Fn() -> SystemGroup<ShutdownEvent> + Sync + Send>,
     ) -> Self {
+        for (id, instance) in &mut instances {
+            instance.world.add_resource(instance_id(), id.clone());
+        }
         Self {
             assets,
             instances,
@@ -214,6 +392,7 @@ impl ServerState {
             create_server_systems,
             create_on_forking_systems,
             create_shutdown_systems,
+            authenticator: Arc::new(AllowAnonymous),
         }
     }
 
@@ -221,6 +400,7 @@ impl ServerState {
         for instance in self.instances.values_mut() {
             instance.step(Instant::now(), FIXED_SERVER_TICK_TIME);
         }
+        self.apply_instance_commands();
     }
     pub fn broadcast_diffs(&mut self) {
         for instance in self.instances.values_mut() {
@@ -247,6 +427,16 @@ impl ServerState {
     pub fn get_player_world(&self, user_id: &str) -> Option<&World> {
         self.get_player_world_instance(user_id).map(|i| &i.world)
     }
+    /// Registers the connection of a player, and returns their previous connection if they
+    /// reconnected. Reconnecting players stay in the instance they were in.
+    pub fn connect_player(&mut self, user_id: &str, mut player: Player) -> Option<Player> {
+        if let Some(old_player) = self.players.get(user_id) {
+            if self.instances.contains_key(&old_player.instance) {
+                player.instance = old_player.instance.clone();
+            }
+        }
+        self.players.insert(user_id.to_string(), player)
+    }
     pub fn remove_instance(&mut self, instance_id: &str) {
         tracing::debug!("Removing server instance id={}", instance_id);
         let mut sys = (self.create_shutdown_systems)();
@@ -254,6 +444,190 @@ impl ServerState {
         sys.run(&mut old_instance.world, &ShutdownEvent);
         self.instances.remove(instance_id);
     }
+
+    /// Creates the instance `id` by cloning the world of `source_id` without its players, and
+    /// running the forking systems on the clone. Does nothing if the instance already exists.
+    pub fn fork_instance(
+        &mut self,
+        source_id: &str,
+        id: &str,
+        resources: Entity,
+        synced_res: Entity,
+    ) -> anyhow::Result<()> {
+        if self.instances.contains_key(id) {
+            return Ok(());
+        }
+        let source = self
+            .instances
+            .get(source_id)
+            .with_context(|| format!("No instance {source_id:?} to fork"))?;
+        tracing::debug!(source_id, id, "Forking server instance");
+
+        let mut world = source.world.clone();
+        for (player, _) in query(user_id()).collect_cloned(&world, None) {
+            world.despawn(player);
+        }
+        world.add_components(
+            world.resource_entity(),
+            resources
+                .with_merge(ambient_core::async_ecs::async_ecs_resources())
+                .with(instance_id(), id.to_string())
+                .with(instance_commands(), Vec::new()),
+        )?;
+        if let Some(synced) = world.synced_resource_entity() {
+            world.add_components(synced, synced_res)?;
+        }
+
+        let mut on_forking = (self.create_on_forking_systems)();
+        on_forking.run(&mut world, &ForkingEvent);
+
+        world.reset_events();
+
+        let instance = WorldInstance {
+            systems: (self.create_server_systems)(&mut world),
+            world,
+            world_stream: source.world_stream.clone(),
+        };
+        self.instances.insert(id.to_string(), instance);
+        Ok(())
+    }
+
+    /// Moves a connected player to another instance.
+    ///
+    /// The player entity is despawned from its current instance and spawned into the new one, and
+    /// the player's client is sent the changes needed to replace its view of the old world with the
+    /// new one.
+    pub fn transfer_player(&mut self, user_id: &str, new_instance_id: &str) -> anyhow::Result<()> {
+        let old_instance_id = self
+            .players
+            .get(user_id)
+            .with_context(|| format!("Player {user_id:?} is not connected"))?
+            .instance
+            .clone();
+        if old_instance_id == new_instance_id {
+            return Ok(());
+        }
+        anyhow::ensure!(
+            self.instances.contains_key(new_instance_id),
+            "No instance {new_instance_id:?}"
+        );
+        tracing::debug!(user_id, %old_instance_id, new_instance_id, "Transferring player");
+
+        let instances = &mut self.instances;
+
+        // Bring both views up to date, so that the diff below starts from what the player has seen
+        for id in [old_instance_id.as_str(), new_instance_id] {
+            instances.get_mut(id).unwrap().broadcast_diffs();
+        }
+
+        let diff = {
+            let old_instance = &instances[&old_instance_id];
+            let new_instance = &instances[new_instance_id];
+            let relevancy = |world: &World| world.resource_opt(relevancy_filter()).is_some();
+
+            if relevancy(&old_instance.world) || relevancy(&new_instance.world) {
+                // The player only knows part of the old world, so replace it entirely
+                let known: Vec<EntityId> = if relevancy(&old_instance.world) {
+                    get_by_user_id(&old_instance.world, user_id)
+                        .and_then(|id| {
+                            old_instance
+                                .world
+                                .get_ref(id, player_relevant_entities())
+                                .ok()
+                        })
+                        .map(|known| known.iter().copied().collect())
+                        .unwrap_or_default()
+                } else {
+                    let filter = old_instance.world_stream.filter();
+                    filter.all_entities(&old_instance.world).collect()
+                };
+                let mut diff = WorldDiff::new().despawn(known);
+                // With relevancy, the new view is built up by the following broadcasts instead
+                if !relevancy(&new_instance.world) {
+                    let filter = new_instance.world_stream.filter();
+                    diff.changes
+                        .extend(filter.initial_diff(&new_instance.world).changes);
+                }
+                diff
+            } else {
+                WorldDiff::from_a_to_b(
+                    old_instance.world_stream.filter().clone(),
+                    &old_instance.world,
+                    &new_instance.world,
+                )
+            }
+        };
+
+        let mut ed = instances
+            .get_mut(&old_instance_id)
+            .unwrap()
+            .despawn_player(user_id)
+            .context("Player entity not found")?;
+        let entities_tx = ed.remove_self(player_entity_stream()).unwrap();
+        let connection_id = ed.remove_self(player_connection_id()).unwrap();
+        let conn = ed.remove_self(player_transport()).unwrap();
+
+        instances
+            .get_mut(new_instance_id)
+            .unwrap()
+            .spawn_player(create_player_entity_data(
+                conn,
+                user_id.to_string(),
+                entities_tx.clone(),
+                connection_id,
+            ));
+        self.players.get_mut(user_id).unwrap().instance = new_instance_id.to_string();
+
+        entities_tx.send(diff.into()).ok();
+        Ok(())
+    }
+
+    /// Moves the players of an instance to the main instance, and removes it.
+    pub fn destroy_instance(&mut self, id: &str) -> anyhow::Result<()> {
+        anyhow::ensure!(
+            id != MAIN_INSTANCE_ID,
+            "The main instance can't be destroyed"
+        );
+        anyhow::ensure!(self.instances.contains_key(id), "No instance {id:?}");
+
+        let players: Vec<String> = self
+            .players
+            .iter()
+            .filter(|(_, player)| player.instance == id)
+            .map(|(user_id, _)| user_id.clone())
+            .collect();
+        for user_id in players {
+            self.transfer_player(&user_id, MAIN_INSTANCE_ID)?;
+        }
+        self.remove_instance(id);
+        Ok(())
+    }
+
+    /// Applies the [`InstanceCommand`]s requested by the instances during the last step.
+    fn apply_instance_commands(&mut self) {
+        let mut commands = Vec::new();
+        for instance in self.instances.values_mut() {
+            if let Some(requested) = instance.world.resource_mut_opt(instance_commands()) {
+                commands.append(requested);
+            }
+        }
+
+        for command in commands {
+            let result = match &command {
+                InstanceCommand::Create(id) => {
+                    self.fork_instance(MAIN_INSTANCE_ID, id, Entity::new(), Entity::new())
+                }
+                InstanceCommand::Destroy(id) => self.destroy_instance(id),
+                InstanceCommand::TransferPlayer {
+                    user_id,
+                    instance_id,
+                } => self.transfer_player(user_id, instance_id),
+            };
+            if let Err(err) = result {
+                tracing::warn!(?command, "Failed to apply instance command: {err:?}");
+            }
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -263,3 +637,70 @@ pub struct ProxySettings {
     pub pre_cache_assets: bool,
     pub primary_package_id: String,
 }
+
+#[cfg(test)]
+mod tests {
+    use ambient_ecs::WorldContext;
+    use futures::future::BoxFuture;
+
+    use super::*;
+
+    struct ClosedTransport;
+    impl NetworkTransport for ClosedTransport {
+        fn request_bi(&self, _id: u32, _data: Bytes) -> BoxFuture<Result<Bytes, NetworkError>> {
+            Box::pin(async { Err(NetworkError::ConnectionClosed) })
+        }
+        fn request_uni(&self, _id: u32, _data: Bytes) -> BoxFuture<Result<(), NetworkError>> {
+            Box::pin(async { Err(NetworkError::ConnectionClosed) })
+        }
+        fn send_datagram(&self, _id: u32, _data: Bytes) -> BoxFuture<Result<(), NetworkError>> {
+            Box::pin(async { Err(NetworkError::ConnectionClosed) })
+        }
+    }
+
+    fn connect(state: &mut ServerState, user_id: &str) -> flume::Receiver<FrozenWorldDiff> {
+        let (entities_tx, entities_rx) = flume::unbounded();
+        state.connect_player(user_id, Player::new_local(MAIN_INSTANCE_ID));
+        let instance_id = state.players[user_id].instance.clone();
+        state
+            .instances
+            .get_mut(&instance_id)
+            .unwrap()
+            .spawn_or_reconnect_player(
+                user_id,
+                create_player_entity_data(
+                    Arc::new(ClosedTransport),
+                    user_id.to_string(),
+                    entities_tx,
+                    Uuid::new_v4(),
+                ),
+            );
+        entities_rx
+    }
+
+    #[tokio::test]
+    async fn reconnect_after_transfer() {
+        ambient_ecs::init_components();
+        crate::init_all_components();
+
+        let mut state = ServerState::new_local(AssetCache::new(tokio::runtime::Handle::current()));
+        let world_stream = state.instances[MAIN_INSTANCE_ID].world_stream.clone();
+        state.instances.insert(
+            "match".to_string(),
+            WorldInstance {
+                world: World::new("match_server", WorldContext::Server),
+                world_stream,
+                systems: SystemGroup::new("", vec![]),
+            },
+        );
+
+        let _entities_rx = connect(&mut state, "player");
+        state.transfer_player("player", "match").unwrap();
+        let _entities_rx = connect(&mut state, "player");
+
+        assert_eq!(state.players["player"].instance, "match");
+        assert_eq!(state.instances[MAIN_INSTANCE_ID].player_count(), 0);
+        assert_eq!(state.instances["match"].player_count(), 1);
+        assert_eq!(state.player_count(), 1);
+    }
+}
@@ -48,6 +48,8 @@ pub struct GameClientView {
     /// The url to connect to
     pub url: String,
     pub user_id: String,
+    /// Sent to the server to prove that this client may use `user_id`
+    pub auth_token: Option<String>,
     pub fail_on_version_mismatch: bool,
     pub systems_and_resources: Cb<dyn Fn() -> (SystemGroup, Entity) + Sync + Send>,
     /// Invoked when the game client is loaded
@@ -64,6 +66,7 @@ impl ElementComponent for GameClientView {
         let Self {
             url,
             user_id,
+            auth_token,
             fail_on_version_mismatch,
             systems_and_resources,
             on_loaded,
@@ -124,6 +127,7 @@ impl ElementComponent for GameClientView {
                     conn,
                     &assets,
                     user_id,
+                    auth_token,
                     fail_on_version_mismatch,
                     move |assets, user_id| {
                         let (systems, resources) = systems_and_resources();
@@ -367,6 +371,7 @@ async fn handle_connection(
     mut conn: Connection,
     assets: &AssetCache,
     user_id: String,
+    auth_token: Option<String>,
     fail_on_version_mismatch: bool,
     mut on_loaded: impl FnMut(&AssetCache, &str) -> anyhow::Result<(SharedClientGameState, CleanupFunc)>,
     control_rx: flume::Receiver<Control>,
@@ -383,7 +388,10 @@ async fn handle_connection(
     tracing::debug!("Attempting to connect using {user_id:?}");
 
     request_send
-        .send(ClientRequest::Connect(user_id.clone()))
+        .send(ClientRequest::Connect {
+            user_id: user_id.clone(),
+            auth_token,
+        })
         .await?;
 
     let mut client = ClientProtoState::Pending(user_id.clone());
@@ -406,11 +414,21 @@ async fn handle_connection(
         return Ok(());
     }
 
-    let mut diff_stream = RawFramedRecvStream::new(
-        conn.accept_uni()
-            .await
-            .ok_or(NetworkError::ConnectionClosed)??,
-    );
+    // The server opens the diff stream once it has accepted us, or tells us why it didn't
+    let mut diff_stream = loop {
+        tokio::select! {
+            stream = conn.accept_uni() => {
+                break RawFramedRecvStream::new(stream.ok_or(NetworkError::ConnectionClosed)??);
+            }
+            Some(frame) = push_recv.next() => {
+                client.process_push(&assets, fail_on_version_mismatch, frame?)?;
+                if !client.is_connected() {
+                    tracing::warn!("Connection failed or was denied");
+                    return Ok(());
+                }
+            }
+        }
+    };
 
     let (shared_client_state, cleanup) = on_loaded(&assets, &user_id)?;
     let on_disconnect = move || cleanup();
@@ -14,7 +14,7 @@ use ambient_ecs::{
 };
 use ambient_native_std::asset_url::AbsAssetUrl;
 use ambient_network::ServerWorldExt;
-use ambient_package::PackageContent;
+use ambient_package::{Http, PackageContent};
 use ambient_package_semantic::{
     Item, ItemId, ItemSource, LocalOrRemote, Package, PrimitiveType, RetrievableFile, Semantic,
     TypeInner,
@@ -34,6 +34,9 @@ components!("semantic", {
     /// Used to spawn the WASM modules for each package
     @[Resource]
     wasm_spawn: WasmSpawn,
+
+    /// The `[http]` section of the package's manifest. Only available on the server.
+    http_settings: Http,
 });
 
 pub type WasmSpawn =
@@ -240,7 +243,8 @@ fn sync_semantic_to_world(
             .with(self::name(), manifest.package.name.clone())
             .with(self::version(), manifest.package.version.to_string())
             .with(self::authors(), manifest.package.authors.clone())
-            .with(self::asset_url(), base_asset_url.to_string());
+            .with(self::asset_url(), base_asset_url.to_string())
+            .with(self::http_settings(), manifest.http.clone());
         if let Some(description) = &manifest.package.description {
             entity.set(self::description(), description.clone());
         }
@@ -32,9 +32,11 @@ use physxx::{
 use serde::{Deserialize, Serialize};
 
 use crate::{
+    intersection::entity_collision_filter_data,
     main_controller_manager,
     mesh::{PhysxGeometry, PhysxGeometryFromUrl},
     physx::{character_controller, physics, physics_shape, rigid_actor, Physics},
+    trigger::set_trigger_shape,
     wood_physics_material, ColliderScene, PxActorUserData, PxShapeUserData, PxWoodMaterialKey,
 };
 
@@ -208,8 +210,11 @@ pub fn server_systems() -> SystemGroup {
                         desc.position = pos.as_dvec3();
                         desc.up_direction = vec3(0., 0., 1.);
                         let controller = controller_manager.create_controller(&desc);
+                        let filter_data = entity_collision_filter_data(world, id);
                         for shape in controller.get_actor().get_shapes() {
                             shape.set_flag(PxShapeFlag::VISUALIZATION, false);
+                            shape.set_simulation_filter_data(filter_data);
+                            shape.set_query_filter_data(filter_data);
                         }
                         let actor = controller.get_actor();
                         actor.as_actor().set_user_data(id);
@@ -330,6 +335,7 @@ pub fn server_systems() -> SystemGroup {
                         }
                         let is_dynamic = collider_type == ColliderType::Dynamic;
                         let is_kinematic = world.has_component(id, kinematic());
+                        let is_trigger = world.has_component(id, is_trigger());
                         let actor = if is_dynamic && !force_static {
                             let body = PxRigidDynamicRef::new(
                                 physics.physics,
@@ -352,7 +358,10 @@ pub fn server_systems() -> SystemGroup {
                         };
                         if let Some(actor) = actor.to_rigid_body() {
                             actor.set_rigid_body_flag(PxRigidBodyFlag::KINEMATIC, is_kinematic);
-                            actor.set_rigid_body_flag(PxRigidBodyFlag::ENABLE_CCD, !is_kinematic);
+                            actor.set_rigid_body_flag(
+                                PxRigidBodyFlag::ENABLE_CCD,
+                                !is_kinematic && !is_trigger,
+                            );
                         }
                         actor
                             .as_actor()
@@ -367,6 +376,7 @@ pub fn server_systems() -> SystemGroup {
                         };
                         let coff = world.get(id, contact_offset()).ok();
                         let roff = world.get(id, rest_offset()).ok();
+                        let filter_data = entity_collision_filter_data(world, id);
                         for shape in shapes.iter_mut() {
                             if !actor.attach_shape(shape) {
                                 tracing::error!("Failed to attach shape to entity {}", id);
@@ -381,6 +391,9 @@ pub fn server_systems() -> SystemGroup {
                             if let Some(roff) = roff {
                                 shape.set_rest_offset(roff);
                             }
+                            shape.set_simulation_filter_data(filter_data);
+                            shape.set_query_filter_data(filter_data);
+                            set_trigger_shape(shape, is_trigger);
                             shape.update_user_data::<PxShapeUserData>(&|ud| ud.entity = id);
                         }
                         if let Some(actor) = actor.to_rigid_dynamic() {
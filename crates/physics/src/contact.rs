@@ -0,0 +1,171 @@
+use std::collections::{HashMap, HashSet};
+
+use ambient_ecs::{components, query, EntityId, Resource, SystemGroup, World};
+use glam::Vec3;
+use physxx::{PxContactPair, PxContactPairEvents, PxUserData};
+
+use crate::{
+    contact_events, helpers::update_collision_filter, intersection::reports_contacts,
+    physx::physics_shape, report_contacts, PxShapeUserData,
+};
+
+components!("physics::contact", {
+    /// How many pairs of shapes of each pair of entities are touching, by `(a, b)` with `a < b`
+    @[Resource]
+    touching_pairs: HashMap<(EntityId, EntityId), usize>,
+});
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContactPhase {
+    Begin,
+    Persist,
+    End,
+}
+
+/// Two colliders touching, where at least one of them has `report_contacts`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContactEvent {
+    pub a: EntityId,
+    pub b: EntityId,
+    pub phase: ContactPhase,
+    pub points: Vec<Vec3>,
+    /// The normal of each point, pointing from `b` towards `a`
+    pub normals: Vec<Vec3>,
+    /// The total impulse applied to resolve the contact
+    pub impulse: Vec3,
+    /// The velocity of `a` relative to `b`, before the contact was resolved
+    pub relative_velocity: Vec3,
+}
+impl ContactEvent {
+    /// Returns the events for a pair of shapes reported by PhysX, if both of its shapes belong to
+    /// entities and either reports contacts. A pair can start and stop touching in the same step.
+    ///
+    /// Pairs where a shape was removed are skipped, as the shape may not belong to an entity
+    /// anymore; [update_touching_pairs] sends their `End` instead.
+    pub fn from_pair(pair: &PxContactPair) -> Vec<Self> {
+        let [Some(shape_a), Some(shape_b)] = &pair.shapes else {
+            return vec![];
+        };
+        if !reports_contacts(shape_a.get_simulation_filter_data())
+            && !reports_contacts(shape_b.get_simulation_filter_data())
+        {
+            return vec![];
+        }
+        let (Some(a), Some(b)) = (
+            shape_a.get_user_data::<PxShapeUserData>(),
+            shape_b.get_user_data::<PxShapeUserData>(),
+        ) else {
+            return vec![];
+        };
+
+        let event = |phase| Self {
+            a: a.entity,
+            b: b.entity,
+            phase,
+            points: pair.contacts.iter().map(|p| p.position).collect(),
+            normals: pair.contacts.iter().map(|p| p.normal).collect(),
+            impulse: pair.contacts.iter().map(|p| p.impulse).sum(),
+            relative_velocity: pair
+                .pre_solver_velocities
+                .map(|[a, b]| a - b)
+                .unwrap_or_default(),
+        };
+        let mut events = Vec::new();
+        if pair.events.contains(PxContactPairEvents::TOUCH_FOUND) {
+            events.push(event(ContactPhase::Begin));
+        } else if pair.events.contains(PxContactPairEvents::TOUCH_PERSISTS) {
+            events.push(event(ContactPhase::Persist));
+        }
+        if pair.events.contains(PxContactPairEvents::TOUCH_LOST) {
+            events.push(event(ContactPhase::End));
+        }
+        events
+    }
+}
+
+/// Keeps track of which entities are touching, and turns the events that PhysX reports for each
+/// pair of shapes into one `Begin` when two entities start touching and one `End` when they stop.
+/// The `Begin` of a pair of shapes of entities that were already touching is sent as a `Persist`.
+/// Adds the `End` events that PhysX can't report because one of the entities was despawned, or had
+/// its shapes replaced; the entities in `replaced` begin touching again with their new shapes.
+fn update_touching_pairs(world: &mut World, replaced: &HashSet<EntityId>) {
+    let mut touching = world
+        .resource_mut_opt(touching_pairs())
+        .map(std::mem::take)
+        .unwrap_or_default();
+    let mut events = world.resource(contact_events()).lock();
+
+    let mut entity_events = Vec::new();
+    for mut event in events.drain(..) {
+        let pair = (event.a.min(event.b), event.a.max(event.b));
+        match event.phase {
+            ContactPhase::Begin => {
+                let count = touching.entry(pair).or_default();
+                *count += 1;
+                if *count > 1 {
+                    event.phase = ContactPhase::Persist;
+                }
+            }
+            ContactPhase::Persist => {}
+            ContactPhase::End => {
+                let Some(count) = touching.get_mut(&pair) else {
+                    continue;
+                };
+                *count -= 1;
+                if *count > 0 {
+                    continue;
+                }
+                touching.remove(&pair);
+            }
+        }
+        entity_events.push(event);
+    }
+    touching.retain(|&(a, b), _| {
+        if world.exists(a) && world.exists(b) && !replaced.contains(&a) && !replaced.contains(&b) {
+            return true;
+        }
+        entity_events.push(ContactEvent {
+            a,
+            b,
+            phase: ContactPhase::End,
+            points: Vec::new(),
+            normals: Vec::new(),
+            impulse: Vec3::ZERO,
+            relative_velocity: Vec3::ZERO,
+        });
+        false
+    });
+
+    *events = entity_events;
+    drop(events);
+    world.add_resource(touching_pairs(), touching);
+}
+
+pub fn server_systems() -> SystemGroup {
+    SystemGroup::new(
+        "physics/contact/server",
+        vec![
+            query(physics_shape().changed()).to_system(|q, world, qs, _| {
+                let replaced = q.iter(world, qs).map(|(id, _)| id).collect();
+                update_touching_pairs(world, &replaced);
+            }),
+            query(report_contacts().changed())
+                .incl(physics_shape())
+                .to_system(|q, world, qs, _| {
+                    for (id, _) in q.collect_cloned(world, qs) {
+                        update_collision_filter(world, id);
+                    }
+                }),
+            query(report_contacts())
+                .incl(physics_shape())
+                .despawned()
+                .to_system(|q, world, qs, _| {
+                    for (id, _) in q.collect_cloned(world, qs) {
+                        if world.exists(id) {
+                            update_collision_filter(world, id);
+                        }
+                    }
+                }),
+        ],
+    )
+}
@@ -0,0 +1,96 @@
+//! Stepping physics by hand instead of with the server tick, e.g. in `cargo test`.
+
+use std::time::{Duration, Instant};
+
+use ambient_core::{
+    asset_cache,
+    async_ecs::{async_ecs_resources, async_ecs_systems},
+    runtime,
+    transform::TransformSystem,
+};
+use ambient_ecs::{query, FrameEvent, System, SystemGroup, World};
+use itertools::Itertools;
+
+use crate::{
+    collider::collider,
+    create_deterministic_resources, fetch_simulation_system,
+    physx::{physics_shape, sync_ecs_physics},
+    run_simulation_system, server_systems,
+};
+
+/// Steps the physics of a world a tick at a time, running the same physics systems as the
+/// server. Stepping two worlds that were set up the same way gives the same results, even if
+/// their colliders loaded in a different order.
+pub struct PhysicsStepper {
+    simulation: SystemGroup,
+    update: SystemGroup,
+    tick: u64,
+}
+impl PhysicsStepper {
+    /// Adds the physics resources to `world`, which must have an `asset_cache`.
+    pub fn new(world: &mut World) -> Self {
+        let assets = world.resource(asset_cache()).clone();
+        let mut resources = async_ecs_resources().with(runtime(), assets.runtime().clone());
+        create_deterministic_resources(&assets, &mut resources);
+        world
+            .add_components(world.resource_entity(), resources)
+            .unwrap();
+
+        Self {
+            simulation: SystemGroup::new(
+                "physics/stepper/simulation",
+                vec![run_simulation_system(), fetch_simulation_system()],
+            ),
+            update: SystemGroup::new(
+                "physics/stepper/update",
+                vec![
+                    Box::new(async_ecs_systems()),
+                    Box::new(sync_ecs_physics()),
+                    Box::new(TransformSystem::new()),
+                    Box::new(server_systems()),
+                ],
+            ),
+            tick: 0,
+        }
+    }
+
+    /// The number of ticks simulated so far.
+    pub fn tick(&self) -> u64 {
+        self.tick
+    }
+
+    /// Updates the world without simulating until every collider has been loaded, so that
+    /// stepping starts from the same state every time.
+    pub async fn load_colliders(
+        &mut self,
+        world: &mut World,
+        timeout: Duration,
+    ) -> anyhow::Result<()> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            self.update.run(world, &FrameEvent);
+            let loading = query(())
+                .incl(collider())
+                .excl(physics_shape())
+                .iter(world, None)
+                .map(|(id, _)| id)
+                .collect_vec();
+            if loading.is_empty() {
+                return Ok(());
+            }
+            if Instant::now() > deadline {
+                anyhow::bail!("The colliders of {loading:?} did not load in {timeout:?}");
+            }
+            tokio::task::yield_now().await;
+        }
+    }
+
+    /// Simulates `ticks` ticks of `FIXED_SERVER_TICK_TIME` each.
+    pub fn step(&mut self, world: &mut World, ticks: u64) {
+        for _ in 0..ticks {
+            self.simulation.run(world, &FrameEvent);
+            self.update.run(world, &FrameEvent);
+            self.tick += 1;
+        }
+    }
+}
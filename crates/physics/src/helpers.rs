@@ -10,18 +10,20 @@ use glam::{vec3, Mat4, Vec3};
 use itertools::Itertools;
 use physxx::{
     AsPxActor, AsPxRigidActor, PxActor, PxActorRef, PxActorTypeFlag, PxBase, PxBoxGeometry,
-    PxConvexMeshGeometry, PxForceMode, PxJoint, PxMeshScale, PxOverlapCallback, PxPhysicsRef,
-    PxQueryFilterData, PxQueryFlag, PxRevoluteJointRef, PxRigidActor, PxRigidActorRef, PxRigidBody,
-    PxRigidBodyFlag, PxRigidDynamicRef, PxRigidStaticRef, PxSceneRef, PxShape, PxSphereGeometry,
-    PxTransform, PxTriangleMeshGeometry, PxUserData,
+    PxControllerFilters, PxConvexMeshGeometry, PxFilterData, PxForceMode, PxJoint, PxMeshScale,
+    PxOverlapCallback, PxPhysicsRef, PxQueryFilterData, PxQueryFlag, PxRevoluteJointRef,
+    PxRigidActor, PxRigidActorRef, PxRigidBody, PxRigidBodyFlag, PxRigidDynamicRef,
+    PxRigidStaticRef, PxSceneRef, PxShape, PxSphereGeometry, PxTransform, PxTriangleMeshGeometry,
+    PxUserData,
 };
 
 use crate::{
-    collider::{collider_shapes_convex, collider_type, kinematic},
+    collider::{collider_shapes_convex, collider_type, collides_with, kinematic},
+    intersection::{entity_collision_filter_data, layer_filter_data},
     main_physics_scene,
     physx::{
-        physics, physics_controlled, physics_shape, revolute_joint, rigid_actor, rigid_dynamic,
-        rigid_static,
+        character_controller, physics, physics_controlled, physics_shape, revolute_joint,
+        rigid_actor, rigid_dynamic, rigid_static,
     },
     unit_mass, unit_velocity, ColliderScene, PxActorUserData, PxShapeUserData,
 };
@@ -170,6 +172,51 @@ pub fn get_shapes(world: &World, id: EntityId) -> impl Iterator<Item = PxShape>
         )
 }
 
+/// Applies the `collision_layer` and `collides_with` of `id` to all of its shapes, including
+/// those of its character controller.
+pub fn update_collision_filter(world: &World, id: EntityId) {
+    let filter_data = entity_collision_filter_data(world, id);
+    let controller = world.get(id, character_controller()).ok();
+    let shapes = get_shapes(world, id).chain(
+        controller
+            .iter()
+            .flat_map(|controller| controller.get_actor().get_shapes()),
+    );
+    for shape in shapes {
+        shape.set_simulation_filter_data(filter_data);
+        shape.set_query_filter_data(filter_data);
+    }
+
+    // Existing contacts have to be refiltered for the change to take effect
+    if let Ok(actor) = world.get(id, rigid_actor()) {
+        if let Some(scene) = actor.get_scene() {
+            scene.reset_filtering(&actor);
+        }
+    }
+    if let Some(controller) = controller {
+        let actor = controller.get_actor();
+        if let Some(scene) = actor.get_scene() {
+            scene.reset_filtering(&actor);
+        }
+    }
+}
+
+/// The filters to move the character controller of `id` with, so that it only collides with
+/// the layers of its `collides_with`.
+pub fn character_controller_filters(world: &World, id: EntityId) -> PxControllerFilters {
+    match world.get(id, collides_with()) {
+        // Filter data of all zeros doesn't filter anything, so use a bit that no shape has
+        Ok(0) => PxControllerFilters::with_filter_data(PxFilterData {
+            word3: 1 << 31,
+            ..Default::default()
+        }),
+        Ok(mask) if mask != u32::MAX => {
+            PxControllerFilters::with_filter_data(layer_filter_data(mask))
+        }
+        _ => PxControllerFilters::new(),
+    }
+}
+
 pub fn scale_shape(shape: PxShape, scale: Vec3) {
     tracing::debug!("Scaling shape");
     let geo = shape.get_geometry();
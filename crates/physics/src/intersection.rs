@@ -1,20 +1,25 @@
-use std::collections::HashSet;
+use std::{collections::HashSet, f32::consts::FRAC_PI_2};
 
 use ambient_core::{asset_cache, transform::translation};
 use ambient_ecs::{query, ArchetypeFilter, EntityId, World};
 use ambient_meshes::cuboid::CuboidMesh;
 use ambient_native_std::{asset_cache::SyncAssetKeyExt, mesh::Mesh, shapes::Ray};
 use ambient_network::server;
-use glam::Vec3;
+use glam::{Quat, Vec3};
 use itertools::Itertools;
 use ordered_float::OrderedFloat;
 use physxx::{
-    PxConvexFlag, PxConvexMesh, PxConvexMeshDesc, PxConvexMeshGeometry, PxOverlapCallback,
-    PxQueryFilterData, PxRaycastCallback, PxRigidActor, PxShape, PxTransform, PxUserData,
+    PxBoxGeometry, PxCapsuleGeometry, PxConvexFlag, PxConvexMesh, PxConvexMeshDesc,
+    PxConvexMeshGeometry, PxFilterData, PxGeometry, PxOverlapCallback, PxQueryFilterData,
+    PxQueryFlag, PxRaycastCallback, PxRigidActor, PxShape, PxSphereGeometry, PxTransform,
+    PxUserData,
 };
 use serde::{Deserialize, Serialize};
 
-use crate::{main_physics_scene, physx::PhysicsKey, ColliderScene, PxShapeUserData};
+use crate::{
+    collides_with, collision_layer, main_physics_scene, physx::PhysicsKey, report_contacts,
+    ColliderScene, PxShapeUserData,
+};
 
 pub fn get_entities_in_radius(world: &World, center: Vec3, radius: f32) -> Vec<EntityId> {
     query((translation(),))
@@ -124,6 +129,260 @@ pub fn raycast_collider_type_px(
     Vec::new()
 }
 
+/// Like [raycast_first], but only hits entities on the layers of `layer_mask`.
+pub fn raycast_first_in_layers(
+    world: &World,
+    ray: Ray,
+    layer_mask: u32,
+) -> Option<(EntityId, f32)> {
+    if layer_mask == 0 {
+        return None;
+    }
+    let mut filter_data = PxQueryFilterData::new();
+    filter_data.set_data(layer_filter_data(layer_mask));
+    (0..3)
+        .filter_map(|i| {
+            let mut hit = PxRaycastCallback::new(0);
+            let scene = ColliderScene::from_usize(i).get_scene(world);
+            if !scene.raycast(ray.origin, ray.dir, f32::MAX, &mut hit, None, &filter_data) {
+                return None;
+            }
+            let block = hit.block()?;
+            let ud = block.shape?.get_user_data::<PxShapeUserData>()?;
+            Some((ud.entity, block.distance))
+        })
+        .min_by_key(|x| OrderedFloat(x.1))
+}
+
+/// Like [raycast], but only hits entities on the layers of `layer_mask`.
+pub fn raycast_in_layers(world: &World, ray: Ray, layer_mask: u32) -> Vec<(EntityId, f32)> {
+    if layer_mask == 0 {
+        return Vec::new();
+    }
+    let mut filter_data = query_all_filter_data();
+    filter_data.set_data(layer_filter_data(layer_mask));
+    (0..3)
+        .flat_map(|i| {
+            let mut hit = PxRaycastCallback::new(100);
+            let scene = ColliderScene::from_usize(i).get_scene(world);
+            if scene.raycast(ray.origin, ray.dir, f32::MAX, &mut hit, None, &filter_data) {
+                hit.touches()
+            } else {
+                Vec::new()
+            }
+        })
+        .filter_map(|hit| {
+            let shape = hit.shape?;
+            if !shape_in_layers(&shape, layer_mask) {
+                return None;
+            }
+            let ud = shape.get_user_data::<PxShapeUserData>()?;
+            Some((ud.entity, hit.distance))
+        })
+        .sorted_by_key(|x| OrderedFloat(x.1))
+        .collect_vec()
+}
+
+/// The layer of shapes that haven't been assigned to any layer.
+pub const DEFAULT_LAYER: u32 = 1;
+
+/// Set in `word2` of the filter data of shapes that have been assigned their collision layers.
+const COLLISION_FILTER_SET: u32 = 1;
+/// Set in `word3` of the filter data of shapes whose contacts are reported.
+const REPORT_CONTACTS: u32 = 1;
+
+/// The filter data for a shape on the layers of `layer` that collides with the layers of
+/// `collides_with`.
+pub fn collision_filter_data(layer: u32, collides_with: u32) -> PxFilterData {
+    PxFilterData {
+        word0: layer,
+        word1: collides_with,
+        word2: COLLISION_FILTER_SET,
+        word3: 0,
+    }
+}
+
+/// The filter data for queries and character controllers that only hit shapes on the layers of
+/// `layer_mask`, which must not be 0.
+pub fn layer_filter_data(layer_mask: u32) -> PxFilterData {
+    PxFilterData {
+        word0: layer_mask,
+        ..Default::default()
+    }
+}
+
+/// The filter data for the shapes of `id`, from its `collision_layer`, `collides_with` and
+/// `report_contacts`. A `collision_layer` of 0 is treated as the default layer, as a shape on no
+/// layers would be skipped by every filtered query.
+pub fn entity_collision_filter_data(world: &World, id: EntityId) -> PxFilterData {
+    let mut filter_data = collision_filter_data(
+        world
+            .get(id, collision_layer())
+            .ok()
+            .filter(|layer| *layer != 0)
+            .unwrap_or(DEFAULT_LAYER),
+        world.get(id, collides_with()).unwrap_or(u32::MAX),
+    );
+    if world.has_component(id, report_contacts()) {
+        filter_data.word3 |= REPORT_CONTACTS;
+    }
+    filter_data
+}
+
+/// Returns the layers and the layers collided with of a shape with `filter_data`.
+pub fn collision_layers(filter_data: PxFilterData) -> (u32, u32) {
+    if filter_data.word2 == COLLISION_FILTER_SET && filter_data.word0 != 0 {
+        (filter_data.word0, filter_data.word1)
+    } else {
+        (DEFAULT_LAYER, u32::MAX)
+    }
+}
+
+/// Returns true if the contacts of a shape with `filter_data` are reported.
+pub fn reports_contacts(filter_data: PxFilterData) -> bool {
+    filter_data.word3 & REPORT_CONTACTS != 0
+}
+
+/// Returns true if `shape` is on any of the layers in `layer_mask`.
+pub fn shape_in_layers(shape: &PxShape, layer_mask: u32) -> bool {
+    let (layer, _) = collision_layers(shape.get_query_filter_data());
+    layer & layer_mask != 0
+}
+
+/// The shape of a [shape_cast] or an [overlap].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum QueryShape {
+    Sphere {
+        radius: f32,
+    },
+    /// A capsule along the local Z axis; `height` is the distance between the centers of its two spheres.
+    Capsule {
+        radius: f32,
+        height: f32,
+    },
+    Box {
+        half_extents: Vec3,
+    },
+}
+impl QueryShape {
+    fn with_geometry<R>(
+        &self,
+        position: Vec3,
+        rotation: Quat,
+        f: impl FnOnce(&dyn PxGeometry, PxTransform) -> R,
+    ) -> R {
+        match *self {
+            QueryShape::Sphere { radius } => f(
+                &PxSphereGeometry::new(radius),
+                PxTransform::new(position, rotation),
+            ),
+            // PhysX capsules extend along the X axis
+            QueryShape::Capsule { radius, height } => f(
+                &PxCapsuleGeometry::new(radius, height / 2.),
+                PxTransform::new(position, rotation * Quat::from_rotation_y(-FRAC_PI_2)),
+            ),
+            QueryShape::Box { half_extents } => f(
+                &PxBoxGeometry::new(half_extents.x, half_extents.y, half_extents.z),
+                PxTransform::new(position, rotation),
+            ),
+        }
+    }
+}
+
+/// A hit of a [shape_cast].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ShapeCastHit {
+    pub entity: EntityId,
+    /// How far the shape moved before hitting the entity
+    pub distance: f32,
+    /// The point of contact
+    pub position: Vec3,
+    /// The normal of the hit surface at the point of contact
+    pub normal: Vec3,
+}
+
+fn query_all_filter_data() -> PxQueryFilterData {
+    let mut filter_data = PxQueryFilterData::new();
+    // Report every hit instead of only the closest one
+    filter_data.set_flags(PxQueryFlag::STATIC | PxQueryFlag::DYNAMIC | PxQueryFlag::NO_BLOCK);
+    filter_data
+}
+
+/// Moves `shape` from `position` along `direction` for up to `max_distance`, and returns every
+/// entity on the layers of `layer_mask` that it hits, closest first.
+///
+/// Entities that the shape overlaps at its starting position are hit at a distance of 0.
+pub fn shape_cast(
+    world: &World,
+    shape: QueryShape,
+    position: Vec3,
+    rotation: Quat,
+    direction: Vec3,
+    max_distance: f32,
+    layer_mask: u32,
+) -> Vec<ShapeCastHit> {
+    shape.with_geometry(position, rotation, |geometry, pose| {
+        (0..3)
+            .flat_map(|i| {
+                ColliderScene::from_usize(i)
+                    .get_scene(world)
+                    .sweep(
+                        geometry,
+                        &pose,
+                        direction,
+                        max_distance,
+                        query_all_filter_data(),
+                    )
+                    .touches()
+            })
+            .filter_map(|hit| {
+                let shape = hit.shape?;
+                if !shape_in_layers(&shape, layer_mask) {
+                    return None;
+                }
+                let ud = shape.get_user_data::<PxShapeUserData>()?;
+                Some(ShapeCastHit {
+                    entity: ud.entity,
+                    distance: hit.distance,
+                    position: hit.position,
+                    normal: hit.normal,
+                })
+            })
+            .sorted_by_key(|hit| OrderedFloat(hit.distance))
+            .collect_vec()
+    })
+}
+
+/// Returns every entity on the layers of `layer_mask` that overlaps `shape` at `position`.
+pub fn overlap(
+    world: &World,
+    shape: QueryShape,
+    position: Vec3,
+    rotation: Quat,
+    layer_mask: u32,
+) -> Vec<EntityId> {
+    shape.with_geometry(position, rotation, |geometry, pose| {
+        let filter_data = query_all_filter_data();
+        let mut res = Vec::new();
+        for i in 0..3 {
+            let mut hit_call = PxOverlapCallback::new(1000);
+            let scene = ColliderScene::from_usize(i).get_scene(world);
+            if !scene.overlap(geometry, pose, &mut hit_call, &filter_data) {
+                continue;
+            }
+            for hit in hit_call.touches() {
+                if !shape_in_layers(&hit.shape, layer_mask) {
+                    continue;
+                }
+                if let Some(ud) = hit.shape.get_user_data::<PxShapeUserData>() {
+                    res.push(ud.entity);
+                }
+            }
+        }
+        res.into_iter().unique().collect_vec()
+    })
+}
+
 pub fn intersect_frustum(world: &World, frustum_corners: &[Vec3; 8]) -> Vec<EntityId> {
     let mut hit_call = PxOverlapCallback::new(1000);
     let filter_data = PxQueryFilterData::new();
@@ -0,0 +1,336 @@
+use ambient_ecs::{
+    components,
+    generated::physics::{
+        components::{
+            joint_actor_0, joint_actor_1, joint_break_force, joint_break_torque, joint_broken,
+            joint_drive_damping, joint_drive_force_limit, joint_drive_stiffness,
+            joint_drive_velocity, joint_free_axes, joint_kind, joint_limited_axes, joint_limits,
+            joint_local_frame_0, joint_local_frame_1,
+        },
+        types::JointKind,
+    },
+    query, Component, Entity, EntityId, FnSystem, SystemGroup, World,
+};
+use glam::{vec3, Mat4, Vec2, Vec3};
+use physxx::{
+    AsPxJoint, PxBase, PxConstraintFlags, PxD6Axis, PxD6Drive, PxD6JointDrive, PxD6JointRef,
+    PxD6Motion, PxDistanceJointFlag, PxDistanceJointRef, PxFixedJointRef, PxJoint,
+    PxJointAngularLimitPair, PxJointLimitCone, PxJointLinearLimit, PxJointLinearLimitPair,
+    PxJointRef, PxPhysicsRef, PxPrismaticJointFlag, PxPrismaticJointRef, PxRevoluteJointFlag,
+    PxRevoluteJointRef, PxRigidActor, PxRigidActorRef, PxSphericalJointFlag, PxSphericalJointRef,
+    PxTransform, PxUserData,
+};
+
+use crate::{helpers::get_actor, physx::physics_joint};
+
+/// The bit of the twist axis in the free and limited axes of a D6 joint
+const TWIST: u32 = 1 << 3;
+/// The bits of both swing axes in the free and limited axes of a D6 joint
+const SWING: u32 = 1 << 4 | 1 << 5;
+
+components!("physics", {
+    /// The description the joint in `physics_joint` was created from.
+    joint_desc: JointDesc,
+});
+
+/// Everything a joint is created from. The joint is recreated when this changes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct JointDesc {
+    kind: JointKind,
+    actor0: Option<PxRigidActorRef>,
+    actor1: Option<PxRigidActorRef>,
+    local_frame_0: Mat4,
+    local_frame_1: Option<Mat4>,
+    limits: Option<Vec2>,
+    free_axes: u32,
+    limited_axes: u32,
+    drive_velocity: Option<f32>,
+    drive_stiffness: Option<f32>,
+    drive_damping: Option<f32>,
+    drive_force_limit: Option<f32>,
+    break_force: Option<f32>,
+    break_torque: Option<f32>,
+}
+impl JointDesc {
+    fn get(world: &World, id: EntityId) -> Option<Self> {
+        let rigid_actor = |component: Component<EntityId>| {
+            world
+                .get(id, component)
+                .ok()
+                .and_then(|entity| get_actor(world, entity))
+                .and_then(|actor| actor.to_rigid_actor())
+        };
+        Some(Self {
+            kind: world.get(id, joint_kind()).ok()?,
+            actor0: rigid_actor(joint_actor_0()),
+            actor1: rigid_actor(joint_actor_1()),
+            local_frame_0: world
+                .get(id, joint_local_frame_0())
+                .unwrap_or(Mat4::IDENTITY),
+            local_frame_1: world.get(id, joint_local_frame_1()).ok(),
+            limits: world.get(id, joint_limits()).ok(),
+            free_axes: world.get(id, joint_free_axes()).unwrap_or_default(),
+            limited_axes: world.get(id, joint_limited_axes()).unwrap_or_default(),
+            drive_velocity: world.get(id, joint_drive_velocity()).ok(),
+            drive_stiffness: world.get(id, joint_drive_stiffness()).ok(),
+            drive_damping: world.get(id, joint_drive_damping()).ok(),
+            drive_force_limit: world.get(id, joint_drive_force_limit()).ok(),
+            break_force: world.get(id, joint_break_force()).ok(),
+            break_torque: world.get(id, joint_break_torque()).ok(),
+        })
+    }
+
+    /// Whether the actors this joint connects exist. A joint without a second actor is
+    /// connected to the world.
+    fn actors_ready(&self, world: &World, id: EntityId) -> bool {
+        self.actor0.is_some()
+            && (self.actor1.is_some() || !world.has_component(id, joint_actor_1()))
+    }
+
+    fn create(&self, physics: PxPhysicsRef) -> PxJointRef {
+        let frame0 = to_px_transform(self.local_frame_0);
+        // Without a second actor, the joint stays where it is in the world
+        let frame1 = to_px_transform(self.local_frame_1.unwrap_or_else(|| match self.actor1 {
+            Some(_) => Mat4::IDENTITY,
+            None => self.actor0.unwrap().get_global_pose().to_mat4() * self.local_frame_0,
+        }));
+        let (actor0, actor1) = (self.actor0, self.actor1);
+
+        let joint = match self.kind {
+            // PhysX revolute and spherical joints have no spring, so driven ones are made from
+            // D6 joints with the same free axes
+            JointKind::Revolute if self.has_spring() => {
+                let joint = PxD6JointRef::new(physics, actor0, &frame0, actor1, &frame1);
+                let (free_axes, limited_axes) = match self.limits {
+                    Some(_) => (0, TWIST),
+                    None => (TWIST, 0),
+                };
+                self.configure_d6(physics, joint, free_axes, limited_axes, self.limits, None);
+                joint.as_joint()
+            }
+            JointKind::Spherical if self.has_spring() => {
+                let joint = PxD6JointRef::new(physics, actor0, &frame0, actor1, &frame1);
+                let (free_axes, limited_axes) = match self.limits {
+                    Some(_) => (TWIST, SWING),
+                    None => (TWIST | SWING, 0),
+                };
+                self.configure_d6(physics, joint, free_axes, limited_axes, None, self.limits);
+                joint.as_joint()
+            }
+            JointKind::Fixed => {
+                PxFixedJointRef::new(physics, actor0, &frame0, actor1, &frame1).as_joint()
+            }
+            JointKind::Revolute => {
+                let joint = PxRevoluteJointRef::new(physics, actor0, &frame0, actor1, &frame1);
+                if let Some(limits) = self.limits {
+                    joint.set_limit(&PxJointAngularLimitPair::new(limits.x, limits.y, -1.));
+                    joint.set_revolute_flag(PxRevoluteJointFlag::LIMIT_ENABLED, true);
+                }
+                if let Some(velocity) = self.drive_velocity {
+                    joint.set_drive_velocity(velocity, true);
+                    joint.set_revolute_flag(PxRevoluteJointFlag::DRIVE_ENABLED, true);
+                }
+                if let Some(force_limit) = self.drive_force_limit {
+                    joint.set_drive_force_limit(force_limit);
+                }
+                joint.as_joint()
+            }
+            JointKind::Spherical => {
+                let joint = PxSphericalJointRef::new(physics, actor0, &frame0, actor1, &frame1);
+                if let Some(limits) = self.limits {
+                    joint.set_limit_cone(&PxJointLimitCone::new(limits.x, limits.y, -1.));
+                    joint.set_spherical_flag(PxSphericalJointFlag::LIMIT_ENABLED, true);
+                }
+                joint.as_joint()
+            }
+            JointKind::Prismatic => {
+                let joint = PxPrismaticJointRef::new(physics, actor0, &frame0, actor1, &frame1);
+                if let Some(limits) = self.limits {
+                    joint.set_limit(&PxJointLinearLimitPair::new(
+                        physics, limits.x, limits.y, -1.,
+                    ));
+                    joint.set_prismatic_flag(PxPrismaticJointFlag::LIMIT_ENABLED, true);
+                }
+                joint.as_joint()
+            }
+            JointKind::Distance => {
+                let joint = PxDistanceJointRef::new(physics, actor0, &frame0, actor1, &frame1);
+                if let Some(limits) = self.limits {
+                    joint.set_min_distance(limits.x);
+                    joint.set_max_distance(limits.y);
+                    joint.set_distance_flag(PxDistanceJointFlag::MIN_DISTANCE_ENABLED, true);
+                    joint.set_distance_flag(PxDistanceJointFlag::MAX_DISTANCE_ENABLED, true);
+                }
+                if let Some(stiffness) = self.drive_stiffness {
+                    joint.set_stiffness(stiffness);
+                    joint.set_damping(self.drive_damping.unwrap_or_default());
+                    joint.set_distance_flag(PxDistanceJointFlag::SPRING_ENABLED, true);
+                }
+                joint.as_joint()
+            }
+            JointKind::D6 => {
+                let joint = PxD6JointRef::new(physics, actor0, &frame0, actor1, &frame1);
+                // Cone angles are half-angles, so the lower twist limit is used by its magnitude
+                let swing_limit = self.limits.map(|limits| limits.abs());
+                self.configure_d6(
+                    physics,
+                    joint,
+                    self.free_axes,
+                    self.limited_axes,
+                    self.limits,
+                    swing_limit,
+                );
+                joint.as_joint()
+            }
+        };
+        joint.set_break_force(
+            self.break_force.unwrap_or(f32::MAX),
+            self.break_torque.unwrap_or(f32::MAX),
+        );
+        joint
+    }
+
+    fn has_spring(&self) -> bool {
+        self.drive_stiffness.is_some() || self.drive_damping.is_some()
+    }
+
+    /// Sets up a D6 joint with bitmasks of the [PxD6Axis] that are free and limited. The swing
+    /// limit is the half-angles of the limit cone around the Y and Z axes.
+    fn configure_d6(
+        &self,
+        physics: PxPhysicsRef,
+        joint: PxD6JointRef,
+        free_axes: u32,
+        limited_axes: u32,
+        twist_limit: Option<Vec2>,
+        swing_limit: Option<Vec2>,
+    ) {
+        let has_drive = self.has_spring();
+        let drive = PxD6JointDrive::new(
+            self.drive_stiffness.unwrap_or_default(),
+            self.drive_damping.unwrap_or_default(),
+            self.drive_force_limit.unwrap_or(f32::MAX),
+            false,
+        );
+        for (i, axis) in PxD6Axis::ALL.into_iter().enumerate() {
+            let bit = 1 << i;
+            let motion = if free_axes & bit != 0 {
+                PxD6Motion::Free
+            } else if limited_axes & bit != 0 {
+                PxD6Motion::Limited
+            } else {
+                PxD6Motion::Locked
+            };
+            joint.set_motion(axis, motion);
+
+            if has_drive && motion != PxD6Motion::Locked {
+                let index = match axis {
+                    PxD6Axis::X => PxD6Drive::X,
+                    PxD6Axis::Y => PxD6Drive::Y,
+                    PxD6Axis::Z => PxD6Drive::Z,
+                    PxD6Axis::Twist => PxD6Drive::Twist,
+                    PxD6Axis::Swing1 | PxD6Axis::Swing2 => PxD6Drive::Swing,
+                };
+                joint.set_drive(index, &drive);
+            }
+        }
+        if let Some(limits) = twist_limit {
+            joint.set_twist_limit(&PxJointAngularLimitPair::new(limits.x, limits.y, -1.));
+        }
+        if let Some(limits) = swing_limit {
+            joint.set_swing_limit(&PxJointLimitCone::new(limits.x, limits.y, -1.));
+        }
+        if let Some(limits) = self.limits.filter(|_| self.kind == JointKind::D6) {
+            joint.set_distance_limit(&PxJointLinearLimit::new(physics, limits.y, -1.));
+        }
+        if let Some(velocity) = self.drive_velocity {
+            joint.set_drive_velocity(Vec3::ZERO, vec3(velocity, 0., 0.), true);
+        }
+    }
+}
+
+fn to_px_transform(frame: Mat4) -> PxTransform {
+    let (_, rotation, translation) = frame.to_scale_rotation_translation();
+    PxTransform::new(translation, rotation)
+}
+
+fn release_joint(joint: PxJointRef) {
+    joint.remove_user_data::<EntityId>();
+    joint.release();
+}
+
+fn remove_joint(world: &mut World, id: EntityId) {
+    if let Ok(joint) = world.get(id, physics_joint()) {
+        release_joint(joint);
+        world.remove_component(id, physics_joint()).unwrap();
+        world.remove_component(id, joint_desc()).unwrap();
+    }
+}
+
+/// Creates, recreates and releases the joints of all entities with a `joint_kind`.
+fn update_joints(world: &mut World) {
+    let physics = PxPhysicsRef::get();
+    for (id, _) in query(()).incl(joint_kind()).collect_cloned(world, None) {
+        if world.has_component(id, joint_broken()) {
+            remove_joint(world, id);
+            continue;
+        }
+        let Some(desc) = JointDesc::get(world, id) else {
+            continue;
+        };
+        if let Ok(joint) = world.get(id, physics_joint()) {
+            if world.get_ref(id, joint_desc()).ok() == Some(&desc) {
+                if joint
+                    .get_constraint_flags()
+                    .contains(PxConstraintFlags::BROKEN)
+                {
+                    world.add_component(id, joint_broken(), ()).unwrap();
+                }
+                continue;
+            }
+            // The components or the actors changed, e.g. because a collider was reloaded
+            remove_joint(world, id);
+        }
+        if !desc.actors_ready(world, id) {
+            continue;
+        }
+
+        let joint = desc.create(physics);
+        joint.set_user_data(id);
+        world
+            .add_components(
+                id,
+                Entity::new()
+                    .with(physics_joint(), joint)
+                    .with(joint_desc(), desc),
+            )
+            .unwrap();
+    }
+}
+
+pub fn server_systems() -> SystemGroup {
+    SystemGroup::new(
+        "physics/joint/server",
+        vec![
+            Box::new(FnSystem::new(|world, _| {
+                profiling::scope!("update_joints");
+                update_joints(world);
+            })),
+            query((physics_joint(),))
+                .incl(joint_kind())
+                .despawned()
+                .to_system(|q, world, qs, _| {
+                    for (id, (joint,)) in q.collect_cloned(world, qs) {
+                        if !world.exists(id) {
+                            release_joint(joint);
+                        } else if !world.has_component(id, joint_kind()) {
+                            release_joint(joint);
+                            world.remove_component(id, physics_joint()).unwrap();
+                            world.remove_component(id, joint_desc()).ok();
+                        }
+                        // Otherwise `update_joints` released it and is replacing it
+                    }
+                }),
+        ],
+    )
+}
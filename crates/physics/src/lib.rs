@@ -13,23 +13,31 @@ use helpers::release_px_scene;
 use parking_lot::Mutex;
 use physx::{
     actor_aggregate, articulation_cache, articulation_link, articulation_reduce_coordinate,
-    character_controller, fixed_joint, physics_shape, revolute_joint, rigid_actor, rigid_dynamic,
-    rigid_static,
+    character_controller, fixed_joint, physics_joint, physics_shape, revolute_joint, rigid_actor,
+    rigid_dynamic, rigid_static,
 };
 use physxx::{
-    AsPxActor, PxContactPairHeader, PxControllerManagerRef, PxMaterial, PxPvdSceneFlag,
-    PxRigidActor, PxSceneDesc, PxSceneFlags, PxSceneRef, PxSimulationEventCallback, PxUserData,
+    AsPxActor, PxContactPairEvents, PxContactPairHeader, PxControllerManagerRef, PxMaterial,
+    PxPairFilteringMode, PxPvdSceneFlag, PxRigidActor, PxSceneDesc, PxSceneFlags, PxSceneRef,
+    PxSimulationEventCallback, PxTriggerPair, PxUserData,
 };
 use serde::{Deserialize, Serialize};
 
-use crate::physx::PhysicsKey;
+use crate::{contact::ContactEvent, physx::PhysicsKey, trigger::TriggerEvent};
 
 pub mod collider;
+pub mod contact;
+pub mod headless;
 pub mod helpers;
 pub mod intersection;
+pub mod joint;
 pub mod mesh;
 pub mod physx;
+pub mod prediction;
+pub mod ragdoll;
 pub mod rc_asset;
+pub mod snapshot;
+pub mod trigger;
 pub mod visualization;
 
 pub use ambient_ecs::generated::physics::components::*;
@@ -47,16 +55,34 @@ components!("physics", {
     wood_physics_material: PxMaterial,
     @[Debuggable, Resource]
     collisions: Arc<Mutex<Vec<(EntityId, EntityId)>>>,
+    @[Debuggable, Resource]
+    trigger_events: Arc<Mutex<Vec<TriggerEvent>>>,
+    @[Debuggable, Resource]
+    contact_events: Arc<Mutex<Vec<ContactEvent>>>,
 });
 pub fn init_all_components() {
     init_components();
     physx::init_components();
     collider::init_components();
+    contact::init_components();
+    joint::init_components();
+    ragdoll::init_components();
+    trigger::init_components();
     visualization::init_components();
 }
 
 pub const GRAVITY: f32 = 9.82;
 pub fn create_server_resources(assets: &AssetCache, server_resources: &mut Entity) {
+    create_resources(assets, server_resources, false);
+}
+
+/// Creates the physics resources with a main scene whose simulation doesn't depend on the order
+/// actors were added in, at some cost to performance.
+pub fn create_deterministic_resources(assets: &AssetCache, resources: &mut Entity) {
+    create_resources(assets, resources, true);
+}
+
+fn create_resources(assets: &AssetCache, server_resources: &mut Entity, deterministic: bool) {
     let physics = PhysicsKey.get(assets);
     server_resources.set(crate::physx::physics(), physics.clone());
 
@@ -64,12 +90,35 @@ pub fn create_server_resources(assets: &AssetCache, server_resources: &mut Entit
     main_scene_desc.set_cpu_dispatcher(&physics.dispatcher);
     main_scene_desc.set_gravity(vec3(0., 0., -GRAVITY));
     main_scene_desc.update_flags(|flags| flags | PxSceneFlags::ENABLE_CCD);
+    if deterministic {
+        main_scene_desc.update_flags(|flags| flags | PxSceneFlags::ENABLE_ENHANCED_DETERMINISM);
+    }
     main_scene_desc.set_filter_shader(main_physx_scene_filter_shader, true);
+    // Kinematic actors, like character controllers, have to be paired with static and kinematic
+    // triggers for them to be reported
+    main_scene_desc.set_static_kinematic_filtering_mode(PxPairFilteringMode::Keep);
+    main_scene_desc.set_kinematic_kinematic_filtering_mode(PxPairFilteringMode::Keep);
     let collisions = Arc::new(Mutex::new(Vec::new()));
+    let trigger_events = Arc::new(Mutex::new(Vec::new()));
+    let contact_events = Arc::new(Mutex::new(Vec::new()));
     {
         let collisions = collisions.clone();
+        let trigger_events = trigger_events.clone();
+        let contact_events = contact_events.clone();
         main_scene_desc.set_simulation_event_callbacks(PxSimulationEventCallback {
             collision_callback: Some(Box::new(move |header: &PxContactPairHeader| {
+                contact_events
+                    .lock()
+                    .extend(header.pairs.iter().flat_map(ContactEvent::from_pair));
+                // Pairs that report contacts are also reported while they touch and when they
+                // stop touching, but `Collision` is only sent when they start touching
+                let touch_found = header
+                    .pairs
+                    .iter()
+                    .any(|pair| pair.events.contains(PxContactPairEvents::TOUCH_FOUND));
+                if !touch_found {
+                    return;
+                }
                 if let (Some(a), Some(b)) = (header.actors[0], header.actors[1]) {
                     let a = a
                         .borrow_shapes()
@@ -86,10 +135,17 @@ pub fn create_server_resources(assets: &AssetCache, server_resources: &mut Entit
                     }
                 }
             })),
+            trigger_callback: Some(Box::new(move |pairs: &[PxTriggerPair]| {
+                trigger_events
+                    .lock()
+                    .extend(pairs.iter().filter_map(TriggerEvent::from_pair));
+            })),
         });
     }
     let main_scene = PxSceneRef::new(&physics.physics, &main_scene_desc);
     server_resources.set(self::collisions(), collisions);
+    server_resources.set(self::trigger_events(), trigger_events);
+    server_resources.set(self::contact_events(), contact_events);
     server_resources.set(self::collider_loads(), vec![]);
 
     main_scene.get_scene_pvd_client().set_scene_pvd_flags(
@@ -184,14 +240,51 @@ impl SyncAssetKey<PxMaterial> for PxWoodMaterialKey {
 unsafe extern "C" fn main_physx_scene_filter_shader(
     info: *mut physxx::sys::FilterShaderCallbackInfo,
 ) -> u16 {
+    // Only let shapes interact if each is on a layer that the other collides with
+    let (layer0, collides_with0) = intersection::collision_layers((*info).filterData0.into());
+    let (layer1, collides_with1) = intersection::collision_layers((*info).filterData1.into());
+    if layer0 & collides_with1 == 0 || layer1 & collides_with0 == 0 {
+        return (physxx::sys::PxFilterFlag::eSUPPRESS) as u16;
+    }
+    let attributes = [(*info).attributes0, (*info).attributes1];
+    // Triggers don't collide; they only report shapes entering and leaving them
+    if attributes
+        .iter()
+        .any(|attributes| attributes & physxx::sys::PxFilterObjectFlag::eTRIGGER != 0)
+    {
+        (*(*info).pairFlags).mBits = physxx::sys::PxPairFlag::eTRIGGER_DEFAULT as u16;
+        return (physxx::sys::PxFilterFlag::eDEFAULT) as u16;
+    }
+    // Kinematic and static actors are only paired with each other for triggers
+    if attributes
+        .iter()
+        .all(|&attributes| is_kinematic_or_static(attributes))
+    {
+        return (physxx::sys::PxFilterFlag::eSUPPRESS) as u16;
+    }
     (*(*info).pairFlags).mBits |= (physxx::sys::PxPairFlag::eSOLVE_CONTACT
         | physxx::sys::PxPairFlag::eDETECT_DISCRETE_CONTACT
         | physxx::sys::PxPairFlag::eDETECT_CCD_CONTACT
         | physxx::sys::PxPairFlag::eCONTACT_DEFAULT
         | physxx::sys::PxPairFlag::eNOTIFY_TOUCH_FOUND) as u16;
+    if intersection::reports_contacts((*info).filterData0.into())
+        || intersection::reports_contacts((*info).filterData1.into())
+    {
+        (*(*info).pairFlags).mBits |= (physxx::sys::PxPairFlag::eNOTIFY_TOUCH_PERSISTS
+            | physxx::sys::PxPairFlag::eNOTIFY_TOUCH_LOST
+            | physxx::sys::PxPairFlag::eNOTIFY_CONTACT_POINTS
+            | physxx::sys::PxPairFlag::ePRE_SOLVER_VELOCITY)
+            as u16;
+    }
     (physxx::sys::PxFilterFlag::eDEFAULT) as u16
 }
 
+fn is_kinematic_or_static(attributes: u32) -> bool {
+    let object_type = attributes & (physxx::sys::PxFilterObjectType::eMAX_TYPE_COUNT - 1);
+    attributes & physxx::sys::PxFilterObjectFlag::eKINEMATIC != 0
+        || object_type == physxx::sys::PxFilterObjectType::eRIGID_STATIC
+}
+
 pub fn server_systems() -> SystemGroup {
     SystemGroup::new(
         "physics",
@@ -223,6 +316,10 @@ pub fn server_systems() -> SystemGroup {
                     }
                 }),
             Box::new(collider::server_systems()),
+            Box::new(contact::server_systems()),
+            Box::new(joint::server_systems()),
+            Box::new(ragdoll::server_systems()),
+            Box::new(trigger::server_systems()),
             Box::new(visualization::server_systems()),
         ],
     )
@@ -247,6 +344,8 @@ pub fn fetch_simulation_system() -> DynSystem {
         profiling::scope!("fetch_simulation_system");
 
         world.resource(collisions()).lock().clear();
+        world.resource(trigger_events()).lock().clear();
+        world.resource(contact_events()).lock().clear();
         world.resource_mut(collider_loads()).clear();
         let scene = world.resource(main_physics_scene());
         // Ensure the previous simulation has completed
@@ -286,6 +385,15 @@ pub fn on_forking_systems() -> SystemGroup<ForkingEvent> {
             for (id, _) in query(()).incl(revolute_joint()).collect_cloned(world, None) {
                 world.remove_component(id, revolute_joint()).unwrap();
             }
+            for (id, _) in query(()).incl(physics_joint()).collect_cloned(world, None) {
+                world.remove_component(id, physics_joint()).unwrap();
+            }
+            for (id, _) in query(())
+                .incl(ragdoll::ragdoll())
+                .collect_cloned(world, None)
+            {
+                world.remove_component(id, ragdoll::ragdoll()).unwrap();
+            }
             for (id, _) in query(())
                 .incl(articulation_reduce_coordinate())
                 .collect_cloned(world, None)
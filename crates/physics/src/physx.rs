@@ -12,7 +12,7 @@ use glam::{EulerRot, Quat, Vec3};
 use parking_lot::Mutex;
 use physxx::{articulation_reduced_coordinate::*, *};
 
-use crate::helpers::{get_shapes, scale_shape};
+use crate::helpers::{get_shapes, scale_shape, update_collision_filter};
 
 pub use ambient_ecs::generated::physics::components::*;
 
@@ -26,6 +26,7 @@ components!("physics", {
     physics_shape: PxShape,
     fixed_joint: PxFixedJointRef,
     revolute_joint: PxRevoluteJointRef,
+    physics_joint: PxJointRef,
     articulation_reduce_coordinate: PxArticulationRef,
     articulation_link: PxArticulationLinkRef,
     articulation_cache: Option<PxArticulationCacheRef>,
@@ -318,6 +319,16 @@ pub fn sync_ecs_physics() -> SystemGroup {
                         }
                     }
                 }),
+            query(collision_layer().changed()).to_system(|q, world, qs, _| {
+                for (id, _) in q.collect_cloned(world, qs) {
+                    update_collision_filter(world, id);
+                }
+            }),
+            query(collides_with().changed()).to_system(|q, world, qs, _| {
+                for (id, _) in q.collect_cloned(world, qs) {
+                    update_collision_filter(world, id);
+                }
+            }),
             // Sync PhysX changes to ECS.
             query((rigid_dynamic(), translation(), rotation()))
                 .incl(physics_controlled())
@@ -0,0 +1,82 @@
+use std::time::Duration;
+
+use ambient_core::transform::translation;
+use ambient_ecs::{EntityId, World};
+use ambient_network::prediction::PredictionSimulator;
+use glam::Vec2;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    character_movement_direction, character_movement_speed, helpers::character_controller_filters,
+    physx::character_controller,
+};
+
+/// The speed of a player without a `character_movement_speed`, in meters per second
+pub const DEFAULT_CHARACTER_MOVEMENT_SPEED: f32 = 5.;
+
+/// What the player wants to do in a tick; the server decides how far that moves them.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct CharacterMovementInput {
+    /// The direction to move in, with a length of up to 1
+    pub direction: Vec2,
+}
+
+/// Predicts the movement of a player's character controller.
+///
+/// The input of each tick is the player's `character_movement_direction`. The displacement is
+/// computed from it with the player's `character_movement_speed`, so a client can't move faster
+/// than the server allows. On the server, the character controller is moved, so collisions are
+/// resolved there; the client only has the translation, and is corrected whenever the server's
+/// result differs.
+#[derive(Debug, Clone)]
+pub struct CharacterMovementSimulator {
+    /// The minimum distance the character controller moves by
+    pub min_dist: f32,
+}
+impl Default for CharacterMovementSimulator {
+    fn default() -> Self {
+        Self { min_dist: 0.01 }
+    }
+}
+impl PredictionSimulator for CharacterMovementSimulator {
+    fn sample_input(&self, world: &World, player: EntityId) -> Option<Vec<u8>> {
+        let direction = world.get(player, character_movement_direction()).ok()?;
+        bincode::serialize(&CharacterMovementInput { direction }).ok()
+    }
+
+    fn simulate(&self, world: &mut World, player: EntityId, input: &[u8], dt: Duration) {
+        let Ok(input) = bincode::deserialize::<CharacterMovementInput>(input) else {
+            tracing::warn!(?player, "Invalid character movement input");
+            return;
+        };
+        if !input.direction.is_finite() {
+            tracing::warn!(?player, "Invalid character movement direction");
+            return;
+        }
+
+        let speed = world
+            .get(player, character_movement_speed())
+            .unwrap_or(DEFAULT_CHARACTER_MOVEMENT_SPEED);
+        let displacement =
+            (input.direction.clamp_length_max(1.) * speed * dt.as_secs_f32()).extend(0.);
+
+        match world.get(player, character_controller()) {
+            Ok(controller) => {
+                controller.move_controller(
+                    displacement,
+                    self.min_dist,
+                    dt.as_secs_f32(),
+                    &character_controller_filters(world, player),
+                    None,
+                );
+                let position = controller.get_foot_position().as_vec3();
+                world.set(player, translation(), position).ok();
+            }
+            Err(_) => {
+                if let Ok(position) = world.get_mut(player, translation()) {
+                    *position += displacement;
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,545 @@
+use std::{collections::HashMap, f32::consts::FRAC_PI_4};
+
+use ambient_animation::animated_bone_transform;
+use ambient_core::{
+    asset_cache,
+    async_ecs::async_run,
+    delta_time,
+    hierarchy::children,
+    runtime,
+    transform::{get_world_transform, local_to_world, rotation, scale, translation},
+};
+use ambient_ecs::{
+    components,
+    generated::{
+        animation::components::bind_id,
+        physics::components::{
+            density, ragdoll_bind_ids, ragdoll_blend, ragdoll_joint_limits, ragdoll_link_poses,
+            ragdoll_link_radii,
+        },
+    },
+    query, Entity, EntityId, FnSystem, SystemGroup, World,
+};
+use ambient_model::{animation_binder, model_from_url, Model, ModelFromUrl};
+use ambient_native_std::asset_cache::AsyncAssetKeyExt;
+use glam::{Mat4, Quat, Vec2, Vec3};
+use itertools::Itertools;
+use physxx::{
+    articulation_reduced_coordinate::{PxArticulationJointRef, PxArticulationRef},
+    AsPxActor, PxArticulationAxis, PxArticulationJointBase, PxArticulationJointType,
+    PxArticulationLinkRef, PxArticulationMotion, PxCapsuleGeometry, PxRigidActor, PxRigidBody,
+    PxShape, PxSphereGeometry, PxTransform, PxUserData,
+};
+
+use crate::{
+    intersection::entity_collision_filter_data, main_physics_scene, physx::physics,
+    wood_physics_material, PxActorUserData, PxShapeUserData,
+};
+
+components!("physics", {
+    /// The articulation simulating the ragdoll of this entity.
+    ragdoll: Ragdoll,
+    /// The model of this entity is being loaded to create its ragdoll.
+    ragdoll_loading: (),
+    /// The world transforms of the link bones during the last frame, while there is no ragdoll.
+    /// The initial velocities of the links are computed from them.
+    ragdoll_prev_bone_poses: Vec<Mat4>,
+    /// The ragdoll of this entity could not be created from this description, so it isn't
+    /// retried until the description changes.
+    ragdoll_failed: RagdollDesc,
+});
+
+const DEFAULT_LINK_RADIUS: f32 = 0.1;
+const DEFAULT_JOINT_LIMIT: f32 = FRAC_PI_4;
+
+#[derive(Clone)]
+pub struct Ragdoll {
+    articulation: PxArticulationRef,
+    links: Vec<PxArticulationLinkRef>,
+    /// The world scale of each link bone, which the links themselves don't have
+    scales: Vec<Vec3>,
+    desc: RagdollDesc,
+}
+impl std::fmt::Debug for Ragdoll {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Ragdoll")
+            .field("links", &self.links.len())
+            .field("desc", &self.desc)
+            .finish()
+    }
+}
+
+/// Everything a ragdoll is created from. The ragdoll is recreated when this changes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RagdollDesc {
+    model_url: String,
+    bind_ids: Vec<String>,
+    radii: Vec<f32>,
+    joint_limits: Vec<Vec2>,
+    density: f32,
+}
+impl RagdollDesc {
+    /// Returns the description of the ragdoll of `id`, if it should be simulated.
+    fn get(world: &World, id: EntityId) -> Option<Self> {
+        if world.get(id, ragdoll_blend()).unwrap_or_default() <= 0. {
+            return None;
+        }
+        Some(Self {
+            model_url: world.get_ref(id, model_from_url()).ok()?.clone(),
+            bind_ids: world.get_ref(id, ragdoll_bind_ids()).ok()?.clone(),
+            radii: world
+                .get_ref(id, ragdoll_link_radii())
+                .cloned()
+                .unwrap_or_default(),
+            joint_limits: world
+                .get_ref(id, ragdoll_joint_limits())
+                .cloned()
+                .unwrap_or_default(),
+            density: world.get(id, density()).unwrap_or(1.),
+        })
+    }
+
+    /// Returns whether this is still the description of the ragdoll of `id`, without cloning
+    /// the components.
+    fn is_current(&self, world: &World, id: EntityId) -> bool {
+        world.get(id, ragdoll_blend()).unwrap_or_default() > 0.
+            && world
+                .get_ref(id, model_from_url())
+                .map_or(false, |url| *url == self.model_url)
+            && world
+                .get_ref(id, ragdoll_bind_ids())
+                .map_or(false, |bind_ids| *bind_ids == self.bind_ids)
+            && world
+                .get_ref(id, ragdoll_link_radii())
+                .map_or(self.radii.is_empty(), |radii| *radii == self.radii)
+            && world
+                .get_ref(id, ragdoll_joint_limits())
+                .map_or(self.joint_limits.is_empty(), |limits| {
+                    *limits == self.joint_limits
+                })
+            && world.get(id, density()).unwrap_or(1.) == self.density
+    }
+
+    fn radius(&self, link: usize) -> f32 {
+        self.radii.get(link).copied().unwrap_or(DEFAULT_LINK_RADIUS)
+    }
+
+    fn joint_limits(&self, link: usize) -> Vec2 {
+        self.joint_limits
+            .get(link)
+            .copied()
+            .unwrap_or(Vec2::splat(DEFAULT_JOINT_LIMIT))
+    }
+}
+
+/// Returns the transform of each node of `model` relative to the model, in the pose that the
+/// animation of `id` gives it, and the parent of each node.
+fn animated_pose(
+    world: &World,
+    id: EntityId,
+    model: &Model,
+) -> (HashMap<EntityId, Mat4>, HashMap<EntityId, EntityId>) {
+    let mut transforms = HashMap::new();
+    let mut parents = HashMap::new();
+    let mut stack = model
+        .roots()
+        .into_iter()
+        .map(|root| (root, model.get_transform().unwrap_or_default()))
+        .collect::<Vec<_>>();
+    while let Some((node, parent_transform)) = stack.pop() {
+        let (animated_translation, animated_rotation) = match model.0.get_ref(node, bind_id()) {
+            Ok(bind_id) => animated_bone_transform(world, id, bind_id),
+            Err(_) => (None, None),
+        };
+        let transform = parent_transform
+            * Mat4::from_scale_rotation_translation(
+                model.0.get(node, scale()).unwrap_or(Vec3::ONE),
+                animated_rotation
+                    .or_else(|| model.0.get(node, rotation()).ok())
+                    .unwrap_or(Quat::IDENTITY),
+                animated_translation
+                    .or_else(|| model.0.get(node, translation()).ok())
+                    .unwrap_or(Vec3::ZERO),
+            );
+        for &child in model.0.get_ref(node, children()).into_iter().flatten() {
+            parents.insert(child, node);
+            stack.push((child, transform));
+        }
+        transforms.insert(node, transform);
+    }
+    (transforms, parents)
+}
+
+/// Returns the nodes of `model` with the `bind_ids`, or the first bind id that has none.
+fn link_bones<'a>(model: &Model, bind_ids: &'a [String]) -> Result<Vec<EntityId>, &'a str> {
+    bind_ids
+        .iter()
+        .map(|bind_id| {
+            model
+                .get_entity_id_by_bind_id(bind_id)
+                .ok_or(bind_id.as_str())
+        })
+        .collect()
+}
+
+/// Returns the current world transforms of the `bones` of `model`, which have the `bind_ids`.
+///
+/// Where the model of `id` is spawned, this is the `local_to_world` of its bones. Otherwise, like
+/// on the server, the animation of `id` is applied to the model.
+fn current_bone_poses(
+    world: &World,
+    id: EntityId,
+    model: &Model,
+    bind_ids: &[String],
+    bones: &[EntityId],
+) -> Option<Vec<Mat4>> {
+    if let Ok(binder) = world.get_ref(id, animation_binder()) {
+        let poses = bind_ids
+            .iter()
+            .map(|bind_id| world.get(*binder.get(bind_id)?, local_to_world()).ok())
+            .collect::<Option<Vec<_>>>();
+        if poses.is_some() {
+            return poses;
+        }
+    }
+    let entity_transform = get_world_transform(world, id).ok()?;
+    let (node_transforms, _) = animated_pose(world, id, model);
+    Some(
+        bones
+            .iter()
+            .map(|bone| entity_transform * node_transforms[bone])
+            .collect(),
+    )
+}
+
+fn to_px_transform(transform: Mat4) -> PxTransform {
+    let (_, rotation, translation) = transform.to_scale_rotation_translation();
+    PxTransform::new(translation, rotation)
+}
+
+impl Ragdoll {
+    /// Creates the articulation of the ragdoll of `id` in the current pose of `model`, and adds
+    /// it to the main scene. The links move at the velocities of their bones since `prev_poses`,
+    /// the bone poses of the last frame.
+    fn create(
+        world: &World,
+        id: EntityId,
+        model: &Model,
+        desc: RagdollDesc,
+        prev_poses: Option<&[Mat4]>,
+    ) -> Option<Self> {
+        let bones = match link_bones(model, &desc.bind_ids) {
+            Ok(bones) => bones,
+            Err(bind_id) => {
+                tracing::warn!("Ragdoll of {id} has no bone with the bind id {bind_id:?}");
+                return None;
+            }
+        };
+        let (_, parents) = animated_pose(world, id, model);
+        // The links of ancestors come before the links of their descendants
+        let parent_links = bones
+            .iter()
+            .enumerate()
+            .map(|(i, bone)| {
+                let mut node = parents.get(bone);
+                while let Some(ancestor) = node {
+                    if let Some(parent) = bones[..i].iter().position(|b| b == ancestor) {
+                        return Some(parent);
+                    }
+                    node = parents.get(ancestor);
+                }
+                None
+            })
+            .collect::<Vec<_>>();
+        if parent_links.iter().skip(1).any(|parent| parent.is_none()) {
+            tracing::warn!("Ragdoll of {id} has a link that is not below the links before it");
+            return None;
+        }
+        let bone_poses = current_bone_poses(world, id, model, &desc.bind_ids, &bones)?;
+        let (scales, poses): (Vec<_>, Vec<_>) = bone_poses
+            .iter()
+            .map(|pose| {
+                let (scale, rotation, translation) = pose.to_scale_rotation_translation();
+                (
+                    scale,
+                    Mat4::from_rotation_translation(rotation, translation),
+                )
+            })
+            .unzip();
+
+        let physics = world.resource(physics());
+        let material = world.resource(wood_physics_material()).clone();
+        let filter_data = entity_collision_filter_data(world, id);
+        let articulation = PxArticulationRef::new(&physics.physics);
+        let mut links: Vec<PxArticulationLinkRef> = Vec::new();
+        for (i, pose) in poses.iter().enumerate() {
+            let link = PxArticulationLinkRef::new(
+                &articulation,
+                parent_links[i].map(|parent| &links[parent]),
+                &to_px_transform(*pose),
+            );
+
+            // Links are capsules reaching to their first child link, or spheres if they have none
+            let child = (i + 1..poses.len()).find(|&child| parent_links[child] == Some(i));
+            let segment = child
+                .map(|child| {
+                    pose.inverse()
+                        .transform_point3(poses[child].w_axis.truncate())
+                })
+                .unwrap_or_default();
+            let direction = if segment.length() > 0. {
+                segment.normalize()
+            } else if let Some(parent) = parent_links[i] {
+                pose.inverse()
+                    .transform_vector3(pose.w_axis.truncate() - poses[parent].w_axis.truncate())
+                    .normalize_or_zero()
+            } else {
+                Vec3::ZERO
+            };
+            // Capsules and twist limits are around the X axis
+            let axis = if direction == Vec3::ZERO {
+                Quat::IDENTITY
+            } else {
+                Quat::from_rotation_arc(Vec3::X, direction)
+            };
+
+            let radius = desc.radius(i);
+            let half_height = segment.length() / 2. - radius;
+            let shape = if half_height > 0. {
+                let geometry = PxCapsuleGeometry::new(radius, half_height);
+                PxShape::new(physics.physics, &geometry, &[&material], Some(true), None)
+            } else {
+                let geometry = PxSphereGeometry::new(radius);
+                PxShape::new(physics.physics, &geometry, &[&material], Some(true), None)
+            };
+            let shape_pose = Mat4::from_rotation_translation(axis, segment / 2.);
+            shape.set_local_pose(&to_px_transform(shape_pose));
+            shape.set_simulation_filter_data(filter_data);
+            shape.set_query_filter_data(filter_data);
+            shape.set_user_data(PxShapeUserData {
+                entity: id,
+                density: desc.density,
+                base_pose: shape_pose,
+            });
+            link.attach_shape(&shape);
+            link.as_actor().set_user_data(PxActorUserData::default());
+            link.update_mass_and_inertia(vec![desc.density], None, None);
+
+            if let (Some(parent), Some(mut joint)) = (
+                parent_links[i],
+                PxArticulationJointRef::from_inbound_joint(&link),
+            ) {
+                let frame = Mat4::from_quat(axis);
+                joint.set_parent_pose(&to_px_transform(poses[parent].inverse() * *pose * frame));
+                joint.set_child_pose(&to_px_transform(frame));
+                joint.set_joint_type(PxArticulationJointType::Spherical);
+                let limits = desc.joint_limits(i);
+                joint.set_motion(PxArticulationAxis::Twist, PxArticulationMotion::Limited);
+                joint.set_limit(PxArticulationAxis::Twist, -limits.x, limits.x);
+                for axis in [PxArticulationAxis::Swing1, PxArticulationAxis::Swing2] {
+                    joint.set_motion(axis, PxArticulationMotion::Limited);
+                    joint.set_limit(axis, -limits.y, limits.y);
+                }
+            }
+            links.push(link);
+        }
+        world
+            .resource(main_physics_scene())
+            .add_articulation(&articulation);
+
+        let dt = *world.resource(delta_time());
+        if let Some(prev_poses) = prev_poses.filter(|prev| prev.len() == poses.len() && dt > 0.) {
+            for ((link, pose), prev_pose) in links.iter().zip(&poses).zip(prev_poses) {
+                let (_, rotation, translation) = pose.to_scale_rotation_translation();
+                let (_, prev_rotation, prev_translation) =
+                    prev_pose.to_scale_rotation_translation();
+                link.set_linear_velocity((translation - prev_translation) / dt, false);
+                // The shortest rotation from the previous pose
+                let delta = rotation * prev_rotation.inverse();
+                let delta = if delta.w < 0. { -delta } else { delta };
+                let (axis, angle) = delta.to_axis_angle();
+                link.set_angular_velocity(axis * angle / dt, false);
+            }
+        }
+
+        Some(Self {
+            articulation,
+            links,
+            scales,
+            desc,
+        })
+    }
+
+    fn release(mut self, world: &World) {
+        world
+            .resource(main_physics_scene())
+            .remove_articulation(&self.articulation, true);
+        for link in &self.links {
+            for shape in link.get_shapes() {
+                shape.remove_user_data::<PxShapeUserData>();
+            }
+            link.as_actor().remove_user_data::<PxActorUserData>();
+        }
+        self.articulation.release();
+    }
+
+    /// The world transforms of the bones of the links
+    fn link_poses(&self) -> Vec<Mat4> {
+        self.links
+            .iter()
+            .zip(&self.scales)
+            .map(|(link, scale)| link.get_global_pose().to_mat4() * Mat4::from_scale(*scale))
+            .collect()
+    }
+}
+
+fn remove_ragdoll(world: &mut World, id: EntityId) {
+    if let Ok(ragdoll) = world.get_ref(id, ragdoll()).cloned() {
+        ragdoll.release(world);
+        world.remove_component(id, self::ragdoll()).unwrap();
+        world.remove_component(id, ragdoll_link_poses()).ok();
+    }
+}
+
+/// Creates, recreates and releases the ragdolls of all entities with `ragdoll_bind_ids`.
+fn update_ragdolls(world: &mut World) {
+    let ids = query(())
+        .incl(ragdoll_bind_ids())
+        .iter(world, None)
+        .chain(query(()).incl(ragdoll()).iter(world, None))
+        .map(|(id, _)| id)
+        .unique()
+        .collect_vec();
+    for id in ids {
+        if let Ok(ragdoll) = world.get_ref(id, ragdoll()) {
+            if ragdoll.desc.is_current(world, id) {
+                continue;
+            }
+            remove_ragdoll(world, id);
+        }
+        if world.has_component(id, ragdoll_loading())
+            || world
+                .get_ref(id, ragdoll_failed())
+                .map_or(false, |desc| desc.is_current(world, id))
+        {
+            continue;
+        }
+        let Some(desc) = RagdollDesc::get(world, id) else {
+            continue;
+        };
+        let key = match ModelFromUrl::new(&desc.model_url) {
+            Ok(key) => key,
+            Err(err) => {
+                tracing::warn!("Ragdoll of {id} has an invalid model url: {err:#}");
+                world.add_component(id, ragdoll_failed(), desc).unwrap();
+                continue;
+            }
+        };
+        let assets = world.resource(asset_cache()).clone();
+        match key.peek(&assets) {
+            Some(Ok(model)) => {
+                let prev_poses = world.get_ref(id, ragdoll_prev_bone_poses()).ok();
+                let ragdoll = Ragdoll::create(
+                    world,
+                    id,
+                    &model,
+                    desc.clone(),
+                    prev_poses.map(|p| p.as_slice()),
+                );
+                match ragdoll {
+                    Some(ragdoll) => {
+                        world.remove_component(id, ragdoll_prev_bone_poses()).ok();
+                        world.remove_component(id, ragdoll_failed()).ok();
+                        world
+                            .add_components(
+                                id,
+                                Entity::new()
+                                    .with(ragdoll_link_poses(), ragdoll.link_poses())
+                                    .with(self::ragdoll(), ragdoll),
+                            )
+                            .unwrap();
+                    }
+                    None => world.add_component(id, ragdoll_failed(), desc).unwrap(),
+                }
+            }
+            Some(Err(err)) => {
+                tracing::warn!("Failed to load the model of the ragdoll of {id}: {err:#}");
+                world.add_component(id, ragdoll_failed(), desc).unwrap();
+            }
+            None => {
+                world.add_component(id, ragdoll_loading(), ()).unwrap();
+                let async_run = world.resource(async_run()).clone();
+                world.resource(runtime()).spawn(async move {
+                    // Errors are logged when the ragdoll is updated after this
+                    key.get(&assets).await.ok();
+                    async_run.run(move |world| {
+                        world.remove_component(id, ragdoll_loading()).ok();
+                    });
+                });
+            }
+        }
+    }
+}
+
+/// Records the bone poses of the entities that can get a ragdoll, for the velocities of its links.
+fn update_prev_bone_poses(world: &mut World) {
+    let assets = world.resource(asset_cache()).clone();
+    let mut poses = Vec::new();
+    for (id, (bind_ids, url)) in query((ragdoll_bind_ids(), model_from_url()))
+        .excl(ragdoll())
+        .iter(world, None)
+    {
+        let Some(Ok(model)) = ModelFromUrl::new(url)
+            .ok()
+            .and_then(|key| key.peek(&assets))
+        else {
+            continue;
+        };
+        let Ok(bones) = link_bones(&model, bind_ids) else {
+            continue;
+        };
+        if let Some(bone_poses) = current_bone_poses(world, id, &model, bind_ids, &bones) {
+            poses.push((id, bone_poses));
+        }
+    }
+    for (id, bone_poses) in poses {
+        world
+            .add_component(id, ragdoll_prev_bone_poses(), bone_poses)
+            .unwrap();
+    }
+}
+
+pub fn server_systems() -> SystemGroup {
+    SystemGroup::new(
+        "physics/ragdoll/server",
+        vec![
+            Box::new(FnSystem::new(|world, _| {
+                profiling::scope!("update_ragdolls");
+                update_ragdolls(world);
+            })),
+            Box::new(FnSystem::new(|world, _| {
+                profiling::scope!("update_prev_bone_poses");
+                update_prev_bone_poses(world);
+            })),
+            query((ragdoll(),)).to_system(|q, world, qs, _| {
+                let poses = q
+                    .iter(world, qs)
+                    .map(|(id, (ragdoll,))| (id, ragdoll.link_poses()))
+                    .collect_vec();
+                for (id, poses) in poses {
+                    world.set(id, ragdoll_link_poses(), poses).unwrap();
+                }
+            }),
+            query((ragdoll(),))
+                .despawned()
+                .to_system(|q, world, qs, _| {
+                    for (id, (ragdoll,)) in q.collect_cloned(world, qs) {
+                        // Otherwise `update_ragdolls` released it
+                        if !world.exists(id) {
+                            ragdoll.release(world);
+                        }
+                    }
+                }),
+        ],
+    )
+}
@@ -0,0 +1,165 @@
+use ambient_core::transform::{rotation, translation};
+use ambient_ecs::{query, EntityId, World};
+use glam::{DVec3, Quat, Vec3};
+use physxx::{AsPxRigidActor, PxRigidActor, PxRigidBody, PxRigidBodyFlag, PxTransform};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    angular_velocity,
+    helpers::update_actor_entity_transforms,
+    linear_velocity,
+    physx::{character_controller, physics_shape, rigid_dynamic},
+};
+
+/// The state of the dynamic bodies and character controllers of a world's physics, which can
+/// be restored later or compared with another snapshot.
+///
+/// Restoring a snapshot doesn't restore the contact caches of PhysX, so a simulation continued
+/// from a restored snapshot can drift slightly from the original one.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PhysicsSnapshot {
+    /// Sorted by entity
+    pub bodies: Vec<BodySnapshot>,
+    /// Sorted by entity
+    pub character_controllers: Vec<CharacterControllerSnapshot>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct BodySnapshot {
+    pub entity: EntityId,
+    pub translation: Vec3,
+    pub rotation: Quat,
+    pub linear_velocity: Vec3,
+    pub angular_velocity: Vec3,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct CharacterControllerSnapshot {
+    pub entity: EntityId,
+    pub foot_position: DVec3,
+}
+
+impl PhysicsSnapshot {
+    /// Captures the state of every entity with a dynamic body or a character controller.
+    pub fn capture(world: &World) -> Self {
+        let mut bodies = query(rigid_dynamic())
+            .iter(world, None)
+            .map(|(entity, body)| {
+                let pose = body.get_global_pose();
+                BodySnapshot {
+                    entity,
+                    translation: pose.translation(),
+                    rotation: pose.rotation(),
+                    linear_velocity: body.get_linear_velocity(),
+                    angular_velocity: body.get_angular_velocity(),
+                }
+            })
+            .collect::<Vec<_>>();
+        bodies.sort_by_key(|body| body.entity);
+
+        let mut character_controllers = query(character_controller())
+            .iter(world, None)
+            .map(|(entity, controller)| CharacterControllerSnapshot {
+                entity,
+                foot_position: controller.get_foot_position(),
+            })
+            .collect::<Vec<_>>();
+        character_controllers.sort_by_key(|controller| controller.entity);
+
+        Self {
+            bodies,
+            character_controllers,
+        }
+    }
+
+    /// Moves the bodies and character controllers back to where they were in this snapshot.
+    /// Entities that no longer exist are skipped.
+    pub fn restore(&self, world: &mut World) {
+        for snapshot in &self.bodies {
+            let Ok(body) = world.get(snapshot.entity, rigid_dynamic()) else {
+                continue;
+            };
+            body.set_global_pose(
+                &PxTransform::new(snapshot.translation, snapshot.rotation),
+                true,
+            );
+            if !body
+                .get_rigid_body_flags()
+                .contains(PxRigidBodyFlag::KINEMATIC)
+            {
+                body.set_linear_velocity(snapshot.linear_velocity, true);
+                body.set_angular_velocity(snapshot.angular_velocity, true);
+            }
+
+            if world.has_component(snapshot.entity, physics_shape()) {
+                update_actor_entity_transforms(world, body.as_rigid_actor());
+            } else {
+                world
+                    .set(snapshot.entity, translation(), snapshot.translation)
+                    .ok();
+                world
+                    .set(snapshot.entity, rotation(), snapshot.rotation)
+                    .ok();
+            }
+            world
+                .set(snapshot.entity, linear_velocity(), snapshot.linear_velocity)
+                .ok();
+            world
+                .set(
+                    snapshot.entity,
+                    angular_velocity(),
+                    snapshot.angular_velocity,
+                )
+                .ok();
+        }
+
+        for snapshot in &self.character_controllers {
+            let Ok(controller) = world.get(snapshot.entity, character_controller()) else {
+                continue;
+            };
+            controller.set_foot_position(snapshot.foot_position);
+            world
+                .set(
+                    snapshot.entity,
+                    translation(),
+                    snapshot.foot_position.as_vec3(),
+                )
+                .ok();
+        }
+    }
+
+    /// The largest difference in position, rotation (in radians) or velocity between this
+    /// snapshot and `other`, or `None` if they aren't of the same entities.
+    pub fn max_difference(&self, other: &Self) -> Option<f32> {
+        let same_entities = self.bodies.len() == other.bodies.len()
+            && self.character_controllers.len() == other.character_controllers.len()
+            && self
+                .bodies
+                .iter()
+                .zip(&other.bodies)
+                .all(|(a, b)| a.entity == b.entity)
+            && self
+                .character_controllers
+                .iter()
+                .zip(&other.character_controllers)
+                .all(|(a, b)| a.entity == b.entity);
+        if !same_entities {
+            return None;
+        }
+
+        let bodies = self.bodies.iter().zip(&other.bodies).flat_map(|(a, b)| {
+            [
+                a.translation.distance(b.translation),
+                a.rotation.angle_between(b.rotation),
+                a.linear_velocity.distance(b.linear_velocity),
+                a.angular_velocity.distance(b.angular_velocity),
+            ]
+        });
+        let character_controllers = self
+            .character_controllers
+            .iter()
+            .zip(&other.character_controllers)
+            .map(|(a, b)| a.foot_position.distance(b.foot_position) as f32);
+        Some(bodies.chain(character_controllers).fold(0., f32::max))
+    }
+}
@@ -0,0 +1,173 @@
+use std::collections::{HashMap, HashSet};
+
+use ambient_ecs::{components, query, EntityId, Resource, SystemGroup, World};
+use physxx::{PxActor, PxShape, PxShapeFlag, PxTriggerPair, PxTriggerPairStatus, PxUserData};
+
+use crate::{
+    helpers::get_shapes,
+    is_trigger,
+    physx::{physics_shape, rigid_actor},
+    trigger_events, PxShapeUserData,
+};
+
+components!("physics::trigger", {
+    /// How many shapes of each entity are inside each trigger, by `(trigger, other)`
+    @[Resource]
+    trigger_occupants: HashMap<(EntityId, EntityId), usize>,
+    /// The pairs of an entity whose shapes were replaced, which are counted again from the next
+    /// events instead of being entered again
+    @[Resource]
+    trigger_recounts: HashSet<(EntityId, EntityId)>,
+});
+
+/// A collider entering or leaving a trigger.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TriggerEvent {
+    Enter { trigger: EntityId, other: EntityId },
+    Exit { trigger: EntityId, other: EntityId },
+}
+impl TriggerEvent {
+    /// Returns the event for a trigger pair reported by PhysX, if both of its shapes belong to
+    /// entities.
+    ///
+    /// Pairs where a shape was removed are skipped, as the shape may not belong to an entity
+    /// anymore; [update_trigger_occupants] sends their `Exit` instead. These events are for pairs of
+    /// shapes, and are turned into events for pairs of entities by [update_trigger_occupants].
+    pub fn from_pair(pair: &PxTriggerPair) -> Option<Self> {
+        let trigger = pair
+            .trigger_shape
+            .as_ref()?
+            .get_user_data::<PxShapeUserData>()?
+            .entity;
+        let other = pair
+            .other_shape
+            .as_ref()?
+            .get_user_data::<PxShapeUserData>()?
+            .entity;
+        Some(match pair.status {
+            PxTriggerPairStatus::Found => Self::Enter { trigger, other },
+            PxTriggerPairStatus::Lost => Self::Exit { trigger, other },
+        })
+    }
+}
+
+/// Makes `shape` a trigger shape, or a regular simulation shape if `is_trigger` is false.
+pub fn set_trigger_shape(shape: &PxShape, is_trigger: bool) {
+    // A shape can't be a simulation shape and a trigger shape at the same time
+    if is_trigger {
+        shape.set_flag(PxShapeFlag::SIMULATION_SHAPE, false);
+        shape.set_flag(PxShapeFlag::TRIGGER_SHAPE, true);
+    } else {
+        shape.set_flag(PxShapeFlag::TRIGGER_SHAPE, false);
+        shape.set_flag(PxShapeFlag::SIMULATION_SHAPE, true);
+    }
+}
+
+fn update_trigger_shapes(world: &World, id: EntityId) {
+    let is_trigger = world.has_component(id, is_trigger());
+    for shape in get_shapes(world, id) {
+        set_trigger_shape(&shape, is_trigger);
+    }
+    if let Ok(actor) = world.get(id, rigid_actor()) {
+        if let Some(scene) = actor.get_scene() {
+            scene.reset_filtering(&actor);
+        }
+    }
+}
+
+/// Keeps track of what is inside each trigger, and turns the events that PhysX reports for each
+/// pair of shapes into one `Enter` when an entity starts overlapping a trigger and one `Exit` when
+/// it stops. Adds the `Exit` events that PhysX can't report because the trigger or the other
+/// entity was despawned.
+///
+/// The pairs of the entities in `replaced` had their shapes replaced, so the shapes that were
+/// removed will never be reported as leaving. Their pairs are counted again from the events of the
+/// new shapes, and get an `Exit` if those don't overlap anymore.
+fn update_trigger_occupants(world: &mut World, replaced: &HashSet<EntityId>) {
+    let mut occupants = world
+        .resource_mut_opt(trigger_occupants())
+        .map(std::mem::take)
+        .unwrap_or_default();
+    let mut recounts = world
+        .resource_mut_opt(trigger_recounts())
+        .map(std::mem::take)
+        .unwrap_or_default();
+    let mut events = world.resource(trigger_events()).lock();
+
+    let mut entity_events = Vec::new();
+    for event in events.drain(..) {
+        match event {
+            TriggerEvent::Enter { trigger, other } => {
+                let count = occupants.entry((trigger, other)).or_default();
+                *count += 1;
+                if *count == 1 && !recounts.contains(&(trigger, other)) {
+                    entity_events.push(event);
+                }
+            }
+            TriggerEvent::Exit { trigger, other } => {
+                if let Some(count) = occupants.get_mut(&(trigger, other)) {
+                    *count -= 1;
+                    if *count == 0 {
+                        occupants.remove(&(trigger, other));
+                        if !recounts.contains(&(trigger, other)) {
+                            entity_events.push(event);
+                        }
+                    }
+                }
+            }
+        }
+    }
+    for (trigger, other) in recounts.drain() {
+        if !occupants.contains_key(&(trigger, other)) {
+            entity_events.push(TriggerEvent::Exit { trigger, other });
+        }
+    }
+    occupants.retain(|&(trigger, other), _| {
+        if world.exists(trigger) && world.exists(other) {
+            return true;
+        }
+        entity_events.push(TriggerEvent::Exit { trigger, other });
+        false
+    });
+    occupants.retain(|&(trigger, other), _| {
+        if replaced.contains(&trigger) || replaced.contains(&other) {
+            recounts.insert((trigger, other));
+            return false;
+        }
+        true
+    });
+
+    *events = entity_events;
+    drop(events);
+    world.add_resource(trigger_occupants(), occupants);
+    world.add_resource(trigger_recounts(), recounts);
+}
+
+pub fn server_systems() -> SystemGroup {
+    SystemGroup::new(
+        "physics/trigger/server",
+        vec![
+            query(physics_shape().changed()).to_system(|q, world, qs, _| {
+                let replaced = q.iter(world, qs).map(|(id, _)| id).collect();
+                update_trigger_occupants(world, &replaced);
+            }),
+            query(is_trigger().changed())
+                .incl(physics_shape())
+                .to_system(|q, world, qs, _| {
+                    for (id, _) in q.collect_cloned(world, qs) {
+                        update_trigger_shapes(world, id);
+                    }
+                }),
+            query(is_trigger())
+                .incl(physics_shape())
+                .despawned()
+                .to_system(|q, world, qs, _| {
+                    for (id, _) in q.collect_cloned(world, qs) {
+                        if world.exists(id) {
+                            update_trigger_shapes(world, id);
+                        }
+                    }
+                }),
+        ],
+    )
+}
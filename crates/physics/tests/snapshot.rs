@@ -0,0 +1,84 @@
+use std::time::Duration;
+
+use ambient_core::{asset_cache, transform::translation};
+use ambient_ecs::{Entity, EntityId, World, WorldContext};
+use ambient_native_std::asset_cache::AssetCache;
+use ambient_physics::{
+    cube_collider, dynamic, headless::PhysicsStepper, physics_controlled, plane_collider,
+    snapshot::PhysicsSnapshot,
+};
+use glam::{vec3, Vec3};
+use itertools::Itertools;
+
+fn init() {
+    ambient_ecs::init_components();
+    ambient_core::init_all_components();
+    ambient_physics::init_all_components();
+}
+
+/// A stack of cubes falling onto a plane, with the same entity ids every time.
+async fn falling_cubes(ids: &[EntityId]) -> (World, PhysicsStepper) {
+    let mut world = World::new("physics_test", WorldContext::Server);
+    world.add_resource(
+        asset_cache(),
+        AssetCache::new(tokio::runtime::Handle::current()),
+    );
+    let mut stepper = PhysicsStepper::new(&mut world);
+
+    world.spawn_with_id(ids[0], Entity::new().with(plane_collider(), ()));
+    for (i, &id) in ids[1..].iter().enumerate() {
+        world.spawn_with_id(
+            id,
+            Entity::new()
+                .with(translation(), vec3(0.1 * i as f32, 0., 1. + 1.5 * i as f32))
+                .with(cube_collider(), Vec3::ONE)
+                .with(dynamic(), true)
+                .with(physics_controlled(), ()),
+        );
+    }
+    stepper
+        .load_colliders(&mut world, Duration::from_secs(10))
+        .await
+        .unwrap();
+    (world, stepper)
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn stepping_is_deterministic() {
+    init();
+    let ids = (0..6).map(|_| EntityId::new()).collect_vec();
+    let (mut a, mut stepper_a) = falling_cubes(&ids).await;
+    let (mut b, mut stepper_b) = falling_cubes(&ids).await;
+
+    stepper_a.step(&mut a, 120);
+    stepper_b.step(&mut b, 120);
+    assert_eq!(stepper_a.tick(), 120);
+    assert_eq!(PhysicsSnapshot::capture(&a), PhysicsSnapshot::capture(&b));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn snapshots_can_be_restored() {
+    init();
+    let ids = (0..6).map(|_| EntityId::new()).collect_vec();
+    let (mut world, mut stepper) = falling_cubes(&ids).await;
+
+    stepper.step(&mut world, 30);
+    let snapshot = PhysicsSnapshot::capture(&world);
+    assert_eq!(snapshot.bodies.len(), 5);
+    stepper.step(&mut world, 30);
+    let later = PhysicsSnapshot::capture(&world);
+    assert!(snapshot.max_difference(&later).unwrap() > 0.1);
+
+    snapshot.restore(&mut world);
+    let restored = PhysicsSnapshot::capture(&world);
+    assert!(snapshot.max_difference(&restored).unwrap() < 1e-5);
+    stepper.step(&mut world, 30);
+    let replayed = PhysicsSnapshot::capture(&world);
+    assert!(later.max_difference(&replayed).unwrap() < 0.05);
+
+    let bytes = bincode::serialize(&snapshot).unwrap();
+    assert_eq!(
+        bincode::deserialize::<PhysicsSnapshot>(&bytes).unwrap(),
+        snapshot
+    );
+}
@@ -61,6 +61,26 @@ pub struct ModelsPipeline {
     #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub transforms: Vec<ModelTransform>,
+    /// If specified, a list of named events to add to the animations of this model.
+    /// They are sent as `AnimationEvent` messages when an animation player crosses them.
+    ///
+    /// Events can also be specified in a `<model>.events.toml` file next to the model, with an `events` array of the same format.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub animation_events: Vec<AnimationEventMarker>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AnimationEventMarker {
+    /// The name of the animation to add this event to. If not specified, it is added to all animations of the model.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub clip: Option<String>,
+    /// The time of the event in seconds, relative to the start of the animation.
+    pub time: f32,
+    /// The name of the event, i.e. `footstep_left`.
+    pub name: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
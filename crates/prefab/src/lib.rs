@@ -1,8 +1,20 @@
-use std::{collections::HashMap, str::FromStr, sync::Arc};
+use std::{
+    collections::{HashMap, HashSet},
+    str::FromStr,
+    sync::Arc,
+};
 
-use ambient_core::{asset_cache, async_ecs::async_run, hierarchy::children, runtime};
+use ambient_core::{
+    asset_cache,
+    async_ecs::async_run,
+    hierarchy::{children, despawn_recursive, parent},
+    runtime,
+};
 use ambient_decals::decal;
-use ambient_ecs::{query, query_mut, DeserWorldWithWarnings, EntityId, SystemGroup, World};
+use ambient_ecs::{
+    components, query, query_mut, Debuggable, DeserWorldWithWarnings, Entity, EntityId, Store,
+    SystemGroup, World,
+};
 use ambient_model::model_from_url;
 use ambient_native_std::{
     asset_cache::{AssetCache, AsyncAssetKey, AsyncAssetKeyExt},
@@ -13,14 +25,25 @@ use ambient_native_std::{
 use anyhow::Context;
 use async_trait::async_trait;
 
-pub use ambient_ecs::generated::prefab::components::{prefab_from_url, spawned};
+pub use ambient_ecs::generated::prefab::components::{prefab_from_url, prefab_overrides, spawned};
+
+components!("prefab", {
+    /// The id in the prefab of an entity that was spawned from it
+    @[Debuggable, Store]
+    prefab_part: EntityId,
+    /// The entities that were spawned for this prefab instance, other than the instance itself
+    @[Debuggable, Store]
+    prefab_parts: Vec<EntityId>,
+    /// The URLs of the prefabs that this nested prefab was spawned from, to detect cycles
+    @[Debuggable, Store]
+    prefab_chain: Vec<String>,
+});
 
 pub fn systems() -> SystemGroup {
     SystemGroup::new(
         "prefab",
-        vec![query(prefab_from_url())
-            .spawned()
-            .to_system(|q, world, qs, _| {
+        vec![
+            query(prefab_from_url().changed()).to_system(|q, world, qs, _| {
                 let mut to_load = HashMap::<String, Vec<EntityId>>::new();
                 for (id, url) in q.collect_cloned(world, qs) {
                     let url = if url.ends_with("/prefabs/main.json") {
@@ -28,19 +51,24 @@ pub fn systems() -> SystemGroup {
                     } else {
                         format!("{url}/prefabs/main.json")
                     };
+                    if let Ok(chain) = world.get_ref(id, prefab_chain()) {
+                        if chain.contains(&url) {
+                            tracing::error!(
+                                "Prefab {url} contains itself, so it will not be spawned"
+                            );
+                            continue;
+                        }
+                    }
                     to_load.entry(url).or_default().push(id);
                 }
                 for (url, ids) in to_load {
                     let assets = world.resource(asset_cache()).clone();
-                    let url = unwrap_log_err!(AssetUrl::from_str(&url));
-                    let url = PrefabFromUrl(url);
+                    let prefab_url = unwrap_log_err!(AssetUrl::from_str(&url));
+                    let prefab_url = PrefabFromUrl(prefab_url);
                     let runtime = world.resource(runtime()).clone();
                     let async_run = world.resource(async_run()).clone();
                     runtime.spawn(async move {
-                        let obj = unwrap_log_err!(url.get(&assets).await);
-                        let base_ent_id = obj.resource(children())[0];
-                        // TODO: This only handles prefabs with a single entity
-                        let entity = obj.clone_entity(base_ent_id).unwrap();
+                        let prefab = unwrap_log_err!(prefab_url.get(&assets).await);
                         async_run.run(move |world| {
                             for id in ids {
                                 if !world.exists(id) {
@@ -48,16 +76,162 @@ pub fn systems() -> SystemGroup {
                                     continue;
                                 }
 
-                                world.add_components(id, entity.clone()).unwrap();
+                                instantiate(world, id, &url, &prefab);
                                 world.add_component(id, spawned(), ()).unwrap();
                             }
                         });
                     });
                 }
-            })],
+            }),
+        ],
     )
 }
 
+/// Spawns the entities of `prefab` for the instance `id`.
+///
+/// The components of the root of the prefab are added to the instance, and the rest of its
+/// entities are spawned as its descendants. If the instance was spawned before, the entities are
+/// updated in place, so that their `prefab_overrides` and any children added to them are kept.
+fn instantiate(world: &mut World, id: EntityId, url: &str, prefab: &World) {
+    let roots = prefab.resource(children()).clone();
+    let Some(&root) = roots.first() else {
+        return;
+    };
+
+    // The parts from the last time the prefab was spawned, by their id in the prefab
+    let old_parts = world
+        .get_cloned(id, prefab_parts())
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|part| Some((world.get(part, prefab_part()).ok()?, part)))
+        .collect::<HashMap<_, _>>();
+
+    // Any other roots of the prefab are spawned as children of the instance
+    let mut parents = roots[1..]
+        .iter()
+        .map(|prefab_id| (*prefab_id, root))
+        .collect::<HashMap<_, _>>();
+    let mut order = Vec::new();
+    let mut stack = roots.iter().rev().copied().collect::<Vec<_>>();
+    let mut visited = HashSet::new();
+    while let Some(prefab_id) = stack.pop() {
+        if !visited.insert(prefab_id) {
+            continue;
+        }
+        order.push(prefab_id);
+        if let Ok(prefab_children) = prefab.get_ref(prefab_id, children()) {
+            for child in prefab_children.iter().rev() {
+                parents.entry(*child).or_insert(prefab_id);
+                stack.push(*child);
+            }
+        }
+    }
+
+    // Only the entities that could be cloned get a part, and so do their descendants, so that no
+    // empty parts are left behind
+    let mut entities = Vec::new();
+    let mut ids = HashMap::from([(root, id)]);
+    for &prefab_id in &order {
+        let Ok(entity) = prefab.clone_entity(prefab_id) else {
+            continue;
+        };
+        if prefab_id != root {
+            if !ids.contains_key(&parents[&prefab_id]) {
+                continue;
+            }
+            let part = match old_parts.get(&prefab_id) {
+                Some(part) if world.exists(*part) => *part,
+                _ => Entity::new().spawn(world),
+            };
+            ids.insert(prefab_id, part);
+        }
+        entities.push((prefab_id, entity));
+    }
+    let parts = ids.values().copied().collect::<HashSet<_>>();
+
+    let mut chain = world.get_cloned(id, prefab_chain()).unwrap_or_default();
+    chain.push(url.to_string());
+
+    for (prefab_id, mut entity) in entities {
+        let entity_id = ids[&prefab_id];
+        remap_entity_ids(&mut entity, &ids);
+
+        // Keep the children that were not spawned from the prefab, like the ones added at runtime
+        let mut entity_children = entity.get_cloned(children()).unwrap_or_default();
+        if prefab_id == root {
+            entity_children.extend(roots[1..].iter().filter_map(|prefab_id| ids.get(prefab_id)));
+        }
+        entity_children.retain(|child| parts.contains(child));
+        if let Ok(current_children) = world.get_ref(entity_id, children()) {
+            for child in current_children {
+                if !parts.contains(child)
+                    && !old_parts.values().any(|part| part == child)
+                    && !entity_children.contains(child)
+                {
+                    entity_children.push(*child);
+                }
+            }
+        }
+        if !entity_children.is_empty() || world.has_component(entity_id, children()) {
+            entity.set(children(), entity_children);
+        }
+
+        if prefab_id == root {
+            // The root is the instance itself, which already has the URL of this prefab
+            entity.remove_self(prefab_from_url());
+        } else {
+            entity.set(parent(), ids[&parents[&prefab_id]]);
+            entity.set(prefab_part(), prefab_id);
+            if entity.contains(prefab_from_url()) {
+                entity.set(prefab_chain(), chain.clone());
+            }
+        }
+
+        if let Ok(overrides) = world.get_ref(entity_id, prefab_overrides()) {
+            entity.filter(&|desc| desc != prefab_overrides() && !overrides.contains(&desc.path()));
+        }
+        world.add_components(entity_id, entity).unwrap();
+    }
+
+    // Despawn the parts that were removed from the prefab, but not the ones that were moved
+    for (prefab_id, part) in &old_parts {
+        if ids.contains_key(prefab_id) || !world.exists(*part) {
+            continue;
+        }
+        if let Ok(part_children) = world.get_mut(*part, children()) {
+            part_children.retain(|child| !parts.contains(child));
+        }
+        despawn_recursive(world, *part);
+    }
+
+    let parts = order[1..]
+        .iter()
+        .filter_map(|prefab_id| ids.get(prefab_id).copied())
+        .collect();
+    world.add_component(id, prefab_parts(), parts).unwrap();
+}
+
+/// Replaces the ids of entities in the prefab with the ids of the entities spawned for them
+fn remap_entity_ids(entity: &mut Entity, ids: &HashMap<EntityId, EntityId>) {
+    for desc in entity.components() {
+        let Some(mut entry) = entity.remove_raw(desc) else {
+            continue;
+        };
+        if let Some(id) = entry.try_downcast_mut::<EntityId>() {
+            if let Some(new_id) = ids.get(id) {
+                *id = *new_id;
+            }
+        } else if let Some(list) = entry.try_downcast_mut::<Vec<EntityId>>() {
+            for id in list {
+                if let Some(new_id) = ids.get(id) {
+                    *id = *new_id;
+                }
+            }
+        }
+        entity.set_entry(entry);
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct PrefabFromUrl(pub AssetUrl);
 
@@ -84,6 +258,13 @@ impl AsyncAssetKey<Result<Arc<World>, AssetError>> for PrefabFromUrl {
                 .context("Failed to resolve model URL")?
                 .into();
         }
+        for (_id, (url,), _) in query_mut((prefab_from_url(),), ()).iter(&mut world, None) {
+            *url = AssetUrl::from_str(url)
+                .context("Invalid prefab URL")?
+                .resolve(&obj_url)
+                .context("Failed to resolve prefab URL")?
+                .into();
+        }
         #[cfg(not(target_os = "unknown"))]
         for (_id, (def,), _) in
             query_mut((ambient_physics::collider::collider(),), ()).iter(&mut world, None)
@@ -100,3 +281,74 @@ impl AsyncAssetKey<Result<Arc<World>, AssetError>> for PrefabFromUrl {
         Ok(Arc::new(world))
     }
 }
+
+#[cfg(test)]
+mod test {
+    use ambient_core::name;
+    use ambient_ecs::WorldContext;
+
+    use super::*;
+
+    /// A prefab where each part has a name and the indices of its children, and the first part is
+    /// the root
+    fn spawn_prefab(ids: &[EntityId], parts: &[(&str, &[usize])]) -> World {
+        let mut prefab = World::new("prefab", WorldContext::Prefab);
+        for (id, (part_name, part_children)) in ids.iter().zip(parts) {
+            let mut entity = Entity::new().with(name(), part_name.to_string());
+            if !part_children.is_empty() {
+                entity.set(children(), part_children.iter().map(|i| ids[*i]).collect());
+            }
+            prefab.spawn_with_id(*id, entity);
+        }
+        prefab.add_resource(children(), vec![ids[0]]);
+        prefab
+    }
+
+    #[test]
+    fn hierarchies_and_overrides() {
+        ambient_ecs::init_components();
+        init_components();
+
+        let mut world = World::new("prefab_instances", WorldContext::Unknown);
+        let instance = Entity::new().spawn(&mut world);
+        let name_of = |world: &World, id: EntityId| world.get_cloned(id, name()).unwrap();
+
+        let ids = (0..4).map(|_| EntityId::new()).collect::<Vec<_>>();
+        let prefab = spawn_prefab(
+            &[ids[0], ids[1], ids[2]],
+            &[("root", &[1]), ("wall", &[2]), ("window", &[])],
+        );
+        instantiate(&mut world, instance, "building", &prefab);
+        assert_eq!(name_of(&world, instance), "root");
+        let wall = world.get_ref(instance, children()).unwrap()[0];
+        assert_eq!(name_of(&world, wall), "wall");
+        assert_eq!(world.get(wall, parent()), Ok(instance));
+        let window = world.get_ref(wall, children()).unwrap()[0];
+        assert_eq!(name_of(&world, window), "window");
+        assert_eq!(world.get(window, parent()), Ok(wall));
+
+        // Override the name of the wall, and add a child to it at runtime
+        world.set(wall, name(), "painted wall".to_string()).unwrap();
+        world
+            .add_component(wall, prefab_overrides(), vec![name().path()])
+            .unwrap();
+        let poster = Entity::new().with(parent(), wall).spawn(&mut world);
+        world.get_mut(wall, children()).unwrap().push(poster);
+
+        // Reload the prefab with the window replaced by a door
+        let prefab = spawn_prefab(
+            &[ids[0], ids[1], ids[3]],
+            &[("new root", &[1]), ("new wall", &[2]), ("door", &[])],
+        );
+        instantiate(&mut world, instance, "building", &prefab);
+        assert_eq!(name_of(&world, instance), "new root");
+        assert_eq!(world.get_ref(instance, children()).unwrap(), &vec![wall]);
+        assert_eq!(name_of(&world, wall), "painted wall");
+        let wall_children = world.get_cloned(wall, children()).unwrap();
+        assert_eq!(wall_children.len(), 2);
+        assert_eq!(name_of(&world, wall_children[0]), "door");
+        assert_eq!(wall_children[1], poster);
+        assert!(world.exists(poster));
+        assert!(!world.exists(window));
+    }
+}
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Clone, Default, Debug)]
@@ -5,6 +7,10 @@ pub struct GeneralSettings {
     pub user_id: Option<String>,
     pub api_token: Option<String>,
     pub sentry: Sentry,
+    /// Memory budgets in megabytes for the assets which are only kept loaded by the asset cache,
+    /// per asset type, e.g. `texture = 512`. See `AssetCache::set_memory_budget`
+    #[serde(default)]
+    pub asset_memory_budgets: HashMap<String, u64>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -93,6 +93,7 @@ impl wit::server_physics::Host for Bindings {
         &mut self,
         _origin: wit::types::Vec3,
         _direction: wit::types::Vec3,
+        _layer_mask: u32,
     ) -> anyhow::Result<Option<(wit::types::EntityId, f32)>> {
         unsupported()
     }
@@ -101,10 +102,77 @@ impl wit::server_physics::Host for Bindings {
         &mut self,
         _origin: wit::types::Vec3,
         _direction: wit::types::Vec3,
+        _layer_mask: u32,
     ) -> anyhow::Result<Vec<(wit::types::EntityId, f32)>> {
         unsupported()
     }
 
+    fn sphere_cast(
+        &mut self,
+        _origin: wit::types::Vec3,
+        _radius: f32,
+        _direction: wit::types::Vec3,
+        _max_distance: f32,
+        _layer_mask: u32,
+    ) -> anyhow::Result<Vec<wit::server_physics::ShapeCastHit>> {
+        unsupported()
+    }
+
+    fn capsule_cast(
+        &mut self,
+        _origin: wit::types::Vec3,
+        _rotation: wit::types::Quat,
+        _radius: f32,
+        _height: f32,
+        _direction: wit::types::Vec3,
+        _max_distance: f32,
+        _layer_mask: u32,
+    ) -> anyhow::Result<Vec<wit::server_physics::ShapeCastHit>> {
+        unsupported()
+    }
+
+    fn box_cast(
+        &mut self,
+        _origin: wit::types::Vec3,
+        _rotation: wit::types::Quat,
+        _half_extents: wit::types::Vec3,
+        _direction: wit::types::Vec3,
+        _max_distance: f32,
+        _layer_mask: u32,
+    ) -> anyhow::Result<Vec<wit::server_physics::ShapeCastHit>> {
+        unsupported()
+    }
+
+    fn overlap_sphere(
+        &mut self,
+        _center: wit::types::Vec3,
+        _radius: f32,
+        _layer_mask: u32,
+    ) -> anyhow::Result<Vec<wit::types::EntityId>> {
+        unsupported()
+    }
+
+    fn overlap_capsule(
+        &mut self,
+        _center: wit::types::Vec3,
+        _rotation: wit::types::Quat,
+        _radius: f32,
+        _height: f32,
+        _layer_mask: u32,
+    ) -> anyhow::Result<Vec<wit::types::EntityId>> {
+        unsupported()
+    }
+
+    fn overlap_box(
+        &mut self,
+        _center: wit::types::Vec3,
+        _rotation: wit::types::Quat,
+        _half_extents: wit::types::Vec3,
+        _layer_mask: u32,
+    ) -> anyhow::Result<Vec<wit::types::EntityId>> {
+        unsupported()
+    }
+
     fn move_character(
         &mut self,
         _entity: wit::types::EntityId,
@@ -142,7 +210,14 @@ impl wit::server_message::Host for Bindings {
     }
 }
 impl wit::server_http::Host for Bindings {
-    fn get(&mut self, _: String) -> anyhow::Result<()> {
+    fn request(
+        &mut self,
+        _: wit::server_http::Method,
+        _: String,
+        _: Vec<(String, String)>,
+        _: Vec<u8>,
+        _: Option<u32>,
+    ) -> anyhow::Result<u64> {
         unsupported()
     }
 }
@@ -151,3 +226,16 @@ impl wit::server_ambient_package::Host for Bindings {
         unsupported()
     }
 }
+impl wit::server_instance::Host for Bindings {
+    fn create(&mut self, _: String) -> anyhow::Result<()> {
+        unsupported()
+    }
+
+    fn destroy(&mut self, _: String) -> anyhow::Result<()> {
+        unsupported()
+    }
+
+    fn transfer_player(&mut self, _: String, _: String) -> anyhow::Result<()> {
+        unsupported()
+    }
+}
@@ -5,6 +5,7 @@ use std::sync::Arc;
 
 mod implementation;
 mod network;
+pub(crate) mod trigger;
 
 pub fn initialize(
     world: &mut World,
@@ -28,7 +29,10 @@ pub fn initialize(
     Ok(())
 }
 pub fn systems() -> SystemGroup {
-    SystemGroup::new("core/wasm/client", vec![Box::new(shared::systems())])
+    SystemGroup::new(
+        "core/wasm/client",
+        vec![Box::new(trigger::systems()), Box::new(shared::systems())],
+    )
 }
 
 #[derive(Clone)]
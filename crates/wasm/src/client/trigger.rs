@@ -0,0 +1,144 @@
+//! The client has no physics scene, so `TriggerEnter` and `TriggerExit` are approximated here
+//! for clientside modules. The server remains authoritative.
+
+use ambient_core::transform::{get_world_position, get_world_transform};
+use ambient_ecs::{
+    components,
+    generated::{
+        messages,
+        physics::components::{
+            character_controller_height, collider_from_url, collides_with, collision_layer,
+            cube_collider, is_trigger, sphere_collider,
+        },
+    },
+    query, EntityId, FnSystem, SystemGroup, World,
+};
+use glam::Vec3;
+use itertools::Itertools;
+
+use crate::shared::message::MessageExt;
+
+components!("wasm::client", {
+    /// The entities that were inside this trigger when triggers were last approximated.
+    trigger_preview_overlaps: Vec<EntityId>,
+});
+
+/// Colliders without a `collision_layer`, or with a `collision_layer` of 0, are on this layer.
+const DEFAULT_LAYER: u32 = 1;
+
+pub fn systems() -> SystemGroup {
+    SystemGroup::new(
+        "core/wasm/client/trigger",
+        vec![Box::new(FnSystem::new(|world, _| {
+            profiling::scope!("WASM module trigger preview events");
+            preview_trigger_events(world);
+        }))],
+    )
+}
+
+/// Runs `TriggerEnter` and `TriggerExit` for the colliders that entered or left each trigger
+/// since the last call.
+///
+/// Only sphere and cube triggers are supported, and other colliders are treated as points at
+/// their position.
+fn preview_trigger_events(world: &mut World) {
+    let others = [
+        query(()).incl(sphere_collider()),
+        query(()).incl(cube_collider()),
+        query(()).incl(collider_from_url()),
+        query(()).incl(character_controller_height()),
+    ]
+    .into_iter()
+    .flat_map(|q| {
+        q.excl(is_trigger())
+            .iter(world, None)
+            .map(|(id, _)| id)
+            .collect_vec()
+    })
+    .unique()
+    .filter_map(|id| Some((id, get_world_position(world, id).ok()?)))
+    .collect_vec();
+
+    for (trigger, _) in query(()).incl(is_trigger()).collect_cloned(world, None) {
+        let Some(volume) = TriggerVolume::get(world, trigger) else {
+            continue;
+        };
+        let overlaps = others
+            .iter()
+            .filter(|(other, position)| {
+                layers_interact(world, trigger, *other) && volume.contains(*position)
+            })
+            .map(|(other, _)| *other)
+            .collect_vec();
+
+        let previous = world
+            .get_ref(trigger, trigger_preview_overlaps())
+            .cloned()
+            .unwrap_or_default();
+        for &other in overlaps.iter().filter(|other| !previous.contains(other)) {
+            messages::TriggerEnter::new(trigger, other)
+                .run(world, None)
+                .unwrap();
+        }
+        for &other in previous.iter().filter(|other| !overlaps.contains(other)) {
+            messages::TriggerExit::new(trigger, other)
+                .run(world, None)
+                .unwrap();
+        }
+        world
+            .add_component(trigger, trigger_preview_overlaps(), overlaps)
+            .unwrap();
+    }
+}
+
+enum TriggerShape {
+    Sphere { radius: f32 },
+    Cube { half_extents: Vec3 },
+}
+
+struct TriggerVolume {
+    shape: TriggerShape,
+    rotation: glam::Quat,
+    translation: Vec3,
+}
+impl TriggerVolume {
+    fn get(world: &World, id: EntityId) -> Option<Self> {
+        let (scale, rotation, translation) = get_world_transform(world, id)
+            .ok()?
+            .to_scale_rotation_translation();
+        // Scaled the same way as the colliders on the server
+        let shape = match world.get(id, sphere_collider()) {
+            Ok(radius) => TriggerShape::Sphere {
+                radius: radius * scale.x,
+            },
+            Err(_) => TriggerShape::Cube {
+                half_extents: world.get(id, cube_collider()).ok()? * scale / 2.,
+            },
+        };
+        Some(Self {
+            shape,
+            rotation,
+            translation,
+        })
+    }
+
+    fn contains(&self, point: Vec3) -> bool {
+        let local = self.rotation.inverse() * (point - self.translation);
+        match self.shape {
+            TriggerShape::Sphere { radius } => local.length() <= radius,
+            TriggerShape::Cube { half_extents } => local.abs().cmple(half_extents).all(),
+        }
+    }
+}
+
+fn layers_interact(world: &World, a: EntityId, b: EntityId) -> bool {
+    let layer = |id| {
+        world
+            .get(id, collision_layer())
+            .ok()
+            .filter(|layer| *layer != 0)
+            .unwrap_or(DEFAULT_LAYER)
+    };
+    let mask = |id| world.get(id, collides_with()).unwrap_or(u32::MAX);
+    layer(a) & mask(b) != 0 && layer(b) & mask(a) != 0
+}
@@ -2,7 +2,11 @@
 //!
 //! If implementing a trait that is also available on the client, it should go in [super].
 
-use std::str::FromStr;
+use std::{
+    str::FromStr,
+    sync::atomic::{AtomicU64, Ordering},
+    time::Duration,
+};
 
 use ambient_core::{
     asset_cache,
@@ -10,9 +14,14 @@ use ambient_core::{
     player::{is_player, user_id},
     runtime,
 };
-use ambient_ecs::{generated::messages::HttpResponse, query, EntityId, World};
+use ambient_ecs::{
+    generated::{messages::HttpResponse, wasm::components::package_ref},
+    query, EntityId, World,
+};
 use ambient_native_std::asset_url::AbsAssetUrl;
-use ambient_network::server::player_transport;
+use ambient_network::server::{player_transport, request_instance_command, InstanceCommand};
+use ambient_package_semantic_native::http_settings;
+use once_cell::sync::Lazy;
 
 use super::super::Bindings;
 
@@ -94,39 +103,67 @@ fn send_networked(
     Ok(())
 }
 
+static NEXT_HTTP_REQUEST_ID: AtomicU64 = AtomicU64::new(1);
+
+/// Shared by all modules so that connections are pooled. Redirects are returned to the module
+/// instead of being followed, as they could lead to a host that the package is not allowed to use.
+static HTTP_CLIENT: Lazy<reqwest::Client> = Lazy::new(|| {
+    reqwest::Client::builder()
+        .redirect(reqwest::redirect::Policy::none())
+        .build()
+        .expect("Failed to create HTTP client")
+});
+
 impl shared::wit::server_http::Host for Bindings {
-    fn get(&mut self, url: String) -> wasm_bridge::Result<()> {
+    fn request(
+        &mut self,
+        method: shared::wit::server_http::Method,
+        url: String,
+        headers: Vec<(String, String)>,
+        body: Vec<u8>,
+        timeout_ms: Option<u32>,
+    ) -> wasm_bridge::Result<u64> {
+        let request_id = NEXT_HTTP_REQUEST_ID.fetch_add(1, Ordering::Relaxed);
         let id = self.id;
         let world = self.world_mut();
-        let assets = world.resource(asset_cache());
         let runtime = world.resource(runtime());
         let async_run = world.resource(async_run()).clone();
 
-        async fn make_request(url: String) -> wasm_bridge::Result<(u32, Vec<u8>)> {
-            let response = reqwest::get(url).await?;
-            Ok((
-                response.status().as_u16() as u32,
-                response.bytes().await?.to_vec(),
-            ))
-        }
-
-        let resolved_url = AbsAssetUrl::from_str(&url)?
-            .to_download_url(assets)?
-            .to_string();
+        // Errors are reported through the response, so that the module can handle them like
+        // any other failed request
+        let request = build_http_request(world, id, method, &url, headers, body, timeout_ms);
 
         runtime.spawn(async move {
-            let result = make_request(resolved_url).await;
-            let response = match result {
-                Ok((status, body)) => HttpResponse {
+            async fn send(
+                request: anyhow::Result<reqwest::RequestBuilder>,
+            ) -> anyhow::Result<(u32, Vec<String>, Vec<u8>)> {
+                let response = request?.send().await?;
+                let status = response.status().as_u16() as u32;
+                let headers = response
+                    .headers()
+                    .iter()
+                    .map(|(name, value)| {
+                        format!("{name}: {}", String::from_utf8_lossy(value.as_bytes()))
+                    })
+                    .collect();
+                Ok((status, headers, response.bytes().await?.to_vec()))
+            }
+
+            let response = match send(request).await {
+                Ok((status, headers, body)) => HttpResponse {
+                    id: request_id,
                     url,
-                    body,
                     status,
+                    headers,
+                    body,
                     error: None,
                 },
                 Err(err) => HttpResponse {
+                    id: request_id,
                     url,
-                    body: Vec::new(),
                     status: 0,
+                    headers: Vec::new(),
+                    body: Vec::new(),
                     error: Some(err.to_string()),
                 },
             };
@@ -136,13 +173,83 @@ impl shared::wit::server_http::Host for Bindings {
             });
         });
 
-        Ok(())
+        Ok(request_id)
     }
 }
 
+fn build_http_request(
+    world: &World,
+    module_id: EntityId,
+    method: shared::wit::server_http::Method,
+    url: &str,
+    headers: Vec<(String, String)>,
+    body: Vec<u8>,
+    timeout_ms: Option<u32>,
+) -> anyhow::Result<reqwest::RequestBuilder> {
+    use shared::wit::server_http::Method;
+
+    let url = AbsAssetUrl::from_str(url)?;
+    // Asset URLs are resolved by the asset cache and are always allowed; anything else must be
+    // allowed by the manifest of the module's package
+    if matches!(url.0.scheme(), "http" | "https") {
+        let host = url.0.host_str().unwrap_or_default();
+        let allowed = world
+            .get(module_id, package_ref())
+            .ok()
+            .and_then(|package| world.get_ref(package, http_settings()).ok())
+            .is_some_and(|http| http.allows_host(host));
+        anyhow::ensure!(
+            allowed,
+            "the host `{host}` is not in the `allowed_hosts` of the package's `[http]` section"
+        );
+    }
+    let url = url.to_download_url(world.resource(asset_cache()))?;
+
+    let method = match method {
+        Method::Get => reqwest::Method::GET,
+        Method::Head => reqwest::Method::HEAD,
+        Method::Post => reqwest::Method::POST,
+        Method::Put => reqwest::Method::PUT,
+        Method::Patch => reqwest::Method::PATCH,
+        Method::Delete => reqwest::Method::DELETE,
+    };
+
+    let mut request = HTTP_CLIENT.request(method, url.0).body(body);
+    for (name, value) in headers {
+        request = request.header(name, value);
+    }
+    if let Some(timeout_ms) = timeout_ms {
+        request = request.timeout(Duration::from_millis(timeout_ms.into()));
+    }
+    Ok(request)
+}
+
 impl shared::wit::server_ambient_package::Host for Bindings {
     fn load(&mut self, url: String) -> anyhow::Result<()> {
         ambient_package_semantic_native::add(self.world_mut(), url, false)?;
         Ok(())
     }
 }
+
+impl shared::wit::server_instance::Host for Bindings {
+    fn create(&mut self, id: String) -> anyhow::Result<()> {
+        request_instance_command(self.world_mut(), InstanceCommand::Create(id));
+        Ok(())
+    }
+
+    fn destroy(&mut self, id: String) -> anyhow::Result<()> {
+        request_instance_command(self.world_mut(), InstanceCommand::Destroy(id));
+        Ok(())
+    }
+
+    fn transfer_player(&mut self, user_id: String, instance_id: String) -> anyhow::Result<()> {
+        request_instance_command(
+            self.world_mut(),
+            InstanceCommand::TransferPlayer {
+                user_id,
+                instance_id,
+            },
+        );
+        Ok(())
+    }
+}
@@ -5,9 +5,11 @@ use crate::shared::{
     wit,
 };
 use ambient_native_std::shapes::Ray;
-use ambient_physics::physx::character_controller;
+use ambient_physics::{
+    helpers::character_controller_filters, intersection::QueryShape, physx::character_controller,
+};
 use anyhow::Context;
-use physxx::{PxControllerCollisionFlag, PxControllerFilters};
+use physxx::PxControllerCollisionFlag;
 
 impl shared::wit::server_physics::Host for Bindings {
     fn add_force(
@@ -169,11 +171,13 @@ impl shared::wit::server_physics::Host for Bindings {
         &mut self,
         origin: wit::types::Vec3,
         direction: wit::types::Vec3,
+        layer_mask: u32,
     ) -> anyhow::Result<Option<(wit::types::EntityId, f32)>> {
         let direction = get_raycast_direction(direction)?;
-        let result = ambient_physics::intersection::raycast_first(
+        let result = ambient_physics::intersection::raycast_first_in_layers(
             self.world(),
             Ray::new(origin.from_bindgen(), direction),
+            layer_mask,
         )
         .map(|t| (t.0.into_bindgen(), t.1.into_bindgen()));
 
@@ -184,11 +188,13 @@ impl shared::wit::server_physics::Host for Bindings {
         &mut self,
         origin: wit::types::Vec3,
         direction: wit::types::Vec3,
+        layer_mask: u32,
     ) -> anyhow::Result<Vec<(wit::types::EntityId, f32)>> {
         let direction = get_raycast_direction(direction)?;
-        let result = ambient_physics::intersection::raycast(
+        let result = ambient_physics::intersection::raycast_in_layers(
             self.world(),
             Ray::new(origin.from_bindgen(), direction),
+            layer_mask,
         )
         .into_iter()
         .map(|t| (t.0.into_bindgen(), t.1.into_bindgen()))
@@ -197,6 +203,118 @@ impl shared::wit::server_physics::Host for Bindings {
         Ok(result)
     }
 
+    fn sphere_cast(
+        &mut self,
+        origin: wit::types::Vec3,
+        radius: f32,
+        direction: wit::types::Vec3,
+        max_distance: f32,
+        layer_mask: u32,
+    ) -> anyhow::Result<Vec<wit::server_physics::ShapeCastHit>> {
+        shape_cast(
+            self.world(),
+            QueryShape::Sphere { radius },
+            origin,
+            glam::Quat::IDENTITY,
+            direction,
+            max_distance,
+            layer_mask,
+        )
+    }
+
+    fn capsule_cast(
+        &mut self,
+        origin: wit::types::Vec3,
+        rotation: wit::types::Quat,
+        radius: f32,
+        height: f32,
+        direction: wit::types::Vec3,
+        max_distance: f32,
+        layer_mask: u32,
+    ) -> anyhow::Result<Vec<wit::server_physics::ShapeCastHit>> {
+        shape_cast(
+            self.world(),
+            QueryShape::Capsule { radius, height },
+            origin,
+            rotation.from_bindgen(),
+            direction,
+            max_distance,
+            layer_mask,
+        )
+    }
+
+    fn box_cast(
+        &mut self,
+        origin: wit::types::Vec3,
+        rotation: wit::types::Quat,
+        half_extents: wit::types::Vec3,
+        direction: wit::types::Vec3,
+        max_distance: f32,
+        layer_mask: u32,
+    ) -> anyhow::Result<Vec<wit::server_physics::ShapeCastHit>> {
+        shape_cast(
+            self.world(),
+            QueryShape::Box {
+                half_extents: half_extents.from_bindgen(),
+            },
+            origin,
+            rotation.from_bindgen(),
+            direction,
+            max_distance,
+            layer_mask,
+        )
+    }
+
+    fn overlap_sphere(
+        &mut self,
+        center: wit::types::Vec3,
+        radius: f32,
+        layer_mask: u32,
+    ) -> anyhow::Result<Vec<wit::types::EntityId>> {
+        overlap(
+            self.world(),
+            QueryShape::Sphere { radius },
+            center.from_bindgen(),
+            glam::Quat::IDENTITY,
+            layer_mask,
+        )
+    }
+
+    fn overlap_capsule(
+        &mut self,
+        center: wit::types::Vec3,
+        rotation: wit::types::Quat,
+        radius: f32,
+        height: f32,
+        layer_mask: u32,
+    ) -> anyhow::Result<Vec<wit::types::EntityId>> {
+        overlap(
+            self.world(),
+            QueryShape::Capsule { radius, height },
+            center.from_bindgen(),
+            rotation.from_bindgen(),
+            layer_mask,
+        )
+    }
+
+    fn overlap_box(
+        &mut self,
+        center: wit::types::Vec3,
+        rotation: wit::types::Quat,
+        half_extents: wit::types::Vec3,
+        layer_mask: u32,
+    ) -> anyhow::Result<Vec<wit::types::EntityId>> {
+        overlap(
+            self.world(),
+            QueryShape::Box {
+                half_extents: half_extents.from_bindgen(),
+            },
+            center.from_bindgen(),
+            rotation.from_bindgen(),
+            layer_mask,
+        )
+    }
+
     fn move_character(
         &mut self,
         entity: wit::types::EntityId,
@@ -204,16 +322,14 @@ impl shared::wit::server_physics::Host for Bindings {
         min_dist: f32,
         elapsed_time: f32,
     ) -> anyhow::Result<wit::server_physics::CharacterCollision> {
-        match self
-            .world()
-            .get(entity.from_bindgen(), character_controller())
-        {
+        let entity = entity.from_bindgen();
+        match self.world().get(entity, character_controller()) {
             Ok(controller) => {
                 let res = controller.move_controller(
                     displacement.from_bindgen(),
                     min_dist,
                     elapsed_time,
-                    &PxControllerFilters::new(),
+                    &character_controller_filters(self.world(), entity),
                     None,
                 );
                 Ok(wit::server_physics::CharacterCollision {
@@ -267,3 +383,63 @@ fn get_raycast_direction(direction: wit::types::Vec3) -> anyhow::Result<glam::Ve
     }
     Ok(direction)
 }
+
+fn shape_cast(
+    world: &ambient_ecs::World,
+    shape: QueryShape,
+    origin: wit::types::Vec3,
+    rotation: glam::Quat,
+    direction: wit::types::Vec3,
+    max_distance: f32,
+    layer_mask: u32,
+) -> anyhow::Result<Vec<wit::server_physics::ShapeCastHit>> {
+    check_query_shape(&shape)?;
+    let direction = get_raycast_direction(direction)?;
+    if max_distance.is_nan() || max_distance < 0. {
+        anyhow::bail!("Shape cast distance must be non-negative");
+    }
+    Ok(ambient_physics::intersection::shape_cast(
+        world,
+        shape,
+        origin.from_bindgen(),
+        rotation,
+        direction,
+        max_distance,
+        layer_mask,
+    )
+    .into_iter()
+    .map(|hit| wit::server_physics::ShapeCastHit {
+        entity: hit.entity.into_bindgen(),
+        distance: hit.distance,
+        position: hit.position.into_bindgen(),
+        normal: hit.normal.into_bindgen(),
+    })
+    .collect())
+}
+
+fn overlap(
+    world: &ambient_ecs::World,
+    shape: QueryShape,
+    center: glam::Vec3,
+    rotation: glam::Quat,
+    layer_mask: u32,
+) -> anyhow::Result<Vec<wit::types::EntityId>> {
+    check_query_shape(&shape)?;
+    Ok(
+        ambient_physics::intersection::overlap(world, shape, center, rotation, layer_mask)
+            .into_bindgen(),
+    )
+}
+
+/// Returns an error if any dimension of the shape is not positive.
+fn check_query_shape(shape: &QueryShape) -> anyhow::Result<()> {
+    let valid = match *shape {
+        QueryShape::Sphere { radius } => radius > 0.,
+        QueryShape::Capsule { radius, height } => radius > 0. && height > 0.,
+        QueryShape::Box { half_extents } => half_extents.cmpgt(glam::Vec3::ZERO).all(),
+    };
+    if !valid {
+        anyhow::bail!("Shape dimensions must be positive: {shape:?}");
+    }
+    Ok(())
+}
@@ -1,7 +1,12 @@
 use crate::shared::{self, message::MessageExt};
+use ambient_animation::{crossed_animation_events, CrossedAnimationEvent};
 use ambient_ecs::{generated::messages, query, EntityId, FnSystem, SystemGroup, World};
 use ambient_native_std::asset_cache::AssetCache;
 use ambient_network::server::{ForkingEvent, ShutdownEvent};
+use ambient_physics::{
+    contact::{ContactEvent, ContactPhase},
+    trigger::TriggerEvent,
+};
 use std::{path::PathBuf, sync::Arc};
 
 mod implementation;
@@ -47,6 +52,88 @@ pub fn systems() -> SystemGroup {
                         .unwrap();
                 }
             })),
+            Box::new(FnSystem::new(move |world, _| {
+                profiling::scope!("WASM module trigger events");
+                let trigger_events = match world.resource_opt(ambient_physics::trigger_events()) {
+                    Some(trigger_events) => trigger_events.lock().clone(),
+                    None => return,
+                };
+                for event in trigger_events {
+                    match event {
+                        TriggerEvent::Enter { trigger, other } => {
+                            messages::TriggerEnter::new(trigger, other)
+                                .run(world, None)
+                                .unwrap()
+                        }
+                        TriggerEvent::Exit { trigger, other } => {
+                            messages::TriggerExit::new(trigger, other)
+                                .run(world, None)
+                                .unwrap()
+                        }
+                    }
+                }
+            })),
+            Box::new(FnSystem::new(move |world, _| {
+                profiling::scope!("WASM module contact events");
+                let contact_events = match world.resource_opt(ambient_physics::contact_events()) {
+                    Some(contact_events) => contact_events.lock().clone(),
+                    None => return,
+                };
+                for event in contact_events {
+                    let ContactEvent {
+                        a,
+                        b,
+                        phase,
+                        points,
+                        normals,
+                        impulse,
+                        relative_velocity,
+                    } = event;
+                    match phase {
+                        ContactPhase::Begin => messages::ContactBegin::new(
+                            a,
+                            b,
+                            points,
+                            normals,
+                            impulse,
+                            relative_velocity,
+                        )
+                        .run(world, None)
+                        .unwrap(),
+                        ContactPhase::Persist => messages::ContactPersist::new(
+                            a,
+                            b,
+                            points,
+                            normals,
+                            impulse,
+                            relative_velocity,
+                        )
+                        .run(world, None)
+                        .unwrap(),
+                        ContactPhase::End => {
+                            messages::ContactEnd::new(a, b).run(world, None).unwrap()
+                        }
+                    }
+                }
+            })),
+            Box::new(FnSystem::new(move |world, _| {
+                profiling::scope!("WASM module animation events");
+                let players = query(crossed_animation_events())
+                    .iter(world, None)
+                    .filter(|(_, events)| !events.is_empty())
+                    .map(|(id, _)| id)
+                    .collect::<Vec<_>>();
+
+                for player in players {
+                    let events =
+                        std::mem::take(world.get_mut(player, crossed_animation_events()).unwrap());
+                    for CrossedAnimationEvent { node, name, weight } in events {
+                        messages::AnimationEvent::new(player, node, name, weight)
+                            .run(world, None)
+                            .unwrap();
+                    }
+                }
+            })),
             Box::new(FnSystem::new(move |world, _| {
                 profiling::scope!("WASM module collider loads");
                 // trigger collider loads
@@ -72,8 +159,8 @@ pub fn on_forking_systems() -> SystemGroup<ForkingEvent> {
     SystemGroup::new(
         "core/wasm/server/on_forking_systems",
         vec![Box::new(FnSystem::new(move |world, _| {
-            // Reset the states of all the modules when we fork.
-            shared::reload_all(world);
+            // Restart all the modules in the fork, leaving the original world's modules running.
+            shared::restart_all_forked(world);
         }))],
     )
 }
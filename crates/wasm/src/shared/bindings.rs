@@ -41,6 +41,7 @@ pub trait BindingsBound:
     + super::wit::server_physics::Host
     + super::wit::server_http::Host
     + super::wit::server_ambient_package::Host
+    + super::wit::server_instance::Host
     + Clone
     + Sync
     + Send
@@ -81,6 +81,7 @@ pub fn init_all_components() {
     #[cfg(not(target_os = "unknown"))]
     native_bindings::init_components();
     message::init_components();
+    crate::client::trigger::init_components();
 }
 
 pub const MAXIMUM_ERROR_COUNT: usize = 5;
@@ -269,15 +270,35 @@ pub fn initialize<'a, Bindings: bindings::BindingsBound + 'static>(
     Ok(())
 }
 
+/// Restarts all modules in a world that was forked from another world.
+///
+/// The module states are shared with the original world, so they are replaced instead of being
+/// unloaded, which would affect the modules running in the original world.
 #[cfg(not(target_os = "unknown"))]
-pub(crate) fn reload_all(world: &mut World) {
+pub(crate) fn restart_all_forked(world: &mut World) {
     let modules = query((is_module(), module_bytecode(), module_enabled()))
         .iter(world, None)
         .map(|(id, (_, bc, enabled))| (id, enabled.then(|| bc.clone())))
         .collect_vec();
 
     for (module_id, bytecode) in modules {
-        reload(world, module_id, bytecode);
+        let spawned_entities = world
+            .get_ref(module_id, module_state())
+            .map(|sms| sms.spawned_entities())
+            .unwrap_or_default();
+        for id in spawned_entities {
+            if !world.has_component(id, dont_despawn_on_unload()) {
+                world.despawn(id);
+            }
+        }
+        world.remove_component(module_id, module_state()).ok();
+        if let Ok(module_errors) = world.get_mut(module_id, module_errors()) {
+            module_errors.0.clear();
+        }
+
+        if let Some(bytecode) = bytecode.filter(|bc| !bc.0.is_empty()) {
+            load(world, module_id, &bytecode.0);
+        }
     }
 }
 
@@ -0,0 +1,225 @@
+use std::collections::HashMap;
+
+use ambient_audio::{BusEffect, BusId, BusSettings, Ducking};
+use ambient_ecs::{
+    generated::audio::components::*, query, Component, ComponentValue, DynSystem, EntityId,
+    SystemGroup, World,
+};
+
+use crate::audio_bus_owners;
+
+/// The bus a player plays its sounds on, from its `audio_bus` component
+pub fn get_audio_bus(world: &World, id: EntityId) -> BusId {
+    world
+        .get_ref(id, audio_bus())
+        .ok()
+        .and_then(|name| parse_bus(name))
+        .unwrap_or_default()
+}
+
+fn parse_bus(name: &str) -> Option<BusId> {
+    match name.parse() {
+        Ok(bus) => Some(bus),
+        Err(err) => {
+            tracing::warn!("{err}");
+            None
+        }
+    }
+}
+
+/// The settings of the bus configured by an entity with `is_audio_bus`
+fn bus_settings(world: &World, id: EntityId) -> BusSettings {
+    let mut effects = Vec::new();
+    if let Ok(high_pass) = world.get(id, audio_bus_high_pass()) {
+        effects.push(BusEffect::HighPass {
+            freq: high_pass.x,
+            bandwidth: high_pass.y,
+        });
+    }
+    if let Ok(low_pass) = world.get(id, audio_bus_low_pass()) {
+        effects.push(BusEffect::LowPass {
+            freq: low_pass.x,
+            bandwidth: low_pass.y,
+        });
+    }
+    if let Ok(eq) = world.get(id, audio_bus_eq()) {
+        effects.push(BusEffect::Equalizer {
+            low: eq.x,
+            mid: eq.y,
+            high: eq.z,
+        });
+    }
+    if let Ok(compressor) = world.get(id, audio_bus_compressor()) {
+        effects.push(BusEffect::Compressor {
+            threshold: compressor.x,
+            ratio: compressor.y,
+            attack: compressor.z,
+            release: compressor.w,
+        });
+    }
+    if let Ok(delay) = world.get(id, audio_bus_delay()) {
+        effects.push(BusEffect::Delay {
+            time: delay.x,
+            feedback: delay.y,
+            wet: delay.z,
+        });
+    }
+    if let Ok(reverb) = world.get(id, audio_bus_reverb()) {
+        effects.push(BusEffect::Reverb {
+            room_size: reverb.x,
+            damping: reverb.y,
+            wet: reverb.z,
+        });
+    }
+
+    let ducking = world
+        .get_ref(id, audio_bus_ducked_by())
+        .ok()
+        .and_then(|key| parse_bus(key))
+        .map(|key| match world.get(id, audio_bus_ducking()) {
+            Ok(ducking) => Ducking {
+                key,
+                amount: ducking.x,
+                threshold: ducking.y,
+                attack: ducking.z,
+                release: ducking.w,
+            },
+            Err(_) => Ducking {
+                key,
+                ..Default::default()
+            },
+        });
+
+    BusSettings {
+        volume: world.get(id, audio_bus_volume()).unwrap_or(1.0),
+        muted: world.get(id, audio_bus_muted()).unwrap_or(false),
+        effects,
+        ducking,
+    }
+}
+
+/// Applies the settings of the last owner of `bus` that still configures it, or resets it if
+/// there is none.
+fn update_bus(world: &World, owners: &mut HashMap<BusId, Vec<EntityId>>, bus: BusId) {
+    let Some(mixer) = world.resource_opt(crate::audio_mixer()) else {
+        return;
+    };
+    let bus_owners = owners.entry(bus).or_default();
+    bus_owners.retain(|&id| {
+        world.has_component(id, is_audio_bus())
+            && world
+                .get_ref(id, audio_bus())
+                .map_or(false, |name| name.parse::<BusId>().ok() == Some(bus))
+    });
+    match bus_owners.last() {
+        Some(&owner) => mixer.set_bus(bus, bus_settings(world, owner)),
+        None => mixer.set_bus(bus, BusSettings::default()),
+    }
+}
+
+/// Updates the buses of the entities in `ids` when they stop configuring a component, e.g. when
+/// an effect is removed
+fn update_buses_of(world: &mut World, ids: Vec<EntityId>) {
+    if ids.is_empty() {
+        return;
+    }
+    let mut owners = world
+        .resource_mut_opt(audio_bus_owners())
+        .map(std::mem::take)
+        .unwrap_or_default();
+    let buses = owners
+        .iter()
+        .filter(|(_, bus_owners)| bus_owners.iter().any(|id| ids.contains(id)))
+        .map(|(&bus, _)| bus)
+        .collect::<Vec<_>>();
+    for bus in buses {
+        update_bus(world, &mut owners, bus);
+    }
+    world.add_resource(audio_bus_owners(), owners);
+}
+
+/// Updates the buses of the entities which had `component`, when it's removed
+fn removed_system<T: ComponentValue>(component: Component<T>) -> DynSystem {
+    query(())
+        .incl(is_audio_bus())
+        .incl(component)
+        .despawned()
+        .to_system(|q, world, qs, _| {
+            let ids = q.iter(world, qs).map(|(id, _)| id).collect();
+            update_buses_of(world, ids);
+        })
+}
+
+/// Applies the `is_audio_bus` entities to the buses of the mixer.
+///
+/// When several entities configure the same bus, the one that changed last is applied, and the
+/// bus goes back to the defaults when none is left.
+pub fn bus_systems() -> SystemGroup {
+    SystemGroup::new(
+        "audio/buses",
+        vec![
+            query(audio_bus().changed())
+                .incl(is_audio_bus())
+                .optional_changed(audio_bus_volume())
+                .optional_changed(audio_bus_muted())
+                .optional_changed(audio_bus_ducked_by())
+                .optional_changed(audio_bus_ducking())
+                .optional_changed(audio_bus_high_pass())
+                .optional_changed(audio_bus_low_pass())
+                .optional_changed(audio_bus_eq())
+                .optional_changed(audio_bus_compressor())
+                .optional_changed(audio_bus_delay())
+                .optional_changed(audio_bus_reverb())
+                .to_system(|q, world, qs, _| {
+                    let changed = q
+                        .iter(world, qs)
+                        .map(|(id, name)| (id, parse_bus(name)))
+                        .collect::<Vec<_>>();
+                    if changed.is_empty() {
+                        return;
+                    }
+                    let mut owners = world
+                        .resource_mut_opt(audio_bus_owners())
+                        .map(std::mem::take)
+                        .unwrap_or_default();
+                    for (id, bus) in changed {
+                        // The entity may have configured another bus before
+                        let previous = owners
+                            .iter()
+                            .filter(|(_, bus_owners)| bus_owners.contains(&id))
+                            .map(|(&bus, _)| bus)
+                            .collect::<Vec<_>>();
+                        for bus_owners in owners.values_mut() {
+                            bus_owners.retain(|&owner| owner != id);
+                        }
+                        for previous in previous {
+                            update_bus(world, &mut owners, previous);
+                        }
+                        if let Some(bus) = bus {
+                            owners.entry(bus).or_default().push(id);
+                            update_bus(world, &mut owners, bus);
+                        }
+                    }
+                    world.add_resource(audio_bus_owners(), owners);
+                }),
+            removed_system(audio_bus_volume()),
+            removed_system(audio_bus_muted()),
+            removed_system(audio_bus_ducked_by()),
+            removed_system(audio_bus_ducking()),
+            removed_system(audio_bus_high_pass()),
+            removed_system(audio_bus_low_pass()),
+            removed_system(audio_bus_eq()),
+            removed_system(audio_bus_compressor()),
+            removed_system(audio_bus_delay()),
+            removed_system(audio_bus_reverb()),
+            query(())
+                .incl(audio_bus())
+                .incl(is_audio_bus())
+                .despawned()
+                .to_system(|q, world, qs, _| {
+                    let ids = q.iter(world, qs).map(|(id, _)| id).collect();
+                    update_buses_of(world, ids);
+                }),
+        ],
+    )
+}
@@ -1,5 +1,6 @@
 use std::sync::Arc;
 
+use ambient_native_std::download_asset::AssetError;
 use thiserror::Error;
 
 pub type Result<T> = std::result::Result<T, Error>;
@@ -12,4 +13,10 @@ pub enum Error {
     NoAvailableSink,
     #[error(transparent)]
     AudioError(#[from] Arc<ambient_audio::Error>),
+    #[error("Failed to download audio graph")]
+    Download(#[from] AssetError),
+    #[error("Invalid audio url {0:?}")]
+    InvalidUrl(String),
+    #[error("Invalid audio graph: {0}")]
+    InvalidGraph(String),
 }
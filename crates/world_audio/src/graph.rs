@@ -1,26 +1,137 @@
-use std::{fmt::Debug, str::FromStr};
+use std::{str::FromStr, sync::Arc, time::Duration};
 
-use ambient_audio::{Source, VorbisFromUrl};
+use ambient_audio::{
+    AudioFromUrl, Crossfade, MixAll, SampleRate, Silence, Source, StreamingAudioFromUrl, Uniform,
+    VorbisFromUrl,
+};
 use ambient_native_std::{
     self,
-    asset_cache::{AssetCache, AsyncAssetKeyExt},
+    asset_cache::{AssetCache, AsyncAssetKey, AsyncAssetKeyExt},
     asset_url::AbsAssetUrl,
+    download_asset::BytesFromUrl,
 };
-use rand::{thread_rng, Rng, SeedableRng};
+use async_trait::async_trait;
+use rand::{distributions::WeightedIndex, prelude::Distribution, thread_rng, Rng, SeedableRng};
 use rand_chacha::ChaCha12Rng;
 
-use crate::error::Result;
+use crate::error::{Error, Result};
+
+/// The sample rate of silence, which is resampled to the rate of the sources it is played with
+const SILENCE_SAMPLE_RATE: SampleRate = 44100;
 
-#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
 /// Textual representation of a node in the audio graph which specifies how to construct a Sound.
+///
+/// Urls may be relative to the graph asset they are loaded from.
 pub enum AudioNode {
     /// A source which does nothing
     Identity,
+    /// Plays nothing for `duration` seconds
+    Silence {
+        duration: f32,
+    },
     /// Play from a vorbis `.ogg` file from a url
     Vorbis {
         /// Url asset
         url: String,
     },
+    /// Play from a `.wav` file from a url
+    Wav {
+        /// Url asset
+        url: String,
+    },
+    /// Stream a long `.ogg` or `.wav` file from a url while it plays, e.g. music
+    Stream {
+        /// Url asset
+        url: String,
+    },
+    /// Plays one of the children, picked at random
+    Random {
+        children: Vec<AudioNode>,
+        /// The relative likelihood of each child. All children are equally likely if empty
+        #[serde(default)]
+        weights: Vec<f32>,
+    },
+    /// Plays the child at a random pitch and volume
+    Randomize {
+        child: Box<AudioNode>,
+        /// The range of the pitch shift in semitones
+        #[serde(default)]
+        pitch: [f32; 2],
+        /// The range of the gain
+        #[serde(default = "default_volume")]
+        volume: [f32; 2],
+    },
+    /// Plays the children one after the other
+    Sequence {
+        children: Vec<AudioNode>,
+    },
+    /// Plays `intro`, then `body` `count` times, then `outro`.
+    ///
+    /// The body is rebuilt every time it loops, so random nodes in it pick again. If `count` is
+    /// not set, the body loops forever and the outro is never played. Since each time the body
+    /// is rebuilt it can have a different length, a loop never has a known length, even with a
+    /// `count`, so it can't be the `from` of a crossfade.
+    Loop {
+        #[serde(default)]
+        intro: Option<Box<AudioNode>>,
+        body: Box<AudioNode>,
+        #[serde(default)]
+        outro: Option<Box<AudioNode>>,
+        #[serde(default)]
+        count: Option<u32>,
+    },
+    /// Fades from `from` into `to` over the last `duration` seconds of `from`
+    Crossfade {
+        from: Box<AudioNode>,
+        to: Box<AudioNode>,
+        duration: f32,
+    },
+    /// Plays the children at the same time, until the longest has finished
+    Mix {
+        children: Vec<AudioNode>,
+    },
+    Gain {
+        child: Box<AudioNode>,
+        gain: f32,
+    },
+    /// `pan` is from -1 (left) to 1 (right)
+    Pan {
+        child: Box<AudioNode>,
+        pan: f32,
+    },
+    LowPass {
+        child: Box<AudioNode>,
+        freq: f32,
+        bandwidth: f32,
+    },
+    HighPass {
+        child: Box<AudioNode>,
+        freq: f32,
+        bandwidth: f32,
+    },
+}
+
+fn default_volume() -> [f32; 2] {
+    [1.0, 1.0]
+}
+
+/// How nodes were written before they were tagged with their `type`. The graphs of packages
+/// built by older versions still use it.
+#[derive(serde::Deserialize)]
+enum LegacyAudioNode {
+    Identity,
+    Vorbis { url: String },
+}
+
+impl From<LegacyAudioNode> for AudioNode {
+    fn from(node: LegacyAudioNode) -> Self {
+        match node {
+            LegacyAudioNode::Identity => AudioNode::Identity,
+            LegacyAudioNode::Vorbis { url } => AudioNode::Vorbis { url },
+        }
+    }
 }
 
 impl Default for AudioNode {
@@ -30,27 +141,397 @@ impl Default for AudioNode {
 }
 
 impl AudioNode {
+    /// Parses a graph from TOML, or JSON if `json` is set. JSON graphs may also be in the
+    /// representation from before nodes had a `type`.
+    pub fn parse(text: &str, json: bool) -> Result<Self> {
+        if json {
+            serde_json::from_str(text)
+                .or_else(|err| {
+                    serde_json::from_str::<LegacyAudioNode>(text)
+                        .map(Into::into)
+                        .map_err(|_| err)
+                })
+                .map_err(|err| Error::InvalidGraph(err.to_string()))
+        } else {
+            toml::from_str(text).map_err(|err| Error::InvalidGraph(err.to_string()))
+        }
+    }
+
+    /// The children of this node
+    pub fn children(&self) -> Vec<&AudioNode> {
+        match self {
+            AudioNode::Identity
+            | AudioNode::Silence { .. }
+            | AudioNode::Vorbis { .. }
+            | AudioNode::Wav { .. }
+            | AudioNode::Stream { .. } => Vec::new(),
+            AudioNode::Random { children, .. }
+            | AudioNode::Sequence { children }
+            | AudioNode::Mix { children } => children.iter().collect(),
+            AudioNode::Loop {
+                intro, body, outro, ..
+            } => intro
+                .iter()
+                .chain(Some(body))
+                .chain(outro)
+                .map(|v| &**v)
+                .collect(),
+            AudioNode::Crossfade { from, to, .. } => vec![&**from, &**to],
+            AudioNode::Randomize { child, .. }
+            | AudioNode::Gain { child, .. }
+            | AudioNode::Pan { child, .. }
+            | AudioNode::LowPass { child, .. }
+            | AudioNode::HighPass { child, .. } => vec![&**child],
+        }
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut AudioNode> {
+        match self {
+            AudioNode::Identity
+            | AudioNode::Silence { .. }
+            | AudioNode::Vorbis { .. }
+            | AudioNode::Wav { .. }
+            | AudioNode::Stream { .. } => Vec::new(),
+            AudioNode::Random { children, .. }
+            | AudioNode::Sequence { children }
+            | AudioNode::Mix { children } => children.iter_mut().collect(),
+            AudioNode::Loop {
+                intro, body, outro, ..
+            } => intro
+                .iter_mut()
+                .chain(Some(body))
+                .chain(outro)
+                .map(|v| &mut **v)
+                .collect(),
+            AudioNode::Crossfade { from, to, .. } => vec![&mut **from, &mut **to],
+            AudioNode::Randomize { child, .. }
+            | AudioNode::Gain { child, .. }
+            | AudioNode::Pan { child, .. }
+            | AudioNode::LowPass { child, .. }
+            | AudioNode::HighPass { child, .. } => vec![&mut **child],
+        }
+    }
+
+    /// Makes the urls of this graph absolute, relative to `base`
+    pub fn resolve_urls(&mut self, base: &AbsAssetUrl) -> Result<()> {
+        match self {
+            AudioNode::Vorbis { url } | AudioNode::Wav { url } | AudioNode::Stream { url } => {
+                *url = base
+                    .resolve(&*url)
+                    .map_err(|_| Error::InvalidUrl(url.clone()))?
+                    .to_string();
+            }
+            _ => {
+                for child in self.children_mut() {
+                    child.resolve_urls(base)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Loads every track used by this graph, so that it can be built with
+    /// [`AudioNode::try_build`]
+    pub async fn load(&self, assets: &AssetCache) -> Result<()> {
+        let mut stack = vec![self];
+        while let Some(node) = stack.pop() {
+            match node {
+                AudioNode::Vorbis { url } => {
+                    VorbisFromUrl {
+                        url: parse_url(url)?,
+                    }
+                    .get(assets)
+                    .await?;
+                }
+                AudioNode::Wav { url } => {
+                    AudioFromUrl {
+                        url: parse_url(url)?,
+                    }
+                    .get(assets)
+                    .await?;
+                }
+                AudioNode::Stream { url } => {
+                    StreamingAudioFromUrl {
+                        url: parse_url(url)?,
+                    }
+                    .get(assets)
+                    .await?;
+                }
+                _ => stack.extend(node.children()),
+            }
+        }
+        Ok(())
+    }
+
     /// Builds the adapter into a proper source.
     /// If the graph can not immediately be built, it returns None
     pub fn try_build(
         self,
         assets: &AssetCache,
-        _seed: AudioSeed,
+        seed: AudioSeed,
     ) -> Result<Option<Box<dyn Source>>> {
-        match self {
+        self.build(assets, &mut ChaCha12Rng::from_seed(seed.rng_seed))
+    }
+
+    fn build(&self, assets: &AssetCache, rng: &mut ChaCha12Rng) -> Result<Option<Box<dyn Source>>> {
+        let source: Box<dyn Source> = match self {
+            AudioNode::Identity => Box::new(Silence::new(Duration::ZERO, SILENCE_SAMPLE_RATE)),
+            AudioNode::Silence { duration } => {
+                Box::new(Silence::new(secs(*duration), SILENCE_SAMPLE_RATE))
+            }
             AudioNode::Vorbis { url } => {
                 let track = VorbisFromUrl {
-                    url: AbsAssetUrl::from_str(&url).unwrap(),
+                    url: parse_url(url)?,
                 }
                 .peek(assets)
                 .transpose()?;
                 match track {
-                    Some(track) => Ok(Some(Box::new(track.decode()))),
-                    None => Ok(None),
+                    Some(track) => Box::new(track.decode()),
+                    None => return Ok(None),
                 }
             }
-            _ => unimplemented!(),
+            AudioNode::Wav { url } => {
+                let track = AudioFromUrl {
+                    url: parse_url(url)?,
+                }
+                .peek(assets)
+                .transpose()?;
+                match track {
+                    Some(track) => Box::new(track.decode()),
+                    None => return Ok(None),
+                }
+            }
+            AudioNode::Stream { url } => {
+                let track = StreamingAudioFromUrl {
+                    url: parse_url(url)?,
+                }
+                .peek(assets)
+                .transpose()?;
+                match track {
+                    Some(track) => Box::new(track.decode()),
+                    None => return Ok(None),
+                }
+            }
+            AudioNode::Random { children, weights } => {
+                if children.is_empty() {
+                    return AudioNode::Identity.build(assets, rng);
+                }
+                let index = if weights.is_empty() {
+                    rng.gen_range(0..children.len())
+                } else if weights.len() != children.len() {
+                    return Err(Error::InvalidGraph(format!(
+                        "{} weights for {} children",
+                        weights.len(),
+                        children.len()
+                    )));
+                } else {
+                    WeightedIndex::new(weights)
+                        .map_err(|err| Error::InvalidGraph(err.to_string()))?
+                        .sample(rng)
+                };
+                return children[index].build(assets, rng);
+            }
+            AudioNode::Randomize {
+                child,
+                pitch,
+                volume,
+            } => {
+                let semitones = rng.gen_range(pitch[0].min(pitch[1])..=pitch[0].max(pitch[1]));
+                let gain = rng.gen_range(volume[0].min(volume[1])..=volume[0].max(volume[1]));
+                let Some(source) = child.build(assets, rng)? else {
+                    return Ok(None);
+                };
+                source.pitch(2f32.powf(semitones / 12.0)).gain(gain)
+            }
+            AudioNode::Sequence { children } => {
+                let Some(sources) = build_all(children, assets, rng)? else {
+                    return Ok(None);
+                };
+                sources
+                    .into_iter()
+                    .reduce(|acc, source| Box::new(acc.chain(source)))
+                    .unwrap_or_else(|| Box::new(Silence::new(Duration::ZERO, SILENCE_SAMPLE_RATE)))
+            }
+            AudioNode::Loop {
+                intro,
+                body,
+                outro,
+                count,
+            } => {
+                let body_rng = ChaCha12Rng::seed_from_u64(rng.gen());
+                let Some(looped) = Loop::new((**body).clone(), assets.clone(), body_rng, *count)?
+                else {
+                    return Ok(None);
+                };
+                let mut source: Box<dyn Source> = Box::new(looped);
+                if let Some(intro) = intro {
+                    let Some(intro) = intro.build(assets, rng)? else {
+                        return Ok(None);
+                    };
+                    source = Box::new(intro.chain(source));
+                }
+                if let Some(outro) = outro {
+                    let Some(outro) = outro.build(assets, rng)? else {
+                        return Ok(None);
+                    };
+                    source = Box::new(source.chain(outro));
+                }
+                source
+            }
+            AudioNode::Crossfade { from, to, duration } => {
+                let (Some(from), Some(to)) = (from.build(assets, rng)?, to.build(assets, rng)?)
+                else {
+                    return Ok(None);
+                };
+                let Some(from_duration) = from.duration() else {
+                    return Err(Error::InvalidGraph(
+                        "The `from` of a crossfade must have a known length".to_string(),
+                    ));
+                };
+                Box::new(Crossfade::new(from, to, secs(*duration).min(from_duration)))
+            }
+            AudioNode::Mix { children } => {
+                let Some(sources) = build_all(children, assets, rng)? else {
+                    return Ok(None);
+                };
+                mix(sources)
+            }
+            AudioNode::Gain { child, gain } => {
+                let Some(source) = child.build(assets, rng)? else {
+                    return Ok(None);
+                };
+                source.gain(*gain)
+            }
+            AudioNode::Pan { child, pan } => {
+                let Some(source) = child.build(assets, rng)? else {
+                    return Ok(None);
+                };
+                source.pan(*pan)
+            }
+            AudioNode::LowPass {
+                child,
+                freq,
+                bandwidth,
+            } => {
+                let Some(source) = child.build(assets, rng)? else {
+                    return Ok(None);
+                };
+                Box::new(source.low_pass(*freq, *bandwidth))
+            }
+            AudioNode::HighPass {
+                child,
+                freq,
+                bandwidth,
+            } => {
+                let Some(source) = child.build(assets, rng)? else {
+                    return Ok(None);
+                };
+                Box::new(source.high_pass(*freq, *bandwidth))
+            }
+        };
+        Ok(Some(source))
+    }
+}
+
+fn secs(secs: f32) -> Duration {
+    Duration::from_secs_f32(secs.max(0.0))
+}
+
+fn parse_url(url: &str) -> Result<AbsAssetUrl> {
+    AbsAssetUrl::from_str(url).map_err(|_| Error::InvalidUrl(url.to_string()))
+}
+
+fn build_all(
+    nodes: &[AudioNode],
+    assets: &AssetCache,
+    rng: &mut ChaCha12Rng,
+) -> Result<Option<Vec<Box<dyn Source>>>> {
+    let sources = nodes
+        .iter()
+        .map(|node| node.build(assets, rng))
+        .collect::<Result<Vec<_>>>()?;
+    Ok(sources.into_iter().collect())
+}
+
+/// Mixes the sources until the longest has finished
+fn mix(sources: Vec<Box<dyn Source>>) -> Box<dyn Source> {
+    if sources.is_empty() {
+        return Box::new(Silence::new(Duration::ZERO, SILENCE_SAMPLE_RATE));
+    }
+    Box::new(MixAll::new(sources))
+}
+
+/// Plays a node a number of times, rebuilding it every time
+struct Loop {
+    node: AudioNode,
+    assets: AssetCache,
+    rng: ChaCha12Rng,
+    current: Uniform<Box<dyn Source>>,
+    sample_rate: SampleRate,
+    /// The number of times left to play after the current one, or None to loop forever
+    remaining: Option<u32>,
+}
+
+impl Loop {
+    fn new(
+        node: AudioNode,
+        assets: AssetCache,
+        mut rng: ChaCha12Rng,
+        count: Option<u32>,
+    ) -> Result<Option<Self>> {
+        let first = if count == Some(0) {
+            AudioNode::Identity.build(&assets, &mut rng)?
+        } else {
+            node.build(&assets, &mut rng)?
+        };
+        let Some(first) = first else {
+            return Ok(None);
+        };
+        let sample_rate = first.sample_rate();
+        Ok(Some(Self {
+            node,
+            assets,
+            rng,
+            current: Uniform::new(first, sample_rate),
+            sample_rate,
+            remaining: count.map(|count| count.saturating_sub(1)),
+        }))
+    }
+}
+
+impl Source for Loop {
+    fn next_sample(&mut self) -> Option<ambient_audio::Frame> {
+        if let Some(sample) = self.current.next_sample() {
+            return Some(sample);
+        }
+        match &mut self.remaining {
+            Some(0) => return None,
+            Some(remaining) => *remaining -= 1,
+            None => {}
         }
+        match self.node.build(&self.assets, &mut self.rng) {
+            Ok(Some(source)) => self.current = Uniform::new(source, self.sample_rate),
+            Ok(None) => {
+                tracing::warn!("The body of an audio loop is not loaded; stopping the loop");
+                return None;
+            }
+            Err(err) => {
+                tracing::warn!("Failed to build the body of an audio loop: {err}");
+                return None;
+            }
+        }
+        // An empty body ends the loop rather than looping forever without output
+        self.current.next_sample()
+    }
+
+    fn sample_rate(&self) -> SampleRate {
+        self.sample_rate
+    }
+
+    /// Unknown even for a counted loop, as the length of the body can change every time it's
+    /// rebuilt
+    fn sample_count(&self) -> Option<u64> {
+        None
     }
 }
 
@@ -66,6 +547,12 @@ impl AudioSeed {
             rng_seed: thread_rng().gen(),
         }
     }
+
+    pub fn from_u64(seed: u64) -> Self {
+        Self {
+            rng_seed: ChaCha12Rng::seed_from_u64(seed).get_seed(),
+        }
+    }
 }
 
 impl Default for AudioSeed {
@@ -73,3 +560,142 @@ impl Default for AudioSeed {
         Self::new()
     }
 }
+
+/// Loads an audio graph from a `.toml` file, or a `.sgr` file made by the build pipeline, along
+/// with the tracks it uses
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct AudioGraphFromUrl {
+    pub url: AbsAssetUrl,
+}
+
+#[async_trait]
+impl AsyncAssetKey<std::result::Result<Arc<AudioNode>, Arc<Error>>> for AudioGraphFromUrl {
+    async fn load(self, assets: AssetCache) -> std::result::Result<Arc<AudioNode>, Arc<Error>> {
+        let bytes = BytesFromUrl::new(self.url.clone(), true)
+            .get(&assets)
+            .await
+            .map_err(|err| Arc::new(err.into()))?;
+        let text = String::from_utf8_lossy(&bytes);
+
+        let mut node = AudioNode::parse(&text, !self.url.extension_is("toml"))?;
+        node.resolve_urls(&self.url)?;
+        node.load(&assets).await?;
+        Ok(Arc::new(node))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn build(node: &AudioNode, seed: u64) -> Box<dyn Source> {
+        let assets = AssetCache::new(tokio::runtime::Handle::current());
+        node.clone()
+            .try_build(&assets, AudioSeed::from_u64(seed))
+            .unwrap()
+            .unwrap()
+    }
+
+    #[test]
+    fn parse_toml() {
+        let node = AudioNode::parse(
+            r#"
+            type = "loop"
+            count = 3
+            body = { type = "randomize", pitch = [-2, 2], child = { type = "wav", url = "step.wav" } }
+            outro = { type = "silence", duration = 0.5 }
+            "#,
+            false,
+        )
+        .unwrap();
+        let AudioNode::Loop {
+            intro: None,
+            body,
+            outro: Some(_),
+            count: Some(3),
+        } = &node
+        else {
+            panic!("{node:?}");
+        };
+        let AudioNode::Randomize { pitch, volume, .. } = &**body else {
+            panic!("{body:?}");
+        };
+        assert_eq!(*pitch, [-2.0, 2.0]);
+        assert_eq!(*volume, [1.0, 1.0]);
+
+        let mut resolved = node.clone();
+        resolved
+            .resolve_urls(&AbsAssetUrl::from_str("https://example.com/sounds/steps.toml").unwrap())
+            .unwrap();
+        assert_eq!(
+            resolved.children()[0].children()[0],
+            &AudioNode::Wav {
+                url: "https://example.com/sounds/step.wav".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn parse_legacy_json() {
+        let node = AudioNode::parse(r#"{"Vorbis":{"url":"music.ogg"}}"#, true).unwrap();
+        assert_eq!(
+            node,
+            AudioNode::Vorbis {
+                url: "music.ogg".to_string()
+            }
+        );
+        assert_eq!(
+            AudioNode::parse(r#""Identity""#, true).unwrap(),
+            AudioNode::Identity
+        );
+    }
+
+    #[tokio::test]
+    async fn durations() {
+        let silence = |duration| AudioNode::Silence { duration };
+        let sequence = AudioNode::Sequence {
+            children: vec![silence(0.5), silence(0.25)],
+        };
+        assert_eq!(
+            build(&sequence, 0).duration(),
+            Some(Duration::from_millis(750))
+        );
+
+        let mix = AudioNode::Mix {
+            children: vec![silence(0.5), silence(0.25)],
+        };
+        assert_eq!(build(&mix, 0).samples_iter().count(), 22050);
+
+        let looped = AudioNode::Loop {
+            intro: Some(Box::new(silence(0.5))),
+            body: Box::new(sequence),
+            outro: Some(Box::new(silence(0.25))),
+            count: Some(2),
+        };
+        // 0.5s intro, 2 * 0.75s body and 0.25s outro
+        assert_eq!(build(&looped, 0).samples_iter().count(), 44100 * 9 / 4);
+
+        // A loop has no known length, but the mix still ends when it does
+        let mix = AudioNode::Mix {
+            children: vec![looped, silence(0.5)],
+        };
+        let mix = build(&mix, 0);
+        assert_eq!(mix.duration(), None);
+        assert_eq!(mix.samples_iter().count(), 44100 * 9 / 4);
+    }
+
+    #[tokio::test]
+    async fn random_is_seeded() {
+        let node = AudioNode::Random {
+            children: (1..=8)
+                .map(|i| AudioNode::Silence {
+                    duration: i as f32 * 0.1,
+                })
+                .collect(),
+            weights: Vec::new(),
+        };
+        let count = |seed| build(&node, seed).samples_iter().count();
+        assert_eq!(count(1), count(1));
+        assert!((0..16).map(count).any(|v| v != count(1)));
+    }
+}
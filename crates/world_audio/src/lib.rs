@@ -1,9 +1,11 @@
+mod bus;
 mod error;
 mod events;
 mod graph;
 mod sounds;
 pub mod systems;
 pub use ambient_audio as core;
+pub use bus::*;
 pub use error::*;
 pub use events::*;
 pub use graph::*;
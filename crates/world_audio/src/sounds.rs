@@ -1,9 +1,10 @@
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc};
 
 use ambient_audio::{
     hrtf::HrtfLib,
     track::{Track, TrackDecodeStream},
-    Attenuation, AudioEmitter, AudioListener, AudioMixer, Sound, SoundId, Source, Spatial,
+    Attenuation, AudioEmitter, AudioListener, AudioMixer, BusId, OfflineAudioStream, Sound,
+    SoundId, Source, Spatial, StreamHandle,
 };
 use ambient_ecs::{components, query, EntityId, Resource, World};
 use ambient_element::ElementComponentExt;
@@ -29,11 +30,20 @@ components!("audio", {
     audio_sender: Arc<flume::Sender<AudioMessage>>,
     @[Resource]
     audio_mixer: AudioMixer,
+    /// Renders the `audio_mixer` in step with the frames instead of on an audio device
+    @[Resource]
+    offline_audio: Arc<Mutex<OfflineAudioStream>>,
+    /// The `is_audio_bus` entities configuring each bus, in the order they were last changed.
+    /// The last one is applied to the bus.
+    @[Resource]
+    audio_bus_owners: HashMap<BusId, Vec<EntityId>>,
     amplitude_arc: Arc<Mutex<f32>>,
     panning_arc: Arc<Mutex<f32>>,
     onepole_arc: Arc<Mutex<f32>>,
     looping_arc: Arc<Mutex<bool>>,
     sound_id: SoundId,
+    /// Seeks the streaming sound played for this entity, see `audio_seek`
+    stream_handle: StreamHandle,
 });
 
 #[derive(Debug, Clone, Copy, PartialEq)]
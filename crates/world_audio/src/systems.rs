@@ -1,20 +1,31 @@
 use std::{io::Cursor, sync::Arc};
 
-use crate::{audio_emitter, audio_listener, hrtf_lib};
-use ambient_audio::{hrtf::HrtfLib, AudioFromUrl, Source};
+use crate::{
+    audio_emitter, audio_listener, bus_systems, get_audio_bus, hrtf_lib, AudioGraphFromUrl,
+    AudioSeed,
+};
+use ambient_audio::{
+    hrtf::HrtfLib,
+    track::{Track, TrackDecodeStream},
+    AudioFromUrl, BusId, Sound, Source, StreamHandle, StreamingAudioFromUrl,
+};
 use ambient_audio::{Attenuation, AudioEmitter, AudioListener};
 use ambient_core::{
     asset_cache,
-    async_ecs::async_run,
+    async_ecs::{async_run, AsyncRun},
     runtime,
     transform::{local_to_world, translation},
 };
 use ambient_ecs::EntityId;
 use ambient_ecs::{
-    generated::audio::components::*, generated::hierarchy::components::children, query,
+    generated::audio::components::*, generated::hierarchy::components::children, query, FnSystem,
     SystemGroup, World,
 };
-use ambient_native_std::{asset_cache::AsyncAssetKeyExt, asset_url::AbsAssetUrl, unwrap_log_warn};
+use ambient_native_std::{
+    asset_cache::{AssetCache, AsyncAssetKeyExt},
+    asset_url::AbsAssetUrl,
+    unwrap_log_warn,
+};
 use glam::{vec4, Mat4};
 use parking_lot::Mutex;
 use std::str::FromStr;
@@ -144,101 +155,212 @@ pub fn audio_systems() -> SystemGroup {
                     }
                 }
             }),
+            query((audio_seek(), crate::stream_handle())).to_system(|q, world, qs, _| {
+                for (id, (time, handle)) in q.collect_cloned(world, qs) {
+                    handle.seek(time);
+                    let _ = world.remove_component(id, audio_seek());
+                }
+            }),
             query((is_audio_player(), play_now(), audio_url())).to_system(|q, world, qs, _| {
                 for (player, (_, _, url)) in q.collect_cloned(world, qs) {
-                    // check if mute_audio is set
-                    let r = world.resource_entity();
-                    if !world.has_component(r, crate::audio_mixer()) {
-                        continue;
+                    process_url_player(world, player, &url);
+                }
+            }),
+            query((is_audio_player(), play_now(), audio_graph_url())).to_system(
+                |q, world, qs, _| {
+                    for (player, (_, _, url)) in q.collect_cloned(world, qs) {
+                        process_graph_player(world, player, &url);
                     }
+                },
+            ),
+            Box::new(bus_systems()),
+            Box::new(FnSystem::new(|world, _| {
+                let Some(offline_audio) = world.resource_opt(crate::offline_audio()) else {
+                    return;
+                };
+                // Fixed blocks, so the output doesn't depend on how long the frames took
+                let result = offline_audio.lock().advance_block();
+                if let Err(err) = result {
+                    tracing::error!("Failed to render offline audio, stopping: {err}");
+                    let resources = world.resource_entity();
+                    world
+                        .remove_component(resources, crate::offline_audio())
+                        .ok();
+                }
+            })),
+        ],
+    )
+}
 
-                    let amp = world.get(player, amplitude()).unwrap_or(1.0);
-                    let pan = world.get(player, panning()).unwrap_or(0.0);
-                    let freq = world.get(player, onepole_lpf()).unwrap_or(20000.0);
-                    let looping = world.get(player, looping()).unwrap_or(false);
+/// What every audio player applies to the sound it plays
+struct PlayerSettings {
+    amp: f32,
+    pan: f32,
+    freq: f32,
+    bus: BusId,
+}
+impl PlayerSettings {
+    /// Reads the settings of `player` and consumes its `play_now`. Returns `None` if there is no
+    /// mixer, e.g. when audio is muted.
+    fn take(world: &mut World, player: EntityId) -> Option<Self> {
+        let r = world.resource_entity();
+        if !world.has_component(r, crate::audio_mixer()) {
+            return None;
+        }
+        let settings = Self {
+            amp: world.get(player, amplitude()).unwrap_or(1.0),
+            pan: world.get(player, panning()).unwrap_or(0.0),
+            freq: world.get(player, onepole_lpf()).unwrap_or(20000.0),
+            bus: get_audio_bus(world, player),
+        };
+        world.remove_component(player, play_now()).unwrap();
+        Some(settings)
+    }
 
-                    world.remove_component(player, play_now()).unwrap();
+    /// Plays `source` for the sound entity spawned as the last child of `player`, and returns
+    /// that entity and the sound
+    fn play(
+        self,
+        world: &mut World,
+        player: EntityId,
+        source: impl Source + 'static,
+    ) -> Option<(EntityId, Sound)> {
+        let Some(id) = world
+            .get_ref(player, children())
+            .ok()
+            .and_then(|c| c.last())
+            .copied()
+        else {
+            tracing::error!("No children component on parent entity; cannot play audio.");
+            return None;
+        };
 
-                    let assets = world.resource(asset_cache()).clone();
-                    let runtime = world.resource(runtime()).clone();
-                    let async_run = world.resource(async_run()).clone();
-                    let Ok(url) =
-                        AbsAssetUrl::from_str(&url).and_then(|u| u.to_download_url(&assets))
-                    else {
-                        continue;
-                    };
+        let a = Arc::new(Mutex::new(self.amp));
+        let p = Arc::new(Mutex::new(self.pan));
+        let f = Arc::new(Mutex::new(self.freq));
+        let source = source.gain(a.clone()).pan(p.clone()).onepole(f.clone());
+        let _ = world.add_component(id, crate::amplitude_arc(), a);
+        let _ = world.add_component(id, crate::panning_arc(), p);
+        let _ = world.add_component(id, crate::onepole_arc(), f);
+
+        let mixer = world.resource(crate::audio_mixer());
+        let sound = mixer.play_on(self.bus, source);
+        let _ = world.add_component(id, crate::sound_id(), sound.id);
+        Some((id, sound))
+    }
+}
+
+fn process_url_player(world: &mut World, player: EntityId, url: &str) {
+    let Some(settings) = PlayerSettings::take(world, player) else {
+        return;
+    };
+    let looping = world.get(player, looping()).unwrap_or(false);
+    let streaming = world.get(player, audio_streaming()).unwrap_or(false);
+
+    let assets = world.resource(asset_cache()).clone();
+    let runtime = world.resource(runtime()).clone();
+    let async_run = world.resource(async_run()).clone();
+    let Ok(url) = AbsAssetUrl::from_str(url).and_then(|u| u.to_download_url(&assets)) else {
+        return;
+    };
+
+    runtime.spawn(async move {
+        let track = unwrap_log_warn!(load_track(&assets, url, streaming).await);
 
-                    runtime.spawn(async move {
-                        let track =
-                            unwrap_log_warn!(AudioFromUrl { url: url.clone() }.get(&assets).await);
-                        let id_arc = Arc::new(Mutex::new(None));
-                        let id_arc_clone = id_arc.clone();
-                        let count_arc = Arc::new(Mutex::new(None));
-                        let count_arc_clone = count_arc.clone();
-                        let sr_arc = Arc::new(Mutex::new(None));
-                        let sr_arc_clone = sr_arc.clone();
-                        async_run.run(move |world| {
-                            let Some(id) = world
-                                .get_ref(player, children())
-                                .ok()
-                                .and_then(|c| c.last())
-                                .copied()
-                            else {
-                                tracing::error!(
-                                    "No children component on parent entity; cannot play audio."
-                                );
-                                return;
-                            };
-                            id_arc.lock().replace(id);
-
-                            let mut t: Box<dyn Source> = if looping {
-                                Box::new(track.decode().repeat())
-                            } else {
-                                let decoded = track.decode();
-                                let count = decoded.sample_count().unwrap();
-                                let sr = decoded.sample_rate();
-                                *count_arc.lock() = Some(count);
-                                *sr_arc.lock() = Some(sr);
-                                Box::new(decoded)
-                            };
-                            let a = Arc::new(Mutex::new(amp));
-                            t = t.gain(a.clone());
-                            let p = Arc::new(Mutex::new(pan));
-                            t = t.pan(p.clone());
-                            let f = Arc::new(Mutex::new(freq));
-                            t = t.onepole(f.clone());
-
-                            let id = id_arc.lock().unwrap();
-                            let _ = world.add_component(id, crate::amplitude_arc(), a);
-                            let _ = world.add_component(id, crate::panning_arc(), p);
-                            let _ = world.add_component(id, crate::onepole_arc(), f);
-
-                            let mixer = world.resource(crate::audio_mixer());
-                            let sound = mixer.play(t);
-
-                            let _ = world.add_component(id, crate::sound_id(), sound.id);
-                        });
-
-                        let count = *count_arc_clone.lock();
-                        let sr = *sr_arc_clone.lock();
-                        if let Some((count, sr)) = count.zip(sr) {
-                            let dur = count as f32 / sr as f32 * 1.001;
-                            ambient_sys::time::sleep_label(
-                                std::time::Duration::from_secs_f32(dur),
-                                "audio",
-                            )
-                            .await;
-                            async_run.run(move |world| {
-                                if let Some(id) = id_arc_clone.lock().take() {
-                                    world.despawn(id);
-                                }
-                            });
-                        };
-                    });
+        let (tx, rx) = flume::bounded(1);
+        async_run.run(move |world| {
+            let (source, handle) = decode_track(&track, looping);
+            if let Some((id, sound)) = settings.play(world, player, source) {
+                if let Some(handle) = handle {
+                    let _ = world.add_component(id, crate::stream_handle(), handle);
                 }
-            }),
-        ],
-    )
+                let _ = tx.send((id, sound));
+            }
+        });
+
+        despawn_when_finished(async_run, rx).await;
+    });
+}
+
+/// Loads the track at `url`, or only its header if it is streamed
+async fn load_track(
+    assets: &AssetCache,
+    url: AbsAssetUrl,
+    streaming: bool,
+) -> Result<Arc<Track>, Arc<ambient_audio::Error>> {
+    if streaming {
+        StreamingAudioFromUrl { url }.get(assets).await
+    } else {
+        AudioFromUrl { url }.get(assets).await
+    }
+}
+
+/// Decodes `track`, and returns the handle to seek it with if it is streamed
+fn decode_track(track: &Track, looping: bool) -> (Box<dyn Source>, Option<StreamHandle>) {
+    let stream = track.decode();
+    let handle = stream.stream_handle();
+    let source: Box<dyn Source> = match stream {
+        // Keeps playing the same stream, so the handle still works after the first loop
+        TrackDecodeStream::Streaming(stream) if looping => Box::new((*stream).looped()),
+        stream if looping => Box::new(stream.repeat()),
+        stream => Box::new(stream),
+    };
+    (source, handle)
+}
+
+/// Despawns the sound entity sent by the player once the mixer has finished playing it.
+///
+/// This waits for the sound itself rather than its length, as looping sounds and graphs have no
+/// known length, and streams can stall while buffering.
+async fn despawn_when_finished(async_run: AsyncRun, played: flume::Receiver<(EntityId, Sound)>) {
+    let Ok((id, sound)) = played.recv_async().await else {
+        return;
+    };
+    sound.wait().await;
+    async_run.run(move |world| {
+        if world.exists(id) {
+            world.despawn(id);
+        }
+    });
+}
+
+fn process_graph_player(world: &mut World, player: EntityId, url: &str) {
+    let Some(settings) = PlayerSettings::take(world, player) else {
+        return;
+    };
+    let seed = world
+        .get(player, audio_graph_seed())
+        .map(AudioSeed::from_u64)
+        .unwrap_or_default();
+
+    let assets = world.resource(asset_cache()).clone();
+    let runtime = world.resource(runtime()).clone();
+    let async_run = world.resource(async_run()).clone();
+    let url = unwrap_log_warn!(AbsAssetUrl::from_str(url).and_then(|u| u.to_download_url(&assets)));
+
+    runtime.spawn(async move {
+        let graph = unwrap_log_warn!(AudioGraphFromUrl { url }.get(&assets).await);
+        let source = match (*graph).clone().try_build(&assets, seed) {
+            Ok(Some(source)) => source,
+            Ok(None) => {
+                tracing::warn!("The tracks of the audio graph are not loaded");
+                return;
+            }
+            Err(err) => {
+                tracing::warn!("Failed to build audio graph: {err}");
+                return;
+            }
+        };
+
+        let (tx, rx) = flume::bounded(1);
+        async_run.run(move |world| {
+            if let Some(played) = settings.play(world, player, source) {
+                let _ = tx.send(played);
+            }
+        });
+
+        despawn_when_finished(async_run, rx).await;
+    });
 }
 
 fn process_player(world: &mut World, player: EntityId, url: &str) {
@@ -250,6 +372,8 @@ fn process_player(world: &mut World, player: EntityId, url: &str) {
 
     let amp = world.get(player, amplitude()).unwrap_or(1.0);
     let looping = world.get(player, looping()).unwrap_or(false);
+    let streaming = world.get(player, audio_streaming()).unwrap_or(false);
+    let bus = get_audio_bus(world, player);
     world.remove_component(player, play_now()).unwrap();
 
     let assets = world.resource(asset_cache()).clone();
@@ -258,7 +382,7 @@ fn process_player(world: &mut World, player: EntityId, url: &str) {
     let url = unwrap_log_warn!(AbsAssetUrl::from_str(url).and_then(|u| u.to_download_url(&assets)));
 
     runtime.spawn(async move {
-        let track = unwrap_log_warn!(AudioFromUrl { url: url.clone() }.get(&assets).await);
+        let track = unwrap_log_warn!(load_track(&assets, url, streaming).await);
         async_run.run(move |world| {
             let Ok(listener_id) = world.get(player, spatial_audio_listener()) else {
                 return;
@@ -291,13 +415,12 @@ fn process_player(world: &mut World, player: EntityId, url: &str) {
             let hrtf_lib = world.resource(hrtf_lib());
 
             let mixer = world.resource(crate::audio_mixer());
-            let source: Box<dyn Source> = if looping {
-                Box::new(track.decode().repeat().spatial(hrtf_lib, listener, emitter))
-            } else {
-                Box::new(track.decode().spatial(hrtf_lib, listener, emitter))
-            };
-            let sound = mixer.play(source);
+            let (source, handle) = decode_track(&track, looping);
+            let sound = mixer.play_on(bus, source.spatial(hrtf_lib, listener, emitter));
             let _ = world.add_component(emitter_id, crate::sound_id(), sound.id);
+            if let Some(handle) = handle {
+                let _ = world.add_component(emitter_id, crate::stream_handle(), handle);
+            }
         });
     });
 }
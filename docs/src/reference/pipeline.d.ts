@@ -187,6 +187,18 @@ export type Pipeline = {
       /// Re-center this mesh such that the root is located at the origin.
       type: "Center",
     })[],
+    /// If specified, a list of named events to add to the animations of this model.
+    /// They are sent as `AnimationEvent` messages when an animation player crosses them.
+    /// 
+    /// Events can also be specified in a `<model>.events.toml` file next to the model, with an `events` array of the same format.
+    animation_events?: {
+      /// The name of the animation to add this event to. If not specified, it is added to all animations of the model.
+      clip?: string,
+      /// The time of the event in seconds, relative to the start of the animation.
+      time: f32,
+      /// The name of the event, i.e. `footstep_left`.
+      name: string,
+    }[],
   } | {
     /// The materials asset pipeline.
     /// Will import specific materials without needing to be part of a model.
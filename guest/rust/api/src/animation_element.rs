@@ -1,12 +1,17 @@
 use ambient_api_core::{
+    animation::BindId,
     core::{
         animation::{
             self,
-            components::{play_clip_from_url, start_time},
+            components::{
+                additive_weight, blend_space_parameter, blend_space_positions, ik_pole, ik_target,
+                ik_weight, look_at_ik_axis, look_at_ik_bind_id, play_clip_from_url, start_time,
+                two_bone_ik_bind_ids,
+            },
         },
         app::components::name,
     },
-    prelude::epoch_time,
+    prelude::{epoch_time, Vec2, Vec3},
 };
 use ambient_element::{element_component, use_ref_with, Element, ElementComponentExt, Hooks};
 
@@ -109,6 +114,102 @@ impl BlendNode {
     }
 }
 
+/// Blend any number of animations by where a parameter is between their positions in a 1D or 2D
+/// space, such as walking animations placed at the velocities they walk at
+#[element_component]
+pub fn BlendSpace(
+    _hooks: &mut Hooks,
+    /// The animation nodes, and their positions in the space. If the positions are on a line, the
+    /// space is 1D
+    animations: Vec<(Element, Vec2)>,
+    /// The position in the space to blend the animations at
+    parameter: Vec2,
+) -> Element {
+    let (animations, positions): (Vec<_>, Vec<_>) = animations.into_iter().unzip();
+    Element::new()
+        .with(blend_space_positions(), positions)
+        .with(blend_space_parameter(), parameter)
+        .with(name(), "Blend space".to_string())
+        .children(animations)
+}
+
+/// Add how much the layer animation differs from a reference pose on top of the base animation,
+/// for small motions like breathing or recoil
+#[element_component]
+pub fn AdditiveNode(
+    _hooks: &mut Hooks,
+    /// Base animation node
+    base: Element,
+    /// Layer animation node
+    layer: Element,
+    /// The pose that the layer is relative to. If `None`, the layer must be a clip, and its first
+    /// frame is used
+    reference: Option<Element>,
+    /// How much of the layer is added (0 means only the base, 1 means all of the layer)
+    weight: f32,
+) -> Element {
+    Element::new()
+        .with(additive_weight(), weight)
+        .with(name(), "Additive".to_string())
+        .children([base, layer].into_iter().chain(reference).collect())
+}
+
+/// Bend a chain of three bones of the input animation, like a leg or an arm, so that the end
+/// reaches a world-space target
+#[element_component]
+pub fn TwoBoneIk(
+    _hooks: &mut Hooks,
+    /// Input animation node
+    input: Element,
+    /// The root, middle and end bones of the chain, e.g. upper leg, lower leg and foot
+    bones: [BindId; 3],
+    /// The world-space position that the end of the chain reaches for
+    target: Vec3,
+    /// The world-space position that the middle of the chain bends towards, like the direction of a knee
+    pole: Option<Vec3>,
+    /// How much the IK changes the animation, from 0 to 1
+    weight: f32,
+) -> Element {
+    let node = Element::new()
+        .with(
+            two_bone_ik_bind_ids(),
+            bones.iter().map(|x| x.as_str().to_string()).collect(),
+        )
+        .with(ik_target(), target)
+        .with(ik_weight(), weight)
+        .with(name(), "Two-bone IK".to_string())
+        .children(vec![input]);
+    match pole {
+        Some(pole) => node.with(ik_pole(), pole),
+        None => node,
+    }
+}
+
+/// Rotate a bone of the input animation, like a head or an arm holding a weapon, so that it points
+/// towards a world-space target
+#[element_component]
+pub fn LookAtIk(
+    _hooks: &mut Hooks,
+    /// Input animation node
+    input: Element,
+    /// The bone to rotate
+    bone: BindId,
+    /// The axis of the bone that points towards the target, in the space of the bone
+    axis: Vec3,
+    /// The world-space position that the bone points towards
+    target: Vec3,
+    /// How much the IK changes the animation, from 0 to 1
+    weight: f32,
+) -> Element {
+    Element::new()
+        .with(look_at_ik_bind_id(), bone.as_str().to_string())
+        .with(look_at_ik_axis(), axis)
+        .with(ik_target(), target)
+        .with(ik_weight(), weight)
+        .with(name(), "Look-at IK".to_string())
+        .children(vec![input])
+}
+
 /// Transition between multiple animations
 #[element_component]
 pub fn Transition(
@@ -1,15 +1,18 @@
 use crate::{
     core::{
         animation::components::{
-            apply_base_pose, bind_id, bind_ids, blend, clip_duration, freeze_at_percentage,
-            freeze_at_time, is_animation_player, looping, mask_bind_ids, mask_weights,
-            play_clip_from_url, retarget_animation_scaled, retarget_model_from_url, start_time,
+            additive_weight, apply_base_pose, bind_id, bind_ids, blend, blend_space_parameter,
+            blend_space_positions, clip_duration, freeze_at_percentage, freeze_at_time, ik_pole,
+            ik_target, ik_target_entity, ik_weight, is_animation_player, look_at_ik_axis,
+            look_at_ik_bind_id, looping, mask_bind_ids, mask_weights, play_clip_from_url,
+            retarget_animation_scaled, retarget_model_from_url, root_motion_axes, root_motion_bone,
+            start_time, two_bone_ik_bind_ids,
         },
         app::components::name,
         hierarchy::components::{children, parent},
     },
     entity,
-    prelude::{epoch_time, Entity, EntityId},
+    prelude::{epoch_time, Entity, EntityId, Vec2, Vec3},
 };
 use std::time::Duration;
 
@@ -47,6 +50,29 @@ impl AnimationPlayerRef {
         entity::add_component(new_root.0, parent(), self.0);
         old_root
     }
+    /// Enables root motion, with the bone `bind_id` (usually [`BindId::Hips`]) as the root.
+    ///
+    /// The translation of the root bone is removed from the animation. Instead, the entities this
+    /// player is applied to get a `root_motion_delta` every frame, which can be used to move them,
+    /// e.g. with `physics::move_character`.
+    ///
+    /// `None` disables root motion.
+    pub fn set_root_motion(&self, bind_id: Option<BindId>) {
+        match bind_id {
+            Some(bind_id) => {
+                entity::add_component(self.0, root_motion_bone(), bind_id.as_str().to_string())
+            }
+            None => entity::remove_component(self.0, root_motion_bone()),
+        }
+    }
+    /// Sets which axes of the root bone's translation, relative to its parent, are removed from
+    /// the animation and applied as root motion: 1 extracts the axis, 0 keeps it animated.
+    ///
+    /// Defaults to `vec3(1., 0., 1.)`, the horizontal plane of Y-up skeletons like Mixamo's, so that
+    /// the vertical bob of a walk stays in the animation.
+    pub fn set_root_motion_axes(&self, axes: Vec3) {
+        entity::add_component(self.0, root_motion_axes(), axes);
+    }
     /// Despawn this animation player.
     /// Note that dropping this player won't despawn it automatically; only call this method will despawn it.
     pub fn despawn(self) {
@@ -302,6 +328,251 @@ impl AsRef<AnimationNodeRef> for BlendNodeRef {
     }
 }
 
+/// Blend space animation node.
+/// This is an animation node which can be plugged into an animation player or other animation nodes.
+///
+/// It blends any number of animations, each placed at a position in a 1D or 2D parameter space,
+/// by where the parameter is between them. For example, walking animations can be placed at the
+/// velocities they walk at, and blended by the velocity of the character.
+///
+/// This is just a reference to an entity which lives in the ecs. You need to call `despawn` to
+/// remove it.
+#[derive(Debug, Clone, Copy)]
+pub struct BlendSpaceNodeRef(pub AnimationNodeRef);
+impl BlendSpaceNodeRef {
+    /// Create a new 2D blend space, with each animation at its position in the space.
+    ///
+    /// The animations are blended by the triangle of positions around the parameter, and
+    /// parameters outside of the positions are clamped to the closest point between them.
+    pub fn new(animations: Vec<(AnimationNodeRef, Vec2)>) -> Self {
+        let (nodes, positions): (Vec<_>, Vec<_>) = animations
+            .into_iter()
+            .map(|(node, position)| (node.0, position))
+            .unzip();
+        let node = Entity::new()
+            .with(blend_space_positions(), positions)
+            .with(blend_space_parameter(), Vec2::ZERO)
+            .with(name(), "Blend space".to_string())
+            .with(children(), nodes.clone())
+            .spawn();
+        for child in nodes {
+            entity::add_component(child, parent(), node);
+        }
+        Self(AnimationNodeRef(node))
+    }
+    /// Create a new 1D blend space, which blends between the two animations on either side of
+    /// the parameter
+    pub fn new_1d(animations: Vec<(AnimationNodeRef, f32)>) -> Self {
+        Self::new(
+            animations
+                .into_iter()
+                .map(|(node, position)| (node, Vec2::new(position, 0.)))
+                .collect(),
+        )
+    }
+    /// Use an existing node
+    pub fn from_entity(entity: EntityId) -> Self {
+        Self(AnimationNodeRef::from_entity(entity))
+    }
+    /// Set the position in the space to blend the animations at
+    pub fn set_parameter(&self, parameter: Vec2) {
+        entity::set_component(self.0 .0, blend_space_parameter(), parameter);
+    }
+    /// Set the position in a 1D blend space to blend the animations at
+    pub fn set_parameter_1d(&self, parameter: f32) {
+        self.set_parameter(Vec2::new(parameter, 0.));
+    }
+}
+impl AsRef<AnimationNodeRef> for BlendSpaceNodeRef {
+    fn as_ref(&self) -> &AnimationNodeRef {
+        &self.0
+    }
+}
+
+/// Additive animation node.
+/// This is an animation node which can be plugged into an animation player or other animation nodes.
+///
+/// It adds how much a layer animation differs from a reference pose on top of a base animation.
+/// This is useful for layering small motions, like breathing or recoil, on top of any other
+/// animation.
+///
+/// This is just a reference to an entity which lives in the ecs. You need to call `despawn` to
+/// remove it.
+#[derive(Debug, Clone, Copy)]
+pub struct AdditiveNodeRef(pub AnimationNodeRef);
+impl AdditiveNodeRef {
+    /// Create a new additive node, which adds `layer` on top of `base`, scaled by `weight`.
+    ///
+    /// The layer must be a clip, as its first frame is used as the reference pose. Use
+    /// [`Self::with_reference`] to use another pose.
+    pub fn new(
+        base: impl AsRef<AnimationNodeRef>,
+        layer: impl AsRef<AnimationNodeRef>,
+        weight: f32,
+    ) -> Self {
+        Self::spawn(vec![*base.as_ref(), *layer.as_ref()], weight)
+    }
+    /// Create a new additive node, which adds the difference between `layer` and `reference` on
+    /// top of `base`, scaled by `weight`
+    pub fn with_reference(
+        base: impl AsRef<AnimationNodeRef>,
+        layer: impl AsRef<AnimationNodeRef>,
+        reference: impl AsRef<AnimationNodeRef>,
+        weight: f32,
+    ) -> Self {
+        Self::spawn(
+            vec![*base.as_ref(), *layer.as_ref(), *reference.as_ref()],
+            weight,
+        )
+    }
+    fn spawn(inputs: Vec<AnimationNodeRef>, weight: f32) -> Self {
+        let node = Entity::new()
+            .with(additive_weight(), weight)
+            .with(name(), "Additive".to_string())
+            .with(children(), inputs.iter().map(|input| input.0).collect())
+            .spawn();
+        for input in inputs {
+            entity::add_component(input.0, parent(), node);
+        }
+        Self(AnimationNodeRef(node))
+    }
+    /// Use an existing node
+    pub fn from_entity(entity: EntityId) -> Self {
+        Self(AnimationNodeRef::from_entity(entity))
+    }
+    /// Set how much of the layer is added to the base. 0 is only the base, and 1 adds all of it
+    pub fn set_weight(&self, weight: f32) {
+        entity::set_component(self.0 .0, additive_weight(), weight);
+    }
+}
+impl AsRef<AnimationNodeRef> for AdditiveNodeRef {
+    fn as_ref(&self) -> &AnimationNodeRef {
+        &self.0
+    }
+}
+
+/// Two-bone IK animation node.
+/// This is an animation node which can be plugged into an animation player or other animation nodes.
+///
+/// It takes the animation of its input, and bends a chain of three bones, like a leg or an arm,
+/// so that the end of the chain reaches a world-space target. This is useful for placing feet on
+/// uneven ground, or hands on objects.
+///
+/// This is just a reference to an entity which lives in the ecs. You need to call `despawn` to
+/// remove it.
+#[derive(Debug, Clone, Copy)]
+pub struct TwoBoneIkNodeRef(pub AnimationNodeRef);
+impl TwoBoneIkNodeRef {
+    /// Create a new two-bone IK node, which bends the chain from `root` over `middle` to `end`,
+    /// for example [`BindId::LeftUpLeg`], [`BindId::LeftLeg`] and [`BindId::LeftFoot`].
+    ///
+    /// The node has no effect until it has a target.
+    pub fn new(
+        input: impl AsRef<AnimationNodeRef>,
+        root: BindId,
+        middle: BindId,
+        end: BindId,
+    ) -> Self {
+        let input: &AnimationNodeRef = input.as_ref();
+        let node = Entity::new()
+            .with(
+                two_bone_ik_bind_ids(),
+                vec![
+                    root.as_str().to_string(),
+                    middle.as_str().to_string(),
+                    end.as_str().to_string(),
+                ],
+            )
+            .with(name(), "Two-bone IK".to_string())
+            .with(children(), vec![input.0])
+            .spawn();
+        entity::add_component(input.0, parent(), node);
+        Self(AnimationNodeRef(node))
+    }
+    /// Use an existing node
+    pub fn from_entity(entity: EntityId) -> Self {
+        Self(AnimationNodeRef::from_entity(entity))
+    }
+    /// Set the world-space position that the end of the chain reaches for
+    pub fn set_target(&self, target: Vec3) {
+        entity::remove_component(self.0 .0, ik_target_entity());
+        entity::add_component(self.0 .0, ik_target(), target);
+    }
+    /// Make the end of the chain reach for the world-space position of `entity`
+    pub fn set_target_entity(&self, entity: EntityId) {
+        entity::add_component(self.0 .0, ik_target_entity(), entity);
+    }
+    /// Set the world-space position that the middle of the chain bends towards, like the
+    /// direction of a knee or an elbow.
+    ///
+    /// `None` keeps the chain bending the way it does in the animation.
+    pub fn set_pole(&self, pole: Option<Vec3>) {
+        match pole {
+            Some(pole) => entity::add_component(self.0 .0, ik_pole(), pole),
+            None => entity::remove_component(self.0 .0, ik_pole()),
+        }
+    }
+    /// Set how much the IK changes the animation, from 0 to 1
+    pub fn set_weight(&self, weight: f32) {
+        entity::add_component(self.0 .0, ik_weight(), weight);
+    }
+}
+impl AsRef<AnimationNodeRef> for TwoBoneIkNodeRef {
+    fn as_ref(&self) -> &AnimationNodeRef {
+        &self.0
+    }
+}
+
+/// Look-at IK animation node.
+/// This is an animation node which can be plugged into an animation player or other animation nodes.
+///
+/// It takes the animation of its input, and rotates a bone so that it points towards a
+/// world-space target. This is useful for making heads look at things, or for aiming weapons.
+///
+/// This is just a reference to an entity which lives in the ecs. You need to call `despawn` to
+/// remove it.
+#[derive(Debug, Clone, Copy)]
+pub struct LookAtIkNodeRef(pub AnimationNodeRef);
+impl LookAtIkNodeRef {
+    /// Create a new look-at IK node, which points the `axis` of `bone` towards the target.
+    /// The axis is in the space of the bone; for humanoid heads, it's usually [`Vec3::Z`].
+    ///
+    /// The node has no effect until it has a target.
+    pub fn new(input: impl AsRef<AnimationNodeRef>, bone: BindId, axis: Vec3) -> Self {
+        let input: &AnimationNodeRef = input.as_ref();
+        let node = Entity::new()
+            .with(look_at_ik_bind_id(), bone.as_str().to_string())
+            .with(look_at_ik_axis(), axis)
+            .with(name(), "Look-at IK".to_string())
+            .with(children(), vec![input.0])
+            .spawn();
+        entity::add_component(input.0, parent(), node);
+        Self(AnimationNodeRef(node))
+    }
+    /// Use an existing node
+    pub fn from_entity(entity: EntityId) -> Self {
+        Self(AnimationNodeRef::from_entity(entity))
+    }
+    /// Set the world-space position that the bone points towards
+    pub fn set_target(&self, target: Vec3) {
+        entity::remove_component(self.0 .0, ik_target_entity());
+        entity::add_component(self.0 .0, ik_target(), target);
+    }
+    /// Make the bone point towards the world-space position of `entity`
+    pub fn set_target_entity(&self, entity: EntityId) {
+        entity::add_component(self.0 .0, ik_target_entity(), entity);
+    }
+    /// Set how much the IK changes the animation, from 0 to 1
+    pub fn set_weight(&self, weight: f32) {
+        entity::add_component(self.0 .0, ik_weight(), weight);
+    }
+}
+impl AsRef<AnimationNodeRef> for LookAtIkNodeRef {
+    fn as_ref(&self) -> &AnimationNodeRef {
+        &self.0
+    }
+}
+
 /// Animation retargeting configuration.
 #[derive(Debug, Clone)]
 pub enum AnimationRetargeting {
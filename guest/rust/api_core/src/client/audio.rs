@@ -9,9 +9,135 @@ use crate::{
         transform::components::translation,
     },
     entity,
-    prelude::{game_time, Entity, EntityId, Vec3},
+    prelude::{game_time, Entity, EntityId, Vec2, Vec3, Vec4},
 };
 
+/// A named group of sounds which share a volume, effects and ducking.
+///
+/// Every bus other than [`Bus::Master`] is mixed into [`Bus::Master`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Bus {
+    #[default]
+    Master,
+    Music,
+    Sfx,
+    Voice,
+    Ui,
+}
+
+impl Bus {
+    /// The name of the bus, as used by the `audio_bus` component
+    pub fn name(&self) -> &'static str {
+        match self {
+            Bus::Master => "master",
+            Bus::Music => "music",
+            Bus::Sfx => "sfx",
+            Bus::Voice => "voice",
+            Bus::Ui => "ui",
+        }
+    }
+}
+
+/// Configures the volume, effects and ducking of a [`Bus`].
+///
+/// The effects are applied in the order high-pass, low-pass, EQ, compressor, delay, reverb.
+#[derive(Debug, Clone)]
+pub struct AudioBus {
+    /// The entity that configures the bus
+    pub entity: EntityId,
+}
+
+impl AudioBus {
+    pub fn new(bus: Bus) -> Self {
+        let entity = Entity::new()
+            .with(is_audio_bus(), ())
+            .with(audio_bus(), bus.name().to_string())
+            .with(name(), format!("Audio bus {}", bus.name()))
+            .spawn();
+        Self { entity }
+    }
+
+    /// Set the volume of the bus. 0.0 is 0%, 1.0 is 100%
+    pub fn set_volume(&self, volume: f32) {
+        entity::add_component(self.entity, audio_bus_volume(), volume);
+    }
+
+    pub fn set_muted(&self, muted: bool) {
+        entity::add_component(self.entity, audio_bus_muted(), muted);
+    }
+
+    /// Lower the volume of this bus by `amount` (0.0 to 1.0) while `key` plays louder than
+    /// `threshold`. `attack` and `release` are in seconds.
+    pub fn set_ducked_by(&self, key: Bus, amount: f32, threshold: f32, attack: f32, release: f32) {
+        entity::add_component(self.entity, audio_bus_ducked_by(), key.name().to_string());
+        entity::add_component(
+            self.entity,
+            audio_bus_ducking(),
+            Vec4::new(amount, threshold, attack, release),
+        );
+    }
+
+    /// Cut the frequencies below `freq` in Hz. `bandwidth` is in octaves.
+    pub fn set_high_pass(&self, freq: f32, bandwidth: f32) {
+        entity::add_component(
+            self.entity,
+            audio_bus_high_pass(),
+            Vec2::new(freq, bandwidth),
+        );
+    }
+
+    /// Cut the frequencies above `freq` in Hz. `bandwidth` is in octaves.
+    pub fn set_low_pass(&self, freq: f32, bandwidth: f32) {
+        entity::add_component(
+            self.entity,
+            audio_bus_low_pass(),
+            Vec2::new(freq, bandwidth),
+        );
+    }
+
+    /// Set the gains in dB of the lows, mids and highs
+    pub fn set_eq(&self, low: f32, mid: f32, high: f32) {
+        entity::add_component(self.entity, audio_bus_eq(), Vec3::new(low, mid, high));
+    }
+
+    /// `threshold` is in dB. `attack` and `release` are in seconds.
+    pub fn set_compressor(&self, threshold: f32, ratio: f32, attack: f32, release: f32) {
+        entity::add_component(
+            self.entity,
+            audio_bus_compressor(),
+            Vec4::new(threshold, ratio, attack, release),
+        );
+    }
+
+    /// `time` is in seconds, up to 5. `feedback` is the gain of each successive echo.
+    pub fn set_delay(&self, time: f32, feedback: f32, wet: f32) {
+        entity::add_component(
+            self.entity,
+            audio_bus_delay(),
+            Vec3::new(time, feedback, wet),
+        );
+    }
+
+    /// `room_size` and `damping` are from 0.0 to 1.0
+    pub fn set_reverb(&self, room_size: f32, damping: f32, wet: f32) {
+        entity::add_component(
+            self.entity,
+            audio_bus_reverb(),
+            Vec3::new(room_size, damping, wet),
+        );
+    }
+
+    /// Remove all effects from the bus
+    pub fn clear_effects(&self) {
+        entity::remove_component(self.entity, audio_bus_high_pass());
+        entity::remove_component(self.entity, audio_bus_low_pass());
+        entity::remove_component(self.entity, audio_bus_eq());
+        entity::remove_component(self.entity, audio_bus_compressor());
+        entity::remove_component(self.entity, audio_bus_delay());
+        entity::remove_component(self.entity, audio_bus_reverb());
+    }
+}
+
 /// stop the audio on the given entity
 pub fn stop(entity: EntityId) {
     if entity::exists(entity) {
@@ -21,6 +147,17 @@ pub fn stop(entity: EntityId) {
     }
 }
 
+/// seek the streaming audio on the given entity to `time`, see [`AudioPlayer::set_streaming`]
+///
+/// For a [`SpatialAudioPlayer`], this is the emitter entity.
+pub fn seek(entity: EntityId, time: Duration) {
+    if entity::exists(entity) {
+        entity::add_component(entity, audio_seek(), time);
+    } else {
+        eprintln!("Tried to seek audio on non-existent entity {}", entity);
+    }
+}
+
 /// play spatial audio
 #[derive(Debug, Clone)]
 pub struct SpatialAudioPlayer {
@@ -59,6 +196,17 @@ impl SpatialAudioPlayer {
         entity::add_component(self.player, looping(), val);
     }
 
+    /// Stream the sound while it plays rather than loading it up front, see
+    /// [`AudioPlayer::set_streaming`]
+    pub fn set_streaming(&self, val: bool) {
+        entity::add_component(self.player, audio_streaming(), val);
+    }
+
+    /// Set the bus that the sounds are played on
+    pub fn set_bus(&self, bus: Bus) {
+        entity::add_component(self.player, audio_bus(), bus.name().to_string());
+    }
+
     pub fn play_sound_on_entity(&self, url: impl Into<String>, emitter: EntityId) {
         entity::add_component(self.player, spatial_audio_emitter(), emitter);
         entity::add_component(self.player, audio_url(), url.into());
@@ -118,6 +266,11 @@ impl AudioPlayer {
     pub fn set_looping(&self, val: bool) {
         entity::add_component(self.entity, looping(), val);
     }
+    /// Stream the sound while it plays rather than loading it up front, e.g. for long music
+    /// tracks. Streaming sounds can be seeked with [`seek`].
+    pub fn set_streaming(&self, val: bool) {
+        entity::add_component(self.entity, audio_streaming(), val);
+    }
 
     /// Add a simple onepole lowpass filter to the sound with one param: roll off frequency
     pub fn add_one_pole_lpf(&self, rolloff_freq: f32) {
@@ -132,9 +285,28 @@ impl AudioPlayer {
     pub fn set_panning(&self, pan: f32) {
         entity::add_component(self.entity, panning(), pan);
     }
+    /// Set the bus that the sounds are played on
+    pub fn set_bus(&self, bus: Bus) {
+        entity::add_component(self.entity, audio_bus(), bus.name().to_string());
+    }
+    /// Set the seed for the random nodes of the audio graphs played by [`Self::play_graph`]
+    pub fn set_graph_seed(&self, seed: u64) {
+        entity::add_component(self.entity, audio_graph_seed(), seed);
+    }
     /// Play the sound, this will generate a new entity that represents the playing sound.
     pub fn play(&self, url: String) -> EntityId {
+        entity::remove_component(self.entity, audio_graph_url());
         entity::add_component(self.entity, audio_url(), url);
+        self.spawn_playing_sound()
+    }
+    /// Play an audio graph, a `.toml` file describing how to combine and randomize sounds.
+    /// This will generate a new entity that represents the playing sound.
+    pub fn play_graph(&self, url: String) -> EntityId {
+        entity::remove_component(self.entity, audio_url());
+        entity::add_component(self.entity, audio_graph_url(), url);
+        self.spawn_playing_sound()
+    }
+    fn spawn_playing_sound(&self) -> EntityId {
         entity::add_component(self.entity, play_now(), ());
         let id = Entity::new()
             .with(playing_sound(), ())
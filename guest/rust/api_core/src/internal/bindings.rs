@@ -13526,6 +13526,7 @@ pub mod ambient {
                                   
                                   pub type EntityId = super::super::super::ambient::bindings::types::EntityId;
                                   pub type Vec3 = super::super::super::ambient::bindings::types::Vec3;
+                                  pub type Quat = super::super::super::ambient::bindings::types::Quat;
                                   pub type Mat4 = super::super::super::ambient::bindings::types::Mat4;
                                   #[repr(C)]
                                   #[derive(Copy, Clone)]
@@ -13539,6 +13540,19 @@ pub mod ambient {
                                       f.debug_struct("CharacterCollision").field("side", &self.side).field("up", &self.up).field("down", &self.down).finish()
                                     }
                                   }
+                                  #[repr(C)]
+                                  #[derive(Copy, Clone)]
+                                  pub struct ShapeCastHit {
+                                    pub entity: EntityId,
+                                    pub distance: f32,
+                                    pub position: Vec3,
+                                    pub normal: Vec3,
+                                  }
+                                  impl ::core::fmt::Debug for ShapeCastHit {
+                                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                                      f.debug_struct("ShapeCastHit").field("entity", &self.entity).field("distance", &self.distance).field("position", &self.position).field("normal", &self.normal).finish()
+                                    }
+                                  }
                                   #[allow(clippy::all)]
                                   pub fn add_force(entity: EntityId,force: Vec3,){
                                     
@@ -13824,7 +13838,7 @@ pub mod ambient {
                                     }
                                   }
                                   #[allow(clippy::all)]
-                                  pub fn raycast_first(origin: Vec3,direction: Vec3,) -> Option<(EntityId,f32,)>{
+                                  pub fn raycast_first(origin: Vec3,direction: Vec3,layer_mask: u32,) -> Option<(EntityId,f32,)>{
                                     
                                     #[allow(unused_imports)]
                                     use wit_bindgen::rt::{alloc, vec::Vec, string::String};
@@ -13841,9 +13855,9 @@ pub mod ambient {
                                         #[cfg_attr(target_arch = "wasm32", link_name = "raycast-first")]
                                         #[cfg_attr(not(target_arch = "wasm32"), link_name = "ambient:bindings/server-physics_raycast-first")]
                                         fn wit_import(
-                                        _: f32, _: f32, _: f32, _: f32, _: f32, _: f32, _: i32, );
+                                        _: f32, _: f32, _: f32, _: f32, _: f32, _: f32, _: i32, _: i32, );
                                       }
-                                      wit_import(wit_bindgen::rt::as_f32(x0), wit_bindgen::rt::as_f32(y0), wit_bindgen::rt::as_f32(z0), wit_bindgen::rt::as_f32(x1), wit_bindgen::rt::as_f32(y1), wit_bindgen::rt::as_f32(z1), ptr2);
+                                      wit_import(wit_bindgen::rt::as_f32(x0), wit_bindgen::rt::as_f32(y0), wit_bindgen::rt::as_f32(z0), wit_bindgen::rt::as_f32(x1), wit_bindgen::rt::as_f32(y1), wit_bindgen::rt::as_f32(z1), wit_bindgen::rt::as_i32(layer_mask), ptr2);
                                       match i32::from(*((ptr2 + 0) as *const u8)) {
                                         0 => None,
                                         1 => Some((super::super::super::ambient::bindings::types::EntityId{id0:*((ptr2 + 8) as *const i64) as u64, id1:*((ptr2 + 16) as *const i64) as u64, }, *((ptr2 + 24) as *const f32))),
@@ -13855,7 +13869,7 @@ pub mod ambient {
                                     }
                                   }
                                   #[allow(clippy::all)]
-                                  pub fn raycast(origin: Vec3,direction: Vec3,) -> wit_bindgen::rt::vec::Vec::<(EntityId,f32,)>{
+                                  pub fn raycast(origin: Vec3,direction: Vec3,layer_mask: u32,) -> wit_bindgen::rt::vec::Vec::<(EntityId,f32,)>{
                                     
                                     #[allow(unused_imports)]
                                     use wit_bindgen::rt::{alloc, vec::Vec, string::String};
@@ -13872,14 +13886,167 @@ pub mod ambient {
                                         #[cfg_attr(target_arch = "wasm32", link_name = "raycast")]
                                         #[cfg_attr(not(target_arch = "wasm32"), link_name = "ambient:bindings/server-physics_raycast")]
                                         fn wit_import(
-                                        _: f32, _: f32, _: f32, _: f32, _: f32, _: f32, _: i32, );
+                                        _: f32, _: f32, _: f32, _: f32, _: f32, _: f32, _: i32, _: i32, );
                                       }
-                                      wit_import(wit_bindgen::rt::as_f32(x0), wit_bindgen::rt::as_f32(y0), wit_bindgen::rt::as_f32(z0), wit_bindgen::rt::as_f32(x1), wit_bindgen::rt::as_f32(y1), wit_bindgen::rt::as_f32(z1), ptr2);
+                                      wit_import(wit_bindgen::rt::as_f32(x0), wit_bindgen::rt::as_f32(y0), wit_bindgen::rt::as_f32(z0), wit_bindgen::rt::as_f32(x1), wit_bindgen::rt::as_f32(y1), wit_bindgen::rt::as_f32(z1), wit_bindgen::rt::as_i32(layer_mask), ptr2);
                                       let len3 = *((ptr2 + 4) as *const i32) as usize;
                                       Vec::from_raw_parts(*((ptr2 + 0) as *const i32) as *mut _, len3, len3)
                                     }
                                   }
                                   #[allow(clippy::all)]
+                                  pub fn sphere_cast(origin: Vec3,radius: f32,direction: Vec3,max_distance: f32,layer_mask: u32,) -> wit_bindgen::rt::vec::Vec::<ShapeCastHit>{
+                                    
+                                    #[allow(unused_imports)]
+                                    use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+                                    unsafe {
+                                      
+                                      #[repr(align(4))]
+                                      struct RetArea([u8; 8]);
+                                      let mut ret_area = ::core::mem::MaybeUninit::<RetArea>::uninit();
+                                      let super::super::super::ambient::bindings::types::Vec3{ x:x0, y:y0, z:z0, } = origin;
+                                      let super::super::super::ambient::bindings::types::Vec3{ x:x2, y:y2, z:z2, } = direction;
+                                      let ptr5 = ret_area.as_mut_ptr() as i32;
+                                      #[link(wasm_import_module = "ambient:bindings/server-physics")]
+                                      extern "C" {
+                                        #[cfg_attr(target_arch = "wasm32", link_name = "sphere-cast")]
+                                        #[cfg_attr(not(target_arch = "wasm32"), link_name = "ambient:bindings/server-physics_sphere-cast")]
+                                        fn wit_import(
+                                        _: f32, _: f32, _: f32, _: f32, _: f32, _: f32, _: f32, _: f32, _: i32, _: i32, );
+                                      }
+                                      wit_import(wit_bindgen::rt::as_f32(x0), wit_bindgen::rt::as_f32(y0), wit_bindgen::rt::as_f32(z0), wit_bindgen::rt::as_f32(radius), wit_bindgen::rt::as_f32(x2), wit_bindgen::rt::as_f32(y2), wit_bindgen::rt::as_f32(z2), wit_bindgen::rt::as_f32(max_distance), wit_bindgen::rt::as_i32(layer_mask), ptr5);
+                                      let len6 = *((ptr5 + 4) as *const i32) as usize;
+                                      Vec::from_raw_parts(*((ptr5 + 0) as *const i32) as *mut _, len6, len6)
+                                    }
+                                  }
+                                  #[allow(clippy::all)]
+                                  pub fn capsule_cast(origin: Vec3,rotation: Quat,radius: f32,height: f32,direction: Vec3,max_distance: f32,layer_mask: u32,) -> wit_bindgen::rt::vec::Vec::<ShapeCastHit>{
+                                    
+                                    #[allow(unused_imports)]
+                                    use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+                                    unsafe {
+                                      
+                                      #[repr(align(4))]
+                                      struct RetArea([u8; 8]);
+                                      let mut ret_area = ::core::mem::MaybeUninit::<RetArea>::uninit();
+                                      let super::super::super::ambient::bindings::types::Vec3{ x:x0, y:y0, z:z0, } = origin;
+                                      let super::super::super::ambient::bindings::types::Quat{ x:x1, y:y1, z:z1, w:w1, } = rotation;
+                                      let super::super::super::ambient::bindings::types::Vec3{ x:x4, y:y4, z:z4, } = direction;
+                                      let ptr7 = ret_area.as_mut_ptr() as i32;
+                                      #[link(wasm_import_module = "ambient:bindings/server-physics")]
+                                      extern "C" {
+                                        #[cfg_attr(target_arch = "wasm32", link_name = "capsule-cast")]
+                                        #[cfg_attr(not(target_arch = "wasm32"), link_name = "ambient:bindings/server-physics_capsule-cast")]
+                                        fn wit_import(
+                                        _: f32, _: f32, _: f32, _: f32, _: f32, _: f32, _: f32, _: f32, _: f32, _: f32, _: f32, _: f32, _: f32, _: i32, _: i32, );
+                                      }
+                                      wit_import(wit_bindgen::rt::as_f32(x0), wit_bindgen::rt::as_f32(y0), wit_bindgen::rt::as_f32(z0), wit_bindgen::rt::as_f32(x1), wit_bindgen::rt::as_f32(y1), wit_bindgen::rt::as_f32(z1), wit_bindgen::rt::as_f32(w1), wit_bindgen::rt::as_f32(radius), wit_bindgen::rt::as_f32(height), wit_bindgen::rt::as_f32(x4), wit_bindgen::rt::as_f32(y4), wit_bindgen::rt::as_f32(z4), wit_bindgen::rt::as_f32(max_distance), wit_bindgen::rt::as_i32(layer_mask), ptr7);
+                                      let len8 = *((ptr7 + 4) as *const i32) as usize;
+                                      Vec::from_raw_parts(*((ptr7 + 0) as *const i32) as *mut _, len8, len8)
+                                    }
+                                  }
+                                  #[allow(clippy::all)]
+                                  pub fn box_cast(origin: Vec3,rotation: Quat,half_extents: Vec3,direction: Vec3,max_distance: f32,layer_mask: u32,) -> wit_bindgen::rt::vec::Vec::<ShapeCastHit>{
+                                    
+                                    #[allow(unused_imports)]
+                                    use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+                                    unsafe {
+                                      
+                                      #[repr(align(4))]
+                                      struct RetArea([u8; 8]);
+                                      let mut ret_area = ::core::mem::MaybeUninit::<RetArea>::uninit();
+                                      let super::super::super::ambient::bindings::types::Vec3{ x:x0, y:y0, z:z0, } = origin;
+                                      let super::super::super::ambient::bindings::types::Quat{ x:x1, y:y1, z:z1, w:w1, } = rotation;
+                                      let super::super::super::ambient::bindings::types::Vec3{ x:x2, y:y2, z:z2, } = half_extents;
+                                      let super::super::super::ambient::bindings::types::Vec3{ x:x3, y:y3, z:z3, } = direction;
+                                      let ptr6 = ret_area.as_mut_ptr() as i32;
+                                      #[link(wasm_import_module = "ambient:bindings/server-physics")]
+                                      extern "C" {
+                                        #[cfg_attr(target_arch = "wasm32", link_name = "box-cast")]
+                                        #[cfg_attr(not(target_arch = "wasm32"), link_name = "ambient:bindings/server-physics_box-cast")]
+                                        fn wit_import(
+                                        _: f32, _: f32, _: f32, _: f32, _: f32, _: f32, _: f32, _: f32, _: f32, _: f32, _: f32, _: f32, _: f32, _: f32, _: i32, _: i32, );
+                                      }
+                                      wit_import(wit_bindgen::rt::as_f32(x0), wit_bindgen::rt::as_f32(y0), wit_bindgen::rt::as_f32(z0), wit_bindgen::rt::as_f32(x1), wit_bindgen::rt::as_f32(y1), wit_bindgen::rt::as_f32(z1), wit_bindgen::rt::as_f32(w1), wit_bindgen::rt::as_f32(x2), wit_bindgen::rt::as_f32(y2), wit_bindgen::rt::as_f32(z2), wit_bindgen::rt::as_f32(x3), wit_bindgen::rt::as_f32(y3), wit_bindgen::rt::as_f32(z3), wit_bindgen::rt::as_f32(max_distance), wit_bindgen::rt::as_i32(layer_mask), ptr6);
+                                      let len7 = *((ptr6 + 4) as *const i32) as usize;
+                                      Vec::from_raw_parts(*((ptr6 + 0) as *const i32) as *mut _, len7, len7)
+                                    }
+                                  }
+                                  #[allow(clippy::all)]
+                                  pub fn overlap_sphere(center: Vec3,radius: f32,layer_mask: u32,) -> wit_bindgen::rt::vec::Vec::<EntityId>{
+                                    
+                                    #[allow(unused_imports)]
+                                    use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+                                    unsafe {
+                                      
+                                      #[repr(align(4))]
+                                      struct RetArea([u8; 8]);
+                                      let mut ret_area = ::core::mem::MaybeUninit::<RetArea>::uninit();
+                                      let super::super::super::ambient::bindings::types::Vec3{ x:x0, y:y0, z:z0, } = center;
+                                      let ptr3 = ret_area.as_mut_ptr() as i32;
+                                      #[link(wasm_import_module = "ambient:bindings/server-physics")]
+                                      extern "C" {
+                                        #[cfg_attr(target_arch = "wasm32", link_name = "overlap-sphere")]
+                                        #[cfg_attr(not(target_arch = "wasm32"), link_name = "ambient:bindings/server-physics_overlap-sphere")]
+                                        fn wit_import(
+                                        _: f32, _: f32, _: f32, _: f32, _: i32, _: i32, );
+                                      }
+                                      wit_import(wit_bindgen::rt::as_f32(x0), wit_bindgen::rt::as_f32(y0), wit_bindgen::rt::as_f32(z0), wit_bindgen::rt::as_f32(radius), wit_bindgen::rt::as_i32(layer_mask), ptr3);
+                                      let len4 = *((ptr3 + 4) as *const i32) as usize;
+                                      Vec::from_raw_parts(*((ptr3 + 0) as *const i32) as *mut _, len4, len4)
+                                    }
+                                  }
+                                  #[allow(clippy::all)]
+                                  pub fn overlap_capsule(center: Vec3,rotation: Quat,radius: f32,height: f32,layer_mask: u32,) -> wit_bindgen::rt::vec::Vec::<EntityId>{
+                                    
+                                    #[allow(unused_imports)]
+                                    use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+                                    unsafe {
+                                      
+                                      #[repr(align(4))]
+                                      struct RetArea([u8; 8]);
+                                      let mut ret_area = ::core::mem::MaybeUninit::<RetArea>::uninit();
+                                      let super::super::super::ambient::bindings::types::Vec3{ x:x0, y:y0, z:z0, } = center;
+                                      let super::super::super::ambient::bindings::types::Quat{ x:x1, y:y1, z:z1, w:w1, } = rotation;
+                                      let ptr5 = ret_area.as_mut_ptr() as i32;
+                                      #[link(wasm_import_module = "ambient:bindings/server-physics")]
+                                      extern "C" {
+                                        #[cfg_attr(target_arch = "wasm32", link_name = "overlap-capsule")]
+                                        #[cfg_attr(not(target_arch = "wasm32"), link_name = "ambient:bindings/server-physics_overlap-capsule")]
+                                        fn wit_import(
+                                        _: f32, _: f32, _: f32, _: f32, _: f32, _: f32, _: f32, _: f32, _: f32, _: i32, _: i32, );
+                                      }
+                                      wit_import(wit_bindgen::rt::as_f32(x0), wit_bindgen::rt::as_f32(y0), wit_bindgen::rt::as_f32(z0), wit_bindgen::rt::as_f32(x1), wit_bindgen::rt::as_f32(y1), wit_bindgen::rt::as_f32(z1), wit_bindgen::rt::as_f32(w1), wit_bindgen::rt::as_f32(radius), wit_bindgen::rt::as_f32(height), wit_bindgen::rt::as_i32(layer_mask), ptr5);
+                                      let len6 = *((ptr5 + 4) as *const i32) as usize;
+                                      Vec::from_raw_parts(*((ptr5 + 0) as *const i32) as *mut _, len6, len6)
+                                    }
+                                  }
+                                  #[allow(clippy::all)]
+                                  pub fn overlap_box(center: Vec3,rotation: Quat,half_extents: Vec3,layer_mask: u32,) -> wit_bindgen::rt::vec::Vec::<EntityId>{
+                                    
+                                    #[allow(unused_imports)]
+                                    use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+                                    unsafe {
+                                      
+                                      #[repr(align(4))]
+                                      struct RetArea([u8; 8]);
+                                      let mut ret_area = ::core::mem::MaybeUninit::<RetArea>::uninit();
+                                      let super::super::super::ambient::bindings::types::Vec3{ x:x0, y:y0, z:z0, } = center;
+                                      let super::super::super::ambient::bindings::types::Quat{ x:x1, y:y1, z:z1, w:w1, } = rotation;
+                                      let super::super::super::ambient::bindings::types::Vec3{ x:x2, y:y2, z:z2, } = half_extents;
+                                      let ptr4 = ret_area.as_mut_ptr() as i32;
+                                      #[link(wasm_import_module = "ambient:bindings/server-physics")]
+                                      extern "C" {
+                                        #[cfg_attr(target_arch = "wasm32", link_name = "overlap-box")]
+                                        #[cfg_attr(not(target_arch = "wasm32"), link_name = "ambient:bindings/server-physics_overlap-box")]
+                                        fn wit_import(
+                                        _: f32, _: f32, _: f32, _: f32, _: f32, _: f32, _: f32, _: f32, _: f32, _: f32, _: i32, _: i32, );
+                                      }
+                                      wit_import(wit_bindgen::rt::as_f32(x0), wit_bindgen::rt::as_f32(y0), wit_bindgen::rt::as_f32(z0), wit_bindgen::rt::as_f32(x1), wit_bindgen::rt::as_f32(y1), wit_bindgen::rt::as_f32(z1), wit_bindgen::rt::as_f32(w1), wit_bindgen::rt::as_f32(x2), wit_bindgen::rt::as_f32(y2), wit_bindgen::rt::as_f32(z2), wit_bindgen::rt::as_i32(layer_mask), ptr4);
+                                      let len5 = *((ptr4 + 4) as *const i32) as usize;
+                                      Vec::from_raw_parts(*((ptr4 + 0) as *const i32) as *mut _, len5, len5)
+                                    }
+                                  }
+                                  #[allow(clippy::all)]
                                   pub fn move_character(entity: EntityId,displacement: Vec3,min_dist: f32,elapsed_time: f32,) -> CharacterCollision{
                                     
                                     #[allow(unused_imports)]
@@ -14080,21 +14247,144 @@ pub mod ambient {
                                   #[doc(hidden)]
                                   #[cfg(target_arch = "wasm32")]
                                   static __FORCE_SECTION_REF: fn() = super::super::super::__link_section;
-                                  
+                                  #[repr(u8)]
+                                  #[derive(Clone, Copy, PartialEq, Eq)]
+                                  pub enum Method {
+                                    Get,
+                                    Head,
+                                    Post,
+                                    Put,
+                                    Patch,
+                                    Delete,
+                                  }
+                                  impl ::core::fmt::Debug for Method {
+                                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                                      match self {
+                                        Method::Get => {
+                                          f.debug_tuple("Method::Get").finish()
+                                        }
+                                        Method::Head => {
+                                          f.debug_tuple("Method::Head").finish()
+                                        }
+                                        Method::Post => {
+                                          f.debug_tuple("Method::Post").finish()
+                                        }
+                                        Method::Put => {
+                                          f.debug_tuple("Method::Put").finish()
+                                        }
+                                        Method::Patch => {
+                                          f.debug_tuple("Method::Patch").finish()
+                                        }
+                                        Method::Delete => {
+                                          f.debug_tuple("Method::Delete").finish()
+                                        }
+                                      }
+                                    }
+                                  }
+                                  /// Sends a request; the response is delivered with the `HttpResponse` message
+                                  /// with the returned ID.
                                   #[allow(clippy::all)]
-                                  pub fn get(url: &str,){
+                                  pub fn request(method: Method,url: &str,headers: &[(&str,&str,)],body: &[u8],timeout_ms: Option<u32>,) -> u64{
                                     
                                     #[allow(unused_imports)]
                                     use wit_bindgen::rt::{alloc, vec::Vec, string::String};
                                     unsafe {
+                                      let mut cleanup_list = Vec::new();
                                       let vec0 = url;
                                       let ptr0 = vec0.as_ptr() as i32;
                                       let len0 = vec0.len() as i32;
+                                      let vec3 = headers;
+                                      let len3 = vec3.len() as i32;
+                                      let layout3 = alloc::Layout::from_size_align_unchecked(vec3.len() * 16, 4);
+                                      let result3 = if layout3.size() != 0
+                                      {
+                                        let ptr = alloc::alloc(layout3);
+                                        if ptr.is_null()
+                                        {
+                                          alloc::handle_alloc_error(layout3);
+                                        }
+                                        ptr
+                                      }else {
+                                        ::core::ptr::null_mut()
+                                      };
+                                      for (i, e) in vec3.into_iter().enumerate() {
+                                        let base = result3 as i32 + (i as i32) * 16;
+                                        {
+                                          let (t0_0, t0_1, ) = e;
+                                          let vec1 = t0_0;
+                                          let ptr1 = vec1.as_ptr() as i32;
+                                          let len1 = vec1.len() as i32;
+                                          *((base + 4) as *mut i32) = len1;
+                                          *((base + 0) as *mut i32) = ptr1;
+                                          let vec2 = t0_1;
+                                          let ptr2 = vec2.as_ptr() as i32;
+                                          let len2 = vec2.len() as i32;
+                                          *((base + 12) as *mut i32) = len2;
+                                          *((base + 8) as *mut i32) = ptr2;
+                                          
+                                        }}
+                                        cleanup_list.extend_from_slice(&[(result3, layout3),]);
+                                        let vec4 = body;
+                                        let ptr4 = vec4.as_ptr() as i32;
+                                        let len4 = vec4.len() as i32;
+                                        let (result5_0,result5_1,) = match timeout_ms {
+                                          Some(e) => (1i32, wit_bindgen::rt::as_i32(e)),
+                                          None => {
+                                            (0i32, 0i32)
+                                          },
+                                        };
+                                        #[link(wasm_import_module = "ambient:bindings/server-http")]
+                                        extern "C" {
+                                          #[cfg_attr(target_arch = "wasm32", link_name = "request")]
+                                          #[cfg_attr(not(target_arch = "wasm32"), link_name = "ambient:bindings/server-http_request")]
+                                          fn wit_import(
+                                          _: i32, _: i32, _: i32, _: i32, _: i32, _: i32, _: i32, _: i32, _: i32, ) -> i64;
+                                        }
+                                        let ret = wit_import(match method {
+                                          Method::Get => 0,
+                                          Method::Head => 1,
+                                          Method::Post => 2,
+                                          Method::Put => 3,
+                                          Method::Patch => 4,
+                                          Method::Delete => 5,
+                                        }, ptr0, len0, result3 as i32, len3, ptr4, len4, result5_0, result5_1);
+                                        for (ptr, layout) in cleanup_list {
+                                          
+                                          if layout.size() != 0 {
+                                            
+                                            alloc::dealloc(ptr, layout);
+                                            
+                                          }
+                                          
+                                        }
+                                        ret as u64
+                                      }
+                                    }
+                                    
+                                  }
+                                  
+                                  
+                                #[allow(clippy::all)]
+                                pub mod server_ambient_package {
+                                  #[used]
+                                  #[doc(hidden)]
+                                  #[cfg(target_arch = "wasm32")]
+                                  static __FORCE_SECTION_REF: fn() = super::super::super::__link_section;
+                                  
+                                  #[allow(clippy::all)]
+                                  pub fn load(package_url: &str,){
+                                    
+                                    #[allow(unused_imports)]
+                                    use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+                                    unsafe {
+                                      let vec0 = package_url;
+                                      let ptr0 = vec0.as_ptr() as i32;
+                                      let len0 = vec0.len() as i32;
                                       
-                                      #[link(wasm_import_module = "ambient:bindings/server-http")]
+                                      #[link(wasm_import_module = "ambient:bindings/server-ambient-package")]
                                       extern "C" {
-                                        #[cfg_attr(target_arch = "wasm32", link_name = "get")]
-                                        #[cfg_attr(not(target_arch = "wasm32"), link_name = "ambient:bindings/server-http_get")]
+                                        #[cfg_attr(target_arch = "wasm32", link_name = "load")]
+                                        #[cfg_attr(not(target_arch = "wasm32"), link_name = "ambient:bindings/server-ambient-package_load")]
                                         fn wit_import(
                                         _: i32, _: i32, );
                                       }
@@ -14106,26 +14396,47 @@ pub mod ambient {
                                 
                                 
                                 #[allow(clippy::all)]
-                                pub mod server_ambient_package {
+                                pub mod server_instance {
                                   #[used]
                                   #[doc(hidden)]
                                   #[cfg(target_arch = "wasm32")]
                                   static __FORCE_SECTION_REF: fn() = super::super::super::__link_section;
                                   
                                   #[allow(clippy::all)]
-                                  pub fn load(package_url: &str,){
+                                  pub fn create(id: &str,){
                                     
                                     #[allow(unused_imports)]
                                     use wit_bindgen::rt::{alloc, vec::Vec, string::String};
                                     unsafe {
-                                      let vec0 = package_url;
+                                      let vec0 = id;
                                       let ptr0 = vec0.as_ptr() as i32;
                                       let len0 = vec0.len() as i32;
                                       
-                                      #[link(wasm_import_module = "ambient:bindings/server-ambient-package")]
+                                      #[link(wasm_import_module = "ambient:bindings/server-instance")]
                                       extern "C" {
-                                        #[cfg_attr(target_arch = "wasm32", link_name = "load")]
-                                        #[cfg_attr(not(target_arch = "wasm32"), link_name = "ambient:bindings/server-ambient-package_load")]
+                                        #[cfg_attr(target_arch = "wasm32", link_name = "create")]
+                                        #[cfg_attr(not(target_arch = "wasm32"), link_name = "ambient:bindings/server-instance_create")]
+                                        fn wit_import(
+                                        _: i32, _: i32, );
+                                      }
+                                      wit_import(ptr0, len0);
+                                    }
+                                  }
+                                  
+                                  #[allow(clippy::all)]
+                                  pub fn destroy(id: &str,){
+                                    
+                                    #[allow(unused_imports)]
+                                    use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+                                    unsafe {
+                                      let vec0 = id;
+                                      let ptr0 = vec0.as_ptr() as i32;
+                                      let len0 = vec0.len() as i32;
+                                      
+                                      #[link(wasm_import_module = "ambient:bindings/server-instance")]
+                                      extern "C" {
+                                        #[cfg_attr(target_arch = "wasm32", link_name = "destroy")]
+                                        #[cfg_attr(not(target_arch = "wasm32"), link_name = "ambient:bindings/server-instance_destroy")]
                                         fn wit_import(
                                         _: i32, _: i32, );
                                       }
@@ -14133,6 +14444,30 @@ pub mod ambient {
                                     }
                                   }
                                   
+                                  #[allow(clippy::all)]
+                                  pub fn transfer_player(user_id: &str,instance_id: &str,){
+                                    
+                                    #[allow(unused_imports)]
+                                    use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+                                    unsafe {
+                                      let vec0 = user_id;
+                                      let ptr0 = vec0.as_ptr() as i32;
+                                      let len0 = vec0.len() as i32;
+                                      let vec1 = instance_id;
+                                      let ptr1 = vec1.as_ptr() as i32;
+                                      let len1 = vec1.len() as i32;
+                                      
+                                      #[link(wasm_import_module = "ambient:bindings/server-instance")]
+                                      extern "C" {
+                                        #[cfg_attr(target_arch = "wasm32", link_name = "transfer-player")]
+                                        #[cfg_attr(not(target_arch = "wasm32"), link_name = "ambient:bindings/server-instance_transfer-player")]
+                                        fn wit_import(
+                                        _: i32, _: i32, _: i32, _: i32, );
+                                      }
+                                      wit_import(ptr0, len0, ptr1, len1);
+                                    }
+                                  }
+                                  
                                 }
                                 
                               }
@@ -151,6 +151,94 @@ mod raw {
                 pub fn bind_ids() -> Component<Vec<String>> {
                     *BIND_IDS
                 }
+                static ROOT_MOTION_BONE: Lazy<Component<String>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::animation::root_motion_bone")
+                });
+                #[doc = "**Root motion bone**: Enables root motion on this animation player. The translation of the bone with this bind id along the `root_motion_axes` is removed from the animation, and the entities the player is applied to get a `root_motion_delta` instead.\n\n*Attributes*: Debuggable, Networked"]
+                pub fn root_motion_bone() -> Component<String> {
+                    *ROOT_MOTION_BONE
+                }
+                static ROOT_MOTION_DELTA: Lazy<Component<Vec3>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::animation::root_motion_delta")
+                });
+                #[doc = "**Root motion delta**: The world-space translation of the root motion bone during the last frame. Set on entities with an `apply_animation_player` whose player has a `root_motion_bone`.\n\n*Attributes*: Debuggable"]
+                pub fn root_motion_delta() -> Component<Vec3> {
+                    *ROOT_MOTION_DELTA
+                }
+                static TWO_BONE_IK_BIND_IDS: Lazy<Component<Vec<String>>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::animation::two_bone_ik_bind_ids")
+                });
+                #[doc = "**Two-bone IK bind ids**: If attached, this animation node is a two-bone IK node. It takes the output of its only child, and rotates the bones with the bind ids `[root, middle, end]` (e.g. upper leg, lower leg and foot) so that the end reaches `ik_target`.\n\n*Attributes*: Debuggable, Networked"]
+                pub fn two_bone_ik_bind_ids() -> Component<Vec<String>> {
+                    *TWO_BONE_IK_BIND_IDS
+                }
+                static LOOK_AT_IK_BIND_ID: Lazy<Component<String>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::animation::look_at_ik_bind_id")
+                });
+                #[doc = "**Look-at IK bind id**: If attached, this animation node is a look-at IK node. It takes the output of its only child, and rotates the bone with this bind id so that its `look_at_ik_axis` points towards `ik_target`.\n\n*Attributes*: Debuggable, Networked"]
+                pub fn look_at_ik_bind_id() -> Component<String> {
+                    *LOOK_AT_IK_BIND_ID
+                }
+                static LOOK_AT_IK_AXIS: Lazy<Component<Vec3>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::animation::look_at_ik_axis")
+                });
+                #[doc = "**Look-at IK axis**: The axis of the bone of a look-at IK node that is pointed towards the target, in the space of the bone. Defaults to the Z axis.\n\n*Attributes*: Debuggable, Networked"]
+                pub fn look_at_ik_axis() -> Component<Vec3> {
+                    *LOOK_AT_IK_AXIS
+                }
+                static IK_TARGET: Lazy<Component<Vec3>> =
+                    Lazy::new(|| __internal_get_component("ambient_core::animation::ik_target"));
+                #[doc = "**IK target**: The world-space position that an IK node reaches for or looks at.\n\n*Attributes*: Debuggable, Networked"]
+                pub fn ik_target() -> Component<Vec3> {
+                    *IK_TARGET
+                }
+                static IK_TARGET_ENTITY: Lazy<Component<EntityId>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::animation::ik_target_entity")
+                });
+                #[doc = "**IK target entity**: If attached, an IK node uses the world-space position of this entity as its target instead of `ik_target`.\n\n*Attributes*: Debuggable, Networked"]
+                pub fn ik_target_entity() -> Component<EntityId> {
+                    *IK_TARGET_ENTITY
+                }
+                static IK_POLE: Lazy<Component<Vec3>> =
+                    Lazy::new(|| __internal_get_component("ambient_core::animation::ik_pole"));
+                #[doc = "**IK pole**: The world-space position that the middle bone of a two-bone IK node bends towards, like the direction of a knee or an elbow. If not attached, the chain keeps bending the way it does in the animation.\n\n*Attributes*: Debuggable, Networked"]
+                pub fn ik_pole() -> Component<Vec3> {
+                    *IK_POLE
+                }
+                static IK_WEIGHT: Lazy<Component<f32>> =
+                    Lazy::new(|| __internal_get_component("ambient_core::animation::ik_weight"));
+                #[doc = "**IK weight**: How much an IK node changes the output of its child, from 0 to 1. Defaults to 1.\n\n*Attributes*: Debuggable, Networked"]
+                pub fn ik_weight() -> Component<f32> {
+                    *IK_WEIGHT
+                }
+                static BLEND_SPACE_POSITIONS: Lazy<Component<Vec<Vec2>>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::animation::blend_space_positions")
+                });
+                #[doc = "**Blend space positions**: If attached, this animation node is a blend space, which blends its `children` by where `blend_space_parameter` is between them. The value is the position of each child in the parameter space. If the positions are on a line, the blend space is 1D; otherwise, the children are blended by the triangle of the Delaunay triangulation of the positions that the parameter is in.\n\n*Attributes*: Debuggable, Networked"]
+                pub fn blend_space_positions() -> Component<Vec<Vec2>> {
+                    *BLEND_SPACE_POSITIONS
+                }
+                static BLEND_SPACE_PARAMETER: Lazy<Component<Vec2>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::animation::blend_space_parameter")
+                });
+                #[doc = "**Blend space parameter**: The position in the parameter space of a blend space to blend its children at, such as the velocity of a character. Positions outside of the children are clamped to the closest point between them.\n\n*Attributes*: Debuggable, Networked"]
+                pub fn blend_space_parameter() -> Component<Vec2> {
+                    *BLEND_SPACE_PARAMETER
+                }
+                static ADDITIVE_WEIGHT: Lazy<Component<f32>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::animation::additive_weight")
+                });
+                #[doc = "**Additive weight**: If attached, this animation node is an additive layer, which adds the difference between its second child and a reference pose to its first child, scaled by this weight. The reference pose is the third child if there is one, and otherwise the first frame of the second child, which must then be a clip.\n\n*Attributes*: Debuggable, Networked"]
+                pub fn additive_weight() -> Component<f32> {
+                    *ADDITIVE_WEIGHT
+                }
+                static ROOT_MOTION_AXES: Lazy<Component<Vec3>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::animation::root_motion_axes")
+                });
+                #[doc = "**Root motion axes**: Which axes of the translation of the `root_motion_bone`, relative to its parent, are extracted as root motion. 1 extracts the axis and 0 leaves it in the animation. Defaults to `[1, 0, 1]`, the horizontal plane of Y-up skeletons, so that vertical motion like the bob of a walk stays in the animation.\n\n*Attributes*: Debuggable, Networked"]
+                pub fn root_motion_axes() -> Component<Vec3> {
+                    *ROOT_MOTION_AXES
+                }
             }
         }
         pub mod app {
@@ -376,6 +464,107 @@ mod raw {
                 pub fn stop_now() -> Component<()> {
                     *STOP_NOW
                 }
+                static AUDIO_BUS: Lazy<Component<String>> =
+                    Lazy::new(|| __internal_get_component("ambient_core::audio::audio_bus"));
+                #[doc = "**Audio bus**: The bus the audio is played on: master, music, sfx, voice or ui. Defaults to master. On an entity with `is_audio_bus`, the bus that the entity configures.\n\n*Attributes*: MaybeResource, Debuggable"]
+                pub fn audio_bus() -> Component<String> {
+                    *AUDIO_BUS
+                }
+                static IS_AUDIO_BUS: Lazy<Component<()>> =
+                    Lazy::new(|| __internal_get_component("ambient_core::audio::is_audio_bus"));
+                #[doc = "**Is audio bus**: The entity configures the volume, effects and ducking of the bus in its `audio_bus` component. The effects are applied in the order high-pass, low-pass, EQ, compressor, delay, reverb.\n\n*Attributes*: MaybeResource, Debuggable"]
+                pub fn is_audio_bus() -> Component<()> {
+                    *IS_AUDIO_BUS
+                }
+                static AUDIO_BUS_VOLUME: Lazy<Component<f32>> =
+                    Lazy::new(|| __internal_get_component("ambient_core::audio::audio_bus_volume"));
+                #[doc = "**Audio bus volume**: The volume of the bus. Defaults to 1.\n\n*Attributes*: MaybeResource, Debuggable"]
+                pub fn audio_bus_volume() -> Component<f32> {
+                    *AUDIO_BUS_VOLUME
+                }
+                static AUDIO_BUS_MUTED: Lazy<Component<bool>> =
+                    Lazy::new(|| __internal_get_component("ambient_core::audio::audio_bus_muted"));
+                #[doc = "**Audio bus muted**: Whether or not the bus is muted.\n\n*Attributes*: MaybeResource, Debuggable"]
+                pub fn audio_bus_muted() -> Component<bool> {
+                    *AUDIO_BUS_MUTED
+                }
+                static AUDIO_BUS_DUCKED_BY: Lazy<Component<String>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::audio::audio_bus_ducked_by")
+                });
+                #[doc = "**Audio bus ducked by**: The bus which lowers the volume of this bus while it plays, e.g. voice to duck music under dialogue.\n\n*Attributes*: MaybeResource, Debuggable"]
+                pub fn audio_bus_ducked_by() -> Component<String> {
+                    *AUDIO_BUS_DUCKED_BY
+                }
+                static AUDIO_BUS_DUCKING: Lazy<Component<Vec4>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::audio::audio_bus_ducking")
+                });
+                #[doc = "**Audio bus ducking**: How the bus is ducked by `audio_bus_ducked_by`. The values are the amount the volume is lowered in [0, 1], the amplitude of the other bus at which this bus is fully ducked, and the attack and release times in seconds.\n\n*Attributes*: MaybeResource, Debuggable"]
+                pub fn audio_bus_ducking() -> Component<Vec4> {
+                    *AUDIO_BUS_DUCKING
+                }
+                static AUDIO_BUS_EQ: Lazy<Component<Vec3>> =
+                    Lazy::new(|| __internal_get_component("ambient_core::audio::audio_bus_eq"));
+                #[doc = "**Audio bus EQ**: Three band equalizer on the bus. The values are the gains in dB of the lows, mids and highs.\n\n*Attributes*: MaybeResource, Debuggable"]
+                pub fn audio_bus_eq() -> Component<Vec3> {
+                    *AUDIO_BUS_EQ
+                }
+                static AUDIO_BUS_COMPRESSOR: Lazy<Component<Vec4>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::audio::audio_bus_compressor")
+                });
+                #[doc = "**Audio bus compressor**: Compressor on the bus. The values are the threshold in dB, the ratio, and the attack and release times in seconds.\n\n*Attributes*: MaybeResource, Debuggable"]
+                pub fn audio_bus_compressor() -> Component<Vec4> {
+                    *AUDIO_BUS_COMPRESSOR
+                }
+                static AUDIO_BUS_DELAY: Lazy<Component<Vec3>> =
+                    Lazy::new(|| __internal_get_component("ambient_core::audio::audio_bus_delay"));
+                #[doc = "**Audio bus delay**: Delay on the bus. The values are the delay time in seconds (at most 5), the feedback and the wet amount.\n\n*Attributes*: MaybeResource, Debuggable"]
+                pub fn audio_bus_delay() -> Component<Vec3> {
+                    *AUDIO_BUS_DELAY
+                }
+                static AUDIO_BUS_REVERB: Lazy<Component<Vec3>> =
+                    Lazy::new(|| __internal_get_component("ambient_core::audio::audio_bus_reverb"));
+                #[doc = "**Audio bus reverb**: Reverb on the bus. The values are the room size and damping in [0, 1], and the wet amount.\n\n*Attributes*: MaybeResource, Debuggable"]
+                pub fn audio_bus_reverb() -> Component<Vec3> {
+                    *AUDIO_BUS_REVERB
+                }
+                static AUDIO_GRAPH_URL: Lazy<Component<String>> =
+                    Lazy::new(|| __internal_get_component("ambient_core::audio::audio_graph_url"));
+                #[doc = "**Audio graph URL**: The URL of an audio graph asset (a `.toml` file describing a tree of audio nodes) to play instead of `audio_url`.\n\n*Attributes*: MaybeResource, Debuggable"]
+                pub fn audio_graph_url() -> Component<String> {
+                    *AUDIO_GRAPH_URL
+                }
+                static AUDIO_GRAPH_SEED: Lazy<Component<u64>> =
+                    Lazy::new(|| __internal_get_component("ambient_core::audio::audio_graph_seed"));
+                #[doc = "**Audio graph seed**: The seed for the random nodes of the audio graph. If not set, a random seed is used every time the graph is played.\n\n*Attributes*: MaybeResource, Debuggable"]
+                pub fn audio_graph_seed() -> Component<u64> {
+                    *AUDIO_GRAPH_SEED
+                }
+                static AUDIO_STREAMING: Lazy<Component<bool>> =
+                    Lazy::new(|| __internal_get_component("ambient_core::audio::audio_streaming"));
+                #[doc = "**Audio streaming**: If true, the `audio_url` is downloaded and decoded while it plays instead of being loaded up front. Recommended for long tracks like music.\n\n*Attributes*: MaybeResource, Debuggable"]
+                pub fn audio_streaming() -> Component<bool> {
+                    *AUDIO_STREAMING
+                }
+                static AUDIO_BUS_HIGH_PASS: Lazy<Component<Vec2>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::audio::audio_bus_high_pass")
+                });
+                #[doc = "**Audio bus high-pass**: High-pass filter on the bus. The values are the cutoff frequency in Hz and the bandwidth in octaves.\n\n*Attributes*: MaybeResource, Debuggable"]
+                pub fn audio_bus_high_pass() -> Component<Vec2> {
+                    *AUDIO_BUS_HIGH_PASS
+                }
+                static AUDIO_BUS_LOW_PASS: Lazy<Component<Vec2>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::audio::audio_bus_low_pass")
+                });
+                #[doc = "**Audio bus low-pass**: Low-pass filter on the bus. The values are the cutoff frequency in Hz and the bandwidth in octaves.\n\n*Attributes*: MaybeResource, Debuggable"]
+                pub fn audio_bus_low_pass() -> Component<Vec2> {
+                    *AUDIO_BUS_LOW_PASS
+                }
+                static AUDIO_SEEK: Lazy<Component<Duration>> =
+                    Lazy::new(|| __internal_get_component("ambient_core::audio::audio_seek"));
+                #[doc = "**Audio seek**: Seeks the streaming sound of this entity to this time in the track. This is the playing sound entity of an audio player, or the emitter of a spatial audio player. The component is removed once the stream has started and the seek has been applied. Only has an effect on sounds played with `audio_streaming`.\n\n*Attributes*: MaybeResource, Debuggable"]
+                pub fn audio_seek() -> Component<Duration> {
+                    *AUDIO_SEEK
+                }
             }
         }
         pub mod camera {
@@ -2601,7 +2790,7 @@ mod raw {
                 static IS_PERSISTENT_RESOURCES: Lazy<Component<()>> = Lazy::new(|| {
                     __internal_get_component("ambient_core::network::is_persistent_resources")
                 });
-                #[doc = "**Is persistent resources**: If attached, this entity contains global resources that are persisted to disk and synchronized to clients.\n\n*Attributes*: Debuggable, Networked"]
+                #[doc = "**Is persistent resources**: If attached, this entity contains global resources that are persisted to disk and synchronized to clients.\n\n*Attributes*: Debuggable, Networked, Store"]
                 pub fn is_persistent_resources() -> Component<()> {
                     *IS_PERSISTENT_RESOURCES
                 }
@@ -2618,6 +2807,39 @@ mod raw {
                 pub fn no_sync() -> Component<()> {
                     *NO_SYNC
                 }
+                static ALWAYS_RELEVANT: Lazy<Component<()>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::network::always_relevant")
+                });
+                #[doc = "**Always relevant**: If attached, this entity is synchronized to every player, regardless of the relevancy rules.\n\n*Attributes*: Debuggable, Networked, Store"]
+                pub fn always_relevant() -> Component<()> {
+                    *ALWAYS_RELEVANT
+                }
+                static VISIBLE_TO: Lazy<Component<Vec<EntityId>>> =
+                    Lazy::new(|| __internal_get_component("ambient_core::network::visible_to"));
+                #[doc = "**Visible to**: If attached, this entity is only synchronized to the players in this list.\n\n*Attributes*: Debuggable, Networked, Store"]
+                pub fn visible_to() -> Component<Vec<EntityId>> {
+                    *VISIBLE_TO
+                }
+                static RELEVANCY_DISTANCE: Lazy<Component<f32>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::network::relevancy_distance")
+                });
+                #[doc = "**Relevancy distance**: The maximum distance from a player's viewpoint at which entities are synchronized to that player. Attach it to the resource entity to use it for all entities, or to an entity to override it for that entity.\n\n*Attributes*: MaybeResource, Debuggable, Networked, Store"]
+                pub fn relevancy_distance() -> Component<f32> {
+                    *RELEVANCY_DISTANCE
+                }
+                static LAST_INPUT_TICK: Lazy<Component<u64>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::network::last_input_tick")
+                });
+                #[doc = "**Last input tick**: The tick of the last input from this player that the server has simulated. Used by the client to reconcile its predicted components.\n\n*Attributes*: Debuggable, Networked"]
+                pub fn last_input_tick() -> Component<u64> {
+                    *LAST_INPUT_TICK
+                }
+                static INSTANCE_ID: Lazy<Component<String>> =
+                    Lazy::new(|| __internal_get_component("ambient_core::network::instance_id"));
+                #[doc = "**Instance ID**: The ID of the world instance that this world belongs to. The main instance, which players join when they connect, is `main`.\n\n*Attributes*: Debuggable, Resource"]
+                pub fn instance_id() -> Component<String> {
+                    *INSTANCE_ID
+                }
             }
         }
         pub mod package {
@@ -3184,6 +3406,19 @@ mod raw {
                 pub fn collider_loads() -> Component<Vec<EntityId>> {
                     *COLLIDER_LOADS
                 }
+                static COLLIDES_WITH: Lazy<Component<u32>> =
+                    Lazy::new(|| __internal_get_component("ambient_core::physics::collides_with"));
+                #[doc = "**Collides with**: The collision layers this collider collides with, as a bitmask. Two colliders only collide if each is on a layer that the other collides with. Colliders without this component collide with every layer. Also used to filter what character controllers collide with.\n\n*Attributes*: Debuggable, Networked, Store"]
+                pub fn collides_with() -> Component<u32> {
+                    *COLLIDES_WITH
+                }
+                static COLLISION_LAYER: Lazy<Component<u32>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::physics::collision_layer")
+                });
+                #[doc = "**Collision layer**: The collision layers this collider is on, as a bitmask. Colliders without this component, or with a value of `0`, are on the default layer, `1`. Updating this component will update the filtering of each attached shape in the physics scene.\n\n*Attributes*: Debuggable, Networked, Store"]
+                pub fn collision_layer() -> Component<u32> {
+                    *COLLISION_LAYER
+                }
                 static CONTACT_OFFSET: Lazy<Component<f32>> =
                     Lazy::new(|| __internal_get_component("ambient_core::physics::contact_offset"));
                 #[doc = "**Contact offset**: Contact offset (in meters) of this entity in the physics scene.\n\nUpdating this component will update the entity's contact offset for each attached shape in the physics scene.\n\n*Attributes*: Debuggable, Networked, Store"]
@@ -3202,6 +3437,112 @@ mod raw {
                 pub fn dynamic() -> Component<bool> {
                     *DYNAMIC
                 }
+                static IS_TRIGGER: Lazy<Component<()>> =
+                    Lazy::new(|| __internal_get_component("ambient_core::physics::is_trigger"));
+                #[doc = "**Is trigger**: If attached, the collider of this entity is a trigger volume: it does not collide with anything, and `TriggerEnter` and `TriggerExit` messages are sent when other colliders enter or leave it. On the client, these messages are approximated for sphere and cube triggers, so that clientside modules can preview them.\n\n*Attributes*: Debuggable, Networked, Store"]
+                pub fn is_trigger() -> Component<()> {
+                    *IS_TRIGGER
+                }
+                static JOINT_ACTOR_0: Lazy<Component<EntityId>> =
+                    Lazy::new(|| __internal_get_component("ambient_core::physics::joint_actor_0"));
+                #[doc = "**Joint actor 0**: The first entity connected by this joint. The joint is created once this entity has a collider.\n\n*Attributes*: Debuggable, Networked, Store"]
+                pub fn joint_actor_0() -> Component<EntityId> {
+                    *JOINT_ACTOR_0
+                }
+                static JOINT_ACTOR_1: Lazy<Component<EntityId>> =
+                    Lazy::new(|| __internal_get_component("ambient_core::physics::joint_actor_1"));
+                #[doc = "**Joint actor 1**: The second entity connected by this joint. If not attached, the first entity is jointed to its current place in the world.\n\n*Attributes*: Debuggable, Networked, Store"]
+                pub fn joint_actor_1() -> Component<EntityId> {
+                    *JOINT_ACTOR_1
+                }
+                static JOINT_BREAK_FORCE: Lazy<Component<f32>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::physics::joint_break_force")
+                });
+                #[doc = "**Joint break force**: The force at which this joint breaks. If not attached, the joint can not be broken by force.\n\n*Attributes*: Debuggable, Networked, Store"]
+                pub fn joint_break_force() -> Component<f32> {
+                    *JOINT_BREAK_FORCE
+                }
+                static JOINT_BREAK_TORQUE: Lazy<Component<f32>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::physics::joint_break_torque")
+                });
+                #[doc = "**Joint break torque**: The torque at which this joint breaks. If not attached, the joint can not be broken by torque.\n\n*Attributes*: Debuggable, Networked, Store"]
+                pub fn joint_break_torque() -> Component<f32> {
+                    *JOINT_BREAK_TORQUE
+                }
+                static JOINT_BROKEN: Lazy<Component<()>> =
+                    Lazy::new(|| __internal_get_component("ambient_core::physics::joint_broken"));
+                #[doc = "**Joint broken**: Attached by the physics system when this joint has broken. Removing it recreates the joint.\n\n*Attributes*: Debuggable, Networked, Store"]
+                pub fn joint_broken() -> Component<()> {
+                    *JOINT_BROKEN
+                }
+                static JOINT_DRIVE_DAMPING: Lazy<Component<f32>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::physics::joint_drive_damping")
+                });
+                #[doc = "**Joint drive damping**: The damping of the drive of this joint. For distance joints, the damping of the spring. For revolute and spherical joints, the damping of a spring towards the rest orientation. For D6 joints, the damping of the drives of the free and limited axes.\n\n*Attributes*: Debuggable, Networked, Store"]
+                pub fn joint_drive_damping() -> Component<f32> {
+                    *JOINT_DRIVE_DAMPING
+                }
+                static JOINT_DRIVE_FORCE_LIMIT: Lazy<Component<f32>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::physics::joint_drive_force_limit")
+                });
+                #[doc = "**Joint drive force limit**: The maximum force the drive of this joint can apply. Applies to revolute and D6 joints.\n\n*Attributes*: Debuggable, Networked, Store"]
+                pub fn joint_drive_force_limit() -> Component<f32> {
+                    *JOINT_DRIVE_FORCE_LIMIT
+                }
+                static JOINT_DRIVE_STIFFNESS: Lazy<Component<f32>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::physics::joint_drive_stiffness")
+                });
+                #[doc = "**Joint drive stiffness**: The stiffness of the drive of this joint. For distance joints, attaching it makes the joint a spring. For revolute and spherical joints, the stiffness of a spring towards the rest orientation. For D6 joints, the stiffness of the drives of the free and limited axes.\n\n*Attributes*: Debuggable, Networked, Store"]
+                pub fn joint_drive_stiffness() -> Component<f32> {
+                    *JOINT_DRIVE_STIFFNESS
+                }
+                static JOINT_DRIVE_VELOCITY: Lazy<Component<f32>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::physics::joint_drive_velocity")
+                });
+                #[doc = "**Joint drive velocity**: The target velocity of the motor of this joint. For revolute joints, attaching it enables the motor, in radians/second. For D6 joints, the target angular velocity around the twist axis.\n\n*Attributes*: Debuggable, Networked, Store"]
+                pub fn joint_drive_velocity() -> Component<f32> {
+                    *JOINT_DRIVE_VELOCITY
+                }
+                static JOINT_FREE_AXES: Lazy<Component<u32>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::physics::joint_free_axes")
+                });
+                #[doc = "**Joint free axes**: For D6 joints, a bitmask of the axes that can move freely: 1 = X, 2 = Y, 4 = Z, 8 = twist, 16 = swing 1, 32 = swing 2. Axes that are neither free nor limited are locked.\n\n*Attributes*: Debuggable, Networked, Store"]
+                pub fn joint_free_axes() -> Component<u32> {
+                    *JOINT_FREE_AXES
+                }
+                static JOINT_KIND: Lazy<Component<crate::ambient_core::physics::types::JointKind>> =
+                    Lazy::new(|| __internal_get_component("ambient_core::physics::joint_kind"));
+                #[doc = "**Joint kind**: If attached, this entity is a joint connecting `joint_actor_0` to `joint_actor_1`, and this is its kind.\n\n*Attributes*: Debuggable, Networked, Store, Enum"]
+                pub fn joint_kind() -> Component<crate::ambient_core::physics::types::JointKind> {
+                    *JOINT_KIND
+                }
+                static JOINT_LIMITED_AXES: Lazy<Component<u32>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::physics::joint_limited_axes")
+                });
+                #[doc = "**Joint limited axes**: For D6 joints, a bitmask of the axes that are limited by `joint_limits`, using the same bits as `joint_free_axes`.\n\n*Attributes*: Debuggable, Networked, Store"]
+                pub fn joint_limited_axes() -> Component<u32> {
+                    *JOINT_LIMITED_AXES
+                }
+                static JOINT_LIMITS: Lazy<Component<Vec2>> =
+                    Lazy::new(|| __internal_get_component("ambient_core::physics::joint_limits"));
+                #[doc = "**Joint limits**: The lower and upper limits of this joint. For revolute joints, the angle limits in radians. For prismatic joints, the translation limits along the X axis. For distance joints, the minimum and maximum distance. For spherical joints, the half-angles of the limit cone around the Y and Z axes. For D6 joints, the twist angle limits; limited swing axes use the magnitudes of the lower and upper limits as the half-angles of the cone around the Y and Z axes, and limited linear axes use the upper limit as distance.\n\n*Attributes*: Debuggable, Networked, Store"]
+                pub fn joint_limits() -> Component<Vec2> {
+                    *JOINT_LIMITS
+                }
+                static JOINT_LOCAL_FRAME_0: Lazy<Component<Mat4>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::physics::joint_local_frame_0")
+                });
+                #[doc = "**Joint local frame 0**: The frame of this joint relative to `joint_actor_0`. Defaults to the identity.\n\n*Attributes*: Debuggable, Networked, Store"]
+                pub fn joint_local_frame_0() -> Component<Mat4> {
+                    *JOINT_LOCAL_FRAME_0
+                }
+                static JOINT_LOCAL_FRAME_1: Lazy<Component<Mat4>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::physics::joint_local_frame_1")
+                });
+                #[doc = "**Joint local frame 1**: The frame of this joint relative to `joint_actor_1`, or to the world if there is no second actor. Defaults to the identity if there is a second actor, and to the current world frame of the joint otherwise.\n\n*Attributes*: Debuggable, Networked, Store"]
+                pub fn joint_local_frame_1() -> Component<Mat4> {
+                    *JOINT_LOCAL_FRAME_1
+                }
                 static KINEMATIC: Lazy<Component<()>> =
                     Lazy::new(|| __internal_get_component("ambient_core::physics::kinematic"));
                 #[doc = "**Kinematic**: If attached, and this entity is dynamic, this entity will also be kinematic (i.e. unable to be affected by other entities motion). Otherwise, it will receive forces normally.\n\n*Attributes*: Debuggable, Networked, Store"]
@@ -3241,6 +3582,47 @@ mod raw {
                 pub fn plane_collider() -> Component<()> {
                     *PLANE_COLLIDER
                 }
+                static RAGDOLL_BIND_IDS: Lazy<Component<Vec<String>>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::physics::ragdoll_bind_ids")
+                });
+                #[doc = "**Ragdoll bind IDs**: The bind IDs of the bones of this entity's `model_from_url` that get a ragdoll link, starting with the root of the ragdoll.\n\nEach link is attached to the link of its closest ancestor bone in the list.\n\n*Attributes*: Debuggable, Networked, Store"]
+                pub fn ragdoll_bind_ids() -> Component<Vec<String>> {
+                    *RAGDOLL_BIND_IDS
+                }
+                static RAGDOLL_BLEND: Lazy<Component<f32>> =
+                    Lazy::new(|| __internal_get_component("ambient_core::physics::ragdoll_blend"));
+                #[doc = "**Ragdoll blend**: How much the bones in `ragdoll_bind_ids` are driven by the ragdoll instead of animation, from 0 to 1.\n\nThe ragdoll is simulated on the server while this is more than 0, starting from the current pose of the model and the velocities of its bones.\n\n*Attributes*: Debuggable, Networked, Store"]
+                pub fn ragdoll_blend() -> Component<f32> {
+                    *RAGDOLL_BLEND
+                }
+                static RAGDOLL_JOINT_LIMITS: Lazy<Component<Vec<Vec2>>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::physics::ragdoll_joint_limits")
+                });
+                #[doc = "**Ragdoll joint limits**: The twist and swing limits, in radians, of the joint connecting each ragdoll link to its parent link. Defaults to 45 degrees for links without a limit.\n\n*Attributes*: Debuggable, Networked, Store"]
+                pub fn ragdoll_joint_limits() -> Component<Vec<Vec2>> {
+                    *RAGDOLL_JOINT_LIMITS
+                }
+                static RAGDOLL_LINK_POSES: Lazy<Component<Vec<Mat4>>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::physics::ragdoll_link_poses")
+                });
+                #[doc = "**Ragdoll link poses**: The world transforms of the ragdoll links, in the order of `ragdoll_bind_ids`. Updated by the server every frame while the ragdoll is simulated, so it is not stored.\n\n*Attributes*: Debuggable, Networked"]
+                pub fn ragdoll_link_poses() -> Component<Vec<Mat4>> {
+                    *RAGDOLL_LINK_POSES
+                }
+                static RAGDOLL_LINK_RADII: Lazy<Component<Vec<f32>>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::physics::ragdoll_link_radii")
+                });
+                #[doc = "**Ragdoll link radii**: The radius of the capsule collider of each ragdoll link. Defaults to 0.1 for links without a radius.\n\n*Attributes*: Debuggable, Networked, Store"]
+                pub fn ragdoll_link_radii() -> Component<Vec<f32>> {
+                    *RAGDOLL_LINK_RADII
+                }
+                static REPORT_CONTACTS: Lazy<Component<()>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::physics::report_contacts")
+                });
+                #[doc = "**Report contacts**: If attached, the contacts of this collider with other colliders are reported with `ContactBegin`, `ContactPersist` and `ContactEnd` messages, including the contact points, normals, impulse and relative velocity. This has a cost, so it should only be attached to colliders that need it.\n\n*Attributes*: Debuggable, Networked, Store"]
+                pub fn report_contacts() -> Component<()> {
+                    *REPORT_CONTACTS
+                }
                 static REST_OFFSET: Lazy<Component<f32>> =
                     Lazy::new(|| __internal_get_component("ambient_core::physics::rest_offset"));
                 #[doc = "**Rest offset**: Rest offset (in meters) of this entity in the physics scene.\n\nUpdating this component will update the entity's rest offset for each attached shape in the physics scene.\n\n*Attributes*: Debuggable, Networked, Store"]
@@ -3279,6 +3661,116 @@ mod raw {
                 pub fn visualize_collider() -> Component<()> {
                     *VISUALIZE_COLLIDER
                 }
+                static CHARACTER_MOVEMENT_DIRECTION: Lazy<Component<Vec2>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::physics::character_movement_direction")
+                });
+                #[doc = "**Character movement direction**: The direction in the XY plane that the local player wants to move their character controller in, with a length of up to 1. Set it on the player entity on the client to have the movement predicted by the client and simulated by the server.\n\n*Attributes*: Debuggable"]
+                pub fn character_movement_direction() -> Component<Vec2> {
+                    *CHARACTER_MOVEMENT_DIRECTION
+                }
+                static CHARACTER_MOVEMENT_SPEED: Lazy<Component<f32>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::physics::character_movement_speed")
+                });
+                #[doc = "**Character movement speed**: The maximum speed, in meters per second, at which the server moves a player with `character_movement_direction`. Defaults to 5.\n\n*Attributes*: Debuggable, Networked, Store"]
+                pub fn character_movement_speed() -> Component<f32> {
+                    *CHARACTER_MOVEMENT_SPEED
+                }
+            }
+            #[doc = r" Auto-generated type definitions."]
+            pub mod types {
+                use crate::{global::serde, message::*};
+                #[derive(
+                    Copy,
+                    Clone,
+                    Debug,
+                    PartialEq,
+                    Eq,
+                    serde :: Serialize,
+                    serde :: Deserialize,
+                    Default,
+                )]
+                #[serde(crate = "self::serde")]
+                #[doc = "**JointKind**: The kind of a joint."]
+                pub enum JointKind {
+                    #[default]
+                    #[doc = "Rigidly connects the actors."]
+                    Fixed,
+                    #[doc = "Allows rotation around the X axis of the joint frames."]
+                    Revolute,
+                    #[doc = "Allows rotation around any axis, like a ball-and-socket."]
+                    Spherical,
+                    #[doc = "Allows translation along the X axis of the joint frames."]
+                    Prismatic,
+                    #[doc = "Keeps the distance between the joint frames within the limits."]
+                    Distance,
+                    #[doc = "Configurable joint: each of its six axes can be locked, limited or free."]
+                    D6,
+                }
+                impl crate::ecs::EnumComponent for JointKind {
+                    fn to_u32(&self) -> u32 {
+                        match self {
+                            Self::Fixed => JointKind::Fixed as u32,
+                            Self::Revolute => JointKind::Revolute as u32,
+                            Self::Spherical => JointKind::Spherical as u32,
+                            Self::Prismatic => JointKind::Prismatic as u32,
+                            Self::Distance => JointKind::Distance as u32,
+                            Self::D6 => JointKind::D6 as u32,
+                        }
+                    }
+                    fn from_u32(value: u32) -> Option<Self> {
+                        if value == JointKind::Fixed as u32 {
+                            return Some(Self::Fixed);
+                        }
+                        if value == JointKind::Revolute as u32 {
+                            return Some(Self::Revolute);
+                        }
+                        if value == JointKind::Spherical as u32 {
+                            return Some(Self::Spherical);
+                        }
+                        if value == JointKind::Prismatic as u32 {
+                            return Some(Self::Prismatic);
+                        }
+                        if value == JointKind::Distance as u32 {
+                            return Some(Self::Distance);
+                        }
+                        if value == JointKind::D6 as u32 {
+                            return Some(Self::D6);
+                        }
+                        None
+                    }
+                }
+                impl crate::ecs::SupportedValue for JointKind {
+                    fn from_result(result: crate::ecs::WitComponentValue) -> Option<Self> {
+                        use crate::ecs::EnumComponent;
+                        u32::from_result(result).and_then(Self::from_u32)
+                    }
+                    fn into_result(self) -> crate::ecs::WitComponentValue {
+                        use crate::ecs::EnumComponent;
+                        self.to_u32().into_result()
+                    }
+                    fn from_value(value: crate::ecs::ComponentValue) -> Option<Self> {
+                        use crate::ecs::EnumComponent;
+                        u32::from_value(value).and_then(Self::from_u32)
+                    }
+                    fn into_value(self) -> crate::ecs::ComponentValue {
+                        use crate::ecs::EnumComponent;
+                        self.to_u32().into_value()
+                    }
+                }
+                impl MessageSerde for JointKind {
+                    fn serialize_message_part(
+                        &self,
+                        output: &mut Vec<u8>,
+                    ) -> Result<(), MessageSerdeError> {
+                        crate::ecs::EnumComponent::to_u32(self).serialize_message_part(output)
+                    }
+                    fn deserialize_message_part(
+                        input: &mut dyn std::io::Read,
+                    ) -> Result<Self, MessageSerdeError> {
+                        crate::ecs::EnumComponent::from_u32(u32::deserialize_message_part(input)?)
+                            .ok_or(MessageSerdeError::InvalidValue)
+                    }
+                }
             }
             #[doc = r" Auto-generated concept definitions. Concepts are collections of components that describe some form of gameplay concept."]
             #[doc = r""]
@@ -3396,7 +3888,7 @@ mod raw {
                 };
                 static PREFAB_FROM_URL: Lazy<Component<String>> =
                     Lazy::new(|| __internal_get_component("ambient_core::prefab::prefab_from_url"));
-                #[doc = "**Prefab from URL**: Load and attach a prefab from a URL or relative path.\n\nWhen loaded, the components from this prefab will add to or replace the existing components for the entity.\n\n*Attributes*: Debuggable, Store"]
+                #[doc = "**Prefab from URL**: Load and attach a prefab from a URL or relative path.\n\nWhen loaded, the components of the root of this prefab will add to or replace the existing components for the entity, and the rest of its entities are spawned as descendants of the entity. Setting this again reloads the prefab, keeping the components in `prefab_overrides`.\n\n*Attributes*: Debuggable, Store"]
                 pub fn prefab_from_url() -> Component<String> {
                     *PREFAB_FROM_URL
                 }
@@ -3406,6 +3898,13 @@ mod raw {
                 pub fn spawned() -> Component<()> {
                     *SPAWNED
                 }
+                static PREFAB_OVERRIDES: Lazy<Component<Vec<String>>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::prefab::prefab_overrides")
+                });
+                #[doc = "**Prefab overrides**: The components of this entity that were set for this instance of a prefab, as a list of component paths (e.g. `ambient_core::transform::translation`). When the prefab is reloaded, these components keep their values instead of being replaced by the ones in the prefab. This can be attached to the root of the instance, or any of the entities spawned from the prefab.\n\n*Attributes*: Debuggable, Store"]
+                pub fn prefab_overrides() -> Component<Vec<String>> {
+                    *PREFAB_OVERRIDES
+                }
             }
         }
         pub mod primitives {
@@ -4501,7 +5000,7 @@ mod raw {
                 }
                 static ROTATION: Lazy<Component<Quat>> =
                     Lazy::new(|| __internal_get_component("ambient_core::transform::rotation"));
-                #[doc = "**Rotation**: The rotation of this entity.\n\n*Attributes*: Debuggable, Networked, Store"]
+                #[doc = "**Rotation**: The rotation of this entity.\n\n*Attributes*: Debuggable, Interpolated, Networked, Store"]
                 pub fn rotation() -> Component<Quat> {
                     *ROTATION
                 }
@@ -4520,7 +5019,7 @@ mod raw {
                 }
                 static TRANSLATION: Lazy<Component<Vec3>> =
                     Lazy::new(|| __internal_get_component("ambient_core::transform::translation"));
-                #[doc = "**Translation**: The translation/position of this entity.\n\n*Attributes*: Debuggable, Networked, Store"]
+                #[doc = "**Translation**: The translation/position of this entity.\n\n*Attributes*: Debuggable, Interpolated, Networked, Predicted, Store"]
                 pub fn translation() -> Component<Vec3> {
                     *TRANSLATION
                 }
@@ -5031,6 +5530,263 @@ mod raw {
             }
             impl RuntimeMessage for ColliderLoads {}
             #[derive(Clone, Debug)]
+            #[doc = "**TriggerEnter**: Sent when a collider enters a trigger (an entity with `is_trigger`)."]
+            pub struct TriggerEnter {
+                pub trigger: EntityId,
+                pub other: EntityId,
+            }
+            impl TriggerEnter {
+                #[allow(clippy::too_many_arguments)]
+                pub fn new(trigger: impl Into<EntityId>, other: impl Into<EntityId>) -> Self {
+                    Self {
+                        trigger: trigger.into(),
+                        other: other.into(),
+                    }
+                }
+            }
+            impl Message for TriggerEnter {
+                fn id() -> &'static str {
+                    "ambient_core::TriggerEnter"
+                }
+                fn serialize_message(&self) -> Result<Vec<u8>, MessageSerdeError> {
+                    let mut output = vec![];
+                    self.trigger.serialize_message_part(&mut output)?;
+                    self.other.serialize_message_part(&mut output)?;
+                    Ok(output)
+                }
+                fn deserialize_message(mut input: &[u8]) -> Result<Self, MessageSerdeError> {
+                    Ok(Self {
+                        trigger: EntityId::deserialize_message_part(&mut input)?,
+                        other: EntityId::deserialize_message_part(&mut input)?,
+                    })
+                }
+            }
+            impl RuntimeMessage for TriggerEnter {}
+            #[derive(Clone, Debug)]
+            #[doc = "**TriggerExit**: Sent when a collider leaves a trigger (an entity with `is_trigger`)."]
+            pub struct TriggerExit {
+                pub trigger: EntityId,
+                pub other: EntityId,
+            }
+            impl TriggerExit {
+                #[allow(clippy::too_many_arguments)]
+                pub fn new(trigger: impl Into<EntityId>, other: impl Into<EntityId>) -> Self {
+                    Self {
+                        trigger: trigger.into(),
+                        other: other.into(),
+                    }
+                }
+            }
+            impl Message for TriggerExit {
+                fn id() -> &'static str {
+                    "ambient_core::TriggerExit"
+                }
+                fn serialize_message(&self) -> Result<Vec<u8>, MessageSerdeError> {
+                    let mut output = vec![];
+                    self.trigger.serialize_message_part(&mut output)?;
+                    self.other.serialize_message_part(&mut output)?;
+                    Ok(output)
+                }
+                fn deserialize_message(mut input: &[u8]) -> Result<Self, MessageSerdeError> {
+                    Ok(Self {
+                        trigger: EntityId::deserialize_message_part(&mut input)?,
+                        other: EntityId::deserialize_message_part(&mut input)?,
+                    })
+                }
+            }
+            impl RuntimeMessage for TriggerExit {}
+            #[derive(Clone, Debug)]
+            #[doc = "**ContactBegin**: Sent when two colliders start touching, if either has `report_contacts`."]
+            pub struct ContactBegin {
+                pub a: EntityId,
+                pub b: EntityId,
+                pub points: Vec<Vec3>,
+                pub normals: Vec<Vec3>,
+                pub impulse: Vec3,
+                pub relative_velocity: Vec3,
+            }
+            impl ContactBegin {
+                #[allow(clippy::too_many_arguments)]
+                pub fn new(
+                    a: impl Into<EntityId>,
+                    b: impl Into<EntityId>,
+                    points: impl Into<Vec<Vec3>>,
+                    normals: impl Into<Vec<Vec3>>,
+                    impulse: impl Into<Vec3>,
+                    relative_velocity: impl Into<Vec3>,
+                ) -> Self {
+                    Self {
+                        a: a.into(),
+                        b: b.into(),
+                        points: points.into(),
+                        normals: normals.into(),
+                        impulse: impulse.into(),
+                        relative_velocity: relative_velocity.into(),
+                    }
+                }
+            }
+            impl Message for ContactBegin {
+                fn id() -> &'static str {
+                    "ambient_core::ContactBegin"
+                }
+                fn serialize_message(&self) -> Result<Vec<u8>, MessageSerdeError> {
+                    let mut output = vec![];
+                    self.a.serialize_message_part(&mut output)?;
+                    self.b.serialize_message_part(&mut output)?;
+                    self.points.serialize_message_part(&mut output)?;
+                    self.normals.serialize_message_part(&mut output)?;
+                    self.impulse.serialize_message_part(&mut output)?;
+                    self.relative_velocity.serialize_message_part(&mut output)?;
+                    Ok(output)
+                }
+                fn deserialize_message(mut input: &[u8]) -> Result<Self, MessageSerdeError> {
+                    Ok(Self {
+                        a: EntityId::deserialize_message_part(&mut input)?,
+                        b: EntityId::deserialize_message_part(&mut input)?,
+                        points: Vec::<Vec3>::deserialize_message_part(&mut input)?,
+                        normals: Vec::<Vec3>::deserialize_message_part(&mut input)?,
+                        impulse: Vec3::deserialize_message_part(&mut input)?,
+                        relative_velocity: Vec3::deserialize_message_part(&mut input)?,
+                    })
+                }
+            }
+            impl RuntimeMessage for ContactBegin {}
+            #[derive(Clone, Debug)]
+            #[doc = "**ContactPersist**: Sent every frame while two colliders keep touching, if either has `report_contacts`."]
+            pub struct ContactPersist {
+                pub a: EntityId,
+                pub b: EntityId,
+                pub points: Vec<Vec3>,
+                pub normals: Vec<Vec3>,
+                pub impulse: Vec3,
+                pub relative_velocity: Vec3,
+            }
+            impl ContactPersist {
+                #[allow(clippy::too_many_arguments)]
+                pub fn new(
+                    a: impl Into<EntityId>,
+                    b: impl Into<EntityId>,
+                    points: impl Into<Vec<Vec3>>,
+                    normals: impl Into<Vec<Vec3>>,
+                    impulse: impl Into<Vec3>,
+                    relative_velocity: impl Into<Vec3>,
+                ) -> Self {
+                    Self {
+                        a: a.into(),
+                        b: b.into(),
+                        points: points.into(),
+                        normals: normals.into(),
+                        impulse: impulse.into(),
+                        relative_velocity: relative_velocity.into(),
+                    }
+                }
+            }
+            impl Message for ContactPersist {
+                fn id() -> &'static str {
+                    "ambient_core::ContactPersist"
+                }
+                fn serialize_message(&self) -> Result<Vec<u8>, MessageSerdeError> {
+                    let mut output = vec![];
+                    self.a.serialize_message_part(&mut output)?;
+                    self.b.serialize_message_part(&mut output)?;
+                    self.points.serialize_message_part(&mut output)?;
+                    self.normals.serialize_message_part(&mut output)?;
+                    self.impulse.serialize_message_part(&mut output)?;
+                    self.relative_velocity.serialize_message_part(&mut output)?;
+                    Ok(output)
+                }
+                fn deserialize_message(mut input: &[u8]) -> Result<Self, MessageSerdeError> {
+                    Ok(Self {
+                        a: EntityId::deserialize_message_part(&mut input)?,
+                        b: EntityId::deserialize_message_part(&mut input)?,
+                        points: Vec::<Vec3>::deserialize_message_part(&mut input)?,
+                        normals: Vec::<Vec3>::deserialize_message_part(&mut input)?,
+                        impulse: Vec3::deserialize_message_part(&mut input)?,
+                        relative_velocity: Vec3::deserialize_message_part(&mut input)?,
+                    })
+                }
+            }
+            impl RuntimeMessage for ContactPersist {}
+            #[derive(Clone, Debug)]
+            #[doc = "**ContactEnd**: Sent when two colliders stop touching, if either has `report_contacts`."]
+            pub struct ContactEnd {
+                pub a: EntityId,
+                pub b: EntityId,
+            }
+            impl ContactEnd {
+                #[allow(clippy::too_many_arguments)]
+                pub fn new(a: impl Into<EntityId>, b: impl Into<EntityId>) -> Self {
+                    Self {
+                        a: a.into(),
+                        b: b.into(),
+                    }
+                }
+            }
+            impl Message for ContactEnd {
+                fn id() -> &'static str {
+                    "ambient_core::ContactEnd"
+                }
+                fn serialize_message(&self) -> Result<Vec<u8>, MessageSerdeError> {
+                    let mut output = vec![];
+                    self.a.serialize_message_part(&mut output)?;
+                    self.b.serialize_message_part(&mut output)?;
+                    Ok(output)
+                }
+                fn deserialize_message(mut input: &[u8]) -> Result<Self, MessageSerdeError> {
+                    Ok(Self {
+                        a: EntityId::deserialize_message_part(&mut input)?,
+                        b: EntityId::deserialize_message_part(&mut input)?,
+                    })
+                }
+            }
+            impl RuntimeMessage for ContactEnd {}
+            #[derive(Clone, Debug)]
+            #[doc = "**AnimationEvent**: Sent to server-side modules when an animation player crosses an event of one of its clips. `weight` is how much the clip contributes to the output of the player, from 0 to 1."]
+            pub struct AnimationEvent {
+                pub player: EntityId,
+                pub node: EntityId,
+                pub name: String,
+                pub weight: f32,
+            }
+            impl AnimationEvent {
+                #[allow(clippy::too_many_arguments)]
+                pub fn new(
+                    player: impl Into<EntityId>,
+                    node: impl Into<EntityId>,
+                    name: impl Into<String>,
+                    weight: impl Into<f32>,
+                ) -> Self {
+                    Self {
+                        player: player.into(),
+                        node: node.into(),
+                        name: name.into(),
+                        weight: weight.into(),
+                    }
+                }
+            }
+            impl Message for AnimationEvent {
+                fn id() -> &'static str {
+                    "ambient_core::AnimationEvent"
+                }
+                fn serialize_message(&self) -> Result<Vec<u8>, MessageSerdeError> {
+                    let mut output = vec![];
+                    self.player.serialize_message_part(&mut output)?;
+                    self.node.serialize_message_part(&mut output)?;
+                    self.name.serialize_message_part(&mut output)?;
+                    self.weight.serialize_message_part(&mut output)?;
+                    Ok(output)
+                }
+                fn deserialize_message(mut input: &[u8]) -> Result<Self, MessageSerdeError> {
+                    Ok(Self {
+                        player: EntityId::deserialize_message_part(&mut input)?,
+                        node: EntityId::deserialize_message_part(&mut input)?,
+                        name: String::deserialize_message_part(&mut input)?,
+                        weight: f32::deserialize_message_part(&mut input)?,
+                    })
+                }
+            }
+            impl RuntimeMessage for AnimationEvent {}
+            #[derive(Clone, Debug)]
             #[doc = "**ModuleLoad**: Sent to a module when it loads."]
             pub struct ModuleLoad;
             impl ModuleLoad {
@@ -5361,24 +6117,30 @@ mod raw {
             }
             impl RuntimeMessage for WindowCursorLockChange {}
             #[derive(Clone, Debug)]
-            #[doc = "**HttpResponse**: Sent when an HTTP response is received."]
+            #[doc = "**HttpResponse**: Sent when an HTTP response is received, or the request failed. `id` is the ID returned when the request was sent, and each header is formatted as `name: value`."]
             pub struct HttpResponse {
+                pub id: u64,
                 pub url: String,
                 pub status: u32,
+                pub headers: Vec<String>,
                 pub body: Vec<u8>,
                 pub error: Option<String>,
             }
             impl HttpResponse {
                 #[allow(clippy::too_many_arguments)]
                 pub fn new(
+                    id: impl Into<u64>,
                     url: impl Into<String>,
                     status: impl Into<u32>,
+                    headers: impl Into<Vec<String>>,
                     body: impl Into<Vec<u8>>,
                     error: impl Into<Option<String>>,
                 ) -> Self {
                     Self {
+                        id: id.into(),
                         url: url.into(),
                         status: status.into(),
+                        headers: headers.into(),
                         body: body.into(),
                         error: error.into(),
                     }
@@ -5390,16 +6152,20 @@ mod raw {
                 }
                 fn serialize_message(&self) -> Result<Vec<u8>, MessageSerdeError> {
                     let mut output = vec![];
+                    self.id.serialize_message_part(&mut output)?;
                     self.url.serialize_message_part(&mut output)?;
                     self.status.serialize_message_part(&mut output)?;
+                    self.headers.serialize_message_part(&mut output)?;
                     self.body.serialize_message_part(&mut output)?;
                     self.error.serialize_message_part(&mut output)?;
                     Ok(output)
                 }
                 fn deserialize_message(mut input: &[u8]) -> Result<Self, MessageSerdeError> {
                     Ok(Self {
+                        id: u64::deserialize_message_part(&mut input)?,
                         url: String::deserialize_message_part(&mut input)?,
                         status: u32::deserialize_message_part(&mut input)?,
+                        headers: Vec::<String>::deserialize_message_part(&mut input)?,
                         body: Vec::<u8>::deserialize_message_part(&mut input)?,
                         error: Option::<String>::deserialize_message_part(&mut input)?,
                     })
@@ -1,4 +1,4 @@
-use std::fmt;
+use std::{fmt, time::Duration};
 
 use thiserror::Error;
 
@@ -13,24 +13,174 @@ impl fmt::Display for HttpError {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// The method of an HTTP [Request].
+pub enum Method {
+    /// `GET`
+    Get,
+    /// `HEAD`
+    Head,
+    /// `POST`
+    Post,
+    /// `PUT`
+    Put,
+    /// `PATCH`
+    Patch,
+    /// `DELETE`
+    Delete,
+}
+impl From<Method> for wit::server_http::Method {
+    fn from(method: Method) -> Self {
+        match method {
+            Method::Get => Self::Get,
+            Method::Head => Self::Head,
+            Method::Post => Self::Post,
+            Method::Put => Self::Put,
+            Method::Patch => Self::Patch,
+            Method::Delete => Self::Delete,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+/// An HTTP request.
+///
+/// Requests to `http` and `https` URLs are only allowed if the host is listed in the
+/// `allowed_hosts` of the `[http]` section of this package's `ambient.toml`. Asset URLs
+/// are always allowed. Redirects are not followed; the redirect response is returned instead.
+pub struct Request {
+    /// The method of the request.
+    pub method: Method,
+    /// The URL to send the request to.
+    pub url: String,
+    /// The headers of the request, as `(name, value)` pairs.
+    pub headers: Vec<(String, String)>,
+    /// The body of the request.
+    pub body: Vec<u8>,
+    /// How long to wait for the response before failing. If `None`, the request never times out.
+    pub timeout: Option<Duration>,
+}
+impl Request {
+    /// Creates a request with no headers, no body and no timeout.
+    pub fn new(method: Method, url: impl Into<String>) -> Self {
+        Self {
+            method,
+            url: url.into(),
+            headers: vec![],
+            body: vec![],
+            timeout: None,
+        }
+    }
+
+    /// Adds a header to the request.
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Sets the body of the request.
+    pub fn body(mut self, body: impl Into<Vec<u8>>) -> Self {
+        self.body = body.into();
+        self
+    }
+
+    /// Sets how long to wait for the response before failing.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sends the request without waiting for the response, and returns its ID.
+    ///
+    /// The response will be delivered as a [HttpResponse] message with the same `id`.
+    pub fn dispatch(&self) -> u64 {
+        let headers = self
+            .headers
+            .iter()
+            .map(|(name, value)| (name.as_str(), value.as_str()))
+            .collect::<Vec<_>>();
+
+        wit::server_http::request(
+            self.method.into(),
+            &self.url,
+            &headers,
+            &self.body,
+            self.timeout
+                .map(|timeout| timeout.as_millis().try_into().unwrap_or(u32::MAX)),
+        )
+    }
+
+    /// Sends the request, and returns the response.
+    ///
+    /// Any errors in sending or receiving will be returned as an [HttpError]. Responses with
+    /// an error status are returned as [Response]s; check [Response::status].
+    pub async fn send(self) -> Result<Response, HttpError> {
+        let id = self.dispatch();
+        let response =
+            global::wait_for_runtime_message(move |message: &HttpResponse| message.id == id).await;
+
+        if let Some(error) = response.error {
+            return Err(HttpError(error));
+        }
+
+        Ok(Response {
+            id: response.id,
+            status: response.status,
+            headers: response
+                .headers
+                .iter()
+                .filter_map(|header| header.split_once(':'))
+                .map(|(name, value)| (name.to_string(), value.trim().to_string()))
+                .collect(),
+            body: response.body,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+/// The response to an HTTP [Request].
+pub struct Response {
+    /// The ID of the request that this is the response to.
+    pub id: u64,
+    /// The status code of the response.
+    pub status: u32,
+    /// The headers of the response, as `(name, value)` pairs.
+    pub headers: Vec<(String, String)>,
+    /// The body of the response.
+    pub body: Vec<u8>,
+}
+impl Response {
+    /// Returns true if the status code is in the `2xx` range.
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+
+    /// Returns the value of the first header with the given name, ignoring case.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(header, _)| header.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
 /// Sends an HTTP GET request to the given URL, and returns the response body.
 ///
 /// Any errors in sending or receiving will be returned as an [HttpError].
 ///
-/// **NOTE**: This may be replaced with `wasi-http` support in the future,
-/// which will allow the use of native Rust libraries like `reqwest`.
+/// See [Request] for the hosts that requests can be sent to, and for other methods.
 pub async fn get(url: impl AsRef<str>) -> Result<Vec<u8>, HttpError> {
-    let url = url.as_ref();
-    wit::server_http::get(url);
-
-    let response = global::wait_for_runtime_message({
-        let url = url.to_owned();
-        move |message: &HttpResponse| message.url == url
-    })
-    .await;
+    Ok(Request::new(Method::Get, url.as_ref()).send().await?.body)
+}
 
-    match response.error {
-        Some(error) => Err(HttpError(error)),
-        None => Ok(response.body),
-    }
+/// Sends an HTTP POST request with the given body to the given URL, and returns the response.
+///
+/// Any errors in sending or receiving will be returned as an [HttpError].
+///
+/// See [Request] for the hosts that requests can be sent to, and for other methods.
+pub async fn post(url: impl AsRef<str>, body: impl Into<Vec<u8>>) -> Result<Response, HttpError> {
+    Request::new(Method::Post, url.as_ref())
+        .body(body)
+        .send()
+        .await
 }
@@ -0,0 +1,33 @@
+use crate::{core::network::components::instance_id, entity, internal::wit};
+
+/// The ID of the main instance, which players join when they connect.
+pub const MAIN: &str = "main";
+
+/// Creates the instance `id` as a copy of the main instance. The packages and modules of the main
+/// instance are started from scratch in the new instance, without any of its players, or the
+/// entities spawned by its modules.
+///
+/// The instance is created at the end of the current tick. Does nothing if it already exists.
+pub fn create(id: impl AsRef<str>) {
+    wit::server_instance::create(id.as_ref());
+}
+
+/// Moves the players of the instance `id` to the main instance, and removes it at the end of the
+/// current tick. The main instance can't be destroyed.
+pub fn destroy(id: impl AsRef<str>) {
+    wit::server_instance::destroy(id.as_ref());
+}
+
+/// Moves the player with `user_id` to the instance `instance_id` at the end of the current tick.
+///
+/// The player entity is despawned from its current instance and a new one is spawned in the other
+/// instance, so any components added to it have to be added again. The player's client is sent the
+/// new instance's world in place of the old one.
+pub fn transfer_player(user_id: impl AsRef<str>, instance_id: impl AsRef<str>) {
+    wit::server_instance::transfer_player(user_id.as_ref(), instance_id.as_ref());
+}
+
+/// Returns the ID of the instance this module is running in.
+pub fn current() -> String {
+    entity::get_component(entity::resources(), instance_id()).unwrap_or_else(|| MAIN.to_string())
+}
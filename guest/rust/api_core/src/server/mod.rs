@@ -3,3 +3,6 @@ pub mod physics;
 
 /// **\[Server-only\]** HTTP-related functionality, including sending requests and receiving responses.
 pub mod http;
+
+/// **\[Server-only\]** World instances, which allow running several copies of the world (e.g. a lobby and many matches) in one server.
+pub mod instance;
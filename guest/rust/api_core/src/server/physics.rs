@@ -1,5 +1,5 @@
 use crate::{
-    global::{EntityId, Vec3},
+    global::{EntityId, Quat, Vec3},
     internal::{
         conversion::{FromBindgen, IntoBindgen},
         wit,
@@ -157,16 +157,32 @@ pub struct RaycastHit {
 ///
 /// `direction` must be normalized.
 pub fn raycast(origin: Vec3, direction: Vec3) -> Vec<RaycastHit> {
-    wit::server_physics::raycast(origin.into_bindgen(), direction.into_bindgen())
-        .into_iter()
-        .map(|(entity, distance)| raycast_result_to_hit(origin, direction, entity, distance))
-        .collect()
+    raycast_with_layers(origin, direction, ALL_LAYERS)
 }
 /// Casts a ray from `origin` in `direction`, and returns the first [RaycastHit] if it hits.
 ///
 /// `direction` must be normalized.
 pub fn raycast_first(origin: Vec3, direction: Vec3) -> Option<RaycastHit> {
-    wit::server_physics::raycast_first(origin.into_bindgen(), direction.into_bindgen())
+    raycast_first_with_layers(origin, direction, ALL_LAYERS)
+}
+/// Like [raycast], but only hits colliders on the layers of `layer_mask`.
+///
+/// See the `collision_layer` component for how colliders are assigned to layers.
+pub fn raycast_with_layers(origin: Vec3, direction: Vec3, layer_mask: u32) -> Vec<RaycastHit> {
+    wit::server_physics::raycast(origin.into_bindgen(), direction.into_bindgen(), layer_mask)
+        .into_iter()
+        .map(|(entity, distance)| raycast_result_to_hit(origin, direction, entity, distance))
+        .collect()
+}
+/// Like [raycast_first], but only hits colliders on the layers of `layer_mask`.
+///
+/// See the `collision_layer` component for how colliders are assigned to layers.
+pub fn raycast_first_with_layers(
+    origin: Vec3,
+    direction: Vec3,
+    layer_mask: u32,
+) -> Option<RaycastHit> {
+    wit::server_physics::raycast_first(origin.into_bindgen(), direction.into_bindgen(), layer_mask)
         .map(|(entity, distance)| raycast_result_to_hit(origin, direction, entity, distance))
 }
 fn raycast_result_to_hit(
@@ -182,6 +198,145 @@ fn raycast_result_to_hit(
     }
 }
 
+/// The layer of colliders that haven't been assigned to any layer. Layer masks are bitmasks of layers.
+pub const DEFAULT_LAYER: u32 = 1;
+/// A layer mask that includes every layer.
+pub const ALL_LAYERS: u32 = u32::MAX;
+
+/// The result of a shape cast: the entity that was hit, and where.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ShapeCastHit {
+    /// The entity that was hit.
+    pub entity: EntityId,
+    /// How far the shape moved from the origin before hitting the entity.
+    /// Entities that the shape overlaps at the origin are hit at a distance of 0.
+    pub distance: f32,
+    /// The point of contact.
+    pub position: Vec3,
+    /// The normal of the surface that was hit at the point of contact.
+    pub normal: Vec3,
+}
+impl FromBindgen for wit::server_physics::ShapeCastHit {
+    type Item = ShapeCastHit;
+    fn from_bindgen(self) -> Self::Item {
+        ShapeCastHit {
+            entity: self.entity.from_bindgen(),
+            distance: self.distance,
+            position: self.position.from_bindgen(),
+            normal: self.normal.from_bindgen(),
+        }
+    }
+}
+/// Moves a sphere of `radius` from `origin` in `direction` for up to `max_distance`, and returns
+/// the [ShapeCastHit]s along the way, closest first. Only colliders on the layers in `layer_mask` are hit.
+///
+/// `direction` must be normalized.
+pub fn sphere_cast(
+    origin: Vec3,
+    radius: f32,
+    direction: Vec3,
+    max_distance: f32,
+    layer_mask: u32,
+) -> Vec<ShapeCastHit> {
+    wit::server_physics::sphere_cast(
+        origin.into_bindgen(),
+        radius,
+        direction.into_bindgen(),
+        max_distance,
+        layer_mask,
+    )
+    .from_bindgen()
+}
+/// Moves a capsule from `origin` in `direction` for up to `max_distance`, and returns the
+/// [ShapeCastHit]s along the way, closest first. Only colliders on the layers in `layer_mask` are hit.
+///
+/// The capsule extends along its local Z axis, rotated by `rotation`; `height` is the distance
+/// between the centers of its two spheres. `direction` must be normalized.
+pub fn capsule_cast(
+    origin: Vec3,
+    rotation: Quat,
+    radius: f32,
+    height: f32,
+    direction: Vec3,
+    max_distance: f32,
+    layer_mask: u32,
+) -> Vec<ShapeCastHit> {
+    wit::server_physics::capsule_cast(
+        origin.into_bindgen(),
+        rotation.into_bindgen(),
+        radius,
+        height,
+        direction.into_bindgen(),
+        max_distance,
+        layer_mask,
+    )
+    .from_bindgen()
+}
+/// Moves a box with `half_extents`, rotated by `rotation`, from `origin` in `direction` for up to
+/// `max_distance`, and returns the [ShapeCastHit]s along the way, closest first. Only colliders on
+/// the layers in `layer_mask` are hit.
+///
+/// `direction` must be normalized.
+pub fn box_cast(
+    origin: Vec3,
+    rotation: Quat,
+    half_extents: Vec3,
+    direction: Vec3,
+    max_distance: f32,
+    layer_mask: u32,
+) -> Vec<ShapeCastHit> {
+    wit::server_physics::box_cast(
+        origin.into_bindgen(),
+        rotation.into_bindgen(),
+        half_extents.into_bindgen(),
+        direction.into_bindgen(),
+        max_distance,
+        layer_mask,
+    )
+    .from_bindgen()
+}
+/// Returns the entities with colliders on the layers in `layer_mask` that overlap a sphere of
+/// `radius` at `center`.
+pub fn overlap_sphere(center: Vec3, radius: f32, layer_mask: u32) -> Vec<EntityId> {
+    wit::server_physics::overlap_sphere(center.into_bindgen(), radius, layer_mask).from_bindgen()
+}
+/// Returns the entities with colliders on the layers in `layer_mask` that overlap a capsule at `center`.
+///
+/// The capsule extends along its local Z axis, rotated by `rotation`; `height` is the distance
+/// between the centers of its two spheres.
+pub fn overlap_capsule(
+    center: Vec3,
+    rotation: Quat,
+    radius: f32,
+    height: f32,
+    layer_mask: u32,
+) -> Vec<EntityId> {
+    wit::server_physics::overlap_capsule(
+        center.into_bindgen(),
+        rotation.into_bindgen(),
+        radius,
+        height,
+        layer_mask,
+    )
+    .from_bindgen()
+}
+/// Returns the entities with colliders on the layers in `layer_mask` that overlap a box with
+/// `half_extents`, rotated by `rotation`, at `center`.
+pub fn overlap_box(
+    center: Vec3,
+    rotation: Quat,
+    half_extents: Vec3,
+    layer_mask: u32,
+) -> Vec<EntityId> {
+    wit::server_physics::overlap_box(
+        center.into_bindgen(),
+        rotation.into_bindgen(),
+        half_extents.into_bindgen(),
+        layer_mask,
+    )
+    .from_bindgen()
+}
+
 /// Collision results when using [move_character].
 pub struct CharacterCollision {
     /// Side
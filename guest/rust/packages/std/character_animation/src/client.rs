@@ -1,6 +1,6 @@
 use ambient_api::{
     animation::PlayClipFromUrlNodeRef,
-    animation_element::{AnimationPlayer, BlendNode, PlayClipFromUrl, Transition},
+    animation_element::{AnimationPlayer, BlendNode, BlendSpace, PlayClipFromUrl, Transition},
     core::animation::components::{apply_animation_player, start_time},
     element::use_ref_with,
     prelude::*,
@@ -90,48 +90,55 @@ fn Walk(hooks: &mut Hooks, animations: Animations, running: bool, direction: Vec
     let mut lag_dir = lagging_direction.lock();
     *lag_dir = lag_dir.lerp(direction.extend(if running { 1. } else { 0. }), 0.1);
 
-    fn walkblend(items: &[(String, f32)]) -> Element {
-        BlendNode::normalize_multiblend(
-            items
-                .iter()
-                .map(|(a, w)| {
+    let ld = *lag_dir;
+    let f = Vec2::X;
+    let r = Vec2::Y;
+    let blend_space = |animations: [(&String, Vec2); 9]| {
+        BlendSpace {
+            animations: animations
+                .into_iter()
+                .map(|(url, position)| {
                     (
                         PlayClipFromUrl {
-                            url: a.clone(),
+                            url: url.clone(),
                             looping: true,
                         }
                         .el()
                         .with(start_time(), Duration::ZERO),
-                        1. - w.clamp(0., 1.),
+                        position,
                     )
                 })
                 .collect(),
-        )
-    }
-    let f = Vec3::X;
-    let r = Vec3::Y;
-    let run = Vec3::Z;
-    let ld = *lag_dir;
+            parameter: ld.xy(),
+        }
+        .el()
+    };
 
-    walkblend(&[
-        (animations.idle, ld.xy().distance(Vec2::ZERO)),
-        (animations.walk_forward, ld.distance(f)),
-        (animations.walk_forward_left, ld.distance(f - r)),
-        (animations.walk_forward_right, ld.distance(f + r)),
-        (animations.walk_backward, ld.distance(-f)),
-        (animations.walk_backward_left, ld.distance(-f - r)),
-        (animations.walk_backward_right, ld.distance(-f + r)),
-        (animations.walk_left, ld.distance(-r)),
-        (animations.walk_right, ld.distance(r)),
-        (animations.run_forward, ld.distance(f + run)),
-        (animations.run_forward_left, ld.distance(f - r + run)),
-        (animations.run_forward_right, ld.distance(f + r + run)),
-        (animations.run_backward, ld.distance(-f + run)),
-        (animations.run_backward_left, ld.distance(-f - r + run)),
-        (animations.run_backward_right, ld.distance(-f + r + run)),
-        (animations.run_left, ld.distance(-r + run)),
-        (animations.run_right, ld.distance(r + run)),
-    ])
+    BlendNode::el(
+        blend_space([
+            (&animations.idle, Vec2::ZERO),
+            (&animations.walk_forward, f),
+            (&animations.walk_forward_left, f - r),
+            (&animations.walk_forward_right, f + r),
+            (&animations.walk_backward, -f),
+            (&animations.walk_backward_left, -f - r),
+            (&animations.walk_backward_right, -f + r),
+            (&animations.walk_left, -r),
+            (&animations.walk_right, r),
+        ]),
+        blend_space([
+            (&animations.idle, Vec2::ZERO),
+            (&animations.run_forward, f),
+            (&animations.run_forward_left, f - r),
+            (&animations.run_forward_right, f + r),
+            (&animations.run_backward, -f),
+            (&animations.run_backward_left, -f - r),
+            (&animations.run_backward_right, -f + r),
+            (&animations.run_left, -r),
+            (&animations.run_right, r),
+        ]),
+        ld.z,
+    )
 }
 
 #[main]
@@ -89,7 +89,12 @@ impl<T: AsArticulationJointBase> PxArticulationJointBase for T {
         }
     }
 }
-pub struct PxArticulationJointBaseRef(*mut physx_sys::PxArticulationJointBase);
+pub struct PxArticulationJointBaseRef(pub(crate) *mut physx_sys::PxArticulationJointBase);
+impl AsArticulationJointBase for PxArticulationJointBaseRef {
+    fn as_articulation_joint_base_ptr(&self) -> *mut physx_sys::PxArticulationJointBase {
+        self.0
+    }
+}
 
 #[derive(Debug, Clone, Copy)]
 pub struct PxArticulationLinkRef(pub(crate) *mut physx_sys::PxArticulationLink);
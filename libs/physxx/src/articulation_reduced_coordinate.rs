@@ -51,6 +51,18 @@ unsafe impl Send for PxArticulationRef {}
 #[derive(Clone, Copy)]
 pub struct PxArticulationJointRef(*mut physx_sys::PxArticulationJointReducedCoordinate);
 impl PxArticulationJointRef {
+    /// The joint connecting `link` to its parent, if `link` belongs to a reduced coordinate
+    /// articulation and is not its root.
+    pub fn from_inbound_joint(link: &PxArticulationLinkRef) -> Option<Self> {
+        let joint = link.get_inbound_joint().0;
+        if joint.is_null() {
+            None
+        } else {
+            Some(Self(
+                joint as *mut physx_sys::PxArticulationJointReducedCoordinate,
+            ))
+        }
+    }
     pub fn set_joint_type(&mut self, joint_type: PxArticulationJointType) {
         unsafe {
             physx_sys::PxArticulationJointReducedCoordinate_setJointType_mut(
@@ -4,7 +4,7 @@ use glam::{DVec3, Vec3};
 use physx_sys::PxControllerCollisionFlag::*;
 
 use crate::{
-    to_glam_vec3, to_glam_vec3_f64, to_physx_vec3, to_physx_vec3_f64, PxMaterial,
+    to_glam_vec3, to_glam_vec3_f64, to_physx_vec3, to_physx_vec3_f64, PxFilterData, PxMaterial,
     PxRigidDynamicRef, PxSceneRef,
 };
 
@@ -152,10 +152,31 @@ unsafe impl Send for PxControllerDescRef {}
 //     }
 // }
 
-pub struct PxControllerFilters(physx_sys::PxControllerFilters);
+pub struct PxControllerFilters(
+    physx_sys::PxControllerFilters,
+    // Owns the filter data pointed to by the filters, if any
+    Option<Box<physx_sys::PxFilterData>>,
+);
 impl PxControllerFilters {
     pub fn new() -> Self {
-        Self(unsafe { physx_sys::PxControllerFilters_new(null_mut(), null_mut(), null_mut()) })
+        Self(
+            unsafe { physx_sys::PxControllerFilters_new(null_mut(), null_mut(), null_mut()) },
+            None,
+        )
+    }
+    /// Only collide with shapes whose query filter data shares a bit with `filter_data`.
+    pub fn with_filter_data(filter_data: PxFilterData) -> Self {
+        let filter_data = Box::new(physx_sys::PxFilterData::from(filter_data));
+        Self(
+            unsafe {
+                physx_sys::PxControllerFilters_new(
+                    filter_data.as_ref() as *const _,
+                    null_mut(),
+                    null_mut(),
+                )
+            },
+            Some(filter_data),
+        )
     }
 }
 
@@ -4,7 +4,8 @@ use glam::Vec3;
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    to_glam_vec3, AsPxBase, PxBaseRef, PxPhysicsRef, PxRigidActorRef, PxTransform, PxUserData,
+    to_glam_vec3, to_physx_vec3, AsPxBase, PxBaseRef, PxPhysicsRef, PxRigidActorRef, PxTransform,
+    PxUserData,
 };
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -241,6 +242,9 @@ impl PxRevoluteJointRef {
     pub fn set_drive_velocity(&self, velocity: f32, autoawake: bool) {
         unsafe { physx_sys::PxRevoluteJoint_setDriveVelocity_mut(self.0, velocity, autoawake) }
     }
+    pub fn set_drive_force_limit(&self, limit: f32) {
+        unsafe { physx_sys::PxRevoluteJoint_setDriveForceLimit_mut(self.0, limit) }
+    }
     pub fn get_revolute_flags(&self) -> PxRevoluteJointFlag {
         PxRevoluteJointFlag::from_bits(
             unsafe { physx_sys::PxRevoluteJoint_getRevoluteJointFlags(self.0) }.mBits as u32,
@@ -282,6 +286,260 @@ impl AsPxJoint for PxRevoluteJointRef {
 unsafe impl Sync for PxRevoluteJointRef {}
 unsafe impl Send for PxRevoluteJointRef {}
 
+bitflags! {
+    pub struct PxSphericalJointFlag: u32 {
+        const LIMIT_ENABLED = physx_sys::PxSphericalJointFlag::eLIMIT_ENABLED;
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct PxSphericalJointRef(pub(crate) *mut physx_sys::PxSphericalJoint);
+impl PxSphericalJointRef {
+    pub fn new(
+        physics: PxPhysicsRef,
+        actor0: Option<PxRigidActorRef>,
+        local_frame_0: &PxTransform,
+        actor1: Option<PxRigidActorRef>,
+        local_frame_1: &PxTransform,
+    ) -> Self {
+        Self(unsafe {
+            physx_sys::phys_PxSphericalJointCreate(
+                physics.0,
+                actor0.map_or(null_mut(), |v| v.0),
+                &local_frame_0.0,
+                actor1.map_or(null_mut(), |v| v.0),
+                &local_frame_1.0,
+            )
+        })
+    }
+    pub fn set_spherical_flag(&self, flag: PxSphericalJointFlag, value: bool) {
+        unsafe {
+            physx_sys::PxSphericalJoint_setSphericalJointFlag_mut(self.0, flag.bits() as _, value)
+        }
+    }
+    pub fn set_limit_cone(&self, limit: &PxJointLimitCone) {
+        unsafe { physx_sys::PxSphericalJoint_setLimitCone_mut(self.0, &limit.0) }
+    }
+}
+impl AsPxBase for PxSphericalJointRef {
+    fn as_base(&self) -> PxBaseRef {
+        PxBaseRef(self.0 as _)
+    }
+}
+impl AsPxJoint for PxSphericalJointRef {
+    fn as_joint(&self) -> PxJointRef {
+        PxJointRef(self.0 as _)
+    }
+}
+unsafe impl Sync for PxSphericalJointRef {}
+unsafe impl Send for PxSphericalJointRef {}
+
+bitflags! {
+    pub struct PxPrismaticJointFlag: u32 {
+        const LIMIT_ENABLED = physx_sys::PxPrismaticJointFlag::eLIMIT_ENABLED;
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct PxPrismaticJointRef(pub(crate) *mut physx_sys::PxPrismaticJoint);
+impl PxPrismaticJointRef {
+    pub fn new(
+        physics: PxPhysicsRef,
+        actor0: Option<PxRigidActorRef>,
+        local_frame_0: &PxTransform,
+        actor1: Option<PxRigidActorRef>,
+        local_frame_1: &PxTransform,
+    ) -> Self {
+        Self(unsafe {
+            physx_sys::phys_PxPrismaticJointCreate(
+                physics.0,
+                actor0.map_or(null_mut(), |v| v.0),
+                &local_frame_0.0,
+                actor1.map_or(null_mut(), |v| v.0),
+                &local_frame_1.0,
+            )
+        })
+    }
+    pub fn set_prismatic_flag(&self, flag: PxPrismaticJointFlag, value: bool) {
+        unsafe {
+            physx_sys::PxPrismaticJoint_setPrismaticJointFlag_mut(self.0, flag.bits() as _, value)
+        }
+    }
+    pub fn set_limit(&self, limit: &PxJointLinearLimitPair) {
+        unsafe { physx_sys::PxPrismaticJoint_setLimit_mut(self.0, &limit.0) }
+    }
+}
+impl AsPxBase for PxPrismaticJointRef {
+    fn as_base(&self) -> PxBaseRef {
+        PxBaseRef(self.0 as _)
+    }
+}
+impl AsPxJoint for PxPrismaticJointRef {
+    fn as_joint(&self) -> PxJointRef {
+        PxJointRef(self.0 as _)
+    }
+}
+unsafe impl Sync for PxPrismaticJointRef {}
+unsafe impl Send for PxPrismaticJointRef {}
+
+bitflags! {
+    pub struct PxDistanceJointFlag: u32 {
+        const MAX_DISTANCE_ENABLED = physx_sys::PxDistanceJointFlag::eMAX_DISTANCE_ENABLED;
+        const MIN_DISTANCE_ENABLED = physx_sys::PxDistanceJointFlag::eMIN_DISTANCE_ENABLED;
+        const SPRING_ENABLED = physx_sys::PxDistanceJointFlag::eSPRING_ENABLED;
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct PxDistanceJointRef(pub(crate) *mut physx_sys::PxDistanceJoint);
+impl PxDistanceJointRef {
+    pub fn new(
+        physics: PxPhysicsRef,
+        actor0: Option<PxRigidActorRef>,
+        local_frame_0: &PxTransform,
+        actor1: Option<PxRigidActorRef>,
+        local_frame_1: &PxTransform,
+    ) -> Self {
+        Self(unsafe {
+            physx_sys::phys_PxDistanceJointCreate(
+                physics.0,
+                actor0.map_or(null_mut(), |v| v.0),
+                &local_frame_0.0,
+                actor1.map_or(null_mut(), |v| v.0),
+                &local_frame_1.0,
+            )
+        })
+    }
+    pub fn set_distance_flag(&self, flag: PxDistanceJointFlag, value: bool) {
+        unsafe {
+            physx_sys::PxDistanceJoint_setDistanceJointFlag_mut(self.0, flag.bits() as _, value)
+        }
+    }
+    pub fn set_min_distance(&self, distance: f32) {
+        unsafe { physx_sys::PxDistanceJoint_setMinDistance_mut(self.0, distance) }
+    }
+    pub fn set_max_distance(&self, distance: f32) {
+        unsafe { physx_sys::PxDistanceJoint_setMaxDistance_mut(self.0, distance) }
+    }
+    pub fn set_stiffness(&self, stiffness: f32) {
+        unsafe { physx_sys::PxDistanceJoint_setStiffness_mut(self.0, stiffness) }
+    }
+    pub fn set_damping(&self, damping: f32) {
+        unsafe { physx_sys::PxDistanceJoint_setDamping_mut(self.0, damping) }
+    }
+}
+impl AsPxBase for PxDistanceJointRef {
+    fn as_base(&self) -> PxBaseRef {
+        PxBaseRef(self.0 as _)
+    }
+}
+impl AsPxJoint for PxDistanceJointRef {
+    fn as_joint(&self) -> PxJointRef {
+        PxJointRef(self.0 as _)
+    }
+}
+unsafe impl Sync for PxDistanceJointRef {}
+unsafe impl Send for PxDistanceJointRef {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u32)]
+pub enum PxD6Axis {
+    X = physx_sys::PxD6Axis::eX,
+    Y = physx_sys::PxD6Axis::eY,
+    Z = physx_sys::PxD6Axis::eZ,
+    Twist = physx_sys::PxD6Axis::eTWIST,
+    Swing1 = physx_sys::PxD6Axis::eSWING1,
+    Swing2 = physx_sys::PxD6Axis::eSWING2,
+}
+impl PxD6Axis {
+    pub const ALL: [PxD6Axis; 6] = [
+        PxD6Axis::X,
+        PxD6Axis::Y,
+        PxD6Axis::Z,
+        PxD6Axis::Twist,
+        PxD6Axis::Swing1,
+        PxD6Axis::Swing2,
+    ];
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u32)]
+pub enum PxD6Motion {
+    Locked = physx_sys::PxD6Motion::eLOCKED,
+    Limited = physx_sys::PxD6Motion::eLIMITED,
+    Free = physx_sys::PxD6Motion::eFREE,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u32)]
+pub enum PxD6Drive {
+    X = physx_sys::PxD6Drive::eX,
+    Y = physx_sys::PxD6Drive::eY,
+    Z = physx_sys::PxD6Drive::eZ,
+    Swing = physx_sys::PxD6Drive::eSWING,
+    Twist = physx_sys::PxD6Drive::eTWIST,
+    Slerp = physx_sys::PxD6Drive::eSLERP,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct PxD6JointRef(pub(crate) *mut physx_sys::PxD6Joint);
+impl PxD6JointRef {
+    pub fn new(
+        physics: PxPhysicsRef,
+        actor0: Option<PxRigidActorRef>,
+        local_frame_0: &PxTransform,
+        actor1: Option<PxRigidActorRef>,
+        local_frame_1: &PxTransform,
+    ) -> Self {
+        Self(unsafe {
+            physx_sys::phys_PxD6JointCreate(
+                physics.0,
+                actor0.map_or(null_mut(), |v| v.0),
+                &local_frame_0.0,
+                actor1.map_or(null_mut(), |v| v.0),
+                &local_frame_1.0,
+            )
+        })
+    }
+    pub fn set_motion(&self, axis: PxD6Axis, motion: PxD6Motion) {
+        unsafe { physx_sys::PxD6Joint_setMotion_mut(self.0, axis as u32, motion as u32) }
+    }
+    pub fn set_distance_limit(&self, limit: &PxJointLinearLimit) {
+        unsafe { physx_sys::PxD6Joint_setDistanceLimit_mut(self.0, &limit.0) }
+    }
+    pub fn set_twist_limit(&self, limit: &PxJointAngularLimitPair) {
+        unsafe { physx_sys::PxD6Joint_setTwistLimit_mut(self.0, &limit.to_physx() as _) }
+    }
+    pub fn set_swing_limit(&self, limit: &PxJointLimitCone) {
+        unsafe { physx_sys::PxD6Joint_setSwingLimit_mut(self.0, &limit.0) }
+    }
+    pub fn set_drive(&self, index: PxD6Drive, drive: &PxD6JointDrive) {
+        unsafe { physx_sys::PxD6Joint_setDrive_mut(self.0, index as u32, &drive.0) }
+    }
+    pub fn set_drive_velocity(&self, linear: Vec3, angular: Vec3, autowake: bool) {
+        unsafe {
+            physx_sys::PxD6Joint_setDriveVelocity_mut(
+                self.0,
+                &to_physx_vec3(linear),
+                &to_physx_vec3(angular),
+                autowake,
+            )
+        }
+    }
+}
+impl AsPxBase for PxD6JointRef {
+    fn as_base(&self) -> PxBaseRef {
+        PxBaseRef(self.0 as _)
+    }
+}
+impl AsPxJoint for PxD6JointRef {
+    fn as_joint(&self) -> PxJointRef {
+        PxJointRef(self.0 as _)
+    }
+}
+unsafe impl Sync for PxD6JointRef {}
+unsafe impl Send for PxD6JointRef {}
+
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct PxJointAngularLimitPair {
     pub restitution: f32,
@@ -321,3 +579,55 @@ impl PxJointAngularLimitPair {
         }
     }
 }
+
+#[derive(Clone, Copy)]
+pub struct PxJointLinearLimitPair(pub(crate) physx_sys::PxJointLinearLimitPair);
+impl PxJointLinearLimitPair {
+    pub fn new(
+        physics: PxPhysicsRef,
+        lower_limit: f32,
+        upper_limit: f32,
+        contact_dist: f32,
+    ) -> Self {
+        Self(unsafe {
+            physx_sys::PxJointLinearLimitPair_new(
+                physx_sys::PxPhysics_getTolerancesScale(physics.0),
+                lower_limit,
+                upper_limit,
+                contact_dist,
+            )
+        })
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct PxJointLinearLimit(pub(crate) physx_sys::PxJointLinearLimit);
+impl PxJointLinearLimit {
+    pub fn new(physics: PxPhysicsRef, extent: f32, contact_dist: f32) -> Self {
+        Self(unsafe {
+            physx_sys::PxJointLinearLimit_new(
+                physx_sys::PxPhysics_getTolerancesScale(physics.0),
+                extent,
+                contact_dist,
+            )
+        })
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct PxJointLimitCone(pub(crate) physx_sys::PxJointLimitCone);
+impl PxJointLimitCone {
+    pub fn new(y_limit_angle: f32, z_limit_angle: f32, contact_dist: f32) -> Self {
+        Self(unsafe { physx_sys::PxJointLimitCone_new(y_limit_angle, z_limit_angle, contact_dist) })
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct PxD6JointDrive(pub(crate) physx_sys::PxD6JointDrive);
+impl PxD6JointDrive {
+    pub fn new(stiffness: f32, damping: f32, force_limit: f32, is_acceleration: bool) -> Self {
+        Self(unsafe {
+            physx_sys::PxD6JointDrive_new_1(stiffness, damping, force_limit, is_acceleration)
+        })
+    }
+}
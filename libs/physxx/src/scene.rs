@@ -13,9 +13,9 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     sweep::PxSweepHit, to_glam_vec3, to_physx_vec3, AsArticulationBase, AsPxActor, PxActorRef,
-    PxAggregateRef, PxCollectionRef, PxConstraintRef, PxDefaultCpuDispatcherRef, PxGeometry,
-    PxHitFlags, PxPhysicsRef, PxPvdSceneClientRef, PxRaycastHit, PxRigidActorRef, PxShape,
-    PxTransform,
+    PxAggregateRef, PxCollectionRef, PxConstraintRef, PxDefaultCpuDispatcherRef, PxFilterData,
+    PxGeometry, PxHitFlags, PxPhysicsRef, PxPvdSceneClientRef, PxRaycastHit, PxRigidActorRef,
+    PxShape, PxTransform,
 };
 
 pub struct PxSceneDesc(physx_sys::PxSceneDesc);
@@ -53,20 +53,28 @@ impl PxSceneDesc {
             );
         }
     }
-    pub fn set_simulation_event_callbacks<C: FnMut(&PxContactPairHeader)>(
+    pub fn set_simulation_event_callbacks<
+        C: FnMut(&PxContactPairHeader),
+        T: FnMut(&[PxTriggerPair]),
+    >(
         &mut self,
-        callbacks: PxSimulationEventCallback<C>,
+        callbacks: PxSimulationEventCallback<C, T>,
     ) {
         unsafe {
             unsafe extern "C" fn collision_callback_trampoline<C: FnMut(&PxContactPairHeader)>(
                 user_data: *mut std::ffi::c_void,
                 pair_header: *const physx_sys::PxContactPairHeader,
-                _pairs: *const physx_sys::PxContactPair,
-                _nb_pairs: u32,
+                pairs: *const physx_sys::PxContactPair,
+                nb_pairs: u32,
             ) {
                 let mut cb: Box<C> = Box::from_raw(user_data as _);
                 let pair_header_flags =
                     PxContactPairHeaderFlag::from_bits((*pair_header).flags.mBits).unwrap();
+                let mut pairs = std::slice::from_raw_parts(pairs, nb_pairs as usize)
+                    .iter()
+                    .map(|pair| PxContactPair::from_physx(pair))
+                    .collect::<Vec<_>>();
+                read_pre_solver_velocities(&*pair_header, &mut pairs);
                 cb(&PxContactPairHeader {
                     actors: [
                         if pair_header_flags.contains(PxContactPairHeaderFlag::REMOVED_ACTOR_0) {
@@ -80,9 +88,44 @@ impl PxSceneDesc {
                             PxRigidActorRef::from_ptr((*pair_header).actors[1])
                         },
                     ],
+                    pairs,
                 });
                 Box::into_raw(cb);
             }
+            unsafe extern "C" fn trigger_callback_trampoline<T: FnMut(&[PxTriggerPair])>(
+                user_data: *mut std::ffi::c_void,
+                pairs: *const physx_sys::PxTriggerPair,
+                nb_pairs: u32,
+            ) {
+                let mut cb: Box<T> = Box::from_raw(user_data as _);
+                let pairs = std::slice::from_raw_parts(pairs, nb_pairs as usize)
+                    .iter()
+                    .map(|pair| {
+                        let flags = PxTriggerPairFlag::from_bits_truncate(pair.flags.mBits);
+                        PxTriggerPair {
+                            trigger_shape: if flags
+                                .contains(PxTriggerPairFlag::REMOVED_SHAPE_TRIGGER)
+                            {
+                                None
+                            } else {
+                                Some(PxShape::from_ptr(pair.triggerShape))
+                            },
+                            other_shape: if flags.contains(PxTriggerPairFlag::REMOVED_SHAPE_OTHER) {
+                                None
+                            } else {
+                                Some(PxShape::from_ptr(pair.otherShape))
+                            },
+                            status: if pair.status == physx_sys::PxPairFlag::eNOTIFY_TOUCH_LOST {
+                                PxTriggerPairStatus::Lost
+                            } else {
+                                PxTriggerPairStatus::Found
+                            },
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                cb(&pairs);
+                Box::into_raw(cb);
+            }
             let mut cbs = physx_sys::SimulationEventCallbackInfo {
                 ..Default::default()
             };
@@ -90,9 +133,21 @@ impl PxSceneDesc {
                 cbs.collision_callback = Some(collision_callback_trampoline::<C>);
                 cbs.collision_user_data = Box::into_raw(cb) as _;
             }
+            if let Some(cb) = callbacks.trigger_callback {
+                cbs.trigger_callback = Some(trigger_callback_trampoline::<T>);
+                cbs.trigger_user_data = Box::into_raw(cb) as _;
+            }
             self.0.simulationEventCallback = physx_sys::create_simulation_event_callbacks(&cbs);
         }
     }
+    /// How pairs of kinematic and static actors are filtered. They are suppressed by default.
+    pub fn set_static_kinematic_filtering_mode(&mut self, mode: PxPairFilteringMode) {
+        self.0.staticKineFilteringMode = mode as u32;
+    }
+    /// How pairs of kinematic actors are filtered. They are suppressed by default.
+    pub fn set_kinematic_kinematic_filtering_mode(&mut self, mode: PxPairFilteringMode) {
+        self.0.kineKineFilteringMode = mode as u32;
+    }
     pub fn get_flags(&mut self) -> PxSceneFlags {
         PxSceneFlags::from_bits(self.0.flags.mBits).unwrap()
     }
@@ -106,12 +161,150 @@ impl PxSceneDesc {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u32)]
+pub enum PxPairFilteringMode {
+    /// Pass the pairs to the filter shader
+    Keep = physx_sys::PxPairFilteringMode::eKEEP,
+    /// Suppress the pairs, but keep tracking them in case their filtering changes
+    Suppress = physx_sys::PxPairFilteringMode::eSUPPRESS,
+    /// Discard the pairs
+    Kill = physx_sys::PxPairFilteringMode::eKILL,
+}
+
 pub struct PxContactPairHeader {
     pub actors: [Option<PxRigidActorRef>; 2],
+    /// The pairs of shapes of the actors that are in contact
+    pub pairs: Vec<PxContactPair>,
 }
 
-pub struct PxSimulationEventCallback<C: FnMut(&PxContactPairHeader)> {
+bitflags! {
+    pub struct PxContactPairEvents: u16 {
+        const TOUCH_FOUND = physx_sys::PxPairFlag::eNOTIFY_TOUCH_FOUND as u16;
+        const TOUCH_PERSISTS = physx_sys::PxPairFlag::eNOTIFY_TOUCH_PERSISTS as u16;
+        const TOUCH_LOST = physx_sys::PxPairFlag::eNOTIFY_TOUCH_LOST as u16;
+    }
+}
+
+bitflags! {
+    pub struct PxContactPairFlag: u16 {
+        const REMOVED_SHAPE_0 = physx_sys::PxContactPairFlag::eREMOVED_SHAPE_0 as u16;
+        const REMOVED_SHAPE_1 = physx_sys::PxContactPairFlag::eREMOVED_SHAPE_1 as u16;
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PxContactPairPoint {
+    pub position: Vec3,
+    /// Negative if the shapes are penetrating
+    pub separation: f32,
+    /// Points from the second shape towards the first shape
+    pub normal: Vec3,
+    /// The impulse applied at this point to resolve the contact
+    pub impulse: Vec3,
+}
+
+/// A pair of shapes in contact. Shapes that have been removed from the scene are `None`.
+///
+/// `contacts` is only filled in if the pair was reported with `NOTIFY_CONTACT_POINTS`, and
+/// `pre_solver_velocities` if it was reported with `PRE_SOLVER_VELOCITY`.
+#[derive(Debug)]
+pub struct PxContactPair {
+    pub shapes: [Option<PxShape>; 2],
+    pub events: PxContactPairEvents,
+    pub contacts: Vec<PxContactPairPoint>,
+    /// The linear velocities of the two actors before the contact was resolved
+    pub pre_solver_velocities: Option<[Vec3; 2]>,
+}
+impl PxContactPair {
+    unsafe fn from_physx(pair: &physx_sys::PxContactPair) -> Self {
+        let flags = PxContactPairFlag::from_bits_truncate(pair.flags.mBits);
+        let shape = |i: usize, removed: PxContactPairFlag| {
+            if flags.contains(removed) {
+                None
+            } else {
+                Some(PxShape::from_ptr(pair.shapes[i]))
+            }
+        };
+        let mut points = Vec::with_capacity(pair.contactCount as usize);
+        let count = physx_sys::PxContactPair_extractContacts(
+            pair,
+            points.as_mut_ptr(),
+            pair.contactCount as u32,
+        );
+        points.set_len(count as usize);
+        Self {
+            shapes: [
+                shape(0, PxContactPairFlag::REMOVED_SHAPE_0),
+                shape(1, PxContactPairFlag::REMOVED_SHAPE_1),
+            ],
+            events: PxContactPairEvents::from_bits_truncate(pair.events.mBits),
+            contacts: points
+                .iter()
+                .map(|point: &physx_sys::PxContactPairPoint| PxContactPairPoint {
+                    position: to_glam_vec3(&point.position),
+                    separation: point.separation,
+                    normal: to_glam_vec3(&point.normal),
+                    impulse: to_glam_vec3(&point.impulse),
+                })
+                .collect(),
+            pre_solver_velocities: None,
+        }
+    }
+}
+
+unsafe fn read_pre_solver_velocities(
+    header: &physx_sys::PxContactPairHeader,
+    pairs: &mut [PxContactPair],
+) {
+    if header.extraDataStreamSize == 0 {
+        return;
+    }
+    let mut iter = physx_sys::PxContactPairExtraDataIterator_new(
+        header.extraDataStream,
+        header.extraDataStreamSize as u32,
+    );
+    while physx_sys::PxContactPairExtraDataIterator_nextItemSet_mut(&mut iter) {
+        if iter.preSolverVelocity.is_null() {
+            continue;
+        }
+        if let Some(pair) = pairs.get_mut(iter.contactPairIndex as usize) {
+            let velocity = &*iter.preSolverVelocity;
+            pair.pre_solver_velocities = Some([
+                to_glam_vec3(&velocity.linearVelocity[0]),
+                to_glam_vec3(&velocity.linearVelocity[1]),
+            ]);
+        }
+    }
+}
+
+pub struct PxSimulationEventCallback<C: FnMut(&PxContactPairHeader), T: FnMut(&[PxTriggerPair])> {
     pub collision_callback: Option<Box<C>>,
+    pub trigger_callback: Option<Box<T>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PxTriggerPairStatus {
+    /// The other shape started touching the trigger shape
+    Found,
+    /// The other shape stopped touching the trigger shape
+    Lost,
+}
+
+/// A trigger shape and the shape that entered or left it. Shapes that have been removed
+/// from the scene are `None`.
+#[derive(Debug)]
+pub struct PxTriggerPair {
+    pub trigger_shape: Option<PxShape>,
+    pub other_shape: Option<PxShape>,
+    pub status: PxTriggerPairStatus,
+}
+
+bitflags! {
+    pub struct PxTriggerPairFlag: u8 {
+        const REMOVED_SHAPE_TRIGGER = physx_sys::PxTriggerPairFlag::eREMOVED_SHAPE_TRIGGER as u8;
+        const REMOVED_SHAPE_OTHER = physx_sys::PxTriggerPairFlag::eREMOVED_SHAPE_OTHER as u8;
+    }
 }
 
 bitflags! {
@@ -290,6 +483,11 @@ impl PxSceneRef {
         assert!(error == 0, "fetchResults has failed");
         fetched
     }
+    /// Marks the shapes of the actor for refiltering, so that changes to their
+    /// simulation filter data take effect.
+    pub fn reset_filtering(&self, actor: &dyn AsPxActor) -> bool {
+        unsafe { physx_sys::PxScene_resetFiltering_mut(self.0, actor.as_actor().0) }
+    }
     pub fn get_scene_pvd_client(&self) -> PxPvdSceneClientRef {
         PxPvdSceneClientRef(unsafe { physx_sys::PxScene_getScenePvdClient_mut(self.0) })
     }
@@ -421,6 +619,10 @@ impl PxQueryFilterData {
     pub fn set_flags(&mut self, flags: PxQueryFlag) {
         self.0.flags.mBits = flags.bits as u16;
     }
+    /// Only hit shapes whose query filter data shares a bit with `data`. All zeros hits every shape.
+    pub fn set_data(&mut self, data: PxFilterData) {
+        self.0.data = data.into();
+    }
 }
 impl Default for PxQueryFilterData {
     fn default() -> Self {
@@ -21,6 +21,35 @@ impl Default for PxShapeFlag {
     }
 }
 
+/// User-defined data used to filter the shape in scene queries and the simulation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct PxFilterData {
+    pub word0: u32,
+    pub word1: u32,
+    pub word2: u32,
+    pub word3: u32,
+}
+impl From<physx_sys::PxFilterData> for PxFilterData {
+    fn from(data: physx_sys::PxFilterData) -> Self {
+        Self {
+            word0: data.word0,
+            word1: data.word1,
+            word2: data.word2,
+            word3: data.word3,
+        }
+    }
+}
+impl From<PxFilterData> for physx_sys::PxFilterData {
+    fn from(data: PxFilterData) -> Self {
+        Self {
+            word0: data.word0,
+            word1: data.word1,
+            word2: data.word2,
+            word3: data.word3,
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Hash)]
 pub struct PxShape(pub *mut physx_sys::PxShape, pub usize);
 impl PxShape {
@@ -113,6 +142,18 @@ impl PxShape {
     pub fn set_rest_offset(&self, offset: f32) {
         unsafe { physx_sys::PxShape_setRestOffset_mut(self.0, offset) }
     }
+    pub fn get_query_filter_data(&self) -> PxFilterData {
+        unsafe { physx_sys::PxShape_getQueryFilterData(self.0) }.into()
+    }
+    pub fn set_query_filter_data(&self, data: PxFilterData) {
+        unsafe { physx_sys::PxShape_setQueryFilterData_mut(self.0, &data.into()) }
+    }
+    pub fn get_simulation_filter_data(&self) -> PxFilterData {
+        unsafe { physx_sys::PxShape_getSimulationFilterData(self.0) }.into()
+    }
+    pub fn set_simulation_filter_data(&self, data: PxFilterData) {
+        unsafe { physx_sys::PxShape_setSimulationFilterData_mut(self.0, &data.into()) }
+    }
 }
 impl AsPxBase for PxShape {
     fn as_base(&self) -> PxBaseRef {
@@ -33,6 +33,9 @@ pub struct BuildSettings {
     #[serde(default)]
     /// Build with deployment in mind (i.e. ignore local dependencies).
     pub deploy: bool,
+    #[serde(skip)]
+    /// Rebuild everything, even if it is unchanged since the last build.
+    pub force: bool,
 }
 
 impl BuildMetadata {
@@ -40,6 +40,8 @@ pub struct Manifest {
     pub includes: HashMap<SnakeCaseIdentifier, PathBuf>,
     #[serde(default)]
     pub dependencies: IndexMap<SnakeCaseIdentifier, Dependency>,
+    #[serde(default)]
+    pub http: Http,
 }
 impl Manifest {
     pub fn parse(manifest: &str) -> Result<Self, ManifestParseError> {
@@ -243,6 +245,27 @@ impl Default for BuildRust {
     }
 }
 
+#[derive(Deserialize, Clone, Debug, PartialEq, Default, Serialize)]
+pub struct Http {
+    /// The hosts that server modules may send HTTP requests to. A leading `*.` matches any subdomain.
+    #[serde(default)]
+    pub allowed_hosts: Vec<String>,
+}
+impl Http {
+    pub fn allows_host(&self, host: &str) -> bool {
+        let host = host.to_ascii_lowercase();
+        self.allowed_hosts.iter().any(|allowed| {
+            let allowed = allowed.to_ascii_lowercase();
+            match allowed.strip_prefix("*.") {
+                Some(domain) => host
+                    .strip_suffix(domain)
+                    .is_some_and(|subdomain| subdomain.ends_with('.')),
+                None => host == allowed,
+            }
+        })
+    }
+}
+
 #[derive(Deserialize, Clone, Debug, PartialEq, Serialize)]
 pub struct Dependency {
     #[serde(default)]
@@ -266,8 +289,8 @@ mod tests {
 
     use crate::{
         Build, BuildRust, Component, ComponentType, Components, Concept, ConceptValue,
-        ContainerType, Dependency, Enum, Identifier, ItemPathBuf, Manifest, ManifestParseError,
-        Package, PackageId, PascalCaseIdentifier, SnakeCaseIdentifier,
+        ContainerType, Dependency, Enum, Http, Identifier, ItemPathBuf, Manifest,
+        ManifestParseError, Package, PackageId, PascalCaseIdentifier, SnakeCaseIdentifier,
     };
     use semver::Version;
 
@@ -385,6 +408,7 @@ mod tests {
                 enums: Default::default(),
                 includes: Default::default(),
                 dependencies: Default::default(),
+                http: Default::default(),
             })
         )
     }
@@ -572,6 +596,7 @@ mod tests {
                 enums: Default::default(),
                 includes: Default::default(),
                 dependencies: Default::default(),
+                http: Default::default(),
             }
         );
 
@@ -634,6 +659,7 @@ mod tests {
                 )]),
                 includes: Default::default(),
                 dependencies: Default::default(),
+                http: Default::default(),
             })
         )
     }
@@ -711,6 +737,7 @@ mod tests {
                 enums: Default::default(),
                 includes: Default::default(),
                 dependencies: Default::default(),
+                http: Default::default(),
             })
         )
     }
@@ -780,8 +807,40 @@ mod tests {
                             enabled: None,
                         }
                     )
-                ])
+                ]),
+                http: Default::default(),
             })
         )
     }
+
+    #[test]
+    fn can_parse_http_allowed_hosts() {
+        const TOML: &str = r#"
+        [package]
+        id = "lktsfudbjw2qikhyumt573ozxhadkiwm"
+        name = "Leaderboard"
+        version = "0.0.1"
+        content = { type = "Playable" }
+
+        [http]
+        allowed_hosts = ["api.example.com", "*.example.org"]
+        "#;
+
+        let manifest = Manifest::parse(TOML).unwrap();
+        assert_eq!(
+            manifest.http,
+            Http {
+                allowed_hosts: vec!["api.example.com".to_string(), "*.example.org".to_string()]
+            }
+        );
+
+        assert!(manifest.http.allows_host("api.example.com"));
+        assert!(manifest.http.allows_host("API.example.com"));
+        assert!(!manifest.http.allows_host("example.com"));
+        assert!(!manifest.http.allows_host("evilapi.example.com"));
+        assert!(manifest.http.allows_host("scores.example.org"));
+        assert!(manifest.http.allows_host("eu.scores.example.org"));
+        assert!(!manifest.http.allows_host("example.org"));
+        assert!(!manifest.http.allows_host("notexample.org"));
+    }
 }
@@ -128,7 +128,7 @@ pub fn generate(
             quote! {
                 use std::time::Duration;
                 use glam::{Vec2, Vec3, Vec4, UVec2, UVec3, UVec4, Mat4, Quat};
-                use crate::{EntityId, Debuggable, Networked, Store, Resource, MaybeResource, Name, Description, Enum, components};
+                use crate::{EntityId, Debuggable, Interpolated, Networked, Predicted, Store, Resource, MaybeResource, Name, Description, Enum, components};
                 use ambient_shared_types::{ProceduralMeshHandle, ProceduralTextureHandle, ProceduralSamplerHandle, ProceduralMaterialHandle};
                 components!(#namespace_path, {
                     #(#components)*
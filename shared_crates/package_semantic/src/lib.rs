@@ -628,6 +628,8 @@ pub struct StandardDefinitions {
 pub struct StandardAttributes {
     pub debuggable: ItemId<Attribute>,
     pub networked: ItemId<Attribute>,
+    pub interpolated: ItemId<Attribute>,
+    pub predicted: ItemId<Attribute>,
     pub resource: ItemId<Attribute>,
     pub maybe_resource: ItemId<Attribute>,
     pub store: ItemId<Attribute>,
@@ -690,6 +692,8 @@ pub fn create_root_scope(
     let attributes = StandardAttributes {
         debuggable: make_attribute(items, root_scope, "Debuggable")?,
         networked: make_attribute(items, root_scope, "Networked")?,
+        interpolated: make_attribute(items, root_scope, "Interpolated")?,
+        predicted: make_attribute(items, root_scope, "Predicted")?,
         resource: make_attribute(items, root_scope, "Resource")?,
         maybe_resource: make_attribute(items, root_scope, "MaybeResource")?,
         store: make_attribute(items, root_scope, "Store")?,
@@ -17,6 +17,7 @@ pub fn MainApp(_hooks: &mut Hooks, server_url: String, settings: Settings) -> El
         user_id: settings
             .user_id
             .unwrap_or_else(ambient_client_shared::util::random_username),
+        auth_token: settings.auth_token,
         fail_on_version_mismatch: !settings.allow_version_mismatch,
         systems_and_resources: cb(|| {
             let mut resources = Entity::new();
@@ -71,6 +72,8 @@ fn systems() -> SystemGroup {
             // Box::new(ambient_physics::client_systems()),
             // Box::new(ambient_gizmos::client_systems()),
             Box::new(wasm::systems()),
+            Box::new(ambient_network::prediction::client_systems()),
+            Box::new(ambient_network::interpolation::client_systems()),
             Box::new(player::systems_final()),
         ],
     )
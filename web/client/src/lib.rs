@@ -38,6 +38,8 @@ pub struct Settings {
     #[serde(default)]
     user_id: Option<String>,
     #[serde(default)]
+    auth_token: Option<String>,
+    #[serde(default)]
     debugger: bool,
 }
 
@@ -102,6 +104,7 @@ fn init(settings: &Settings) -> anyhow::Result<()> {
     ambient_world_audio::init_components();
     ambient_wasm::shared::init_all_components();
     ambient_decals::init_components();
+    ambient_prefab::init_components();
     ambient_primitives::init_components();
     ambient_package_semantic_native::init_components();
 